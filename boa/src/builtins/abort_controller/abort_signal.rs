@@ -0,0 +1,422 @@
+//! This module implements the global `AbortSignal` object.
+//!
+//! An `AbortSignal` is a minimal event target: it only ever dispatches a single `abort` event,
+//! to the `onabort` handler and to the listeners registered with `addEventListener`.
+//!
+//! More information:
+//!  - [DOM specification][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://dom.spec.whatwg.org/#interface-AbortSignal
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/AbortSignal
+
+use crate::{
+    builtins::{BuiltIn, JsArgs},
+    gc::{Finalize, Trace},
+    object::{ConstructorBuilder, FunctionBuilder, JsObject, ObjectData, ObjectInitializer},
+    property::{Attribute, PropertyDescriptor},
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, JsResult, JsString, JsValue,
+};
+
+/// An entry of the event listener list of an `AbortSignal`.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum EventListener {
+    /// The `onabort` event handler, kept at the position where it was first set.
+    OnAbort,
+
+    /// A listener registered with `addEventListener`.
+    Callback {
+        event_type: JsString,
+        callback: JsObject,
+    },
+}
+
+impl EventListener {
+    /// Returns `true` if this is a listener for `event_type` registered with `callback`.
+    fn matches(&self, event_type: &JsString, callback: &JsObject) -> bool {
+        match self {
+            Self::OnAbort => false,
+            Self::Callback {
+                event_type: listener_type,
+                callback: listener,
+            } => listener_type == event_type && JsObject::equals(listener, callback),
+        }
+    }
+}
+
+/// The internal representation of an `AbortSignal` object.
+#[derive(Debug, Clone, Default, Trace, Finalize)]
+pub struct AbortSignal {
+    aborted: bool,
+    reason: JsValue,
+    on_abort: Option<JsObject>,
+    listeners: Vec<EventListener>,
+}
+
+impl BuiltIn for AbortSignal {
+    const NAME: &'static str = "AbortSignal";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_aborted = FunctionBuilder::native(context, Self::get_aborted)
+            .name("get aborted")
+            .constructor(false)
+            .build();
+        let get_reason = FunctionBuilder::native(context, Self::get_reason)
+            .name("get reason")
+            .constructor(false)
+            .build();
+        let get_on_abort = FunctionBuilder::native(context, Self::get_on_abort)
+            .name("get onabort")
+            .constructor(false)
+            .build();
+        let set_on_abort = FunctionBuilder::native(context, Self::set_on_abort)
+            .name("set onabort")
+            .length(1)
+            .constructor(false)
+            .build();
+
+        let abort_signal = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().abort_signal_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_method(Self::abort, "abort", 0)
+        .accessor("aborted", Some(get_aborted), None, Attribute::CONFIGURABLE)
+        .accessor("reason", Some(get_reason), None, Attribute::CONFIGURABLE)
+        .accessor(
+            "onabort",
+            Some(get_on_abort),
+            Some(set_on_abort),
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::throw_if_aborted, "throwIfAborted", 0)
+        .method(Self::add_event_listener, "addEventListener", 2)
+        .method(Self::remove_event_listener, "removeEventListener", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        abort_signal.into()
+    }
+}
+
+impl AbortSignal {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `AbortSignal` objects can only be created by an `AbortController` or by `AbortSignal.abort`.
+    fn constructor(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        context.throw_type_error("AbortSignal: illegal constructor")
+    }
+
+    /// Creates a new, non-aborted `AbortSignal` object.
+    pub(crate) fn create(context: &mut Context) -> JsObject {
+        let prototype = context.standard_objects().abort_signal_object().prototype();
+        JsObject::from_proto_and_data(prototype, ObjectData::abort_signal(Self::default()))
+    }
+
+    /// Abstract operation `signal abort`.
+    ///
+    /// Marks the signal as aborted and dispatches the `abort` event to the `onabort` handler and
+    /// the listeners, in the order they were registered. Aborting an already aborted signal does
+    /// nothing, so listeners are invoked at most once. Exceptions thrown by a listener are passed
+    /// to [`HostHooks::report_exception`] and don't prevent the remaining listeners from running.
+    ///
+    /// More information:
+    ///  - [DOM specification][spec]
+    ///
+    /// [spec]: https://dom.spec.whatwg.org/#abortsignal-signal-abort
+    /// [`HostHooks::report_exception`]: crate::host::HostHooks::report_exception
+    pub(crate) fn signal_abort(
+        signal: &JsObject,
+        reason: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. If signal is aborted, then return.
+        if Self::with_signal(signal, |signal| signal.aborted) {
+            return Ok(());
+        }
+
+        // 2. Set signal's abort reason to reason if it is given; otherwise to a new "AbortError" DOMException.
+        let reason = if reason.is_undefined() {
+            Self::abort_error(context)?
+        } else {
+            reason.clone()
+        };
+
+        let (on_abort, listeners) = {
+            let mut object = signal.borrow_mut();
+            let signal = object
+                .as_abort_signal_mut()
+                .expect("`signal` must be an AbortSignal");
+            signal.aborted = true;
+            signal.reason = reason;
+            // Nothing can be dispatched to the listeners anymore, so release them.
+            (
+                signal.on_abort.take(),
+                std::mem::take(&mut signal.listeners),
+            )
+        };
+
+        // 3. Fire an event named abort at signal.
+        let event = ObjectInitializer::new(context)
+            .property("type", "abort", Attribute::READONLY)
+            .property("target", signal.clone(), Attribute::READONLY)
+            .build();
+        let this = JsValue::new(signal.clone());
+        let args = [event.into()];
+
+        for listener in &listeners {
+            let callback = match listener {
+                EventListener::OnAbort => on_abort.clone(),
+                EventListener::Callback {
+                    event_type,
+                    callback,
+                } if event_type == "abort" => Some(callback.clone()),
+                EventListener::Callback { .. } => None,
+            };
+            if let Some(callback) = callback {
+                if let Err(error) = callback.call(&this, &args, context) {
+                    context.host_hooks().report_exception(&error, context);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Creates the default abort reason, an `Error` named `AbortError`.
+    fn abort_error(context: &mut Context) -> JsResult<JsValue> {
+        let error = context.construct_error("This operation was aborted");
+        if let Some(error) = error.as_object() {
+            error.define_property_or_throw(
+                "name",
+                PropertyDescriptor::builder()
+                    .value("AbortError")
+                    .writable(true)
+                    .enumerable(false)
+                    .configurable(true),
+                context,
+            )?;
+        }
+        Ok(error)
+    }
+
+    /// Runs `f` with the internal state of the `AbortSignal` object `signal`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `signal` is not an `AbortSignal` object.
+    fn with_signal<R>(signal: &JsObject, f: impl FnOnce(&Self) -> R) -> R {
+        f(signal
+            .borrow()
+            .as_abort_signal()
+            .expect("`signal` must be an AbortSignal"))
+    }
+
+    /// Returns the `this` value as an `AbortSignal` object, or throws a `TypeError`.
+    fn this_signal(this: &JsValue, context: &mut Context) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|object| object.borrow().as_abort_signal().is_some())
+            .cloned()
+            .ok_or_else(|| context.construct_type_error("`this` is not an AbortSignal"))
+    }
+
+    /// `AbortSignal.abort( [reason] )`
+    ///
+    /// Returns an `AbortSignal` that is already aborted with `reason`.
+    ///
+    /// More information:
+    ///  - [DOM specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://dom.spec.whatwg.org/#dom-abortsignal-abort
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/AbortSignal/abort
+    pub(crate) fn abort(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let signal = Self::create(context);
+        Self::signal_abort(&signal, args.get_or_undefined(0), context)?;
+        Ok(signal.into())
+    }
+
+    /// `get AbortSignal.prototype.aborted`
+    ///
+    /// More information:
+    ///  - [DOM specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://dom.spec.whatwg.org/#dom-abortsignal-aborted
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/AbortSignal/aborted
+    pub(crate) fn get_aborted(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let signal = Self::this_signal(this, context)?;
+        Ok(Self::with_signal(&signal, |signal| signal.aborted).into())
+    }
+
+    /// `get AbortSignal.prototype.reason`
+    ///
+    /// More information:
+    ///  - [DOM specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://dom.spec.whatwg.org/#dom-abortsignal-reason
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/AbortSignal/reason
+    pub(crate) fn get_reason(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let signal = Self::this_signal(this, context)?;
+        Ok(Self::with_signal(&signal, |signal| signal.reason.clone()))
+    }
+
+    /// `get AbortSignal.prototype.onabort`
+    pub(crate) fn get_on_abort(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let signal = Self::this_signal(this, context)?;
+        Ok(Self::with_signal(&signal, |signal| signal.on_abort.clone())
+            .map_or_else(JsValue::null, JsValue::new))
+    }
+
+    /// `set AbortSignal.prototype.onabort`
+    ///
+    /// Non-callable values clear the handler.
+    pub(crate) fn set_on_abort(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let signal = Self::this_signal(this, context)?;
+        let handler = args.get_or_undefined(0).as_callable().cloned();
+        let mut object = signal.borrow_mut();
+        let signal = object
+            .as_abort_signal_mut()
+            .expect("`signal` must be an AbortSignal");
+        // Handlers set after the signal was aborted can never be invoked.
+        if signal.aborted {
+            return Ok(JsValue::undefined());
+        }
+        let position = signal
+            .listeners
+            .iter()
+            .position(|listener| matches!(listener, EventListener::OnAbort));
+        match (&handler, position) {
+            (Some(_), None) => signal.listeners.push(EventListener::OnAbort),
+            (None, Some(position)) => {
+                signal.listeners.remove(position);
+            }
+            _ => {}
+        }
+        signal.on_abort = handler;
+        Ok(JsValue::undefined())
+    }
+
+    /// `AbortSignal.prototype.throwIfAborted()`
+    ///
+    /// Throws the abort reason if the signal has been aborted.
+    ///
+    /// More information:
+    ///  - [DOM specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://dom.spec.whatwg.org/#dom-abortsignal-throwifaborted
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/AbortSignal/throwIfAborted
+    pub(crate) fn throw_if_aborted(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let signal = Self::this_signal(this, context)?;
+        let (aborted, reason) =
+            Self::with_signal(&signal, |signal| (signal.aborted, signal.reason.clone()));
+        if aborted {
+            return Err(reason);
+        }
+        Ok(JsValue::undefined())
+    }
+
+    /// `AbortSignal.prototype.addEventListener( type, callback )`
+    ///
+    /// Registers `callback` to be called when an event of type `type` is dispatched. Registering
+    /// the same callback twice for the same type has no effect.
+    ///
+    /// More information:
+    ///  - [DOM specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://dom.spec.whatwg.org/#dom-eventtarget-addeventlistener
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener
+    pub(crate) fn add_event_listener(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let signal = Self::this_signal(this, context)?;
+        let event_type = args.get_or_undefined(0).to_string(context)?;
+        let callback = match args.get_or_undefined(1).as_callable() {
+            Some(callback) => callback.clone(),
+            None => return Ok(JsValue::undefined()),
+        };
+
+        let mut object = signal.borrow_mut();
+        let signal = object
+            .as_abort_signal_mut()
+            .expect("`signal` must be an AbortSignal");
+        let registered = signal
+            .listeners
+            .iter()
+            .any(|listener| listener.matches(&event_type, &callback));
+        // An aborted signal never dispatches events again.
+        if !signal.aborted && !registered {
+            signal.listeners.push(EventListener::Callback {
+                event_type,
+                callback,
+            });
+        }
+        Ok(JsValue::undefined())
+    }
+
+    /// `AbortSignal.prototype.removeEventListener( type, callback )`
+    ///
+    /// More information:
+    ///  - [DOM specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://dom.spec.whatwg.org/#dom-eventtarget-removeeventlistener
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/removeEventListener
+    pub(crate) fn remove_event_listener(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let signal = Self::this_signal(this, context)?;
+        let event_type = args.get_or_undefined(0).to_string(context)?;
+        let callback = match args.get_or_undefined(1).as_object() {
+            Some(callback) => callback.clone(),
+            None => return Ok(JsValue::undefined()),
+        };
+
+        let mut object = signal.borrow_mut();
+        object
+            .as_abort_signal_mut()
+            .expect("`signal` must be an AbortSignal")
+            .listeners
+            .retain(|listener| !listener.matches(&event_type, &callback));
+        Ok(JsValue::undefined())
+    }
+}
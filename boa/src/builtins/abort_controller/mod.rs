@@ -0,0 +1,159 @@
+//! This module implements the global `AbortController` object.
+//!
+//! An `AbortController` owns an [`AbortSignal`] that it can abort, letting scripts cancel
+//! operations that accept the signal.
+//!
+//! More information:
+//!  - [DOM specification][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://dom.spec.whatwg.org/#interface-abortcontroller
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/AbortController
+
+use crate::{
+    builtins::{BuiltIn, JsArgs},
+    context::StandardObjects,
+    gc::{Finalize, Trace},
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+pub mod abort_signal;
+#[cfg(test)]
+mod tests;
+
+pub(crate) use abort_signal::AbortSignal;
+
+/// The internal representation of an `AbortController` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct AbortController {
+    signal: JsObject,
+}
+
+impl BuiltIn for AbortController {
+    const NAME: &'static str = "AbortController";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_signal = FunctionBuilder::native(context, Self::get_signal)
+            .name("get signal")
+            .constructor(false)
+            .build();
+
+        let abort_controller = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().abort_controller_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .accessor("signal", Some(get_signal), None, Attribute::CONFIGURABLE)
+        .method(Self::abort, "abort", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        abort_controller.into()
+    }
+}
+
+impl AbortController {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `new AbortController()`
+    ///
+    /// More information:
+    ///  - [DOM specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://dom.spec.whatwg.org/#dom-abortcontroller-abortcontroller
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/AbortController/AbortController
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin AbortController constructor without new is forbidden",
+            );
+        }
+
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardObjects::abort_controller_object,
+            context,
+        )?;
+
+        // 1. Let signal be a new AbortSignal object.
+        // 2. Set this's signal to signal.
+        let signal = AbortSignal::create(context);
+        let controller =
+            JsObject::from_proto_and_data(prototype, ObjectData::abort_controller(Self { signal }));
+
+        Ok(controller.into())
+    }
+
+    /// Returns the signal of the `this` value, or throws a `TypeError` if it isn't an
+    /// `AbortController` object.
+    fn this_signal(this: &JsValue, context: &mut Context) -> JsResult<JsObject> {
+        this.as_object()
+            .and_then(|object| {
+                object
+                    .borrow()
+                    .as_abort_controller()
+                    .map(|controller| controller.signal.clone())
+            })
+            .ok_or_else(|| context.construct_type_error("`this` is not an AbortController"))
+    }
+
+    /// `get AbortController.prototype.signal`
+    ///
+    /// More information:
+    ///  - [DOM specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://dom.spec.whatwg.org/#dom-abortcontroller-signal
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/AbortController/signal
+    pub(crate) fn get_signal(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Ok(Self::this_signal(this, context)?.into())
+    }
+
+    /// `AbortController.prototype.abort( [reason] )`
+    ///
+    /// Aborts the controller's signal with `reason`, invoking its `abort` listeners the first
+    /// time it is called.
+    ///
+    /// More information:
+    ///  - [DOM specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://dom.spec.whatwg.org/#dom-abortcontroller-abort
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/AbortController/abort
+    pub(crate) fn abort(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let signal = Self::this_signal(this, context)?;
+        AbortSignal::signal_abort(&signal, args.get_or_undefined(0), context)?;
+        Ok(JsValue::undefined())
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{forward, host::HostHooks, Context, JsValue};
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn abort_sets_aborted_and_reason() {
+    let mut context = Context::new();
+    let init = r#"
+        var controller = new AbortController();
+        var signal = controller.signal;
+        var before = signal.aborted;
+        controller.abort("stop");
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "before"), "false");
+    assert_eq!(forward(&mut context, "signal.aborted"), "true");
+    assert_eq!(forward(&mut context, "signal.reason"), "\"stop\"");
+    assert_eq!(
+        forward(&mut context, "controller.signal === signal"),
+        "true"
+    );
+}
+
+#[test]
+fn default_reason_is_abort_error() {
+    let mut context = Context::new();
+    let init = r#"
+        var controller = new AbortController();
+        controller.abort();
+        var reason = controller.signal.reason;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "reason instanceof Error"), "true");
+    assert_eq!(forward(&mut context, "reason.name"), "\"AbortError\"");
+}
+
+#[test]
+fn listeners_are_invoked_once() {
+    let mut context = Context::new();
+    let init = r#"
+        var controller = new AbortController();
+        var signal = controller.signal;
+        var log = [];
+        function listener(event) {
+            log.push(event.type + ":" + (this === signal) + ":" + (event.target === signal));
+        }
+        signal.addEventListener("abort", listener);
+        signal.addEventListener("abort", listener);
+        signal.addEventListener("abort", function() { log.push("second"); });
+        signal.addEventListener("other", function() { log.push("other"); });
+        var removed = function() { log.push("removed"); };
+        signal.addEventListener("abort", removed);
+        signal.removeEventListener("abort", removed);
+        signal.onabort = function() { log.push("onabort"); };
+        controller.abort();
+        controller.abort();
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"abort:true:true,second,onabort\""
+    );
+}
+
+#[test]
+fn throw_if_aborted() {
+    let mut context = Context::new();
+    let init = r#"
+        var controller = new AbortController();
+        controller.signal.throwIfAborted();
+        controller.abort("reason");
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { controller.signal.throwIfAborted() } catch (e) { e }"
+        ),
+        "\"reason\""
+    );
+}
+
+#[test]
+fn static_abort_and_illegal_constructor() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "AbortSignal.abort(1).aborted"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "AbortSignal.abort(1).reason"), "1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new AbortSignal() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { AbortController() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn onabort_runs_in_registration_order() {
+    let mut context = Context::new();
+    let init = r#"
+        var controller = new AbortController();
+        var signal = controller.signal;
+        var log = [];
+        signal.addEventListener("abort", function() { log.push("first"); });
+        signal.onabort = function() { log.push("replaced"); };
+        signal.addEventListener("abort", function() { log.push("last"); });
+        signal.onabort = function() { log.push("onabort"); };
+        controller.abort();
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"first,onabort,last\""
+    );
+
+    let init = r#"
+        var controller = new AbortController();
+        var signal = controller.signal;
+        var log = [];
+        signal.onabort = function() { log.push("cleared"); };
+        signal.addEventListener("abort", function() { log.push("listener"); });
+        signal.onabort = null;
+        signal.onabort = function() { log.push("onabort"); };
+        controller.abort();
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "log.join()"), "\"listener,onabort\"");
+}
+
+#[test]
+fn throwing_listener_is_reported() {
+    struct Reporter(Rc<RefCell<Vec<JsValue>>>);
+
+    impl HostHooks for Reporter {
+        fn report_exception(&self, error: &JsValue, _: &mut Context) {
+            self.0.borrow_mut().push(error.clone());
+        }
+    }
+
+    let reported = Rc::new(RefCell::new(Vec::new()));
+    let mut context = Context::with_host_hooks(Reporter(reported.clone()));
+    let init = r#"
+        var controller = new AbortController();
+        var signal = controller.signal;
+        var log = [];
+        signal.addEventListener("abort", function() { log.push("first"); throw 1; });
+        signal.onabort = function() { log.push("onabort"); throw 2; };
+        signal.addEventListener("abort", function() { log.push("last"); });
+        var result = controller.abort();
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "result"), "undefined");
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"first,onabort,last\""
+    );
+    assert_eq!(*reported.borrow(), vec![JsValue::new(1), JsValue::new(2)]);
+}
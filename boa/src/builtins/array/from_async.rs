@@ -0,0 +1,435 @@
+//! This module implements `Array.fromAsync`.
+//!
+//! `Array.fromAsync` is specified as an async function, but builtins can't suspend. Its loop is
+//! written as a state machine instead: every `Await` registers a continuation on the awaited
+//! value, which picks the loop up where it stopped once the value settles.
+//!
+//! More information:
+//!  - [ECMAScript proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fromAsync
+
+use crate::{
+    builtins::{
+        iterable::IteratorHint,
+        promise::{Promise, PromiseCapability},
+        Array, JsArgs,
+    },
+    gc::{Finalize, Trace},
+    object::{FunctionBuilder, JsObject},
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use gc::{Gc, GcCell};
+
+/// Where `Array.fromAsync` takes its values from.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum Source {
+    /// An async iterator, whose results are awaited.
+    AsyncIterator { iterator: JsValue, next: JsValue },
+    /// A sync iterator, whose values are awaited.
+    SyncIterator { iterator: JsValue, next: JsValue },
+    /// An array-like object, whose elements are awaited.
+    ArrayLike { object: JsObject, length: usize },
+}
+
+/// The state of a running `Array.fromAsync` call.
+#[derive(Debug, Trace, Finalize)]
+struct FromAsync {
+    capability: PromiseCapability,
+    source: Source,
+    array: JsObject,
+    mapfn: Option<JsObject>,
+    this_arg: JsValue,
+    k: usize,
+}
+
+/// The points where `Array.fromAsync` resumes once an awaited value settles.
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    /// The result of the `next` method of an async iterator was awaited.
+    IteratorResult,
+    /// A value of a sync iterator or an array-like was awaited.
+    Value,
+    /// The result of the mapping function was awaited.
+    MappedValue,
+}
+
+impl Step {
+    /// Returns `true` if the iterator must be closed when the value awaited before this step
+    /// is rejected.
+    ///
+    /// A rejected iterator result comes from the iterator itself, which is left as is.
+    fn closes_iterator(self) -> bool {
+        !matches!(self, Self::IteratorResult)
+    }
+}
+
+/// The data of the functions resuming `Array.fromAsync` after an `Await`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct Continuation {
+    state: Gc<GcCell<FromAsync>>,
+    #[unsafe_ignore_trace]
+    step: Step,
+}
+
+impl Array {
+    /// `Array.fromAsync ( asyncItems [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// Returns a promise for an array holding the awaited values of an async iterable, an
+    /// iterable or an array-like, optionally passed through an async mapping function.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fromAsync
+    pub(crate) fn from_async(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_constructor = context.standard_objects().promise_object().constructor();
+        let capability = Promise::new_promise_capability(&promise_constructor.into(), context)
+            .expect("%Promise% can always create a capability");
+
+        // 3. Let fromAsyncClosure be a new Abstract Closure with no parameters that captures C, mapfn, and thisArg and performs the following steps when called:
+        // 4. Perform AsyncFunctionStart(promiseCapability, fromAsyncClosure).
+        if let Err(error) = FromAsync::start(this, args, capability.clone(), context) {
+            FromAsync::reject(&capability, error, context);
+        }
+
+        // 5. Return promiseCapability.[[Promise]].
+        Ok(capability.promise.clone().into())
+    }
+}
+
+impl FromAsync {
+    /// Runs the steps of `fromAsyncClosure` up to its first `Await`.
+    fn start(
+        c: &JsValue,
+        args: &[JsValue],
+        capability: PromiseCapability,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let items = args.get_or_undefined(0);
+
+        // a. If mapfn is undefined, let mapping be false.
+        // b. Else,
+        //     i. If IsCallable(mapfn) is false, throw a TypeError exception.
+        //     ii. Let mapping be true.
+        let mapfn = match args.get_or_undefined(1) {
+            JsValue::Undefined => None,
+            mapfn => Some(mapfn.as_callable().cloned().ok_or_else(|| {
+                context.construct_type_error("Array.fromAsync: mapping function is not callable")
+            })?),
+        };
+        let this_arg = args.get_or_undefined(2).clone();
+
+        // c. Let usingAsyncIterator be ? GetMethod(asyncItems, @@asyncIterator).
+        // d. If usingAsyncIterator is undefined, then
+        //     i. Let usingSyncIterator be ? GetMethod(asyncItems, @@iterator).
+        let source =
+            if let Some(method) = items.get_method(WellKnownSymbols::async_iterator(), context)? {
+                // f. If usingAsyncIterator is not undefined, then
+                //     i. Set iteratorRecord to ? GetIterator(asyncItems, async, usingAsyncIterator).
+                let record =
+                    items.get_iterator(context, Some(IteratorHint::Async), Some(method.into()))?;
+                Source::AsyncIterator {
+                    iterator: record.iterator_object().clone(),
+                    next: record.next_function().clone(),
+                }
+            } else if let Some(method) = items.get_method(WellKnownSymbols::iterator(), context)? {
+                // g. Else if usingSyncIterator is not undefined, then
+                //     i. Set iteratorRecord to ? CreateAsyncFromSyncIterator(GetIterator(asyncItems, sync, usingSyncIterator)).
+                let record =
+                    items.get_iterator(context, Some(IteratorHint::Sync), Some(method.into()))?;
+                Source::SyncIterator {
+                    iterator: record.iterator_object().clone(),
+                    next: record.next_function().clone(),
+                }
+            } else {
+                // i. Else,
+                //     i. NOTE: asyncItems is neither an AsyncIterable nor an Iterable so assume it is an array-like object.
+                //     ii. Let arrayLike be ! ToObject(asyncItems).
+                //     iii. Let len be ? LengthOfArrayLike(arrayLike).
+                let object = items.to_object(context)?;
+                let length = object.length_of_array_like(context)?;
+                Source::ArrayLike { object, length }
+            };
+
+        // h.i. If IsConstructor(C) is true, then
+        //     1. Let A be ? Construct(C).
+        // i.iv. If IsConstructor(C) is true, then
+        //     1. Let A be ? Construct(C, « 𝔽(len) »).
+        // Else,
+        //     1. Let A be ! ArrayCreate(0) or ? ArrayCreate(len).
+        let length = match source {
+            Source::ArrayLike { length, .. } => Some(length),
+            _ => None,
+        };
+        let array = match c.as_constructor() {
+            Some(constructor) => {
+                let args: Vec<JsValue> = length.into_iter().map(JsValue::from).collect();
+                constructor
+                    .construct(&args, c, context)?
+                    .as_object()
+                    .cloned()
+                    .ok_or_else(|| {
+                        context.construct_type_error("object constructor didn't return an object")
+                    })?
+            }
+            None => Array::array_create(length.unwrap_or(0), None, context)?,
+        };
+
+        // h.iii. / i.v. Let k be 0.
+        let state = Gc::new(GcCell::new(Self {
+            capability,
+            source,
+            array,
+            mapfn,
+            this_arg,
+            k: 0,
+        }));
+        Self::run(&state, context, Self::next);
+        Ok(())
+    }
+
+    /// Runs `f`, rejecting the promise returned by `Array.fromAsync` if it throws.
+    fn run<F>(state: &Gc<GcCell<Self>>, context: &mut Context, f: F)
+    where
+        F: FnOnce(&Gc<GcCell<Self>>, &mut Context) -> JsResult<()>,
+    {
+        if let Err(error) = f(state, context) {
+            let capability = state.borrow().capability.clone();
+            Self::reject(&capability, error, context);
+        }
+    }
+
+    /// Rejects the promise returned by `Array.fromAsync`.
+    fn reject(capability: &PromiseCapability, error: JsValue, context: &mut Context) {
+        // Perform ! Call(promiseCapability.[[Reject]], undefined, « result.[[Value]] »).
+        capability
+            .reject
+            .call(&JsValue::undefined(), &[error], context)
+            .expect("the reject function of %Promise% cannot throw");
+    }
+
+    /// Calls the `return` method of the iterator the values come from, if any, because the
+    /// loop is left with an error.
+    ///
+    /// The error the loop is left with takes precedence over any error thrown by `return`.
+    fn close_iterator(state: &Gc<GcCell<Self>>, context: &mut Context) {
+        let iterator = match &state.borrow().source {
+            Source::AsyncIterator { iterator, .. } | Source::SyncIterator { iterator, .. } => {
+                iterator.clone()
+            }
+            Source::ArrayLike { .. } => return,
+        };
+        if let Ok(Some(method)) = iterator.get_method("return", context) {
+            let _ = method.call(&iterator, &[], context);
+        }
+    }
+
+    /// `Await`s `value`, resuming the loop at `step` once it settles.
+    fn await_value(
+        state: &Gc<GcCell<Self>>,
+        value: JsValue,
+        step: Step,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let data = Continuation {
+            state: state.clone(),
+            step,
+        };
+        let on_fulfilled = FunctionBuilder::native_with_data(context, Self::resume, data.clone())
+            .name("")
+            .length(1)
+            .build();
+        let on_rejected = FunctionBuilder::native_with_data(context, Self::resume_throw, data)
+            .name("")
+            .length(1)
+            .build();
+
+        Promise::perform_await(value, on_fulfilled, on_rejected, context).map_err(|error| {
+            if step.closes_iterator() {
+                Self::close_iterator(state, context);
+            }
+            error
+        })
+    }
+
+    /// Resumes the loop with the fulfilled value of an `Await`.
+    fn resume(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let data = context
+            .current_function_data::<Continuation>()
+            .expect("Array.fromAsync continuations must have their data")
+            .clone();
+        let (state, step) = (data.state.clone(), data.step);
+        let value = args.get_or_undefined(0).clone();
+
+        Self::run(&state, context, |state, context| match step {
+            Step::IteratorResult => Self::step_iterator_result(state, value, context),
+            Step::Value => Self::map_value(state, value, context),
+            Step::MappedValue => Self::add_value(state, value, context),
+        });
+        Ok(JsValue::undefined())
+    }
+
+    /// Resumes the loop with the rejection reason of an `Await`, which ends it.
+    fn resume_throw(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let data = context
+            .current_function_data::<Continuation>()
+            .expect("Array.fromAsync continuations must have their data")
+            .clone();
+        let (state, step) = (data.state.clone(), data.step);
+
+        if step.closes_iterator() {
+            Self::close_iterator(&state, context);
+        }
+        let capability = state.borrow().capability.clone();
+        Self::reject(&capability, args.get_or_undefined(0).clone(), context);
+        Ok(JsValue::undefined())
+    }
+
+    /// Fetches the next value of the source and awaits it, or resolves the promise once the
+    /// source is exhausted.
+    fn next(state: &Gc<GcCell<Self>>, context: &mut Context) -> JsResult<()> {
+        let (source, k) = {
+            let state = state.borrow();
+            (state.source.clone(), state.k)
+        };
+
+        match &source {
+            Source::AsyncIterator { iterator, next } => {
+                // 3. Let nextResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+                let result = context.call(next, iterator, &[])?;
+
+                // 4. Set nextResult to ? Await(nextResult).
+                Self::await_value(state, result, Step::IteratorResult, context)
+            }
+            Source::SyncIterator { iterator, next } => {
+                // The steps of %AsyncFromSyncIteratorPrototype%.next, which await the value of
+                // the result of the sync iterator.
+                let result = context.call(next, iterator, &[])?;
+                if !result.is_object() {
+                    return Err(context.construct_type_error("iterator result is not an object"));
+                }
+                if result.get_field("done", context)?.to_boolean() {
+                    return Self::finish(state, context);
+                }
+                let value = result.get_field("value", context)?;
+                Self::await_value(state, value, Step::Value, context)
+            }
+            Source::ArrayLike { object, length } => {
+                // vii. Repeat, while k < len,
+                if k >= *length {
+                    return Self::finish(state, context);
+                }
+
+                // 1. Let Pk be ! ToString(𝔽(k)).
+                // 2. Let kValue be ? Get(arrayLike, Pk).
+                let value = object.get(k, context)?;
+
+                // 3. Set kValue to ? Await(kValue).
+                Self::await_value(state, value, Step::Value, context)
+            }
+        }
+    }
+
+    /// Unwraps the awaited result of the `next` method of an async iterator.
+    fn step_iterator_result(
+        state: &Gc<GcCell<Self>>,
+        result: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 5. If Type(nextResult) is not Object, throw a TypeError exception.
+        if !result.is_object() {
+            return Err(context.construct_type_error("iterator result is not an object"));
+        }
+
+        // 6. Let done be ? IteratorComplete(nextResult).
+        // 7. If done is true,
+        if result.get_field("done", context)?.to_boolean() {
+            return Self::finish(state, context);
+        }
+
+        // 8. Let nextValue be ? IteratorValue(nextResult).
+        let value = result.get_field("value", context)?;
+        Self::map_value(state, value, context)
+    }
+
+    /// Passes a value through the mapping function, if there is one.
+    fn map_value(state: &Gc<GcCell<Self>>, value: JsValue, context: &mut Context) -> JsResult<()> {
+        let (mapfn, this_arg, k) = {
+            let state = state.borrow();
+            (state.mapfn.clone(), state.this_arg.clone(), state.k)
+        };
+
+        let mapfn = match mapfn {
+            Some(mapfn) => mapfn,
+            // Else, let mappedValue be nextValue.
+            None => return Self::add_value(state, value, context),
+        };
+
+        // 9. If mapping is true, then
+        //     a. Let mappedValue be Call(mapfn, thisArg, « nextValue, 𝔽(k) »).
+        //     b. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+        let mapped_value = match mapfn.call(&this_arg, &[value, k.into()], context) {
+            Ok(mapped_value) => mapped_value,
+            Err(error) => {
+                Self::close_iterator(state, context);
+                return Err(error);
+            }
+        };
+
+        //     c. Set mappedValue to Await(mappedValue).
+        //     d. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+        Self::await_value(state, mapped_value, Step::MappedValue, context)
+    }
+
+    /// Adds a value to the array, and moves on to the next one.
+    fn add_value(state: &Gc<GcCell<Self>>, value: JsValue, context: &mut Context) -> JsResult<()> {
+        let (array, k) = {
+            let state = state.borrow();
+            (state.array.clone(), state.k)
+        };
+
+        // 10. Let defineStatus be CreateDataPropertyOrThrow(A, Pk, mappedValue).
+        // 11. If defineStatus is an abrupt completion, return ? AsyncIteratorClose(iteratorRecord, defineStatus).
+        if let Err(error) = array.create_data_property_or_throw(k, value, context) {
+            Self::close_iterator(state, context);
+            return Err(error);
+        }
+
+        // 12. Set k to k + 1.
+        state.borrow_mut().k += 1;
+        Self::next(state, context)
+    }
+
+    /// Sets the length of the array and resolves the promise with it.
+    fn finish(state: &Gc<GcCell<Self>>, context: &mut Context) -> JsResult<()> {
+        let (array, k, resolve) = {
+            let state = state.borrow();
+            (
+                state.array.clone(),
+                state.k,
+                state.capability.resolve.clone(),
+            )
+        };
+
+        // a. Perform ? Set(A, "length", 𝔽(k), true).
+        array.set("length", k, true, context)?;
+
+        // b. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+        resolve
+            .call(&JsValue::undefined(), &[array.into()], context)
+            .expect("the resolve function of %Promise% cannot throw");
+        Ok(())
+    }
+}
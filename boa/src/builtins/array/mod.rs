@@ -10,6 +10,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array
 
 pub mod array_iterator;
+mod from_async;
 #[cfg(test)]
 mod tests;
 
@@ -27,7 +28,11 @@ use crate::{
     value::{IntegerOrInfinity, JsValue},
     BoaProfiler, Context, JsResult, JsString,
 };
-use std::cmp::{max, min, Ordering};
+use std::{
+    cell::RefCell,
+    cmp::{max, min, Ordering},
+    collections::HashSet,
+};
 
 use super::JsArgs;
 
@@ -53,6 +58,7 @@ impl BuiltIn for Array {
             .build();
 
         let values_function = Self::values_intrinsic(context);
+        let unscopables = Self::unscopables_intrinsic(context);
 
         let array = ConstructorBuilder::with_standard_object(
             context,
@@ -82,7 +88,13 @@ impl BuiltIn for Array {
             values_function,
             Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
         )
+        .property(
+            WellKnownSymbols::unscopables(),
+            unscopables,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
         .method(Self::at, "at", 1)
+        .method(Self::with, "with", 2)
         .method(Self::concat, "concat", 1)
         .method(Self::push, "push", 1)
         .method(Self::index_of, "indexOf", 1)
@@ -95,6 +107,7 @@ impl BuiltIn for Array {
         .method(Self::pop, "pop", 0)
         .method(Self::join, "join", 1)
         .method(Self::to_string, "toString", 0)
+        .method(Self::to_locale_string, "toLocaleString", 0)
         .method(Self::reverse, "reverse", 0)
         .method(Self::shift, "shift", 0)
         .method(Self::unshift, "unshift", 1)
@@ -115,6 +128,7 @@ impl BuiltIn for Array {
         .method(Self::entries, "entries", 0)
         .method(Self::copy_within, "copyWithin", 3)
         // Static Methods
+        .static_method(Self::from_async, "fromAsync", 1)
         .static_method(Self::is_array, "isArray", 1)
         .static_method(Self::of, "of", 0)
         .build();
@@ -291,10 +305,7 @@ impl Array {
             return Ok(spreadable.to_boolean());
         }
         // 4. Return ? IsArray(O).
-        match this.as_object() {
-            Some(obj) => Ok(obj.is_array()),
-            _ => Ok(false),
-        }
+        this.is_array(context)
     }
 
     /// `get Array [ @@species ]`
@@ -411,13 +422,13 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.isarray
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/isArray
-    pub(crate) fn is_array(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
-        Ok(args
-            .get_or_undefined(0)
-            .as_object()
-            .map(|obj| obj.borrow().is_array())
-            .unwrap_or_default()
-            .into())
+    pub(crate) fn is_array(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Return ? IsArray(arg).
+        args.get_or_undefined(0).is_array(context).map(Into::into)
     }
 
     /// `Array.of(...items)`
@@ -506,6 +517,60 @@ impl Array {
         obj.get(k, context)
     }
 
+    /// `Array.prototype.with ( index, value )`
+    ///
+    /// Returns a copy of the array with the element at `index` replaced by `value`, leaving the
+    /// original array untouched. Negative indices count back from the end of the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.with
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/with
+    pub(crate) fn with(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+        // 3. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        let relative_index = args.get_or_undefined(0).to_integer_or_infinity(context)?;
+        let actual_index = match relative_index {
+            // 4. If relativeIndex ≥ 0, let actualIndex be relativeIndex.
+            IntegerOrInfinity::Integer(i) if i >= 0 && (i as usize) < len => i as usize,
+            // 5. Else, let actualIndex be len + relativeIndex.
+            IntegerOrInfinity::Integer(i) if i < 0 && (i.unsigned_abs() as usize) <= len => {
+                len - i.unsigned_abs() as usize
+            }
+            // 6. If actualIndex ≥ len or actualIndex < 0, throw a RangeError exception.
+            _ => return context.throw_range_error("Array.prototype.with: index out of range"),
+        };
+        // 7. Let A be ? ArrayCreate(len).
+        let a = Self::array_create(len, None, context)?;
+        // 8. Let k be 0.
+        // 9. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. If k is actualIndex, let fromValue be value.
+            // c. Else, let fromValue be ? Get(O, Pk).
+            let from_value = if k == actual_index {
+                args.get_or_undefined(1).clone()
+            } else {
+                o.get(k, context)?
+            };
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pk, fromValue).
+            a.create_data_property_or_throw(k, from_value, context)
+                .expect("CreateDataPropertyOrThrow must not fail on a new array");
+            // e. Set k to k + 1.
+        }
+        // 10. Return A.
+        Ok(a.into())
+    }
+
     /// `Array.prototype.concat(...arguments)`
     ///
     /// When the concat method is called with zero or more arguments, it returns an
@@ -729,6 +794,14 @@ impl Array {
     ) -> JsResult<JsValue> {
         // 1. Let O be ? ToObject(this value).
         let o = this.to_object(context)?;
+
+        // Diverge from the spec here to avoid overflowing the stack on cyclic arrays.
+        let _guard = if let Some(guard) = JoinGuard::enter(&o) {
+            guard
+        } else {
+            return Ok(JsValue::new(""));
+        };
+
         // 2. Let len be ? LengthOfArrayLike(O).
         let len = o.length_of_array_like(context)?;
         // 3. If separator is undefined, let sep be the single-element String ",".
@@ -796,6 +869,64 @@ impl Array {
         }
     }
 
+    /// `Array.prototype.toLocaleString( [ reserved1 [ , reserved2 ] ] )`
+    ///
+    /// Returns a string representing the elements of the array, each converted with its own
+    /// `toLocaleString` method and separated by commas.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toLocaleString
+    pub(crate) fn to_locale_string(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let array be ? ToObject(this value).
+        let array = this.to_object(context)?;
+
+        // Diverge from the spec here to avoid overflowing the stack on cyclic arrays.
+        let _guard = if let Some(guard) = JoinGuard::enter(&array) {
+            guard
+        } else {
+            return Ok(JsValue::new(""));
+        };
+
+        // 2. Let len be ? LengthOfArrayLike(array).
+        let len = array.length_of_array_like(context)?;
+        // 3. Let separator be the String value for the list-separator String appropriate for the host environment's current locale (this is derived in an implementation-defined way).
+        let separator = ",";
+
+        // 4. Let R be the empty String.
+        let mut r = String::new();
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        for k in 0..len {
+            // a. If k > 0, then
+            if k > 0 {
+                // i. Set R to the string-concatenation of R and separator.
+                r.push_str(separator);
+            }
+            // b. Let nextElement be ? Get(array, ! ToString(k)).
+            let next_element = array.get(k, context)?;
+            // c. If nextElement is not undefined or null, then
+            if !next_element.is_null_or_undefined() {
+                // i. Let S be ? ToString(? Invoke(nextElement, "toLocaleString")).
+                let s = next_element
+                    .invoke("toLocaleString", &[], context)?
+                    .to_string(context)?;
+                // ii. Set R to the string-concatenation of R and S.
+                r.push_str(&s);
+            }
+            // d. Increase k by 1.
+        }
+        // 7. Return R.
+        Ok(r.into())
+    }
+
     /// `Array.prototype.reverse()`
     ///
     /// The elements of the array are rearranged so as to reverse their order.
@@ -1166,6 +1297,16 @@ impl Array {
 
         let search_element = args.get_or_undefined(0);
 
+        // Fast path: reading an own data property of an array has no observable side effects,
+        // so the indexed storage can be scanned directly. The scan stops at the first hole or
+        // accessor and the generic loop below takes over from that index.
+        if let Some(index) = Self::dense_index_of(&o, search_element, k, len) {
+            match index {
+                Ok(found) => return Ok(JsValue::new(found)),
+                Err(next) => k = next,
+            }
+        }
+
         // 10. Repeat, while k < len,
         while k < len {
             // a. Let kPresent be ? HasProperty(O, ! ToString(𝔽(k))).
@@ -1187,6 +1328,40 @@ impl Array {
         Ok(JsValue::new(-1))
     }
 
+    /// Scans the own indexed data properties of an array object for `search_element`, starting
+    /// at `k` and stopping before `len`.
+    ///
+    /// Returns `None` if `o` is not an array, `Some(Ok(index))` if the element was found and
+    /// `Some(Err(k))` with the index of the first element that can't be read without side
+    /// effects (a hole or an accessor), or `len` if the whole range was scanned.
+    fn dense_index_of(
+        o: &JsObject,
+        search_element: &JsValue,
+        mut k: i64,
+        len: i64,
+    ) -> Option<Result<i64, i64>> {
+        let object = o.borrow();
+        if !object.is_array() {
+            return None;
+        }
+        let properties = object.properties();
+        while k < len {
+            // Array indices are always below 2^32 - 1, so the conversion can't fail for arrays.
+            let index = u32::try_from(k).ok()?;
+            match properties
+                .get(&index.into())
+                .and_then(PropertyDescriptor::value)
+            {
+                Some(element_k) if search_element.strict_equals(element_k) => {
+                    return Some(Ok(k));
+                }
+                Some(_) => k += 1,
+                None => break,
+            }
+        }
+        Some(Err(k))
+    }
+
     /// `Array.prototype.lastIndexOf( searchElement[, fromIndex ] )`
     ///
     ///
@@ -1499,7 +1674,8 @@ impl Array {
         let mut depth_num = 1;
 
         // 4. If depth is not undefined, then set depthNum to IntegerOrInfinity(depth)
-        if let Some(depth) = args.get(0) {
+        let depth = args.get_or_undefined(0);
+        if !depth.is_undefined() {
             // a. Set depthNum to ? ToIntegerOrInfinity(depth).
             // b. If depthNum < 0, set depthNum to 0.
             match depth.to_integer_or_infinity(context)? {
@@ -2243,9 +2419,9 @@ impl Array {
                 // 10. If ySmaller is true, return 1𝔽.
                 // 11. Return +0𝔽.
 
-                // NOTE: skipped IsLessThan because it just makes a lexicographic comparation
+                // NOTE: skipped IsLessThan because it just compares the code units
                 // when x and y are strings
-                Ok(x_str.cmp(&y_str))
+                Ok(x_str.cmp_code_units(&y_str))
             };
 
         // 2. Let obj be ? ToObject(this value).
@@ -2278,18 +2454,7 @@ impl Array {
         // 8. Sort items using an implementation-defined sequence of calls to SortCompare.
         // If any such call returns an abrupt completion, stop before performing any further
         // calls to SortCompare or steps in this algorithm and return that completion.
-        let mut sort_err = Ok(());
-        items.sort_by(|x, y| {
-            if sort_err.is_ok() {
-                sort_compare(x, y, context).unwrap_or_else(|err| {
-                    sort_err = Err(err);
-                    Ordering::Equal
-                })
-            } else {
-                Ordering::Equal
-            }
-        });
-        sort_err?;
+        Self::sort_items(&mut items, |x, y| sort_compare(x, y, context))?;
 
         // 9. Let j be 0.
         // 10. Repeat, while j < itemCount,
@@ -2310,6 +2475,44 @@ impl Array {
         Ok(obj.into())
     }
 
+    /// Sorts the snapshot of elements taken by `Array.prototype.sort` and
+    /// `%TypedArray%.prototype.sort` with a stable merge sort.
+    ///
+    /// A user comparator doesn't have to be a total order and may run arbitrary code, so
+    /// this never panics on inconsistent results (as `slice::sort_by` is allowed to), and it
+    /// stops calling `compare` as soon as it returns an error.
+    pub(crate) fn sort_items<F>(items: &mut Vec<JsValue>, mut compare: F) -> JsResult<()>
+    where
+        F: FnMut(&JsValue, &JsValue) -> JsResult<Ordering>,
+    {
+        let len = items.len();
+        let mut merged = Vec::with_capacity(len);
+        let mut width = 1;
+        while width < len {
+            merged.clear();
+            for start in (0..len).step_by(2 * width) {
+                let mid = min(start + width, len);
+                let end = min(start + 2 * width, len);
+                let (mut left, mut right) = (start, mid);
+                while left < mid && right < end {
+                    // Only take from the right run when it is strictly smaller, to keep the sort stable.
+                    if compare(&items[left], &items[right])? == Ordering::Greater {
+                        merged.push(items[right].clone());
+                        right += 1;
+                    } else {
+                        merged.push(items[left].clone());
+                        left += 1;
+                    }
+                }
+                merged.extend_from_slice(&items[left..mid]);
+                merged.extend_from_slice(&items[right..end]);
+            }
+            std::mem::swap(items, &mut merged);
+            width *= 2;
+        }
+        Ok(())
+    }
+
     /// `Array.prototype.reduce( callbackFn [ , initialValue ] )`
     ///
     /// More information:
@@ -2723,4 +2926,77 @@ impl Array {
             .constructor(false)
             .build()
     }
+
+    /// `Array.prototype [ @@unscopables ]`
+    ///
+    /// The names of the methods that a `with` statement doesn't bind, since they were added to
+    /// arrays after code using `with` was written.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype-@@unscopables
+    fn unscopables_intrinsic(context: &mut Context) -> JsObject {
+        // 1. Let unscopableList be OrdinaryObjectCreate(null).
+        let unscopable_list = JsObject::empty();
+
+        // 2-17. Perform ! CreateDataPropertyOrThrow(unscopableList, name, true) for each name.
+        for name in [
+            "at",
+            "copyWithin",
+            "entries",
+            "fill",
+            "find",
+            "findIndex",
+            "findLast",
+            "findLastIndex",
+            "flat",
+            "flatMap",
+            "includes",
+            "keys",
+            "toReversed",
+            "toSorted",
+            "toSpliced",
+            "values",
+        ] {
+            unscopable_list
+                .create_data_property_or_throw(name, true, context)
+                .expect("CreateDataPropertyOrThrow for new object cannot fail");
+        }
+
+        // 18. Return unscopableList.
+        unscopable_list
+    }
+}
+
+/// Marks an array as being joined by `join` or `toLocaleString` until dropped.
+///
+/// Nested calls on an array that is already being joined are detected through a thread local set
+/// of the arrays currently on the join stack, so they can return the empty String instead of
+/// recursing forever, which matches the behaviour of other engines.
+#[derive(Debug)]
+struct JoinGuard {
+    /// The ptr being kept in the set, so we can delete it when we drop.
+    ptr: usize,
+}
+
+impl JoinGuard {
+    thread_local! {
+        /// The pointers of the arrays currently being joined.
+        static STACK: RefCell<HashSet<usize>> = RefCell::new(HashSet::new());
+    }
+
+    /// Pushes `o` on the join stack, returning `None` if it is already being joined.
+    fn enter(o: &JsObject) -> Option<Self> {
+        let ptr = (o.as_ref() as *const _) as usize;
+        Self::STACK
+            .with(|stack| stack.borrow_mut().insert(ptr))
+            .then(|| Self { ptr })
+    }
+}
+
+impl Drop for JoinGuard {
+    fn drop(&mut self) {
+        Self::STACK.with(|stack| stack.borrow_mut().remove(&self.ptr));
+    }
 }
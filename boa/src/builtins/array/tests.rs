@@ -1,6 +1,10 @@
 use super::Array;
 use crate::builtins::Number;
-use crate::{forward, Context, JsValue};
+use crate::{
+    forward,
+    property::{Attribute, PropertyDescriptor},
+    Context, JsValue,
+};
 
 #[test]
 fn is_array() {
@@ -173,6 +177,52 @@ fn copy_within() {
     assert_eq!(negatives, String::from("\"1.2.3.3.4\""));
 }
 
+#[test]
+fn with() {
+    let mut context = Context::new();
+    let init = r#"
+        var source = [1, 2, 3];
+        var holey = [1];
+        holey[2] = 3;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "source.with(1, 5).join('.')"),
+        "\"1.5.3\""
+    );
+    assert_eq!(
+        forward(&mut context, "source.with(-1, 5).join('.')"),
+        "\"1.2.5\""
+    );
+    assert_eq!(
+        forward(&mut context, "source.with(-3, 5).join('.')"),
+        "\"5.2.3\""
+    );
+    assert_eq!(forward(&mut context, "source.join('.')"), "\"1.2.3\"");
+    assert_eq!(
+        forward(&mut context, "source.with(0, 1) !== source"),
+        "true"
+    );
+
+    for index in ["3", "-4", "Infinity", "-Infinity"] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ source.with({}, 0) }} catch (e) {{ e.name }}", index)
+            ),
+            "\"RangeError\""
+        );
+    }
+
+    assert_eq!(
+        forward(&mut context, "holey.with(0, 0).hasOwnProperty(1)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "holey.with(0, 0)[1]"), "undefined");
+    assert_eq!(forward(&mut context, "holey.hasOwnProperty(1)"), "false");
+}
+
 #[test]
 fn join() {
     let mut context = Context::new();
@@ -193,6 +243,90 @@ fn join() {
     assert_eq!(many, String::from("\"a.b.c\""));
 }
 
+#[test]
+fn join_separator_to_string_once() {
+    let mut context = Context::new();
+    let init = r#"
+        var calls = 0;
+        var order = [];
+        var sep = {
+            toString() {
+                calls++;
+                order.push('sep');
+                return '-';
+            }
+        };
+        var arrayLike = {
+            get length() {
+                order.push('length');
+                return 3;
+            },
+            0: 'a',
+            1: 'b',
+            2: 'c'
+        };
+        "#;
+    eprintln!("{}", forward(&mut context, init));
+    assert_eq!(forward(&mut context, "[1, 2, 3].join(sep)"), "\"1-2-3\"");
+    assert_eq!(forward(&mut context, "calls"), "1");
+    assert_eq!(forward(&mut context, "calls = 0; [].join(sep)"), "\"\"");
+    assert_eq!(forward(&mut context, "calls"), "1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "order = []; Array.prototype.join.call(arrayLike, sep)"
+        ),
+        "\"a-b-c\""
+    );
+    assert_eq!(forward(&mut context, "order.join()"), "\"length,sep\"");
+    assert_eq!(forward(&mut context, "[1, 2].join(undefined)"), "\"1,2\"");
+}
+
+#[test]
+fn join_cyclic() {
+    let mut context = Context::new();
+    let init = r#"
+        var cyclic = [1, 2];
+        cyclic.push(cyclic);
+        var nested = [1, [2, cyclic]];
+        "#;
+    eprintln!("{}", forward(&mut context, init));
+    assert_eq!(forward(&mut context, "cyclic.join()"), "\"1,2,\"");
+    assert_eq!(forward(&mut context, "cyclic.toString()"), "\"1,2,\"");
+    assert_eq!(forward(&mut context, "String(cyclic)"), "\"1,2,\"");
+    assert_eq!(forward(&mut context, "nested.join('-')"), "\"1-2,1,2,\"");
+    // Arrays that are only repeated, not cyclic, are joined every time.
+    assert_eq!(
+        forward(&mut context, "var a = [1]; [a, a].join()"),
+        "\"1,1\""
+    );
+}
+
+#[test]
+fn to_locale_string() {
+    let mut context = Context::new();
+    let init = r#"
+        var empty = [ ];
+        var many = [1, null, 2, undefined, 3];
+        var cyclic = [1];
+        cyclic.push(cyclic);
+        "#;
+    eprintln!("{}", forward(&mut context, init));
+    assert_eq!(forward(&mut context, "empty.toLocaleString()"), "\"\"");
+    assert_eq!(
+        forward(&mut context, "many.toLocaleString()"),
+        "\"1,,2,,3\""
+    );
+    assert_eq!(forward(&mut context, "cyclic.toLocaleString()"), "\"1,\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "[{ toLocaleString() { return 'x'; } }, 4].toLocaleString()"
+        ),
+        "\"x,4\""
+    );
+}
+
 #[test]
 fn to_string() {
     let mut context = Context::new();
@@ -296,6 +430,67 @@ fn find_index() {
     assert_eq!(missing, String::from("-1"));
 }
 
+#[test]
+fn find_last() {
+    let mut context = Context::new();
+
+    let code = r#"
+        var mixed = [1, 2, "x", 4, 5, null];
+        var odd = [1, 3, 5];
+        function even(item) {
+            return typeof item === "number" && item % 2 === 0;
+        }
+        "#;
+    forward(&mut context, code);
+
+    assert_eq!(forward(&mut context, "mixed.findLast(even)"), "4");
+    assert_eq!(forward(&mut context, "mixed.findLastIndex(even)"), "3");
+    assert_eq!(forward(&mut context, "odd.findLast(even)"), "undefined");
+    assert_eq!(forward(&mut context, "odd.findLastIndex(even)"), "-1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1, 2].findLast(function (x) { return x === this.wanted; }, { wanted: 1 })"
+        ),
+        "1"
+    );
+}
+
+#[test]
+fn find_last_visits_every_index_from_the_end() {
+    let mut context = Context::new();
+
+    // Holes are visited as undefined, and the length is only read once.
+    let code = r#"
+        var holey = [1, 2, 3];
+        delete holey[1];
+        var visited = [];
+        holey.findLastIndex(function (value, index) {
+            visited.push(index + ":" + value);
+            holey.length = 0;
+        });
+
+        var length_reads = 0;
+        var array_like = { get length() { length_reads++; return 2; }, 0: "a", 1: "b" };
+        var found = Array.prototype.findLast.call(array_like, x => x === "a");
+        "#;
+    forward(&mut context, code);
+
+    assert_eq!(
+        forward(&mut context, "visited.join()"),
+        "\"2:3,1:undefined,0:undefined\""
+    );
+    assert_eq!(forward(&mut context, "found"), "\"a\"");
+    assert_eq!(forward(&mut context, "length_reads"), "1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype[Symbol.unscopables].findLast && Array.prototype[Symbol.unscopables].findLastIndex"
+        ),
+        "true"
+    );
+}
+
 #[test]
 fn flat() {
     let mut context = Context::new();
@@ -348,6 +543,104 @@ fn flat_infinity() {
     assert_eq!(forward(&mut context, "flat_arr.length"), "1");
 }
 
+#[test]
+fn flat_depth() {
+    let mut context = Context::new();
+    forward(&mut context, "var nested = [1, [2, [3]]];");
+
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(nested.flat(1))"),
+        "\"[1,2,[3]]\""
+    );
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(nested.flat(Infinity))"),
+        "\"[1,2,3]\""
+    );
+
+    // The depth is converted with ToIntegerOrInfinity, and only undefined means 1.
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(nested.flat(undefined))"),
+        "\"[1,2,[3]]\""
+    );
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(nested.flat('1.9'))"),
+        "\"[1,2,[3]]\""
+    );
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(nested.flat(-1))"),
+        "\"[1,[2,[3]]]\""
+    );
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(nested.flat(NaN))"),
+        "\"[1,[2,[3]]]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "JSON.stringify(nested.flat({ valueOf() { return 2; } }))"
+        ),
+        "\"[1,2,3]\""
+    );
+}
+
+#[test]
+fn flat_and_flat_map_on_array_likes() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        "var array_like = { length: 2, 0: [1, [2]], 1: 'a', 2: 'ignored' };",
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "JSON.stringify(Array.prototype.flat.call(array_like))"
+        ),
+        r#""[1,[2],"a"]""#
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype.flatMap.call(array_like, x => [typeof x]).join()"
+        ),
+        "\"object,string\""
+    );
+}
+
+#[test]
+fn flat_drops_holes() {
+    let mut context = Context::new();
+
+    // Array literal elisions are filled with `undefined`, so holes are made with `delete`.
+    let code = r#"
+        var top = ['a', 'hole', ['b', 'c'], 'd'];
+        delete top[1];
+        var flat_top = top.flat();
+
+        var nested = ['e', 'hole', 'f'];
+        delete nested[1];
+        var flat_nested = [top, nested].flat(Infinity);
+        "#;
+    forward(&mut context, code);
+
+    assert_eq!(forward(&mut context, "flat_top.length"), "4");
+    assert_eq!(forward(&mut context, "flat_top.join()"), "\"a,b,c,d\"");
+    assert_eq!(
+        forward(&mut context, "flat_top.every((_, i) => i in flat_top)"),
+        "true"
+    );
+
+    assert_eq!(forward(&mut context, "flat_nested.length"), "6");
+    assert_eq!(
+        forward(&mut context, "flat_nested.join()"),
+        "\"a,b,c,d,e,f\""
+    );
+    assert_eq!(
+        forward(&mut context, "flat_nested.indexOf(undefined)"),
+        "-1"
+    );
+}
+
 #[test]
 fn flat_map() {
     let mut context = Context::new();
@@ -405,6 +698,77 @@ fn flat_map_not_callable() {
     assert_eq!(forward(&mut context, code), "true");
 }
 
+#[test]
+fn flat_map_arrays_and_scalars() {
+    let mut context = Context::new();
+
+    // Only arrays returned by the callback are flattened, and only one level deep.
+    assert_eq!(
+        forward(
+            &mut context,
+            "JSON.stringify([1, 2].flatMap(x => x === 1 ? [x, [x]] : x))"
+        ),
+        "\"[1,[1],2]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var array_like = [1].flatMap(x => ({ length: 1, 0: x })); array_like.length"
+        ),
+        "1"
+    );
+    assert_eq!(forward(&mut context, "array_like[0][0]"), "1");
+    assert_eq!(
+        forward(&mut context, "[1].flatMap(x => 'ab').join()"),
+        "\"ab\""
+    );
+}
+
+#[test]
+fn flat_map_callback_arguments() {
+    let mut context = Context::new();
+
+    let code = r#"
+        var calls = [];
+        var source = [5, 6];
+        source.flatMap(function (element, index, array) {
+            calls.push([element, index, array === source, this.tag].join());
+            return [];
+        }, { tag: "this" });
+    "#;
+    forward(&mut context, code);
+
+    assert_eq!(
+        forward(&mut context, "calls.join(' ')"),
+        "\"5,0,true,this 6,1,true,this\""
+    );
+}
+
+#[test]
+fn flat_map_throwing_callback() {
+    let mut context = Context::new();
+
+    let code = r#"
+        var calls = 0;
+        var message;
+        try {
+            [1, 2, 3].flatMap(x => {
+                calls++;
+                if (x === 2) {
+                    throw new Error("boom");
+                }
+                return [x];
+            });
+        } catch (err) {
+            message = err.message;
+        }
+    "#;
+    forward(&mut context, code);
+
+    assert_eq!(forward(&mut context, "message"), "\"boom\"");
+    assert_eq!(forward(&mut context, "calls"), "2");
+}
+
 #[test]
 fn push() {
     let mut context = Context::new();
@@ -475,6 +839,92 @@ fn unshift() {
     assert_eq!(forward(&mut context, "arr[1]"), "2");
 }
 
+#[test]
+fn shift_and_unshift_preserve_holes() {
+    let mut context = Context::new();
+    let init = r#"
+        function layout(array) {
+            let result = [];
+            for (let i = 0; i < array.length; i++) {
+                result.push(i in array ? array[i] : "_");
+            }
+            return result.join();
+        }
+        var unshifted = [1, 2, 3];
+        delete unshifted[1];
+        var shifted = [1, 2, 3];
+        delete shifted[1];
+        var arrayLike = { length: 3, 0: "a", 2: "c" };
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "unshifted.unshift(0)"), "4");
+    assert_eq!(forward(&mut context, "layout(unshifted)"), "\"0,1,_,3\"");
+    assert_eq!(forward(&mut context, "shifted.shift()"), "1");
+    assert_eq!(forward(&mut context, "shifted.length"), "2");
+    assert_eq!(forward(&mut context, "layout(shifted)"), "\"_,3\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype.unshift.call(arrayLike, 'x', 'y')"
+        ),
+        "5"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(arrayLike).join()"),
+        "\"0,1,2,4,length\""
+    );
+    assert_eq!(forward(&mut context, "layout(arrayLike)"), "\"x,y,a,_,c\"");
+}
+
+#[test]
+fn shift_and_pop_on_empty() {
+    let mut context = Context::new();
+    let init = r#"
+        var empty = [];
+        var arrayLike = {};
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "empty.shift()"), "undefined");
+    assert_eq!(forward(&mut context, "empty.pop()"), "undefined");
+    assert_eq!(forward(&mut context, "empty.length"), "0");
+    assert_eq!(
+        forward(&mut context, "Array.prototype.shift.call(arrayLike)"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut context, "arrayLike.length"), "0");
+}
+
+#[test]
+fn length_overflow() {
+    let mut context = Context::new();
+    let init = r#"
+        function attempt(method, ...args) {
+            let arrayLike = { length: 2 ** 53 - 1 };
+            try {
+                Array.prototype[method].call(arrayLike, ...args);
+            } catch (e) {
+                return e.name + ":" + (arrayLike.length === 2 ** 53 - 1);
+            }
+            return "no error";
+        }
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "attempt('push', 1)"),
+        "\"TypeError:true\""
+    );
+    assert_eq!(
+        forward(&mut context, "attempt('unshift', 1)"),
+        "\"TypeError:true\""
+    );
+    // Adding no elements never overflows.
+    assert_eq!(forward(&mut context, "attempt('push')"), "\"no error\"");
+    assert_eq!(forward(&mut context, "attempt('unshift')"), "\"no error\"");
+}
+
 #[test]
 fn reverse() {
     let mut context = Context::new();
@@ -552,6 +1002,94 @@ fn index_of() {
     assert_eq!(second_in_many, String::from("4"));
 }
 
+#[test]
+fn index_of_dense_large() {
+    const LEN: u32 = 1_000_000;
+
+    let mut context = Context::new();
+    // Every `borrow_mut` of an object re-roots all of its properties, so the backing store is
+    // filled under a single borrow instead of through `CreateDataPropertyOrThrow`.
+    let large = Array::array_create(0, None, &mut context).unwrap();
+    {
+        let mut object = large.borrow_mut();
+        for i in 0..LEN {
+            object.insert(
+                i,
+                PropertyDescriptor::builder()
+                    .value(i)
+                    .writable(true)
+                    .enumerable(true)
+                    .configurable(true),
+            );
+        }
+        object.insert(
+            "length",
+            PropertyDescriptor::builder()
+                .value(LEN)
+                .writable(true)
+                .enumerable(false)
+                .configurable(false),
+        );
+    }
+    context.register_global_property("large", large, Attribute::all());
+
+    assert_eq!(forward(&mut context, "large.indexOf(0)"), "0");
+    assert_eq!(forward(&mut context, "large.indexOf(999999)"), "999999");
+    assert_eq!(forward(&mut context, "large.indexOf(1000000)"), "-1");
+    assert_eq!(forward(&mut context, "large.indexOf(500000, -10)"), "-1");
+    assert_eq!(forward(&mut context, "large.indexOf('5')"), "-1");
+}
+
+#[test]
+fn index_of_fast_and_slow_paths_agree() {
+    let mut context = Context::new();
+    let init = r#"
+        function slowIndexOf(arr, value, from) {
+            var arrayLike = { length: arr.length };
+            for (var i = 0; i < arr.length; i++) {
+                if (i in arr) {
+                    arrayLike[i] = arr[i];
+                }
+            }
+            return Array.prototype.indexOf.call(arrayLike, value, from);
+        }
+
+        var getterCalls = 0;
+        var withGetter = [1, 2, 3, 4];
+        Object.defineProperty(withGetter, 2, {
+            get: function() { getterCalls++; return 'x'; }
+        });
+
+        var holey = [1, 2, 3, NaN, -0];
+        delete holey[1];
+        Array.prototype[1] = 'inherited';
+        "#;
+    forward(&mut context, init);
+
+    for (value, from) in [
+        ("1", "undefined"),
+        ("3", "undefined"),
+        ("3", "3"),
+        ("0", "undefined"),
+        ("NaN", "undefined"),
+        ("'inherited'", "undefined"),
+        ("'missing'", "-2"),
+    ] {
+        let fast = forward(&mut context, format!("holey.indexOf({}, {})", value, from));
+        let slow = forward(
+            &mut context,
+            format!("slowIndexOf(holey, {}, {})", value, from),
+        );
+        assert_eq!(fast, slow);
+    }
+
+    assert_eq!(forward(&mut context, "holey.indexOf('inherited')"), "1");
+    assert_eq!(forward(&mut context, "holey.indexOf(0)"), "4");
+    assert_eq!(forward(&mut context, "withGetter.indexOf('x')"), "2");
+    assert_eq!(forward(&mut context, "withGetter.indexOf(4)"), "3");
+    assert_eq!(forward(&mut context, "getterCalls"), "2");
+}
+
 #[test]
 fn last_index_of() {
     let mut context = Context::new();
@@ -1394,6 +1932,63 @@ fn array_values_symbol_iterator() {
     assert_eq!(forward(&mut context, init), "true");
 }
 
+#[test]
+fn array_iterator_over_advancing() {
+    let mut context = Context::new();
+    let init = r#"
+        function drain(iterator, count) {
+            let results = [];
+            for (let i = 0; i < count; i++) {
+                let next = iterator.next();
+                results.push(String(next.value) + ":" + next.done);
+            }
+            return results.join();
+        }
+        var array = [1, 2];
+        var values = array.values();
+        var exhausted = drain(values, 4);
+        array.push(3);
+        var afterPush = drain(values, 1);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "exhausted"),
+        "\"1:false,2:false,undefined:true,undefined:true\""
+    );
+    assert_eq!(forward(&mut context, "afterPush"), "\"undefined:true\"");
+    assert_eq!(
+        forward(&mut context, "drain([1].keys(), 3)"),
+        "\"0:false,undefined:true,undefined:true\""
+    );
+    assert_eq!(
+        forward(&mut context, "drain([1].entries(), 3)"),
+        "\"0,1:false,undefined:true,undefined:true\""
+    );
+}
+
+#[test]
+fn array_iterator_ignores_prototype_tampering() {
+    let mut context = Context::new();
+    let init = r#"
+        var iterator = [1].values();
+        Array.prototype[Symbol.iterator] = function () { throw new Error("replaced"); };
+        Object.prototype.done = false;
+        Object.prototype.value = 42;
+        var first = iterator.next();
+        var second = iterator.next();
+        var third = iterator.next();
+        delete Object.prototype.done;
+        delete Object.prototype.value;
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "first.value"), "1");
+    assert_eq!(forward(&mut context, "first.done"), "false");
+    assert_eq!(forward(&mut context, "second.value"), "undefined");
+    assert_eq!(forward(&mut context, "second.done"), "true");
+    assert_eq!(forward(&mut context, "third.value"), "undefined");
+    assert_eq!(forward(&mut context, "third.done"), "true");
+}
+
 #[test]
 fn array_spread_arrays() {
     let mut context = Context::new();
@@ -1569,3 +2164,210 @@ fn array_sort() {
         "\"1,5,9,40,80,200,700\""
     );
 }
+
+#[test]
+fn array_sort_compares_code_units() {
+    let mut context = Context::new();
+    let init = r#"
+        let arr = ['\uFFFF', '\u{1F600}', 'b', '\uE000', 'a'];
+        let sorted = arr.sort().map(s => s.codePointAt(0).toString(16));
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "sorted.join()"),
+        "\"61,62,1f600,e000,ffff\""
+    );
+}
+
+#[test]
+fn array_sort_comparator_truncates_array() {
+    let mut context = Context::new();
+    let init = r#"
+        let arr = [5, 1, 4, 2, 3, 6];
+        let result = arr.sort((a, b) => {
+            arr.length = 2;
+            return a - b;
+        });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result === arr"), "true");
+    assert_eq!(forward(&mut context, "arr.join()"), "\"1,2,3,4,5,6\"");
+}
+
+#[test]
+fn array_sort_inconsistent_comparator() {
+    let mut context = Context::new();
+    let init = r#"
+        let arr = [];
+        for (let i = 0; i < 200; i++) arr.push(i);
+        let flip = 1;
+        arr.sort(() => (flip = -flip));
+        let sum = arr.reduce((a, b) => a + b, 0);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "arr.length"), "200");
+    assert_eq!(forward(&mut context, "sum"), "19900");
+}
+
+#[test]
+fn array_sort_is_stable_and_stops_on_throw() {
+    let mut context = Context::new();
+    let init = r#"
+        let pairs = [{k: 1, v: 'a'}, {k: 0, v: 'b'}, {k: 1, v: 'c'}, {k: 0, v: 'd'}];
+        let order = pairs.sort((x, y) => x.k - y.k).map(e => e.v).join('');
+        let calls = 0;
+        let error;
+        try {
+            [3, 2, 1, 0].sort(() => { calls++; throw 'stop'; });
+        } catch (e) {
+            error = e;
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "order"), "\"bdac\"");
+    assert_eq!(forward(&mut context, "error"), "\"stop\"");
+    assert_eq!(forward(&mut context, "calls"), "1");
+}
+
+#[test]
+fn species_create_subclass() {
+    let mut context = Context::new();
+    let init = r#"
+        function MyArray() {
+            return Reflect.construct(Array, arguments, MyArray);
+        }
+        Object.setPrototypeOf(MyArray, Array);
+        MyArray.prototype = Object.create(Array.prototype, {
+            constructor: { value: MyArray, writable: true, configurable: true },
+        });
+        var sub = new MyArray(1, 2, 3);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "sub instanceof MyArray"), "true");
+    for (method, expected) in [
+        ("map(x => x * 2)", "\"2,4,6\""),
+        ("filter(x => x > 1)", "\"2,3\""),
+        ("slice(1)", "\"2,3\""),
+        ("concat([4])", "\"1,2,3,4\""),
+        ("flatMap(x => [x])", "\"1,2,3\""),
+    ] {
+        assert_eq!(
+            forward(&mut context, format!("sub.{} instanceof MyArray", method)),
+            "true",
+            "{}",
+            method
+        );
+        assert_eq!(
+            forward(&mut context, format!("sub.{}.join()", method)),
+            expected,
+            "{}",
+            method
+        );
+    }
+
+    forward(&mut context, "var removed = sub.splice(0, 1);");
+    assert_eq!(forward(&mut context, "removed instanceof MyArray"), "true");
+    assert_eq!(forward(&mut context, "removed.join()"), "\"1\"");
+}
+
+#[test]
+fn species_create_custom_constructor() {
+    let mut context = Context::new();
+    let init = r#"
+        var custom = [1, 2, 3];
+        custom.constructor = {};
+        custom.constructor[Symbol.species] = function (length) {
+            this.length = length;
+        };
+        var result = custom.map(x => x * 2);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "Array.isArray(result)"), "false");
+    assert_eq!(forward(&mut context, "result.length"), "3");
+    assert_eq!(forward(&mut context, "result[2]"), "6");
+
+    let init = r#"
+        var plain = [1];
+        plain.constructor = {};
+        plain.constructor[Symbol.species] = null;
+        var invalid = [1];
+        invalid.constructor = {};
+        invalid.constructor[Symbol.species] = 1;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Array.isArray(plain.map(x => x))"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { invalid.map(x => x) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn from_async() {
+    let mut context = Context::new();
+    let init = r#"
+        var results = {};
+        function record(name, promise) {
+            promise.then(
+                function (a) { results[name] = Array.isArray(a) + ":" + a.join(); },
+                function (e) { results[name] = e.name; }
+            );
+        }
+        record("iterable", Array.fromAsync([Promise.resolve(1), 2, 3]));
+        record("arrayLike", Array.fromAsync({ length: 2, 0: "x", 1: Promise.resolve("y") }));
+        record("mapped", Array.fromAsync([1, 2], function (x, i) {
+            return Promise.resolve(x * 10 + i + this.offset);
+        }, { offset: 100 }));
+
+        var asyncIterable = {};
+        asyncIterable[Symbol.asyncIterator] = function () {
+            var i = 0;
+            return {
+                next: function () {
+                    i += 1;
+                    return Promise.resolve({ value: i, done: i > 3 });
+                }
+            };
+        };
+        record("asyncIterable", Array.fromAsync(asyncIterable));
+        record("notCallable", Array.fromAsync([], 1));
+
+        var closed = false;
+        var iterable = {};
+        iterable[Symbol.iterator] = function () {
+            return {
+                next: function () { return { value: 1, done: false }; },
+                return: function () { closed = true; return {}; }
+            };
+        };
+        record("mapperThrows", Array.fromAsync(iterable, function () { throw new RangeError(); }));
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+
+    assert_eq!(forward(&mut context, "results.iterable"), "\"true:1,2,3\"");
+    assert_eq!(forward(&mut context, "results.arrayLike"), "\"true:x,y\"");
+    assert_eq!(forward(&mut context, "results.mapped"), "\"true:110,121\"");
+    assert_eq!(
+        forward(&mut context, "results.asyncIterable"),
+        "\"true:1,2,3\""
+    );
+    assert_eq!(
+        forward(&mut context, "results.notCallable"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "results.mapperThrows"),
+        "\"RangeError\""
+    );
+    assert_eq!(forward(&mut context, "closed"), "true");
+}
@@ -43,6 +43,11 @@ impl BuiltIn for ArrayBuffer {
             .constructor(false)
             .build();
 
+        let get_byte_length = FunctionBuilder::native(context, Self::byte_length)
+            .name("get byteLength")
+            .constructor(false)
+            .build();
+
         ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -57,7 +62,12 @@ impl BuiltIn for ArrayBuffer {
             Attribute::CONFIGURABLE,
         )
         .static_method(Self::is_view, "isView", 1)
-        .method(Self::byte_length, "byteLength", 0)
+        .accessor(
+            "byteLength",
+            Some(get_byte_length),
+            None,
+            Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
         .method(Self::slice, "slice", 2)
         .property(
             WellKnownSymbols::to_string_tag(),
@@ -343,6 +353,35 @@ impl ArrayBuffer {
         self.array_buffer_data.is_none()
     }
 
+    /// `25.1.2.3 DetachArrayBuffer ( arrayBuffer [ , key ] )`
+    ///
+    /// Releases the data of the buffer, leaving it with a length of zero. Hosts can use this to
+    /// transfer the contents of a buffer out of the engine.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-detacharraybuffer
+    pub fn detach(&mut self, key: &JsValue, context: &mut Context) -> JsResult<()> {
+        // 1. Assert: IsSharedArrayBuffer(arrayBuffer) is false.
+        // 2. If key is not present, set key to undefined.
+        // 3. If SameValue(arrayBuffer.[[ArrayBufferDetachKey]], key) is false, throw a TypeError exception.
+        if !JsValue::same_value(&self.array_buffer_detach_key, key) {
+            return Err(
+                context.construct_type_error("Cannot detach array buffer with different key")
+            );
+        }
+
+        // 4. Set arrayBuffer.[[ArrayBufferData]] to null.
+        self.array_buffer_data = None;
+
+        // 5. Set arrayBuffer.[[ArrayBufferByteLength]] to 0.
+        self.array_buffer_byte_length = 0;
+
+        // 6. Return NormalCompletion(null).
+        Ok(())
+    }
+
     /// `25.1.2.4 CloneArrayBuffer ( srcBuffer, srcByteOffset, srcLength, cloneConstructor )`
     ///
     /// More information:
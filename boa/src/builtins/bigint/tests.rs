@@ -200,6 +200,10 @@ fn pow() {
         forward(&mut context, "100n ** 10n"),
         "100000000000000000000n"
     );
+    assert_eq!(
+        forward(&mut context, "2n ** 128n"),
+        "340282366920938463463374607431768211456n"
+    );
 }
 
 #[test]
@@ -411,3 +415,34 @@ fn remainder_by_zero() {
     let mut context = Context::new();
     assert_throws(&mut context, "1n % 0n", "RangeError");
 }
+
+#[test]
+fn mixed_arithmetic() {
+    let mut context = Context::new();
+    assert_throws(&mut context, "1n + 1", "TypeError");
+    assert_throws(&mut context, "1 * 2n", "TypeError");
+    assert_throws(&mut context, "+1n", "TypeError");
+    assert_throws(&mut context, "1n >>> 0n", "TypeError");
+}
+
+#[test]
+fn mixed_comparison() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "1n < 2"), "true");
+    assert_eq!(forward(&mut context, "3n > 2.5"), "true");
+    assert_eq!(forward(&mut context, "2n < 2.5"), "true");
+    assert_eq!(forward(&mut context, "-3n < -2.5"), "true");
+    assert_eq!(forward(&mut context, "-2.5 < -2n"), "true");
+    assert_eq!(forward(&mut context, "2.5 <= 2n"), "false");
+    assert_eq!(forward(&mut context, "NaN < 1n"), "false");
+    assert_eq!(forward(&mut context, "Infinity > 10n"), "true");
+}
+
+#[test]
+fn update_and_bitwise_not() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "let a = 1n; a++; a"), "2n");
+    assert_eq!(forward(&mut context, "let b = 1n; --b"), "0n");
+    assert_eq!(forward(&mut context, "~5n"), "-6n");
+    assert_eq!(forward(&mut context, "typeof 10n"), "\"bigint\"");
+}
@@ -12,6 +12,9 @@ use crate::{
 };
 use gc::{Finalize, Trace};
 
+#[cfg(test)]
+mod tests;
+
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct DataView {
     viewed_array_buffer: JsObject,
@@ -85,6 +88,21 @@ impl BuiltIn for DataView {
 impl DataView {
     pub(crate) const LENGTH: usize = 1;
 
+    #[cfg(any(feature = "encoding", feature = "structured_clone"))]
+    pub(crate) fn viewed_array_buffer(&self) -> &JsObject {
+        &self.viewed_array_buffer
+    }
+
+    #[cfg(any(feature = "encoding", feature = "structured_clone"))]
+    pub(crate) fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+
+    #[cfg(any(feature = "encoding", feature = "structured_clone"))]
+    pub(crate) fn byte_length(&self) -> usize {
+        self.byte_length
+    }
+
     /// `25.3.2.1 DataView ( buffer [ , byteOffset [ , byteLength ] ] )`
     ///
     /// More information:
@@ -0,0 +1,100 @@
+use crate::{forward, forward_val, Context, JsValue};
+
+/// Runs `src` and returns the name of the error it throws, or `"ok"` if it doesn't throw.
+fn thrown(context: &mut Context, src: &str) -> String {
+    forward(
+        context,
+        &format!("try {{ {}; \"ok\" }} catch (e) {{ e.name }}", src),
+    )
+}
+
+#[test]
+fn endianness() {
+    let mut context = Context::new();
+    let init = r#"
+        var view = new DataView(new ArrayBuffer(16), 2);
+        view.setFloat32(0, 1.5, true);
+        "#;
+    forward(&mut context, init);
+
+    // 1.5 is 0x3FC00000, whose most significant byte is stored last in little-endian order.
+    assert_eq!(
+        forward(
+            &mut context,
+            "[0, 1, 2, 3].map(i => view.getUint8(i)).join()"
+        ),
+        "\"0,0,192,63\""
+    );
+    assert_eq!(forward(&mut context, "view.getFloat32(0, true)"), "1.5");
+    assert_eq!(
+        forward(&mut context, "view.getFloat32(0)"),
+        "6.896490392174587e-41"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "view.setFloat32(4, view.getFloat32(0)); view.getUint8(4) + ',' + view.getUint8(7)"
+        ),
+        "\"0,63\""
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "view.setUint16(0, 0x0102); [view.getUint8(0), view.getUint8(1), view.getUint16(0, true)].join()"
+        ),
+        "\"1,2,513\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "view.setInt32(4, -2, true); [view.getInt32(4, true), view.getUint32(4, true), view.getInt8(4)].join()"
+        ),
+        "\"-2,4294967294,-2\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "view.setBigInt64(0, -5n, true); view.getBigUint64(0, true)"
+        ),
+        "18446744073709551611n"
+    );
+}
+
+#[test]
+fn out_of_range_and_detached() {
+    let mut context = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(16);
+        var view = new DataView(buffer, 2);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(thrown(&mut context, "view.getInt8(13)"), "\"ok\"");
+    assert_eq!(thrown(&mut context, "view.getInt8(14)"), "\"RangeError\"");
+    assert_eq!(thrown(&mut context, "view.getInt16(13)"), "\"RangeError\"");
+    assert_eq!(
+        thrown(&mut context, "view.setInt8(-1, 0)"),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        thrown(&mut context, "new DataView(new ArrayBuffer(2), 3)"),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        thrown(&mut context, "DataView.prototype.getInt8.call({}, 0)"),
+        "\"TypeError\""
+    );
+
+    let buffer = forward_val(&mut context, "buffer").unwrap();
+    buffer
+        .as_object()
+        .unwrap()
+        .borrow_mut()
+        .as_array_buffer_mut()
+        .unwrap()
+        .detach(&JsValue::undefined(), &mut context)
+        .unwrap();
+    assert_eq!(thrown(&mut context, "view.getInt8(0)"), "\"TypeError\"");
+    assert_eq!(thrown(&mut context, "view.setInt8(0, 1)"), "\"TypeError\"");
+}
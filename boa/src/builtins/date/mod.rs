@@ -98,66 +98,70 @@ impl BuiltIn for Date {
     fn init(context: &mut Context) -> JsValue {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
-        let date_object = ConstructorBuilder::new(context, Self::constructor)
-            .name(Self::NAME)
-            .length(Self::LENGTH)
-            .method(getter_method!(get_date), "getDate", 0)
-            .method(getter_method!(get_day), "getDay", 0)
-            .method(getter_method!(get_full_year), "getFullYear", 0)
-            .method(getter_method!(get_hours), "getHours", 0)
-            .method(getter_method!(get_milliseconds), "getMilliseconds", 0)
-            .method(getter_method!(get_minutes), "getMinutes", 0)
-            .method(getter_method!(get_month), "getMonth", 0)
-            .method(getter_method!(get_seconds), "getSeconds", 0)
-            .method(getter_method!(get_time), "getTime", 0)
-            .method(getter_method!(get_year), "getYear", 0)
-            .method(Self::get_timezone_offset, "getTimezoneOffset", 0)
-            .method(getter_method!(get_utc_date), "getUTCDate", 0)
-            .method(getter_method!(get_utc_day), "getUTCDay", 0)
-            .method(getter_method!(get_utc_full_year), "getUTCFullYear", 0)
-            .method(getter_method!(get_utc_hours), "getUTCHours", 0)
-            .method(
-                getter_method!(get_utc_milliseconds),
-                "getUTCMilliseconds",
-                0,
-            )
-            .method(getter_method!(get_utc_minutes), "getUTCMinutes", 0)
-            .method(getter_method!(get_utc_month), "getUTCMonth", 0)
-            .method(getter_method!(get_utc_seconds), "getUTCSeconds", 0)
-            .method(Self::set_date, "setDate", 1)
-            .method(Self::set_full_year, "setFullYear", 3)
-            .method(Self::set_hours, "setHours", 4)
-            .method(Self::set_milliseconds, "setMilliseconds", 1)
-            .method(Self::set_minutes, "setMinutes", 3)
-            .method(Self::set_month, "setMonth", 2)
-            .method(Self::set_seconds, "setSeconds", 2)
-            .method(Self::set_year, "setYear", 1)
-            .method(Self::set_time, "setTime", 1)
-            .method(Self::set_utc_date, "setUTCDate", 1)
-            .method(Self::set_utc_full_year, "setUTCFullYear", 3)
-            .method(Self::set_utc_hours, "setUTCHours", 4)
-            .method(Self::set_utc_milliseconds, "setUTCMilliseconds", 1)
-            .method(Self::set_utc_minutes, "setUTCMinutes", 3)
-            .method(Self::set_utc_month, "setUTCMonth", 2)
-            .method(Self::set_utc_seconds, "setUTCSeconds", 2)
-            .method(Self::to_date_string, "toDateString", 0)
-            .method(getter_method!(to_gmt_string), "toGMTString", 0)
-            .method(Self::to_iso_string, "toISOString", 0)
-            .method(Self::to_json, "toJSON", 1)
-            // Locale strings
-            .method(Self::to_string, "toString", 0)
-            .method(Self::to_time_string, "toTimeString", 0)
-            .method(getter_method!(to_utc_string), "toUTCString", 0)
-            .method(getter_method!(value_of), "valueOf", 0)
-            .method(
-                Self::to_primitive,
-                (WellKnownSymbols::to_primitive(), "[Symbol.toPrimitive]"),
-                1,
-            )
-            .static_method(Self::now, "now", 0)
-            .static_method(Self::parse, "parse", 1)
-            .static_method(Self::utc, "UTC", 7)
-            .build();
+        let date_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().date_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(getter_method!(get_date), "getDate", 0)
+        .method(getter_method!(get_day), "getDay", 0)
+        .method(getter_method!(get_full_year), "getFullYear", 0)
+        .method(getter_method!(get_hours), "getHours", 0)
+        .method(getter_method!(get_milliseconds), "getMilliseconds", 0)
+        .method(getter_method!(get_minutes), "getMinutes", 0)
+        .method(getter_method!(get_month), "getMonth", 0)
+        .method(getter_method!(get_seconds), "getSeconds", 0)
+        .method(getter_method!(get_time), "getTime", 0)
+        .method(getter_method!(get_year), "getYear", 0)
+        .method(Self::get_timezone_offset, "getTimezoneOffset", 0)
+        .method(getter_method!(get_utc_date), "getUTCDate", 0)
+        .method(getter_method!(get_utc_day), "getUTCDay", 0)
+        .method(getter_method!(get_utc_full_year), "getUTCFullYear", 0)
+        .method(getter_method!(get_utc_hours), "getUTCHours", 0)
+        .method(
+            getter_method!(get_utc_milliseconds),
+            "getUTCMilliseconds",
+            0,
+        )
+        .method(getter_method!(get_utc_minutes), "getUTCMinutes", 0)
+        .method(getter_method!(get_utc_month), "getUTCMonth", 0)
+        .method(getter_method!(get_utc_seconds), "getUTCSeconds", 0)
+        .method(Self::set_date, "setDate", 1)
+        .method(Self::set_full_year, "setFullYear", 3)
+        .method(Self::set_hours, "setHours", 4)
+        .method(Self::set_milliseconds, "setMilliseconds", 1)
+        .method(Self::set_minutes, "setMinutes", 3)
+        .method(Self::set_month, "setMonth", 2)
+        .method(Self::set_seconds, "setSeconds", 2)
+        .method(Self::set_year, "setYear", 1)
+        .method(Self::set_time, "setTime", 1)
+        .method(Self::set_utc_date, "setUTCDate", 1)
+        .method(Self::set_utc_full_year, "setUTCFullYear", 3)
+        .method(Self::set_utc_hours, "setUTCHours", 4)
+        .method(Self::set_utc_milliseconds, "setUTCMilliseconds", 1)
+        .method(Self::set_utc_minutes, "setUTCMinutes", 3)
+        .method(Self::set_utc_month, "setUTCMonth", 2)
+        .method(Self::set_utc_seconds, "setUTCSeconds", 2)
+        .method(Self::to_date_string, "toDateString", 0)
+        .method(getter_method!(to_gmt_string), "toGMTString", 0)
+        .method(Self::to_iso_string, "toISOString", 0)
+        .method(Self::to_json, "toJSON", 1)
+        // Locale strings
+        .method(Self::to_string, "toString", 0)
+        .method(Self::to_time_string, "toTimeString", 0)
+        .method(getter_method!(to_utc_string), "toUTCString", 0)
+        .method(getter_method!(value_of), "valueOf", 0)
+        .method(
+            Self::to_primitive,
+            (WellKnownSymbols::to_primitive(), "[Symbol.toPrimitive]"),
+            1,
+        )
+        .static_method(Self::now, "now", 0)
+        .static_method(Self::parse, "parse", 1)
+        .static_method(Self::utc, "UTC", 7)
+        .build();
 
         date_object.into()
     }
@@ -343,11 +347,8 @@ impl Date {
         if new_target.is_undefined() {
             Ok(Self::make_date_string())
         } else {
-            let prototype = get_prototype_from_constructor(
-                new_target,
-                StandardObjects::object_object,
-                context,
-            )?;
+            let prototype =
+                get_prototype_from_constructor(new_target, StandardObjects::date_object, context)?;
             Ok(if args.is_empty() {
                 Self::make_date_now(prototype)
             } else if args.len() == 1 {
@@ -406,10 +407,7 @@ impl Date {
         let tv = match this_time_value(value, context) {
             Ok(dt) => dt.0,
             _ => match value.to_primitive(context, PreferredType::Default)? {
-                JsValue::String(ref str) => match chrono::DateTime::parse_from_rfc3339(str) {
-                    Ok(dt) => Some(dt.naive_utc()),
-                    _ => None,
-                },
+                JsValue::String(ref str) => parse_date_string(str),
                 tv => {
                     let tv = tv.to_number(context)?;
                     if tv.is_nan() {
@@ -1846,10 +1844,10 @@ impl Date {
             return Ok(JsValue::nan());
         }
 
-        match DateTime::parse_from_rfc3339(&args[0].to_string(context)?) {
-            Ok(v) => Ok(JsValue::new(v.naive_utc().timestamp_millis() as f64)),
-            _ => Ok(JsValue::new(f64::NAN)),
-        }
+        let date = args[0].to_string(context)?;
+        Ok(parse_date_string(&date)
+            .and_then(|date| Self::time_clip(date.timestamp_millis() as f64))
+            .map_or_else(JsValue::nan, JsValue::new))
     }
 
     /// `Date.UTC()`
@@ -1929,3 +1927,190 @@ pub fn this_time_value(value: &JsValue, context: &mut Context) -> JsResult<Date>
         .and_then(|obj| obj.borrow().as_date().copied())
         .ok_or_else(|| context.construct_type_error("'this' is not a Date"))
 }
+
+/// Parses `date` as a UTC date and time.
+///
+/// The string must be in the [Date Time String Format][spec], or in one of the formats returned by
+/// `Date.prototype.toString` and `Date.prototype.toUTCString`, so that `Date.parse` can read the
+/// result of any of them back.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date-time-string-format
+fn parse_date_string(date: &str) -> Option<NaiveDateTime> {
+    if let Some(date_time) = DateTimeStringParser::new(date).parse() {
+        return Some(date_time);
+    }
+
+    // `toString` may be followed by the name of the time zone, like `(Central European Time)`.
+    let without_zone_name = match date.find(" (") {
+        Some(index) if date.ends_with(')') => &date[..index],
+        _ => date,
+    };
+    DateTime::parse_from_str(without_zone_name, "%a %b %d %Y %H:%M:%S GMT%z")
+        .map(|date_time| date_time.naive_utc())
+        .or_else(|_| NaiveDateTime::parse_from_str(date, "%a, %d %b %Y %H:%M:%S GMT"))
+        .ok()
+}
+
+/// A parser for the [Date Time String Format][spec], a simplification of ISO 8601:
+/// `YYYY-MM-DDTHH:mm:ss.sssZ`.
+///
+/// Every element after the year is optional, but the offset can only follow a time. The year can
+/// also be written with a sign and six digits, like `+002023` or `-000001`. Date-only forms are in
+/// UTC, while a time without an offset is in local time.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date-time-string-format
+#[derive(Debug)]
+struct DateTimeStringParser<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> DateTimeStringParser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input: input.as_bytes(),
+            position: 0,
+        }
+    }
+
+    fn parse(mut self) -> Option<NaiveDateTime> {
+        let year = match self.peek()? {
+            b'+' => {
+                self.position += 1;
+                self.digits(6)? as i32
+            }
+            b'-' => {
+                self.position += 1;
+                // The year zero can't be written as `-000000`.
+                match self.digits(6)? {
+                    0 => return None,
+                    year => -(year as i32),
+                }
+            }
+            _ => self.digits(4)? as i32,
+        };
+        let (month, day) = if self.next_if(b'-') {
+            let month = self.digits(2)?;
+            let day = if self.next_if(b'-') {
+                self.digits(2)?
+            } else {
+                1
+            };
+            (month, day)
+        } else {
+            (1, 1)
+        };
+        let date = NaiveDate::from_ymd_opt(year, month, day)?;
+
+        if self.peek().is_none() {
+            return Some(date.and_hms(0, 0, 0));
+        }
+        if !self.next_if(b'T') && !self.next_if(b't') {
+            return None;
+        }
+
+        let hour = self.digits(2)?;
+        if !self.next_if(b':') {
+            return None;
+        }
+        let minute = self.digits(2)?;
+        let mut second = 0;
+        let mut millisecond = 0;
+        if self.next_if(b':') {
+            second = self.digits(2)?;
+            if self.next_if(b'.') {
+                // Only the milliseconds are kept from the fraction.
+                let start = self.position;
+                while matches!(self.peek(), Some(b'0'..=b'9')) {
+                    self.position += 1;
+                }
+                let fraction = &self.input[start..self.position];
+                if fraction.is_empty() {
+                    return None;
+                }
+                millisecond = fraction
+                    .iter()
+                    .chain(b"00")
+                    .take(3)
+                    .fold(0, |ms, digit| ms * 10 + u32::from(digit - b'0'));
+            }
+        }
+
+        // `24:00` is the end of the day, and the same instant as `00:00` on the next day.
+        let (hour, date) = if hour == 24 && minute == 0 && second == 0 && millisecond == 0 {
+            (0, date.succ_opt()?)
+        } else {
+            (hour, date)
+        };
+        let date_time = date.and_time(NaiveTime::from_hms_milli_opt(
+            hour,
+            minute,
+            second,
+            millisecond,
+        )?);
+
+        let offset = match self.peek() {
+            None => {
+                // Converting from the local time zone panics instead of failing when the result
+                // overflows, so date-times within a day of the limits of `NaiveDateTime` are
+                // rejected, as no offset is longer than that.
+                date_time.checked_sub_signed(Duration::days(1))?;
+                date_time.checked_add_signed(Duration::days(1))?;
+                return ignore_ambiguity(Local.from_local_datetime(&date_time))
+                    .map(|dt| dt.naive_utc());
+            }
+            Some(b'Z' | b'z') => {
+                self.position += 1;
+                0
+            }
+            Some(sign @ (b'+' | b'-')) => {
+                self.position += 1;
+                let hours = self.digits(2)?;
+                self.next_if(b':');
+                let minutes = self.digits(2)?;
+                if hours > 23 || minutes > 59 {
+                    return None;
+                }
+                let offset = i64::from(hours * 60 + minutes);
+                if sign == b'-' {
+                    -offset
+                } else {
+                    offset
+                }
+            }
+            Some(_) => return None,
+        };
+
+        if self.peek().is_some() {
+            return None;
+        }
+        date_time.checked_sub_signed(Duration::minutes(offset))
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.position).copied()
+    }
+
+    fn next_if(&mut self, expected: u8) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Reads exactly `count` decimal digits.
+    fn digits(&mut self, count: usize) -> Option<u32> {
+        let digits = self.input.get(self.position..self.position + count)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        self.position += count;
+        Some(
+            digits
+                .iter()
+                .fold(0, |value, digit| value * 10 + u32::from(digit - b'0')),
+        )
+    }
+}
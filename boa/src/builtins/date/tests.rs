@@ -120,6 +120,162 @@ fn date_ctor_call_string_invalid() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn date_ctor_call_string_date_only_is_utc() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    for (src, expected) in [
+        ("new Date('2023-01-15')", NaiveDate::from_ymd(2023, 01, 15)),
+        ("new Date('2023-01')", NaiveDate::from_ymd(2023, 01, 01)),
+        ("new Date('2023')", NaiveDate::from_ymd(2023, 01, 01)),
+        (
+            "new Date('+002023-01-15')",
+            NaiveDate::from_ymd(2023, 01, 15),
+        ),
+        ("new Date('-000001-12-31')", NaiveDate::from_ymd(-1, 12, 31)),
+    ] {
+        assert_eq!(
+            Some(expected.and_hms(0, 0, 0)),
+            forward_dt_utc(&mut context, src),
+            "{}",
+            src
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn date_ctor_call_string_date_time_without_offset_is_local(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    for (src, expected) in [
+        (
+            "new Date('2023-01-15T10:30')",
+            NaiveDate::from_ymd(2023, 01, 15).and_hms(10, 30, 0),
+        ),
+        (
+            "new Date('2023-01-15T10:30:45')",
+            NaiveDate::from_ymd(2023, 01, 15).and_hms(10, 30, 45),
+        ),
+        (
+            "new Date('2023-01-15T10:30:45.5')",
+            NaiveDate::from_ymd(2023, 01, 15).and_hms_milli(10, 30, 45, 500),
+        ),
+        (
+            "new Date('2023T10:30')",
+            NaiveDate::from_ymd(2023, 01, 01).and_hms(10, 30, 0),
+        ),
+    ] {
+        assert_eq!(
+            Some(expected),
+            forward_dt_local(&mut context, src),
+            "{}",
+            src
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn date_ctor_call_string_with_offset() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    for (src, expected) in [
+        (
+            "new Date('2023-01-15T10:30:00.000Z')",
+            NaiveDate::from_ymd(2023, 01, 15).and_hms(10, 30, 0),
+        ),
+        (
+            "new Date('2023-01-15T10:30:00+05:30')",
+            NaiveDate::from_ymd(2023, 01, 15).and_hms(5, 0, 0),
+        ),
+        (
+            "new Date('2023-01-15T10:30:00-0800')",
+            NaiveDate::from_ymd(2023, 01, 15).and_hms(18, 30, 0),
+        ),
+        (
+            "new Date('2023-01-15T10:30:00.123456Z')",
+            NaiveDate::from_ymd(2023, 01, 15).and_hms_milli(10, 30, 0, 123),
+        ),
+        (
+            "new Date('2023-01-15T24:00:00Z')",
+            NaiveDate::from_ymd(2023, 01, 16).and_hms(0, 0, 0),
+        ),
+    ] {
+        assert_eq!(Some(expected), forward_dt_utc(&mut context, src), "{}", src);
+    }
+    Ok(())
+}
+
+#[test]
+fn date_ctor_call_string_malformed_is_invalid() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    for src in [
+        "",
+        "2023-13-01",
+        "2023-02-30",
+        "2023-1-15",
+        "20230115",
+        "2023-01-15T10",
+        "2023-01-15T24:00:01Z",
+        "2023-01-15T10:30:60Z",
+        "2023-01-15T10:30:00.Z",
+        "2023-01-15T10:30:00+05",
+        "2023-01-15T10:30:00+24:00",
+        "2023-01-15T10:30:00Z trailing",
+        "-000000-01-01T00:00:00Z",
+    ] {
+        assert_eq!(
+            None,
+            forward_dt_utc(&mut context, &format!("new Date('{}')", src)),
+            "{}",
+            src
+        );
+        assert_eq!(
+            "NaN",
+            forward(&mut context, &format!("Date.parse('{}')", src)),
+            "{}",
+            src
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn date_ctor_parse_at_the_year_limits() {
+    let mut context = Context::new();
+
+    for (src, expected) in [
+        ("+262143-12-31T23:59Z", "8210298412740000"),
+        ("-262144-01-01T00:00Z", "-8334632851200000"),
+        // The offset moves these date-times past the limits.
+        ("+262143-12-31T23:59-01:00", "NaN"),
+        ("-262144-01-01T00:00+01:00", "NaN"),
+        // Local date-times this close to the limits could overflow in any time zone.
+        ("+262143-12-31T23:59", "NaN"),
+        ("-262144-01-01T00:00", "NaN"),
+    ] {
+        assert_eq!(
+            forward(&mut context, &format!("Date.parse('{}')", src)),
+            expected,
+            "{}",
+            src
+        );
+    }
+
+    // Local date-times further from the limits are still parsed.
+    for src in ["+262143-12-29T00:00", "-262144-01-03T00:00"] {
+        assert_eq!(
+            forward(&mut context, &format!("isNaN(Date.parse('{}'))", src)),
+            "false",
+            "{}",
+            src
+        );
+    }
+}
+
 #[test]
 fn date_ctor_call_number() -> Result<(), Box<dyn std::error::Error>> {
     let mut context = Context::new();
@@ -216,6 +372,46 @@ fn date_ctor_parse_call() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn date_ctor_parse_call_reads_date_strings() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    assert_eq!(
+        Ok(JsValue::new(1673740800000f64)),
+        forward_val(&mut context, "Date.parse('2023-01-15')")
+    );
+    assert_eq!(
+        Ok(JsValue::new(1673778600000f64)),
+        forward_val(&mut context, "Date.parse('2023-01-15T10:30:00.000Z')")
+    );
+    assert_eq!(
+        Ok(JsValue::new(1673778600000f64)),
+        forward_val(&mut context, "Date.parse('Sun, 15 Jan 2023 10:30:00 GMT')")
+    );
+    assert_eq!(
+        Ok(JsValue::new(1673778600000f64)),
+        forward_val(
+            &mut context,
+            "Date.parse('Sun Jan 15 2023 16:00:00 GMT+0530 (India Standard Time)')"
+        )
+    );
+
+    // The strings returned by `toString`, `toUTCString` and `toISOString` can be read back.
+    forward(&mut context, "var date = new Date(2023, 0, 15, 10, 30, 15)");
+    for method in ["toString", "toUTCString", "toISOString"] {
+        assert_eq!(
+            "true",
+            forward(
+                &mut context,
+                &format!("Date.parse(date.{}()) === date.getTime()", method)
+            ),
+            "{}",
+            method
+        );
+    }
+    Ok(())
+}
+
 #[test]
 fn date_ctor_utc_call() -> Result<(), Box<dyn std::error::Error>> {
     let mut context = Context::new();
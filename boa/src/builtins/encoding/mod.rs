@@ -0,0 +1,22 @@
+//! This module implements the global `TextEncoder` and `TextDecoder` objects.
+//!
+//! Only the UTF-8 encoding is supported, which is the only encoding `TextEncoder` is defined
+//! for and the default of `TextDecoder`.
+//!
+//! More information:
+//!  - [Encoding specification][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://encoding.spec.whatwg.org/#interface-textencoder
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Encoding_API
+
+pub mod text_decoder;
+pub mod text_encoder;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use self::{text_decoder::TextDecoder, text_encoder::TextEncoder};
+
+/// The name of the only encoding supported by Boa.
+const UTF_8: &str = "utf-8";
@@ -0,0 +1,152 @@
+use crate::{forward, Context};
+
+#[test]
+fn encode_ascii() {
+    let mut context = Context::new();
+    let init = r#"
+        var encoded = new TextEncoder().encode("Boa");
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "encoded instanceof Uint8Array"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "encoded.join()"), "\"66,111,97\"");
+    assert_eq!(
+        forward(&mut context, "new TextEncoder().encode().length"),
+        "0"
+    );
+    assert_eq!(
+        forward(&mut context, "new TextEncoder().encoding"),
+        "\"utf-8\""
+    );
+}
+
+#[test]
+fn encode_multi_byte() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "new TextEncoder().encode('é€😀').join()"),
+        "\"195,169,226,130,172,240,159,152,128\""
+    );
+}
+
+#[test]
+fn encode_lone_surrogate() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "new TextEncoder().encode('a\\uD800b').join()"),
+        "\"97,239,191,189,98\""
+    );
+}
+
+#[test]
+fn round_trip() {
+    let mut context = Context::new();
+    let init = r#"
+        var encoder = new TextEncoder();
+        var decoder = new TextDecoder();
+        function roundTrip(string) {
+            return decoder.decode(encoder.encode(string)) === string;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "roundTrip('')"), "true");
+    assert_eq!(forward(&mut context, "roundTrip('hello, world')"), "true");
+    assert_eq!(forward(&mut context, "roundTrip('ünïcödé € 😀')"), "true");
+}
+
+#[test]
+fn decode_buffer_sources() {
+    let mut context = Context::new();
+    let init = r#"
+        var decoder = new TextDecoder();
+        var bytes = new Uint8Array([0, 104, 105, 0]);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "decoder.decode(bytes.subarray(1, 3))"),
+        "\"hi\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "decoder.decode(new DataView(bytes.buffer, 1, 2))"
+        ),
+        "\"hi\""
+    );
+    assert_eq!(
+        forward(&mut context, "decoder.decode(bytes.buffer).length"),
+        "4"
+    );
+    assert_eq!(forward(&mut context, "decoder.decode()"), "\"\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { decoder.decode('hi') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn decode_invalid_input() {
+    let mut context = Context::new();
+    let init = r#"
+        var invalid = new Uint8Array([97, 0xFF, 98]);
+        var fatal = new TextDecoder("utf-8", { fatal: true });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "new TextDecoder().decode(invalid)"),
+        "\"a\u{FFFD}b\""
+    );
+    assert_eq!(forward(&mut context, "fatal.fatal"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { fatal.decode(invalid) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn decode_bom() {
+    let mut context = Context::new();
+    let init = r#"
+        var bytes = new Uint8Array([0xEF, 0xBB, 0xBF, 97]);
+        var ignoring = new TextDecoder("utf8", { ignoreBOM: true });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "new TextDecoder().decode(bytes)"),
+        "\"a\""
+    );
+    assert_eq!(forward(&mut context, "ignoring.ignoreBOM"), "true");
+    assert_eq!(
+        forward(&mut context, "ignoring.decode(bytes)"),
+        "\"\u{FEFF}a\""
+    );
+}
+
+#[test]
+fn unsupported_label() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new TextDecoder('latin1') } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "new TextDecoder(' UTF-8 ').encoding"),
+        "\"utf-8\""
+    );
+}
@@ -0,0 +1,289 @@
+//! This module implements the global `TextDecoder` object.
+//!
+//! More information:
+//!  - [Encoding specification][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://encoding.spec.whatwg.org/#interface-textdecoder
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder
+
+use super::UTF_8;
+use crate::{
+    builtins::{BuiltIn, JsArgs},
+    context::StandardObjects,
+    gc::{Finalize, Trace},
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+/// The UTF-8 byte order mark.
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// The internal representation of a `TextDecoder` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct TextDecoder {
+    fatal: bool,
+    ignore_bom: bool,
+}
+
+impl BuiltIn for TextDecoder {
+    const NAME: &'static str = "TextDecoder";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_encoding = FunctionBuilder::native(context, Self::get_encoding)
+            .name("get encoding")
+            .constructor(false)
+            .build();
+        let get_fatal = FunctionBuilder::native(context, Self::get_fatal)
+            .name("get fatal")
+            .constructor(false)
+            .build();
+        let get_ignore_bom = FunctionBuilder::native(context, Self::get_ignore_bom)
+            .name("get ignoreBOM")
+            .constructor(false)
+            .build();
+
+        let text_decoder = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().text_decoder_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .accessor(
+            "encoding",
+            Some(get_encoding),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor("fatal", Some(get_fatal), None, Attribute::CONFIGURABLE)
+        .accessor(
+            "ignoreBOM",
+            Some(get_ignore_bom),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::decode, "decode", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        text_decoder.into()
+    }
+}
+
+impl TextDecoder {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `new TextDecoder( [label [, options]] )`
+    ///
+    /// Only the `"utf-8"` encoding and its aliases are supported, any other label throws a
+    /// `RangeError`.
+    ///
+    /// More information:
+    ///  - [Encoding specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textdecoder
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/TextDecoder
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin TextDecoder constructor without new is forbidden",
+            );
+        }
+
+        // 1. Let encoding be the result of getting an encoding from label.
+        // 2. If encoding is failure or replacement, then throw a RangeError.
+        let label = args.get_or_undefined(0);
+        if !label.is_undefined() {
+            let label = label.to_string(context)?;
+            let label = label
+                .trim_matches(|c| matches!(c, '\t' | '\n' | '\x0C' | '\r' | ' '))
+                .to_ascii_lowercase();
+            if !matches!(label.as_str(), "utf-8" | "utf8" | "unicode-1-1-utf-8") {
+                return context
+                    .throw_range_error(format!("TextDecoder: unsupported encoding '{}'", label));
+            }
+        }
+
+        // 3. If options["fatal"] is true, then set this's error mode to "fatal".
+        // 4. Set this's ignore BOM to options["ignoreBOM"].
+        let (fatal, ignore_bom) = match args.get_or_undefined(1) {
+            JsValue::Undefined | JsValue::Null => (false, false),
+            JsValue::Object(options) => (
+                options.get("fatal", context)?.to_boolean(),
+                options.get("ignoreBOM", context)?.to_boolean(),
+            ),
+            _ => return context.throw_type_error("TextDecoder: options must be an object"),
+        };
+
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardObjects::text_decoder_object,
+            context,
+        )?;
+        let decoder = JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::text_decoder(Self { fatal, ignore_bom }),
+        );
+
+        Ok(decoder.into())
+    }
+
+    /// Returns the state of the `this` value, or throws a `TypeError` if it isn't a
+    /// `TextDecoder` object.
+    fn this_decoder(this: &JsValue, context: &mut Context) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_text_decoder().cloned())
+            .ok_or_else(|| context.construct_type_error("`this` is not a TextDecoder"))
+    }
+
+    /// `get TextDecoder.prototype.encoding`
+    ///
+    /// More information:
+    ///  - [Encoding specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textdecoder-encoding
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/encoding
+    pub(crate) fn get_encoding(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::this_decoder(this, context)?;
+        Ok(UTF_8.into())
+    }
+
+    /// `get TextDecoder.prototype.fatal`
+    ///
+    /// More information:
+    ///  - [Encoding specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textdecoder-fatal
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/fatal
+    pub(crate) fn get_fatal(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Ok(Self::this_decoder(this, context)?.fatal.into())
+    }
+
+    /// `get TextDecoder.prototype.ignoreBOM`
+    ///
+    /// More information:
+    ///  - [Encoding specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textdecoder-ignorebom
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/ignoreBOM
+    pub(crate) fn get_ignore_bom(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Ok(Self::this_decoder(this, context)?.ignore_bom.into())
+    }
+
+    /// `TextDecoder.prototype.decode( [input] )`
+    ///
+    /// Decodes the bytes of an `ArrayBuffer`, typed array or `DataView` as UTF-8. Invalid
+    /// sequences are replaced with U+FFFD, or throw a `TypeError` if the decoder is fatal.
+    ///
+    /// More information:
+    ///  - [Encoding specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textdecoder-decode
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/decode
+    pub(crate) fn decode(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let decoder = Self::this_decoder(this, context)?;
+
+        let bytes = match args.get_or_undefined(0) {
+            JsValue::Undefined => Vec::new(),
+            JsValue::Object(input) => buffer_source_bytes(input).ok_or_else(|| {
+                context.construct_type_error(
+                    "TextDecoder: input must be an ArrayBuffer or an ArrayBuffer view",
+                )
+            })?,
+            _ => {
+                return context.throw_type_error(
+                    "TextDecoder: input must be an ArrayBuffer or an ArrayBuffer view",
+                )
+            }
+        };
+
+        let bytes = match bytes.strip_prefix(BOM) {
+            Some(rest) if !decoder.ignore_bom => rest,
+            _ => &bytes,
+        };
+
+        if decoder.fatal {
+            match std::str::from_utf8(bytes) {
+                Ok(string) => Ok(string.into()),
+                Err(_) => context.throw_type_error("TextDecoder: the input is not valid UTF-8"),
+            }
+        } else {
+            Ok(String::from_utf8_lossy(bytes).as_ref().into())
+        }
+    }
+}
+
+/// Copies the bytes viewed by an `ArrayBuffer`, typed array or `DataView` object.
+///
+/// Returns `None` if `object` is none of those. A detached buffer has no bytes.
+fn buffer_source_bytes(object: &JsObject) -> Option<Vec<u8>> {
+    let object = object.borrow();
+    let (buffer, offset, length) = if let Some(buffer) = object.as_array_buffer() {
+        return Some(buffer.array_buffer_data.clone().unwrap_or_default());
+    } else if let Some(typed_array) = object.as_typed_array() {
+        (
+            typed_array.viewed_array_buffer()?.clone(),
+            typed_array.byte_offset(),
+            typed_array.byte_length(),
+        )
+    } else if let Some(data_view) = object.as_data_view() {
+        (
+            data_view.viewed_array_buffer().clone(),
+            data_view.byte_offset(),
+            data_view.byte_length(),
+        )
+    } else {
+        return None;
+    };
+    drop(object);
+
+    let buffer = buffer.borrow();
+    let bytes = buffer
+        .as_array_buffer()
+        .and_then(|buffer| buffer.array_buffer_data.as_ref())
+        .and_then(|data| data.get(offset..offset + length))
+        .map(<[u8]>::to_vec)
+        .unwrap_or_default();
+    Some(bytes)
+}
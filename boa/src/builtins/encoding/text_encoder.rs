@@ -0,0 +1,176 @@
+//! This module implements the global `TextEncoder` object.
+//!
+//! More information:
+//!  - [Encoding specification][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://encoding.spec.whatwg.org/#interface-textencoder
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder
+
+use super::UTF_8;
+use crate::{
+    builtins::{array_buffer::ArrayBuffer, BuiltIn, JsArgs},
+    context::StandardObjects,
+    gc::{Finalize, Trace},
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+/// The internal representation of a `TextEncoder` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct TextEncoder;
+
+impl BuiltIn for TextEncoder {
+    const NAME: &'static str = "TextEncoder";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_encoding = FunctionBuilder::native(context, Self::get_encoding)
+            .name("get encoding")
+            .constructor(false)
+            .build();
+
+        let text_encoder = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().text_encoder_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .accessor(
+            "encoding",
+            Some(get_encoding),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::encode, "encode", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        text_encoder.into()
+    }
+}
+
+impl TextEncoder {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `new TextEncoder()`
+    ///
+    /// More information:
+    ///  - [Encoding specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textencoder
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder/TextEncoder
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin TextEncoder constructor without new is forbidden",
+            );
+        }
+
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardObjects::text_encoder_object,
+            context,
+        )?;
+
+        Ok(JsObject::from_proto_and_data(prototype, ObjectData::text_encoder(Self)).into())
+    }
+
+    /// Throws a `TypeError` if the `this` value isn't a `TextEncoder` object.
+    fn require_text_encoder(this: &JsValue, context: &mut Context) -> JsResult<()> {
+        if this
+            .as_object()
+            .map(|object| object.borrow().as_text_encoder().is_some())
+            .unwrap_or_default()
+        {
+            Ok(())
+        } else {
+            Err(context.construct_type_error("`this` is not a TextEncoder"))
+        }
+    }
+
+    /// `get TextEncoder.prototype.encoding`
+    ///
+    /// Always returns `"utf-8"`.
+    ///
+    /// More information:
+    ///  - [Encoding specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textencoder-encoding
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder/encoding
+    pub(crate) fn get_encoding(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::require_text_encoder(this, context)?;
+        Ok(UTF_8.into())
+    }
+
+    /// `TextEncoder.prototype.encode( [input] )`
+    ///
+    /// Returns a new `Uint8Array` holding the UTF-8 encoding of `input`.
+    ///
+    /// Strings are stored as UTF-8 internally, so lone surrogates have already been replaced
+    /// with U+FFFD and are encoded as `EF BF BD`.
+    ///
+    /// More information:
+    ///  - [Encoding specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textencoder-encode
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder/encode
+    pub(crate) fn encode(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::require_text_encoder(this, context)?;
+
+        let input = match args.get_or_undefined(0) {
+            JsValue::Undefined => "".into(),
+            input => input.to_string(context)?,
+        };
+        let bytes = input.as_bytes();
+
+        let array_buffer_constructor = context
+            .standard_objects()
+            .array_buffer_object()
+            .constructor();
+        let buffer = ArrayBuffer::allocate(&array_buffer_constructor.into(), bytes.len(), context)?;
+        if let Some(data) = buffer
+            .borrow_mut()
+            .as_array_buffer_mut()
+            .and_then(|buffer| buffer.array_buffer_data.as_mut())
+        {
+            data.copy_from_slice(bytes);
+        }
+
+        let uint8_array = context
+            .standard_objects()
+            .typed_uint8_array_object()
+            .constructor();
+        uint8_array.construct(&[buffer.into()], &uint8_array.clone().into(), context)
+    }
+}
@@ -0,0 +1,129 @@
+//! This module implements the global `AggregateError` object.
+//!
+//! Represents several errors wrapped in a single error, like the rejection reasons of all the
+//! promises given to `Promise.any`.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
+
+use crate::{
+    builtins::{iterable::iterable_to_list, Array, BuiltIn, JsArgs},
+    context::StandardObjects,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+    },
+    profiler::BoaProfiler,
+    property::{Attribute, PropertyDescriptor},
+    Context, JsResult, JsValue,
+};
+
+/// JavaScript `AggregateError` implementation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AggregateError;
+
+impl BuiltIn for AggregateError {
+    const NAME: &'static str = "AggregateError";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let error_prototype = context.standard_objects().error_object().prototype();
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let aggregate_error_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().aggregate_error_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .inherit(error_prototype)
+        .property("name", Self::NAME, attribute)
+        .property("message", "", attribute)
+        .build();
+
+        aggregate_error_object.into()
+    }
+}
+
+impl AggregateError {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 2;
+
+    /// `AggregateError ( errors, message )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-aggregate-error
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, let newTarget be the active function object; else let newTarget be NewTarget.
+        // 2. Let O be ? OrdinaryCreateFromConstructor(newTarget, "%AggregateError.prototype%", « [[ErrorData]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardObjects::aggregate_error_object,
+            context,
+        )?;
+        let obj = JsObject::from_proto_and_data(prototype, ObjectData::error());
+
+        // 3. If message is not undefined, then
+        let message = args.get_or_undefined(1);
+        if !message.is_undefined() {
+            // a. Let msg be ? ToString(message).
+            // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", msg).
+            obj.set("message", message.to_string(context)?, false, context)?;
+        }
+
+        // 4. Let errorsList be ? IterableToList(errors).
+        let errors = iterable_to_list(context, args.get_or_undefined(0).clone(), None)?;
+
+        // 5. Perform ! DefinePropertyOrThrow(O, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: CreateArrayFromList(errorsList) }).
+        Self::define_errors(&obj, errors, context);
+
+        // 6. Return O.
+        Ok(obj.into())
+    }
+
+    /// Creates an `AggregateError` object wrapping `errors`, with the given `message`.
+    pub(crate) fn create(errors: Vec<JsValue>, message: &str, context: &mut Context) -> JsObject {
+        let prototype = context
+            .standard_objects()
+            .aggregate_error_object()
+            .prototype();
+        let obj = JsObject::from_proto_and_data(prototype, ObjectData::error());
+        obj.insert_property(
+            "message",
+            PropertyDescriptor::builder()
+                .value(message)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+        );
+        Self::define_errors(&obj, errors, context);
+        obj
+    }
+
+    /// Defines the `errors` property of an `AggregateError` object.
+    fn define_errors(obj: &JsObject, errors: Vec<JsValue>, context: &mut Context) {
+        let errors = Array::create_array_from_list(errors, context);
+        obj.insert_property(
+            "errors",
+            PropertyDescriptor::builder()
+                .value(errors)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+        );
+    }
+}
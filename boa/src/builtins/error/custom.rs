@@ -0,0 +1,106 @@
+//! This module implements the error types registered by the host with
+//! [`Context::register_error_type`].
+//!
+//! They behave like the native error types of the specification, like `RangeError`: the
+//! constructor inherits from `Error`, and its prototype from `Error.prototype`, so the errors are
+//! `instanceof Error` in JavaScript.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-nativeerror-object-structure
+
+use crate::{
+    builtins::JsArgs,
+    context::StandardObjects,
+    object::{
+        internal_methods::get_prototype_from_constructor, FunctionBuilder, JsObject, ObjectData,
+    },
+    property::PropertyDescriptor,
+    Context, JsResult, JsValue,
+};
+
+/// An error type registered by the host.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CustomError;
+
+impl CustomError {
+    /// The amount of arguments the constructors take.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Creates the constructor of a new error type named `name`.
+    pub(crate) fn create(name: &str, context: &mut Context) -> JsObject {
+        let error = context.standard_objects().error_object().clone();
+        let prototype = JsObject::from_proto_and_data(error.prototype(), ObjectData::ordinary());
+
+        let constructor =
+            FunctionBuilder::native_with_data(context, Self::constructor, prototype.clone())
+                .name(name)
+                .length(Self::LENGTH)
+                .constructor(true)
+                .build();
+        constructor.set_prototype(Some(error.constructor()));
+        constructor.insert_property(
+            "prototype",
+            PropertyDescriptor::builder()
+                .value(prototype.clone())
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+        );
+
+        let property = PropertyDescriptor::builder()
+            .writable(true)
+            .enumerable(false)
+            .configurable(true);
+        prototype.insert_property("constructor", property.clone().value(constructor.clone()));
+        prototype.insert_property("name", property.clone().value(name));
+        prototype.insert_property("message", property.value(""));
+
+        constructor
+    }
+
+    /// `NativeError ( message )`
+    ///
+    /// Creates a new error of the registered type.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-nativeerror
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, let newTarget be the active function object; else let newTarget be NewTarget.
+        // 2. Let O be ? OrdinaryCreateFromConstructor(newTarget, "%NativeError.prototype%", « [[ErrorData]] »).
+        let prototype = if new_target.is_undefined() {
+            context
+                .current_function_data::<JsObject>()
+                .expect("custom error constructors must have their prototype")
+                .clone()
+        } else {
+            get_prototype_from_constructor(new_target, StandardObjects::error_object, context)?
+        };
+        let obj = JsObject::from_proto_and_data(prototype, ObjectData::error());
+
+        // 3. If message is not undefined, then
+        let message = args.get_or_undefined(0);
+        if !message.is_undefined() {
+            // a. Let msg be ? ToString(message).
+            // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", msg).
+            obj.insert_property(
+                "message",
+                PropertyDescriptor::builder()
+                    .value(message.to_string(context)?)
+                    .writable(true)
+                    .enumerable(false)
+                    .configurable(true),
+            );
+        }
+
+        // 5. Return O.
+        Ok(obj.into())
+    }
+}
@@ -11,16 +11,18 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{BuiltIn, JsArgs},
     context::StandardObjects,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
     },
     profiler::BoaProfiler,
-    property::Attribute,
-    Context, JsResult, JsValue,
+    property::{Attribute, PropertyDescriptor},
+    Context, JsResult, JsString, JsValue,
 };
 
+pub(crate) mod aggregate;
+pub(crate) mod custom;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
@@ -31,6 +33,8 @@ pub(crate) mod uri;
 #[cfg(test)]
 mod tests;
 
+pub(crate) use self::aggregate::AggregateError;
+pub(crate) use self::custom::CustomError;
 pub(crate) use self::eval::EvalError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
@@ -63,6 +67,7 @@ impl BuiltIn for Error {
         .property("name", Self::NAME, attribute)
         .property("message", "", attribute)
         .method(Self::to_string, "toString", 0)
+        .static_method(Self::capture_stack_trace, "captureStackTrace", 2)
         .build();
 
         error_object.into()
@@ -92,6 +97,49 @@ impl Error {
         Ok(obj.into())
     }
 
+    /// `Error.captureStackTrace( targetObject [ , constructorOpt ] )`
+    ///
+    /// Sets a `stack` property on `targetObject`, listing the functions that are being called.
+    /// The frames above the call to `constructorOpt`, and that call itself, are left out.
+    ///
+    /// This is not part of the ECMAScript specification, it's provided for compatibility with
+    /// V8, which some libraries depend on.
+    ///
+    /// More information:
+    ///  - [V8 documentation][v8]
+    ///
+    /// [v8]: https://v8.dev/docs/stack-trace-api
+    pub(crate) fn capture_stack_trace(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let target = match args.get_or_undefined(0) {
+            JsValue::Object(target) => target.clone(),
+            _ => return context.throw_type_error("Invalid argument"),
+        };
+        let until = args.get_or_undefined(1).as_object();
+
+        let mut stack = Self::to_string(&target.clone().into(), &[], context)?
+            .to_string(context)?
+            .to_string();
+        for function in context.capture_stack_trace(until) {
+            stack.push_str("\n    at ");
+            stack.push_str(&function);
+        }
+
+        target.define_property_or_throw(
+            "stack",
+            PropertyDescriptor::builder()
+                .value(stack)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+        Ok(JsValue::undefined())
+    }
+
     /// `Error.prototype.toString()`
     ///
     /// The toString() method returns a string representing the specified Error object.
@@ -138,3 +186,35 @@ impl Error {
         }
     }
 }
+
+/// The name and message of a thrown `Error` object, returned by [`JsValue::try_native_error`].
+///
+/// This lets embedders inspect an error that came out of a script or a native function without
+/// catching it in JavaScript.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NativeError {
+    name: JsString,
+    message: JsString,
+}
+
+impl NativeError {
+    /// Reads the `name` and `message` properties of `error`, which may run getters.
+    pub(crate) fn from_object(error: &JsObject, context: &mut Context) -> JsResult<Self> {
+        Ok(Self {
+            name: error.get("name", context)?.to_string(context)?,
+            message: error.get("message", context)?.to_string(context)?,
+        })
+    }
+
+    /// Gets the name of the error, like `"TypeError"`.
+    #[inline]
+    pub fn name(&self) -> &JsString {
+        &self.name
+    }
+
+    /// Gets the message of the error.
+    #[inline]
+    pub fn message(&self) -> &JsString {
+        &self.message
+    }
+}
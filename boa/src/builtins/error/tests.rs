@@ -1,4 +1,4 @@
-use crate::{forward, Context};
+use crate::{forward, object::FunctionBuilder, Context, JsResult, JsValue};
 
 #[test]
 fn error_to_string() {
@@ -84,3 +84,125 @@ fn uri_error_to_string() {
         "\"URIError\""
     );
 }
+
+#[test]
+fn custom_error_type() {
+    fn fetch(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let http_error = context.global_object().get("HttpError", context)?;
+        let error = context.construct(&http_error, &["not found".into()])?;
+        error.set_field("status", 404, true, context)?;
+        Err(error)
+    }
+
+    let mut context = Context::new();
+    context.register_error_type("HttpError");
+    let fetch = FunctionBuilder::native(&mut context, fetch)
+        .name("fetch")
+        .build();
+    context.register_global_property("fetch", fetch, Default::default());
+
+    let src = r#"
+        var error;
+        try { fetch(); } catch (e) { error = e; }
+        "#;
+    forward(&mut context, src);
+    assert_eq!(forward(&mut context, "error instanceof Error"), "true");
+    assert_eq!(forward(&mut context, "error instanceof HttpError"), "true");
+    assert_eq!(forward(&mut context, "error.status"), "404");
+    assert_eq!(
+        forward(&mut context, "error.toString()"),
+        "\"HttpError: not found\""
+    );
+
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(HttpError) === Error"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "HttpError.length"), "1");
+    assert_eq!(
+        forward(&mut context, "HttpError('called') instanceof HttpError"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "new HttpError().hasOwnProperty('message')"),
+        "false"
+    );
+}
+
+#[test]
+fn capture_stack_trace() {
+    let mut context = Context::new();
+    let init = r#"
+        function inner(until) {
+            const target = { message: "oops" };
+            Error.captureStackTrace(target, until);
+            return target;
+        }
+        function middle(until) {
+            return inner(until);
+        }
+        function outer(until) {
+            return middle(until);
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "outer().stack"),
+        "\"Error: oops\n    at inner\n    at middle\n    at outer\""
+    );
+    assert_eq!(
+        forward(&mut context, "outer(middle).stack"),
+        "\"Error: oops\n    at outer\""
+    );
+    assert_eq!(
+        forward(&mut context, "outer(Math.max).stack"),
+        "\"Error: oops\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(outer()).includes('stack')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "typeof Error.captureStackTrace({})"),
+        "\"undefined\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Error.captureStackTrace(1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn native_error_inspection() {
+    fn parse(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        if args.is_empty() {
+            return context.throw_type_error("nothing to parse");
+        }
+        Ok(JsValue::undefined())
+    }
+
+    let mut context = Context::new();
+    context.register_global_function("parse", 1, parse).unwrap();
+
+    // An error returned by a native function, without catching it in JavaScript.
+    let error = context.eval("parse()").unwrap_err();
+    let error = error.try_native_error(&mut context).unwrap().unwrap();
+    assert_eq!(error.name().as_str(), "TypeError");
+    assert_eq!(error.message().as_str(), "nothing to parse");
+
+    let error = context
+        .eval("var e = new RangeError('failed'); e.name = 'AppError'; throw e")
+        .unwrap_err();
+    let error = error.try_native_error(&mut context).unwrap().unwrap();
+    assert_eq!(error.name().as_str(), "AppError");
+    assert_eq!(error.message().as_str(), "failed");
+
+    // Other thrown values are opaque.
+    for thrown in ["throw 'failed'", "throw { message: 'failed' }"] {
+        let error = context.eval(thrown).unwrap_err();
+        assert_eq!(error.try_native_error(&mut context).unwrap(), None);
+    }
+}
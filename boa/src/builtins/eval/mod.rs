@@ -0,0 +1,217 @@
+//! This module implements the global `eval` function.
+//!
+//! `eval` parses its argument as a script and runs it. A direct call, `eval(code)`, runs the code
+//! in the scope of the caller, while any other call runs it in the global scope.
+//!
+//! With the `vm` feature, calls through the `eval` name are compiled to `CallEval`, which makes
+//! them direct evals in the environment of the calling frame. The code is compiled with
+//! `ByteCompiler::compile_script` and run in a call frame of its own, which shares the `this` of
+//! the caller for a direct eval and uses the global object otherwise.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-eval-x
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/eval
+
+use crate::{
+    builtins::{BuiltIn, JsArgs},
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        function_environment_record::{BindingStatus, FunctionEnvironmentRecord},
+    },
+    object::FunctionBuilder,
+    property::Attribute,
+    syntax::{ast::node::StatementList, Parser},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(not(feature = "vm"))]
+use crate::Executable;
+
+#[cfg(feature = "vm")]
+use crate::{
+    bytecompiler::ByteCompiler,
+    vm::{CallFrame, CompletionType},
+    JsString,
+};
+#[cfg(feature = "vm")]
+use gc::Gc;
+
+#[cfg(test)]
+mod tests;
+
+/// The JavaScript `eval` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Eval;
+
+impl BuiltIn for Eval {
+    const NAME: &'static str = "eval";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        FunctionBuilder::native(context, Self::eval)
+            .name(Self::NAME)
+            .length(1)
+            .constructor(false)
+            .build()
+            .into()
+    }
+}
+
+impl Eval {
+    /// `eval( x )`
+    ///
+    /// Called as a function value, like `(0, eval)(x)` or `globalThis.eval(x)`, this is an
+    /// indirect eval: `x` runs as a script in the global scope. Direct calls, `eval(x)`, are
+    /// caught by the call expression and go to [`Eval::perform_eval`] instead.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-eval-x
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/eval
+    fn eval(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? PerformEval(x, false, false).
+        Self::perform_eval(args.get_or_undefined(0), false, false, context)
+    }
+
+    /// `PerformEval ( x, strictCaller, direct )`
+    ///
+    /// Runs `x` as a script and returns its completion value. Any other value than a string is
+    /// returned as is.
+    ///
+    /// A direct eval runs in the scope of the caller, and its `var` declarations are added to the
+    /// function scope of the caller. An indirect eval runs in the global scope. In strict mode,
+    /// `var` declarations stay inside the eval.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub(crate) fn perform_eval(
+        x: &JsValue,
+        strict_caller: bool,
+        direct: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Assert: If direct is false, then strictCaller is also false.
+        debug_assert!(direct || !strict_caller);
+
+        // 2. If Type(x) is not String, return x.
+        let source = match x {
+            JsValue::String(source) => source.clone(),
+            x => return Ok(x.clone()),
+        };
+
+        // 5. Perform ? HostEnsureCanCompileStrings(evalRealm).
+        context.host_hooks().ensure_can_compile_strings(context)?;
+
+        // 11. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
+        //     a. Let script be ParseText(! StringToCodePoints(x), Script).
+        //     b. If script is a List of errors, throw a SyntaxError exception.
+        let mut script = match Parser::new(source.as_bytes(), strict_caller).parse_all() {
+            Ok(script) => script,
+            Err(error) => return context.throw_syntax_error(error.to_string()),
+        };
+
+        // 13. If strictCaller is true, let strictEval be true.
+        // 14. Else, let strictEval be IsStrict of script.
+        if strict_caller {
+            script.set_strict(true);
+        }
+        let strict_eval = script.strict();
+
+        // 16. If direct is true, then
+        //     a. Let lexEnv be NewDeclarativeEnvironment(runningContext's LexicalEnvironment).
+        // 17. Else,
+        //     a. Let lexEnv be NewDeclarativeEnvironment(evalRealm.[[GlobalEnv]]).
+        let outer = if direct {
+            context.get_current_environment()
+        } else {
+            context.get_global_environment()
+        };
+
+        // 18. If strictEval is true, set varEnv to lexEnv.
+        // Without a function environment, `var` declarations would go past the eval to the
+        // nearest function scope, so strict code gets one that keeps the `this` of the caller.
+        if strict_eval {
+            let env = FunctionEnvironmentRecord::new(
+                context.intrinsics().eval(),
+                None,
+                Some(outer),
+                BindingStatus::Lexical,
+                JsValue::undefined(),
+                context,
+            )?;
+            context.push_environment(env);
+        } else {
+            context.push_environment(DeclarativeEnvironmentRecord::new(Some(outer)));
+        }
+
+        // 28. If result.[[Type]] is normal, then
+        //     a. Set result to the result of evaluating body.
+        let result = Self::run(&script, direct, context);
+
+        // 30. Suspend evalContext and remove it from the execution context stack.
+        context.pop_environment();
+
+        // 29. If result.[[Type]] is normal and result.[[Value]] is empty, then
+        //     a. Set result to NormalCompletion(undefined).
+        // 31. Resume the context that is now on the top of the execution context stack as the running execution context.
+        // 32. Return Completion(result).
+        result
+    }
+
+    /// Runs the code of an eval in the current environment.
+    #[cfg(not(feature = "vm"))]
+    fn run(script: &StatementList, _direct: bool, context: &mut Context) -> JsResult<JsValue> {
+        script.run(context)
+    }
+
+    /// Runs the code of an eval in the current environment.
+    ///
+    /// The code runs in a frame of its own, with the `this` of the calling frame for a direct eval.
+    #[cfg(feature = "vm")]
+    fn run(script: &StatementList, direct: bool, context: &mut Context) -> JsResult<JsValue> {
+        let mut compiler = ByteCompiler::new(JsString::new("<eval>"), script.strict());
+        compiler.compile_script(script);
+        let code = compiler.finish();
+
+        let environment = context.get_current_environment();
+        let this = match context.vm.frame {
+            Some(ref frame) if direct => frame.this.clone(),
+            _ => context.global_object().into(),
+        };
+
+        let fp = context.vm.stack.len();
+        context.vm.push_frame(CallFrame {
+            prev: None,
+            code: Gc::new(code),
+            this,
+            pc: 0,
+            fp,
+            exit_on_return: true,
+            environment: environment.clone(),
+            promise_capability: None,
+            handlers: Vec::new(),
+            resumed_with: CompletionType::Normal,
+            yielded: false,
+        });
+        let result = context.run();
+
+        // An exception thrown in a block scope leaves the environment of the block as the
+        // current one.
+        context.pop_environment();
+        context.push_environment(environment);
+
+        result
+    }
+}
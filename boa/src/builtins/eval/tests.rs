@@ -0,0 +1,159 @@
+use crate::{forward, host::HostHooks, Context, JsResult};
+
+/// Evaluates `src` with [`Context::eval`], keeping the state of `context`, and returns the
+/// displayed result. Unlike `forward`, this runs on the VM when the `vm` feature is enabled.
+fn eval(context: &mut Context, src: &str) -> String {
+    match context.eval(src) {
+        Ok(value) => value.display().to_string(),
+        Err(error) => format!("Uncaught {}", error.display()),
+    }
+}
+
+#[test]
+fn returns_the_completion_value() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "eval('1 + 2')"), "3");
+    assert_eq!(
+        forward(&mut context, "eval('if (true) { \"yes\" }')"),
+        "\"yes\""
+    );
+    assert_eq!(forward(&mut context, "eval('')"), "undefined");
+
+    // Other values than strings aren't evaluated.
+    assert_eq!(forward(&mut context, "eval(42)"), "42");
+    assert_eq!(
+        forward(
+            &mut context,
+            "var code = new String('1'); eval(code) === code"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "eval()"), "undefined");
+}
+
+#[test]
+fn direct_eval_runs_in_the_scope_of_the_caller() {
+    let mut context = Context::new();
+    let init = r#"
+        var x = "global";
+        function f(x) {
+            return eval("x");
+        }
+        function hoist() {
+            eval("var hoisted = 5");
+            return hoisted;
+        }
+        function scoped() {
+            eval("let y = 1");
+            try { return y; } catch (e) { return e.name; }
+        }
+        var obj = {
+            method: function () {
+                return eval("this") === obj;
+            }
+        };
+        "#;
+    eval(&mut context, init);
+
+    assert_eq!(eval(&mut context, "f('local')"), "\"local\"");
+    assert_eq!(eval(&mut context, "hoist()"), "5");
+    assert_eq!(
+        eval(
+            &mut context,
+            "var name = \"\"; try { hoisted } catch (e) { name = e.name } name"
+        ),
+        "\"ReferenceError\""
+    );
+    assert_eq!(eval(&mut context, "scoped()"), "\"ReferenceError\"");
+    assert_eq!(eval(&mut context, "obj.method()"), "true");
+}
+
+#[test]
+fn strict_direct_eval_keeps_var_declarations() {
+    let mut context = Context::new();
+    let init = r#"
+        function sloppy() {
+            eval("'use strict'; var x = 5");
+            try { return x; } catch (e) { return e.name; }
+        }
+        function strict() {
+            'use strict';
+            eval("var x = 5");
+            try { return x; } catch (e) { return e.name; }
+        }
+        "#;
+    eval(&mut context, init);
+
+    assert_eq!(eval(&mut context, "sloppy()"), "\"ReferenceError\"");
+    assert_eq!(eval(&mut context, "strict()"), "\"ReferenceError\"");
+}
+
+#[test]
+fn indirect_eval_runs_in_the_global_scope() {
+    let mut context = Context::new();
+    let init = r#"
+        var x = "global";
+        function f(x) {
+            return [(0, eval)("x"), globalThis.eval("x")].join();
+        }
+        function hoist() {
+            var geval = eval;
+            geval("var declared = 1; let scoped = 2;");
+        }
+        hoist();
+        "#;
+    eval(&mut context, init);
+
+    assert_eq!(eval(&mut context, "f('local')"), "\"global,global\"");
+    assert_eq!(eval(&mut context, "declared"), "1");
+    assert_eq!(
+        eval(
+            &mut context,
+            "var name = \"\"; try { scoped } catch (e) { name = e.name } name"
+        ),
+        "\"ReferenceError\""
+    );
+}
+
+#[test]
+fn host_hooks_forbid_eval() {
+    struct NoDynamicCode;
+
+    impl HostHooks for NoDynamicCode {
+        fn ensure_can_compile_strings(&self, context: &mut Context) -> JsResult<()> {
+            Err(context.construct_eval_error("code generation from strings is disallowed"))
+        }
+    }
+
+    let mut context = Context::with_host_hooks(NoDynamicCode);
+
+    assert_eq!(
+        forward(&mut context, "try { eval('1') } catch (e) { e.name }"),
+        "\"EvalError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { (0, eval)('1') } catch (e) { e.name }"),
+        "\"EvalError\""
+    );
+    // Values other than strings aren't compiled.
+    assert_eq!(forward(&mut context, "eval(1)"), "1");
+}
+
+#[test]
+fn errors() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "try { eval('1 +') } catch (e) { e.name }"),
+        "\"SyntaxError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { eval('null.x') } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { new eval('1') } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
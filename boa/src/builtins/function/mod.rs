@@ -15,12 +15,14 @@ use std::{
     any::Any,
     borrow::Cow,
     fmt,
+    future::Future,
     ops::{Deref, DerefMut},
 };
 
 use dyn_clone::DynClone;
 use gc::{Gc, GcCell};
 
+use crate::symbol::WellKnownSymbols;
 use crate::{
     builtins::BuiltIn,
     context::StandardObjects,
@@ -31,10 +33,10 @@ use crate::{
     property::Attribute,
     property::PropertyDescriptor,
     syntax::ast::node::declaration::Declaration,
-    syntax::ast::node::{FormalParameter, RcStatementList},
+    syntax::ast::node::{FormalParameter, FunctionDecl, Node, RcStatementList},
+    syntax::Parser,
     BoaProfiler, Context, JsResult, JsValue,
 };
-use crate::{object::Object, symbol::WellKnownSymbols};
 use crate::{
     object::{ConstructorBuilder, FunctionBuilder},
     property::PropertyKey,
@@ -85,6 +87,24 @@ impl<T> ClosureFunctionSignature for T where
 // Allows cloning Box<dyn ClosureFunctionSignature>
 dyn_clone::clone_trait_object!(ClosureFunctionSignature);
 
+/// Trait representing a native built-in function that runs asynchronously.
+///
+/// The function returns a [`Future`] instead of a value, and the promise returned to
+/// Javascript is settled with its output once it completes.
+pub trait AsyncFunctionSignature<Fut>:
+    Fn(&JsValue, &[JsValue], &mut Context) -> Fut + DynCopy + 'static
+where
+    Fut: Future<Output = JsResult<JsValue>> + 'static,
+{
+}
+
+impl<T, Fut> AsyncFunctionSignature<Fut> for T
+where
+    T: Fn(&JsValue, &[JsValue], &mut Context) -> Fut + Copy + 'static,
+    Fut: Future<Output = JsResult<JsValue>> + 'static,
+{
+}
+
 #[derive(Debug, Trace, Finalize, PartialEq, Clone)]
 pub enum ThisMode {
     Lexical,
@@ -134,9 +154,8 @@ impl ConstructorKind {
 /// can be used as a capture context, so you can pass e.g. a String,
 /// a tuple or even a full struct.
 ///
-/// You can cast to `Any` with `as_any`, `as_mut_any` and downcast
-/// with `Any::downcast_ref` and `Any::downcast_mut` to recover the original
-/// type.
+/// You can recover the original type with `downcast_ref` and `downcast_mut`,
+/// or cast to `Any` with `as_any` and `as_mut_any`.
 #[derive(Clone, Debug, Trace, Finalize)]
 pub struct Captures(Gc<GcCell<Box<dyn NativeObject>>>);
 
@@ -166,6 +185,52 @@ impl Captures {
     pub fn as_mut_any(&self) -> gc::GcCellRefMut<'_, Box<dyn NativeObject>, dyn Any> {
         RefMut::map(self.0.borrow_mut(), |data| data.deref_mut().as_mut_any())
     }
+
+    /// Downcasts a reference to the captures, if they are of type `T`.
+    ///
+    /// Returns `None` if the captures are of another type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the captures are currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn downcast_ref<T>(&self) -> Option<gc::GcCellRef<'_, T>>
+    where
+        T: NativeObject,
+    {
+        let captures = self.0.borrow();
+        if captures.deref().deref().as_any().is::<T>() {
+            Some(Ref::map(captures, |data| {
+                data.deref().as_any().downcast_ref::<T>().unwrap()
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Downcasts a mutable reference to the captures, if they are of type `T`.
+    ///
+    /// Returns `None` if the captures are of another type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the captures are currently borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn downcast_mut<T>(&self) -> Option<gc::GcCellRefMut<'_, Box<dyn NativeObject>, T>>
+    where
+        T: NativeObject,
+    {
+        let captures = self.0.borrow_mut();
+        if captures.deref().deref().as_any().is::<T>() {
+            Some(RefMut::map(captures, |data| {
+                data.deref_mut().as_mut_any().downcast_mut::<T>().unwrap()
+            }))
+        } else {
+            None
+        }
+    }
 }
 
 /// Boa representation of a Function Object.
@@ -179,6 +244,8 @@ pub enum Function {
         #[unsafe_ignore_trace]
         function: NativeFunctionSignature,
         constructor: bool,
+        /// The data of this function instance, see [`FunctionBuilder::native_with_data`].
+        data: Option<Captures>,
     },
     Closure {
         #[unsafe_ignore_trace]
@@ -192,6 +259,14 @@ pub enum Function {
         body: RcStatementList,
         params: Box<[FormalParameter]>,
         environment: Environment,
+        /// The source text of the function, returned verbatim by `toString`.
+        source: Option<Box<str>>,
+        /// Whether the parameters or the body reference `arguments`, in which case calls
+        /// create an arguments object.
+        contains_arguments: bool,
+        /// Whether this is a generator function, whose calls return a generator that runs the
+        /// body when it is resumed.
+        generator: bool,
     },
     #[cfg(feature = "vm")]
     VmOrdinary {
@@ -312,6 +387,7 @@ pub(crate) fn make_builtin_fn<N>(
         ObjectData::function(Function::Native {
             function,
             constructor: false,
+            data: None,
         }),
     );
     let attribute = PropertyDescriptor::builder()
@@ -337,23 +413,64 @@ pub struct BuiltInFunctionObject;
 impl BuiltInFunctionObject {
     pub const LENGTH: usize = 1;
 
+    /// `Function ( p1, p2, … , pn, body )`
+    ///
+    /// Creates a new function from its source text, by joining every argument but the last one
+    /// into the parameter list and using the last one as the body. The function is created in
+    /// the global environment, regardless of where the constructor was called from.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createdynamicfunction
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/Function
     fn constructor(
         new_target: &JsValue,
-        _: &[JsValue],
+        args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
+        context.host_hooks().ensure_can_compile_strings(context)?;
+
+        let (body, params) = match args.split_last() {
+            Some((body, params)) => (body.to_string(context)?, params),
+            None => (JsString::empty(), args),
+        };
+        let params = params
+            .iter()
+            .map(|param| param.to_string(context))
+            .collect::<JsResult<Vec<_>>>()?
+            .join(",");
+
+        // The parameters are checked on their own first, so that they can't close the parameter
+        // list early or open a comment that hides part of the body.
+        Self::parse_dynamic_function(&format!("function anonymous({}\n) {{\n}}", params), context)?;
+        let function = Self::parse_dynamic_function(
+            &format!("function anonymous({}\n) {{\n{}\n}}", params, body),
+            context,
+        )?;
+
+        let function = context.create_dynamic_function(function)?;
+
         let prototype =
             get_prototype_from_constructor(new_target, StandardObjects::function_object, context)?;
+        function.set_prototype(prototype.into());
 
-        let this = JsObject::from_proto_and_data(
-            prototype,
-            ObjectData::function(Function::Native {
-                function: |_, _, _| Ok(JsValue::undefined()),
-                constructor: true,
-            }),
-        );
+        Ok(function.into())
+    }
 
-        Ok(this.into())
+    /// Parses the source text of a dynamic function, which must consist of a single function
+    /// declaration, throwing a `SyntaxError` otherwise.
+    fn parse_dynamic_function(source: &str, context: &mut Context) -> JsResult<FunctionDecl> {
+        let statement_list = match Parser::new(source.as_bytes(), context.strict()).parse_all() {
+            Ok(statement_list) => statement_list,
+            Err(e) => return Err(context.construct_syntax_error(e.to_string())),
+        };
+
+        match statement_list.items() {
+            [Node::FunctionDecl(function)] => Ok(function.clone()),
+            _ => Err(context.construct_syntax_error("invalid source text for a dynamic function")),
+        }
     }
 
     /// `Function.prototype.apply ( thisArg, argArray )`
@@ -379,7 +496,7 @@ impl BuiltInFunctionObject {
         // 3. If argArray is undefined or null, then
         if arg_array.is_null_or_undefined() {
             // a. Perform PrepareForTailCall().
-            // TODO?: 3.a. PrepareForTailCall
+            //    Calls to `apply` in tail position are resolved to `func` by `TailCall::new`.
 
             // b. Return ? Call(func, thisArg).
             return func.call(this_arg, &[], context);
@@ -389,7 +506,7 @@ impl BuiltInFunctionObject {
         let arg_list = arg_array.create_list_from_array_like(&[], context)?;
 
         // 5. Perform PrepareForTailCall().
-        // TODO?: 5. PrepareForTailCall
+        //    Calls to `apply` in tail position are resolved to `func` by `TailCall::new`.
 
         // 6. Return ? Call(func, thisArg, argList).
         func.call(this_arg, &arg_list, context)
@@ -497,43 +614,63 @@ impl BuiltInFunctionObject {
         let this_arg = args.get_or_undefined(0);
 
         // 3. Perform PrepareForTailCall().
-        // TODO?: 3. Perform PrepareForTailCall
+        //    Calls to `call` in tail position are resolved to `func` by `TailCall::new`.
 
         // 4. Return ? Call(func, thisArg, args).
         func.call(this_arg, args.get(1..).unwrap_or(&[]), context)
     }
 
+    /// `Function.prototype.toString ( )`
+    ///
+    /// Returns the source text of functions parsed from source, and the `NativeFunction`
+    /// syntax for native, closure and bound functions.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/toString
     #[allow(clippy::wrong_self_convention)]
     fn to_string(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        let object = this.as_object().map(JsObject::borrow);
+        let object = this
+            .as_object()
+            .ok_or_else(|| context.construct_type_error("Not a function"))?;
+
+        // The name of a bound function (`bound f`) is not a valid property name, so it is left
+        // out of the `NativeFunction` syntax.
+        if object.borrow().as_bound_function().is_some() {
+            return Ok("function () { [native code] }".into());
+        }
+
+        let object = object.borrow();
         let function = object
-            .as_deref()
-            .and_then(Object::as_function)
+            .as_function()
             .ok_or_else(|| context.construct_type_error("Not a function"))?;
 
+        // Functions parsed from source text return it verbatim.
+        if let Function::Ordinary {
+            source: Some(source),
+            ..
+        } = function
+        {
+            return Ok(source.as_ref().into());
+        }
+
         let name = {
-            // Is there a case here where if there is no name field on a value
-            // name should default to None? Do all functions have names set?
             let value = this
                 .as_object()
                 .expect("checked that `this` was an object above")
                 .get("name", &mut *context)?;
             if value.is_null_or_undefined() {
-                None
+                JsString::empty()
             } else {
-                Some(value.to_string(context)?)
+                value.to_string(context)?
             }
         };
 
-        match (function, name) {
-            (
-                Function::Native {
-                    function: _,
-                    constructor: _,
-                },
-                Some(name),
-            ) => Ok(format!("function {}() {{\n  [native Code]\n}}", &name).into()),
-            (Function::Ordinary { body, params, .. }, Some(name)) => {
+        match function {
+            Function::Ordinary { body, params, .. } => {
                 let arguments: String = {
                     let mut argument_list: Vec<Cow<'_, str>> = Vec::new();
                     for params_item in params.iter() {
@@ -580,8 +717,7 @@ impl BuiltInFunctionObject {
                     .into())
                 }
             }
-
-            _ => Ok("TODO".into()),
+            _ => Ok(format!("function {}() {{ [native code] }}", name).into()),
         }
     }
 
@@ -622,7 +758,7 @@ impl BuiltIn for BuiltInFunctionObject {
         let symbol_has_instance = WellKnownSymbols::has_instance();
 
         let has_instance = FunctionBuilder::native(context, Self::has_instance)
-            .name("[Symbol.iterator]")
+            .name("[Symbol.hasInstance]")
             .length(1)
             .constructor(false)
             .build();
@@ -638,7 +774,11 @@ impl BuiltIn for BuiltInFunctionObject {
         .method(Self::bind, "bind", 1)
         .method(Self::call, "call", 1)
         .method(Self::to_string, "toString", 0)
-        .property(symbol_has_instance, has_instance, Attribute::default())
+        .property(
+            symbol_has_instance,
+            has_instance,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        )
         .build();
 
         function_object.into()
@@ -1,8 +1,15 @@
 use crate::{
+    builtins::function::Captures,
     forward, forward_val,
+    host::HostHooks,
     object::FunctionBuilder,
     property::{Attribute, PropertyDescriptor},
-    Context, JsString,
+    Context, JsResult, JsString, JsValue,
+};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{self, Poll},
 };
 
 #[allow(clippy::float_cmp)]
@@ -29,6 +36,86 @@ fn arguments_object() {
     );
 }
 
+#[test]
+fn arguments_object_mapping() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function mapped(a, b) {
+            arguments[0] = 10;
+            b = 20;
+            return [a, arguments[1], arguments.length, arguments.callee === mapped];
+        }
+        // Functions with a non-simple parameter list get an unmapped arguments object.
+        function unmapped(a, b = 0) {
+            arguments[0] = 10;
+            b = 20;
+            return [a, arguments[1], arguments.length];
+        }
+        function unmappedCallee(...rest) {
+            return arguments.callee;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "mapped(1, 2).join()"),
+        "\"10,20,2,true\""
+    );
+    assert_eq!(forward(&mut context, "unmapped(1, 2).join()"), "\"1,2,2\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { unmappedCallee() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "(function () { return [...arguments]; })(1, 2, 3).join()"
+        ),
+        "\"1,2,3\""
+    );
+}
+
+#[test]
+fn arguments_object_nested_references() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function fromArrow() {
+            return (() => arguments[0])();
+        }
+        function fromDefault(a = arguments[1]) {
+            return a;
+        }
+        function fromComputedKey() {
+            return Object.keys({ [arguments[0]]() {} })[0];
+        }
+        function fromInner() {
+            return (function () { return arguments.length; })(1, 2, 3);
+        }
+        function fromEval(a) {
+            return eval("arguments[0]");
+        }
+        function fromArrowEval() {
+            return (() => eval("arguments.length"))();
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "fromArrow('a')"), "\"a\"");
+    assert_eq!(
+        forward(&mut context, "fromDefault(undefined, 'b')"),
+        "\"b\""
+    );
+    assert_eq!(forward(&mut context, "fromComputedKey('c')"), "\"c\"");
+    assert_eq!(forward(&mut context, "fromInner(1)"), "3");
+    assert_eq!(forward(&mut context, "fromEval(1)"), "1");
+    assert_eq!(forward(&mut context, "fromArrowEval(1, 2)"), "2");
+}
+
 #[test]
 fn self_mutating_function_when_calling() {
     let mut context = Context::new();
@@ -260,3 +347,476 @@ fn closure_capture_clone() {
 
     assert_eq!(forward(&mut context, "closure()"), "\"Hello world!\"");
 }
+
+#[test]
+fn native_function_data() {
+    fn read_data(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // Calling another native function doesn't change the data seen after it returns.
+        if let Some(callee) = args.get(0).and_then(JsValue::as_callable) {
+            callee.call(&JsValue::undefined(), &[], context)?;
+        }
+        Ok(context
+            .current_function_data::<JsString>()
+            .map_or_else(JsValue::undefined, |data| data.clone().into()))
+    }
+
+    let mut context = Context::new();
+    for (name, data) in [("first", "one"), ("second", "two")] {
+        let function =
+            FunctionBuilder::native_with_data(&mut context, read_data, JsString::from(data))
+                .name(name)
+                .build();
+        context.register_global_property(name, function, Attribute::all());
+    }
+    let function = FunctionBuilder::native_with_data(&mut context, read_data, 0_i32)
+        .name("other")
+        .build();
+    context.register_global_property("other", function, Attribute::all());
+    let function = FunctionBuilder::native(&mut context, read_data)
+        .name("none")
+        .build();
+    context.register_global_property("none", function, Attribute::all());
+    let function = FunctionBuilder::closure(&mut context, |_, _, context| {
+        Ok(context
+            .current_function_data::<JsString>()
+            .map_or_else(JsValue::undefined, |data| data.clone().into()))
+    })
+    .name("closure")
+    .build();
+    context.register_global_property("closure", function, Attribute::all());
+
+    assert_eq!(forward(&mut context, "first()"), "\"one\"");
+    assert_eq!(forward(&mut context, "second()"), "\"two\"");
+    assert_eq!(forward(&mut context, "first(second)"), "\"one\"");
+    // Data of another type or no data at all can't be read as a `JsString`.
+    assert_eq!(forward(&mut context, "other()"), "undefined");
+    assert_eq!(forward(&mut context, "none()"), "undefined");
+    // Closures don't see the data of the native function that called them.
+    assert_eq!(
+        forward(
+            &mut context,
+            "var seen; first(() => { seen = closure(); }); seen"
+        ),
+        "undefined"
+    );
+}
+
+#[test]
+fn captures_downcast() {
+    let captures = Captures::new(JsString::from("hello"));
+
+    assert_eq!(
+        captures.downcast_ref::<JsString>().as_deref(),
+        Some(&JsString::from("hello"))
+    );
+    assert!(captures.downcast_ref::<String>().is_none());
+    assert!(captures.downcast_mut::<String>().is_none());
+
+    *captures.downcast_mut::<JsString>().unwrap() = JsString::from("world");
+    assert_eq!(
+        captures.downcast_ref::<JsString>().as_deref(),
+        Some(&JsString::from("world"))
+    );
+
+    // The untyped accessors still see the same value.
+    assert_eq!(
+        captures.as_any().downcast_ref::<JsString>(),
+        Some(&JsString::from("world"))
+    );
+}
+
+#[test]
+fn function_prototype_has_instance_attributes() {
+    let mut context = Context::new();
+    let init = r#"
+        var descriptor = Object.getOwnPropertyDescriptor(Function.prototype, Symbol.hasInstance);
+        var original = Function.prototype[Symbol.hasInstance];
+        Function.prototype[Symbol.hasInstance] = function() { return true; };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "descriptor.writable"), "false");
+    assert_eq!(forward(&mut context, "descriptor.enumerable"), "false");
+    assert_eq!(forward(&mut context, "descriptor.configurable"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Function.prototype[Symbol.hasInstance] === original"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "({}) instanceof Array"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "delete Function.prototype[Symbol.hasInstance]"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            (function() {
+                "use strict";
+                try {
+                    Function.prototype[Symbol.hasInstance] = function() { return true; };
+                } catch (e) {
+                    return e.name;
+                }
+            })()
+            "#
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn function_prototype_has_instance_name_and_length() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "Function.prototype[Symbol.hasInstance].name"),
+        "\"[Symbol.hasInstance]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Function.prototype[Symbol.hasInstance].length"
+        ),
+        "1"
+    );
+}
+
+#[test]
+fn host_hooks_forbid_function_constructor() {
+    struct NoDynamicCode;
+
+    impl HostHooks for NoDynamicCode {
+        fn ensure_can_compile_strings(&self, context: &mut Context) -> JsResult<()> {
+            Err(context.construct_eval_error("code generation from strings is disallowed"))
+        }
+    }
+
+    let mut context = Context::with_host_hooks(NoDynamicCode);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Function('return 1') } catch (e) { e.name }"
+        ),
+        "\"EvalError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { Function() } catch (e) { e.message }"),
+        "\"code generation from strings is disallowed\""
+    );
+    assert_eq!(forward(&mut context, "(function () { return 1 })()"), "1");
+
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "typeof new Function('return 1')"),
+        "\"function\""
+    );
+}
+
+#[test]
+fn function_constructor_compiles_source() {
+    let mut context = Context::new();
+    let init = r#"
+        var add = new Function("a", "b", "return a + b");
+        var sum = Function("a, b", "c", "return a + b + c");
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "add(2, 3)"), "5");
+    assert_eq!(forward(&mut context, "sum(1, 2, 3)"), "6");
+    assert_eq!(forward(&mut context, "add.name"), "\"anonymous\"");
+    assert_eq!(forward(&mut context, "add.length"), "2");
+    assert_eq!(forward(&mut context, "Function()()"), "undefined");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(add) === Function.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "new (new Function('this.v = 1'))().v"),
+        "1"
+    );
+}
+
+#[test]
+fn function_constructor_uses_global_scope() {
+    let mut context = Context::new();
+    let init = r#"
+        var x = "global";
+        function inner(x) {
+            return new Function("return x")();
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "inner('local')"), "\"global\"");
+}
+
+#[test]
+fn function_constructor_syntax_errors() {
+    let mut context = Context::new();
+
+    for (params, body) in [
+        ("'a)'", "'return 1'"),
+        ("'/*'", "'*/) {'"),
+        ("'a'", "'}; (function () {'"),
+        ("'a'", "'return {'"),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                format!(
+                    "try {{ new Function({}, {}) }} catch (e) {{ e.name }}",
+                    params, body
+                )
+            ),
+            "\"SyntaxError\""
+        );
+    }
+}
+
+#[test]
+fn function_to_string_returns_source_text() {
+    let mut context = Context::new();
+    let init = r#"
+function add(a, /* b */ b = 1) {
+    // sum
+    return a + b;
+}
+var expr = function  named ( x ) { return x };
+var arrow = x => x * 2;
+var regex = () => /ab+c/g;
+var object = { method(a) { return a; }, get getter() { return 1; } };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "add.toString()"),
+        "\"function add(a, /* b */ b = 1) {\n    // sum\n    return a + b;\n}\""
+    );
+    assert_eq!(
+        forward(&mut context, "expr.toString()"),
+        "\"function  named ( x ) { return x }\""
+    );
+    assert_eq!(forward(&mut context, "arrow.toString()"), "\"x => x * 2\"");
+    assert_eq!(
+        forward(&mut context, "regex.toString()"),
+        "\"() => /ab+c/g\""
+    );
+    assert_eq!(
+        forward(&mut context, "object.method.toString()"),
+        "\"method(a) { return a; }\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(object, 'getter').get.toString()"
+        ),
+        "\"get getter() { return 1; }\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Function('a', 'b', 'return a + b').toString()"
+        ),
+        "\"function anonymous(a,b\n) {\nreturn a + b\n}\""
+    );
+}
+
+#[test]
+fn function_to_string_native_forms() {
+    let mut context = Context::new();
+    context
+        .register_global_closure("closure", 0, |_, _, _| Ok(JsValue::undefined()))
+        .unwrap();
+    let init = r#"
+        function f(a, b) { return a + b; }
+        var bound = f.bind(null, 1);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Math.max.toString()"),
+        "\"function max() { [native code] }\""
+    );
+    assert_eq!(
+        forward(&mut context, "closure.toString()"),
+        "\"function closure() { [native code] }\""
+    );
+    assert_eq!(
+        forward(&mut context, "bound.toString()"),
+        "\"function () { [native code] }\""
+    );
+    assert_eq!(
+        forward(&mut context, "bound.bind(null).toString()"),
+        "\"function () { [native code] }\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Function.prototype.toString.call({}) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn bound_function_construct() {
+    let mut context = Context::new();
+    let init = r#"
+        function Point(x, y) {
+            this.x = x;
+            this.y = y;
+        }
+        Point.prototype.sum = function () { return this.x + this.y; };
+        var BoundPoint = Point.bind({ ignored: true }, 1);
+        var point = new BoundPoint(2);
+        var twice = new (BoundPoint.bind(null, 5))();
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "point.sum()"), "3");
+    assert_eq!(forward(&mut context, "point.ignored"), "undefined");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(point) === Point.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "point instanceof BoundPoint"), "true");
+    assert_eq!(forward(&mut context, "twice.x"), "1");
+    assert_eq!(forward(&mut context, "twice.y"), "5");
+    assert_eq!(
+        forward(
+            &mut context,
+            "var other = Reflect.construct(BoundPoint, [3], Array); Object.getPrototypeOf(other) === Array.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new (Math.max.bind(null))() } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn bound_function_target_can_access_bound_function() {
+    let mut context = Context::new();
+    let init = r#"
+        function F() { Bound.called = true; this.a = 1; }
+        var Bound = F.bind(null);
+        var instance = new Bound();
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "instance.a"), "1");
+    assert_eq!(forward(&mut context, "Bound.called"), "true");
+}
+
+#[test]
+fn call_and_construct_from_rust() {
+    let mut context = Context::new();
+    let init = r#"
+        function greet(greeting, name) { return greeting + ", " + name + this.suffix; }
+        var bound = greet.bind({ suffix: "!" }, "Hello");
+        function Point(x, y) { this.x = x; this.y = y; }
+        "#;
+    forward(&mut context, init);
+
+    let bound = forward_val(&mut context, "bound").unwrap();
+    let result = context
+        .call(&bound, &JsValue::undefined(), &["world".into()])
+        .unwrap();
+    assert_eq!(
+        result.as_string().map(JsString::as_str),
+        Some("Hello, world!")
+    );
+
+    let point = forward_val(&mut context, "Point").unwrap();
+    let instance = context.construct(&point, &[1.into(), 2.into()]).unwrap();
+    let is_point = forward_val(&mut context, "(p) => p instanceof Point && p.x + p.y").unwrap();
+    let result = context
+        .call(&is_point, &JsValue::undefined(), &[instance])
+        .unwrap();
+    assert_eq!(result.as_number(), Some(3.0));
+
+    let error = context
+        .call(&JsValue::new(1), &JsValue::undefined(), &[])
+        .unwrap_err();
+    assert_eq!(
+        error.display().to_string(),
+        "\"TypeError\": \"Value is not callable\""
+    );
+    let arrow = forward_val(&mut context, "() => {}").unwrap();
+    let error = context.construct(&arrow, &[]).unwrap_err();
+    assert_eq!(
+        error.display().to_string(),
+        "\"TypeError\": \"Value is not a constructor\""
+    );
+}
+
+#[test]
+fn async_native_function() {
+    /// A future that is pending the first time it is polled.
+    struct YieldOnce(bool);
+
+    impl Future for YieldOnce {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, _: &mut task::Context<'_>) -> Poll<()> {
+            if self.0 {
+                Poll::Ready(())
+            } else {
+                self.0 = true;
+                Poll::Pending
+            }
+        }
+    }
+
+    let mut context = Context::new();
+    let function = FunctionBuilder::async_native(&mut context, |_, args, _| {
+        let value = args.get(0).cloned().unwrap_or_default();
+        async move {
+            YieldOnce(false).await;
+            if value.is_undefined() {
+                Err("missing".into())
+            } else {
+                Ok(value)
+            }
+        }
+    })
+    .name("later")
+    .length(1)
+    .build();
+    context.register_global_property("later", function, Attribute::all());
+
+    let src = r#"
+        var log = [];
+        log.push(later(1) instanceof Promise);
+        later(2).then(v => log.push(v));
+        later().catch(e => log.push(e));
+        "#;
+    forward(&mut context, src);
+    assert!(context.has_pending_futures());
+
+    context.run_jobs().unwrap();
+    assert!(context.has_pending_futures());
+    assert_eq!(forward(&mut context, "log.join()"), "\"true\"");
+
+    context.run_jobs().unwrap();
+    assert!(!context.has_pending_futures());
+    assert_eq!(forward(&mut context, "log.join()"), "\"true,2,missing\"");
+}
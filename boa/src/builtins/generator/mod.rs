@@ -0,0 +1,333 @@
+//! This module implements the generator objects returned by generator functions.
+//!
+//! A generator keeps the suspended evaluation of its function while it is suspended at a
+//! `yield`: the call frame of the function along with the values it has on the stack with the
+//! VM, or the progress through the body of the function with the tree-walker.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-generator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator
+
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object, JsArgs},
+    exec::{CompletionType, GeneratorBody},
+    gc::{custom_trace, Finalize, Trace},
+    object::{JsObject, ObjectData},
+    property::PropertyDescriptor,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(feature = "vm")]
+use crate::vm::CallFrame;
+
+// The VM can't call the functions created by the tree-walker, which these tests run on.
+#[cfg(all(test, not(feature = "vm")))]
+mod tests;
+
+/// The state of a generator.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-generator-instances
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GeneratorState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+/// The suspended evaluation of a generator function.
+#[derive(Debug, Finalize)]
+pub(crate) enum GeneratorContext {
+    /// The frame of the suspended function, and the values it had on the stack.
+    #[cfg(feature = "vm")]
+    Frame(Box<CallFrame>, Vec<JsValue>),
+
+    /// The body of the suspended function, run by the tree-walker.
+    Body(GeneratorBody),
+}
+
+// The derived implementation of `Trace` also implements `Drop`, which would forbid moving out of
+// the context to resume it.
+unsafe impl Trace for GeneratorContext {
+    custom_trace!(this, {
+        match this {
+            #[cfg(feature = "vm")]
+            Self::Frame(frame, stack) => {
+                mark(frame);
+                mark(stack);
+            }
+            Self::Body(body) => mark(body),
+        }
+    });
+}
+
+/// A generator object, returned by a call to a generator function.
+#[derive(Debug, Trace, Finalize)]
+pub struct Generator {
+    #[unsafe_ignore_trace]
+    state: GeneratorState,
+
+    /// The suspended evaluation of the function.
+    context: Option<GeneratorContext>,
+}
+
+impl Generator {
+    pub(crate) const NAME: &'static str = "Generator";
+
+    /// Creates a generator that runs the body of a generator function, in the `generator_context`
+    /// it was called in, once it is resumed.
+    pub(crate) fn create(
+        function: &JsObject,
+        generator_context: GeneratorContext,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // Let G be ? OrdinaryCreateFromConstructor(functionObject, "%GeneratorFunction.prototype.prototype%", « [[GeneratorState]], [[GeneratorContext]], [[GeneratorBrand]] »).
+        let prototype = function.get("prototype", context)?;
+        let prototype = match prototype.as_object() {
+            Some(prototype) => prototype.clone(),
+            None => context.iterator_prototypes().generator(),
+        };
+
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::generator(Self {
+                state: GeneratorState::SuspendedStart,
+                context: Some(generator_context),
+            }),
+        ))
+    }
+
+    /// Creates the `%GeneratorFunction.prototype.prototype%` object.
+    pub(crate) fn create_prototype(
+        iterator_prototype: JsObject,
+        context: &mut Context,
+    ) -> JsObject {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let generator = JsObject::from_proto_and_data(iterator_prototype, ObjectData::ordinary());
+        make_builtin_fn(Self::next, "next", &generator, 1, context);
+        make_builtin_fn(Self::r#return, "return", &generator, 1, context);
+        make_builtin_fn(Self::throw, "throw", &generator, 1, context);
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+        let to_string_tag_property = PropertyDescriptor::builder()
+            .value(Self::NAME)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true);
+        generator.insert(to_string_tag, to_string_tag_property);
+        generator
+    }
+
+    /// `Generator.prototype.next ( value )`
+    ///
+    /// Resumes the generator with `value` as the result of the `yield` it is suspended at.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.next
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/next
+    pub(crate) fn next(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Return ? GeneratorResume(this value, value, empty).
+        Self::resume(
+            this,
+            CompletionType::Normal,
+            args.get_or_undefined(0).clone(),
+            context,
+        )
+    }
+
+    /// `Generator.prototype.return ( value )`
+    ///
+    /// Resumes the generator as if the `yield` it is suspended at was a `return` statement,
+    /// which runs the `finally` blocks it is in.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.return
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/return
+    pub(crate) fn r#return(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let g be the this value.
+        // 2. Let C be Completion { [[Type]]: return, [[Value]]: value, [[Target]]: empty }.
+        // 3. Return ? GeneratorResumeAbrupt(g, C, empty).
+        Self::resume(
+            this,
+            CompletionType::Return,
+            args.get_or_undefined(0).clone(),
+            context,
+        )
+    }
+
+    /// `Generator.prototype.throw ( exception )`
+    ///
+    /// Resumes the generator as if the `yield` it is suspended at threw `exception`.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.throw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/throw
+    pub(crate) fn throw(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let g be the this value.
+        // 2. Let C be ThrowCompletion(exception).
+        // 3. Return ? GeneratorResumeAbrupt(g, C, empty).
+        Self::resume(
+            this,
+            CompletionType::Throw,
+            args.get_or_undefined(0).clone(),
+            context,
+        )
+    }
+
+    /// `GeneratorResume ( generator, value, generatorBrand )` and
+    /// `GeneratorResumeAbrupt ( generator, abruptCompletion, generatorBrand )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generatorresume
+    fn resume(
+        this: &JsValue,
+        completion: CompletionType,
+        value: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // GeneratorValidate ( generator, generatorBrand )
+        let generator_object = match this {
+            JsValue::Object(object) if object.borrow().as_generator().is_some() => object,
+            _ => {
+                return context.throw_type_error("Generator method called on incompatible receiver")
+            }
+        };
+
+        // Only the VM needs to know whether the frame has started.
+        #[cfg_attr(not(feature = "vm"), allow(unused_variables))]
+        let (generator_context, state) = {
+            let mut object = generator_object.borrow_mut();
+            let generator = object
+                .as_generator_mut()
+                .expect("the generator was checked above");
+            let state = generator.state;
+
+            match state {
+                GeneratorState::Executing => {
+                    drop(object);
+                    return context.throw_type_error("Generator is already running");
+                }
+                // A generator that never started completes without running any of its body
+                // when it's resumed abruptly.
+                GeneratorState::SuspendedStart if completion != CompletionType::Normal => {
+                    generator.state = GeneratorState::Completed;
+                    generator.context = None;
+                }
+                _ => {}
+            }
+
+            if let Some(generator_context) = generator.context.take() {
+                generator.state = GeneratorState::Executing;
+                (generator_context, state)
+            } else {
+                drop(object);
+                return match completion {
+                    CompletionType::Throw => Err(value),
+                    CompletionType::Return => Ok(create_iter_result_object(value, true, context)),
+                    CompletionType::Normal => Ok(create_iter_result_object(
+                        JsValue::undefined(),
+                        true,
+                        context,
+                    )),
+                };
+            }
+        };
+
+        // Resume the suspended evaluation of genContext, with the completion as the result of
+        // the `yield` that suspended it.
+        let (result, generator_context) = match generator_context {
+            #[cfg(feature = "vm")]
+            GeneratorContext::Frame(frame, stack) => {
+                Self::resume_frame(*frame, stack, state, completion, value, context)
+            }
+            GeneratorContext::Body(body) => {
+                let (result, body) = body.resume(completion, value, context);
+                (result, body.map(GeneratorContext::Body))
+            }
+        };
+
+        let mut object = generator_object.borrow_mut();
+        let generator = object
+            .as_generator_mut()
+            .expect("the generator was checked above");
+        generator.state = if generator_context.is_some() {
+            GeneratorState::SuspendedYield
+        } else {
+            GeneratorState::Completed
+        };
+        generator.context = generator_context;
+        result
+    }
+
+    /// Resumes the generator `frame` with the VM, returning the frame again if it yielded.
+    #[cfg(feature = "vm")]
+    fn resume_frame(
+        mut frame: CallFrame,
+        stack: Vec<JsValue>,
+        state: GeneratorState,
+        completion: CompletionType,
+        value: JsValue,
+        context: &mut Context,
+    ) -> (JsResult<JsValue>, Option<GeneratorContext>) {
+        frame.fp = context.vm.stack.len();
+        frame.resumed_with = completion;
+        context.vm.stack.extend(stack);
+        if state == GeneratorState::SuspendedYield {
+            context.vm.push(value);
+        }
+        context.push_environment(frame.environment.clone());
+        context.vm.push_frame(frame);
+
+        let result = context.run();
+
+        context.pop_environment();
+
+        let yielded = matches!(context.vm.frame, Some(ref frame) if frame.yielded);
+
+        if yielded {
+            let mut frame = context
+                .vm
+                .pop_frame()
+                .expect("a generator that yielded has a frame");
+            frame.yielded = false;
+            let stack = context.vm.stack.split_off(frame.fp);
+            (result, Some(GeneratorContext::Frame(frame, stack)))
+        } else {
+            (
+                result.map(|value| create_iter_result_object(value, true, context)),
+                None,
+            )
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{forward, Context};
+
+#[test]
+fn yields_and_receives_values() {
+    let mut context = Context::new();
+    let init = r#"
+        function* gen(start) {
+            let sum = start;
+            for (let i = 0; i < 3; i++) {
+                sum += yield sum;
+            }
+            return sum;
+        }
+        var it = gen(1);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "it.next('ignored').value"), "1");
+    assert_eq!(forward(&mut context, "it.next(2).value"), "3");
+    assert_eq!(forward(&mut context, "it.next(3).value"), "6");
+    assert_eq!(
+        forward(
+            &mut context,
+            "var last = it.next(4); [last.value, last.done].join()"
+        ),
+        "\"10,true\""
+    );
+    assert_eq!(forward(&mut context, "it.next().done"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(it)"),
+        "\"[object Generator]\""
+    );
+}
+
+#[test]
+fn delegates_to_iterables() {
+    let mut context = Context::new();
+    let init = r#"
+        function* inner() {
+            let received = yield 1;
+            yield received;
+            return "inner done";
+        }
+        function* outer() {
+            let result = yield* inner();
+            yield result;
+            yield* ["a", "b"];
+        }
+        let gen = outer();
+        let values = [];
+        let step = gen.next();
+        while (!step.done) {
+            values.push(step.value);
+            step = gen.next("sent");
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "values.join()"),
+        "\"1,sent,inner done,a,b\""
+    );
+}
+
+#[test]
+fn return_runs_finally() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        function* gen() {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                log.push("finally");
+            }
+        }
+        let it = gen();
+        it.next();
+        let result = it.return("early");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result.value"), "\"early\"");
+    assert_eq!(forward(&mut context, "result.done"), "true");
+    assert_eq!(forward(&mut context, "log.join()"), "\"finally\"");
+    assert_eq!(forward(&mut context, "it.next().done"), "true");
+}
+
+#[test]
+fn return_is_delegated() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        function* inner() {
+            try {
+                yield 1;
+            } finally {
+                log.push("inner");
+            }
+        }
+        function* outer() {
+            try {
+                yield* inner();
+            } finally {
+                log.push("outer");
+            }
+        }
+        let it = outer();
+        it.next();
+        let result = it.return("early");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result.value"), "\"early\"");
+    assert_eq!(forward(&mut context, "log.join()"), "\"inner,outer\"");
+}
+
+#[test]
+fn throw_resumes_in_catch() {
+    let mut context = Context::new();
+    let init = r#"
+        function* gen() {
+            while (true) {
+                try {
+                    yield "waiting";
+                } catch (e) {
+                    yield "caught " + e;
+                }
+            }
+        }
+        let it = gen();
+        it.next();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "it.throw('x').value"), "\"caught x\"");
+    assert_eq!(forward(&mut context, "it.next().value"), "\"waiting\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { gen().throw('y') } catch (e) { 'uncaught ' + e }"
+        ),
+        "\"uncaught y\""
+    );
+}
+
+#[test]
+fn for_of_over_generator() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        let object = {
+            *entries() {
+                try {
+                    for (const key of ["a", "b", "c"]) {
+                        switch (key) {
+                            case "b":
+                                continue;
+                            default:
+                                yield `${key}=${yield key}`;
+                        }
+                    }
+                } finally {
+                    log.push("closed");
+                }
+            },
+            *untilAssignment() {
+                for (const value of object.entries()) {
+                    yield value;
+                    if (value.includes("=")) {
+                        break;
+                    }
+                }
+            },
+        };
+        for (const value of object.untilAssignment()) {
+            log.push(value);
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"a,a=undefined,closed\""
+    );
+    assert_eq!(
+        forward(&mut context, "[...object.entries()].join()"),
+        "\"a,a=undefined,c,c=undefined\""
+    );
+}
+
+#[test]
+fn unsupported_yield_throws() {
+    let mut context = Context::new();
+    let init = r#"
+        function* gen() {
+            var [a = yield 1] = [];
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { gen().next() } catch (e) { e.name + ': ' + e.message }"
+        ),
+        "\"SyntaxError: this use of `yield` requires the `vm` feature\""
+    );
+}
@@ -8,3 +8,43 @@ fn global_this_exists_on_global_object_and_evaluates_to_an_object() {
 
     assert_eq!(&exec(scenario), "\"object\"");
 }
+
+#[test]
+fn global_functions_have_name_and_length() {
+    let scenario = r#"
+        var functions = [eval, parseInt, parseFloat, isNaN, isFinite];
+        functions.map(function (f) {
+            var name = Object.getOwnPropertyDescriptor(f, "name");
+            var length = Object.getOwnPropertyDescriptor(f, "length");
+            var attributes = [name, length].every(function (d) {
+                return !d.writable && !d.enumerable && d.configurable;
+            });
+            return name.value + "/" + length.value + (attributes ? "" : "!");
+        }).join(" ");
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"eval/1 parseInt/2 parseFloat/1 isNaN/1 isFinite/1\""
+    );
+}
+
+#[test]
+fn web_apis_are_opt_in() {
+    let globals = [
+        ("AbortController", cfg!(feature = "abort_controller")),
+        ("AbortSignal", cfg!(feature = "abort_controller")),
+        ("TextEncoder", cfg!(feature = "encoding")),
+        ("TextDecoder", cfg!(feature = "encoding")),
+        ("structuredClone", cfg!(feature = "structured_clone")),
+    ];
+
+    for (name, enabled) in globals {
+        assert_eq!(
+            exec(&format!("'{}' in globalThis", name)),
+            enabled.to_string(),
+            "{}",
+            name
+        );
+    }
+}
@@ -8,18 +8,61 @@ use crate::{
 #[derive(Debug, Default)]
 pub struct IntrinsicObjects {
     throw_type_error: JsObject,
+    function_prototype_apply: JsObject,
+    function_prototype_call: JsObject,
+    eval: JsObject,
 }
 
 impl IntrinsicObjects {
     pub fn init(context: &mut Context) -> IntrinsicObjects {
         Self {
             throw_type_error: create_throw_type_error(context),
+            function_prototype_apply: get_function_prototype_method("apply", context),
+            function_prototype_call: get_function_prototype_method("call", context),
+            eval: get_global_function("eval", context),
         }
     }
 
     pub fn throw_type_error(&self) -> JsObject {
         self.throw_type_error.clone()
     }
+
+    /// The original `Function.prototype.apply` method.
+    pub fn function_prototype_apply(&self) -> JsObject {
+        self.function_prototype_apply.clone()
+    }
+
+    /// The original `Function.prototype.call` method.
+    pub fn function_prototype_call(&self) -> JsObject {
+        self.function_prototype_call.clone()
+    }
+
+    /// The original `eval` function, whose direct calls run in the scope of the caller.
+    pub fn eval(&self) -> JsObject {
+        self.eval.clone()
+    }
+}
+
+fn get_function_prototype_method(name: &str, context: &mut Context) -> JsObject {
+    context
+        .standard_objects()
+        .function_object()
+        .prototype()
+        .get(name, context)
+        .expect("Function.prototype method must exist")
+        .as_object()
+        .expect("Function.prototype method must be an object")
+        .clone()
+}
+
+fn get_global_function(name: &str, context: &mut Context) -> JsObject {
+    context
+        .global_object()
+        .get(name, context)
+        .expect("global function must exist")
+        .as_object()
+        .expect("global function must be an object")
+        .clone()
 }
 
 fn create_throw_type_error(context: &mut Context) -> JsObject {
@@ -32,6 +75,7 @@ fn create_throw_type_error(context: &mut Context) -> JsObject {
         ObjectData::function(Function::Native {
             function: throw_type_error,
             constructor: false,
+            data: None,
         }),
     );
 
@@ -1,9 +1,10 @@
 use crate::{
     builtins::{
-        regexp::regexp_string_iterator::RegExpStringIterator,
+        generator::Generator, regexp::regexp_string_iterator::RegExpStringIterator,
         string::string_iterator::StringIterator, ArrayIterator, ForInIterator, MapIterator,
         SetIterator,
     },
+    gc::{Finalize, Trace},
     object::{JsObject, ObjectInitializer},
     symbol::WellKnownSymbols,
     BoaProfiler, Context, JsResult, JsValue,
@@ -18,6 +19,7 @@ pub struct IteratorPrototypes {
     regexp_string_iterator: JsObject,
     map_iterator: JsObject,
     for_in_iterator: JsObject,
+    generator: JsObject,
 }
 
 impl IteratorPrototypes {
@@ -33,6 +35,7 @@ impl IteratorPrototypes {
             ),
             map_iterator: MapIterator::create_prototype(iterator_prototype.clone(), context),
             for_in_iterator: ForInIterator::create_prototype(iterator_prototype.clone(), context),
+            generator: Generator::create_prototype(iterator_prototype.clone(), context),
             iterator_prototype,
         }
     }
@@ -71,6 +74,12 @@ impl IteratorPrototypes {
     pub fn for_in_iterator(&self) -> JsObject {
         self.for_in_iterator.clone()
     }
+
+    /// The `%GeneratorFunction.prototype.prototype%` object.
+    #[inline]
+    pub fn generator(&self) -> JsObject {
+        self.generator.clone()
+    }
 }
 
 /// `CreateIterResultObject( value, done )`
@@ -181,7 +190,7 @@ fn create_iterator_prototype(context: &mut Context) -> JsObject {
     iterator_prototype
 }
 
-#[derive(Debug)]
+#[derive(Debug, Trace, Finalize)]
 pub struct IteratorRecord {
     iterator_object: JsValue,
     next_function: JsValue,
@@ -195,6 +204,16 @@ impl IteratorRecord {
         }
     }
 
+    /// The iterator object.
+    pub fn iterator_object(&self) -> &JsValue {
+        &self.iterator_object
+    }
+
+    /// The `next` method of the iterator.
+    pub fn next_function(&self) -> &JsValue {
+        &self.next_function
+    }
+
     /// Get the next value in the iterator
     ///
     /// More information:
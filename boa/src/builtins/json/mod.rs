@@ -19,7 +19,7 @@ use crate::{
         BuiltIn,
     },
     object::{JsObject, ObjectInitializer, RecursionLimiter},
-    property::{Attribute, PropertyNameKind},
+    property::{Attribute, PropertyKey, PropertyNameKind},
     symbol::WellKnownSymbols,
     value::IntegerOrInfinity,
     BoaProfiler, Context, JsResult, JsString, JsValue,
@@ -104,11 +104,15 @@ impl Json {
 
             // b. Let rootName be the empty String.
             // c. Perform ! CreateDataPropertyOrThrow(root, rootName, unfiltered).
-            root.create_data_property_or_throw("", unfiltered, context)
+            root.create_data_property_or_throw("", unfiltered.clone(), context)
                 .expect("CreateDataPropertyOrThrow should never throw here");
 
-            // d. Return ? InternalizeJSONProperty(root, rootName, reviver).
-            Self::internalize_json_property(&root, "".into(), obj, context)
+            // d. Let snapshot be CreateJSONParseRecord(script, rootName, unfiltered).
+            let snapshot =
+                JsonParseRecord::new(&mut JsonSource::new(&json_string), unfiltered, context)?;
+
+            // e. Return ? InternalizeJSONProperty(root, rootName, reviver, snapshot).
+            Self::internalize_json_property(&root, "".into(), obj, Some(&snapshot), context)
         } else {
             // 12. Else,
             // a. Return unfiltered.
@@ -116,46 +120,85 @@ impl Json {
         }
     }
 
-    /// `25.5.1.1 InternalizeJSONProperty ( holder, name, reviver )`
+    /// `25.5.1.1 InternalizeJSONProperty ( holder, name, reviver, valRecord )`
+    ///
+    /// This follows the [JSON.parse source text access][proposal] proposal: the reviver gets a
+    /// third `context` argument, whose `source` property holds the JSON text of a primitive
+    /// value that hasn't been replaced by an earlier call to the reviver.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-internalizejsonproperty
+    /// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-internalizejsonproperty
+    /// [proposal]: https://github.com/tc39/proposal-json-parse-with-source
     fn internalize_json_property(
         holder: &JsObject,
         name: JsString,
         reviver: &JsObject,
+        val_record: Option<&JsonParseRecord>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let val be ? Get(holder, name).
         let val = holder.get(name.clone(), context)?;
 
-        // 2. If Type(val) is Object, then
+        // 2. Let context be OrdinaryObjectCreate(%Object.prototype%).
+        let reviver_context = context.construct_object();
+
+        // 3. If valRecord is a JSON Parse Record and SameValue(valRecord.[[Value]], val) is true, then
+        let (element_records, entry_records) = match val_record {
+            Some(record) if JsValue::same_value(&record.value, &val) => {
+                // a. If val is not an Object, then
+                //    i. Let parseNode be valRecord.[[ParseNode]].
+                //    ii. Assert: parseNode is not an ArrayLiteral Parse Node and not an ObjectLiteral Parse Node.
+                //    iii. Let sourceText be the source text matched by parseNode.
+                //    iv. Perform ! CreateDataPropertyOrThrow(context, "source", CodePointsToString(sourceText)).
+                if let Some(source) = &record.source {
+                    reviver_context
+                        .create_data_property_or_throw("source", source.clone(), context)
+                        .expect("CreateDataPropertyOrThrow should never throw here");
+                }
+
+                // b. Let elementRecords be valRecord.[[Elements]].
+                // c. Let entryRecords be valRecord.[[Entries]].
+                (record.elements.as_slice(), record.entries.as_slice())
+            }
+            // 4. Else,
+            //    a. Let elementRecords be a new empty List.
+            //    b. Let entryRecords be a new empty List.
+            _ => (&[][..], &[][..]),
+        };
+
+        // 5. If Type(val) is Object, then
         if let Some(obj) = val.as_object() {
             // a. Let isArray be ? IsArray(val).
             // b. If isArray is true, then
             if obj.is_array() {
-                // i. Let I be 0.
-                // ii. Let len be ? LengthOfArrayLike(val).
-                // iii. Repeat, while I < len,
+                // i. Let elementRecordsLen be the number of elements in elementRecords.
+                // ii. Let I be 0.
+                // iii. Let len be ? LengthOfArrayLike(val).
+                // iv. Repeat, while I < len,
                 let len = obj.length_of_array_like(context)? as i64;
                 for i in 0..len {
                     // 1. Let prop be ! ToString(𝔽(I)).
-                    // 2. Let newElement be ? InternalizeJSONProperty(val, prop, reviver).
+                    // 2. If I < elementRecordsLen, let elementRecord be elementRecords[I].
+                    //    Otherwise, let elementRecord be empty.
+                    let element_record = element_records.get(i as usize);
+
+                    // 3. Let newElement be ? InternalizeJSONProperty(val, prop, reviver, elementRecord).
                     let new_element = Self::internalize_json_property(
                         obj,
                         i.to_string().into(),
                         reviver,
+                        element_record,
                         context,
                     )?;
 
-                    // 3. If newElement is undefined, then
+                    // 4. If newElement is undefined, then
                     if new_element.is_undefined() {
                         // a. Perform ? val.[[Delete]](prop).
                         obj.__delete__(&i.into(), context)?;
                     }
-                    // 4. Else,
+                    // 5. Else,
                     else {
                         // a. Perform ? CreateDataProperty(val, prop, newElement).
                         obj.create_data_property(i, new_element, context)?;
@@ -172,16 +215,28 @@ impl Json {
                     // This is safe, because EnumerableOwnPropertyNames with 'key' type only returns strings.
                     let p = p.as_string().unwrap();
 
-                    // 1. Let newElement be ? InternalizeJSONProperty(val, P, reviver).
-                    let new_element =
-                        Self::internalize_json_property(obj, p.clone(), reviver, context)?;
+                    // 1. Let entryRecord be the element of entryRecords whose [[Key]] field is P.
+                    //    If there is no such element, let entryRecord be empty.
+                    let entry_record = entry_records
+                        .iter()
+                        .find(|(key, _)| key == p)
+                        .map(|(_, record)| record);
 
-                    // 2. If newElement is undefined, then
+                    // 2. Let newElement be ? InternalizeJSONProperty(val, P, reviver, entryRecord).
+                    let new_element = Self::internalize_json_property(
+                        obj,
+                        p.clone(),
+                        reviver,
+                        entry_record,
+                        context,
+                    )?;
+
+                    // 3. If newElement is undefined, then
                     if new_element.is_undefined() {
                         // a. Perform ? val.[[Delete]](P).
                         obj.__delete__(&p.clone().into(), context)?;
                     }
-                    // 3. Else,
+                    // 4. Else,
                     else {
                         // a. Perform ? CreateDataProperty(val, P, newElement).
                         obj.create_data_property(p.as_str(), new_element, context)?;
@@ -190,8 +245,12 @@ impl Json {
             }
         }
 
-        // 3. Return ? Call(reviver, holder, « name, val »).
-        reviver.call(&holder.clone().into(), &[name.into(), val], context)
+        // 6. Return ? Call(reviver, holder, « name, val, context »).
+        reviver.call(
+            &holder.clone().into(),
+            &[name.into(), val, reviver_context.into()],
+            context,
+        )
     }
 
     /// `JSON.stringify( value[, replacer[, space]] )`
@@ -738,3 +797,146 @@ struct StateRecord {
     gap: JsString,
     property_list: Option<Vec<JsString>>,
 }
+
+/// A `JSON Parse Record`, the snapshot of a parsed JSON value that `InternalizeJSONProperty`
+/// uses to find the source text of primitive values.
+///
+/// More information:
+///  - [JSON.parse source text access proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json-parse-record
+#[derive(Debug)]
+struct JsonParseRecord {
+    /// The source text of a primitive value, or `None` for an array or an object.
+    source: Option<JsString>,
+    value: JsValue,
+    elements: Vec<JsonParseRecord>,
+    entries: Vec<(JsString, JsonParseRecord)>,
+}
+
+impl JsonParseRecord {
+    /// `CreateJSONParseRecord ( parseNode, key, val )`
+    ///
+    /// Reads the value at the position of `source` and builds the record of `value`, which is
+    /// the result of evaluating that text.
+    fn new(source: &mut JsonSource<'_>, value: JsValue, context: &mut Context) -> JsResult<Self> {
+        let mut elements = Vec::new();
+        let mut entries: Vec<(JsString, JsonParseRecord)> = Vec::new();
+
+        // The value of an entry shadowed by a later one with the same key may not match its
+        // text, so this reads `undefined` instead of assuming the shape of `value`.
+        let get = |key: PropertyKey, context: &mut Context| match value.as_object() {
+            Some(object) => object.get(key, context),
+            None => Ok(JsValue::undefined()),
+        };
+
+        source.skip_whitespace();
+        let start = source.position;
+        match source.peek() {
+            Some(b'[') => {
+                source.position += 1;
+                while !source.close(b']') {
+                    let element = get(elements.len().into(), context)?;
+                    elements.push(Self::new(source, element, context)?);
+                }
+            }
+            Some(b'{') => {
+                source.position += 1;
+                while !source.close(b'}') {
+                    source.skip_whitespace();
+                    let key_start = source.position;
+                    source.skip_string();
+                    let key: JsString =
+                        serde_json::from_str::<String>(&source.text[key_start..source.position])
+                            .expect("the JSON text was already validated")
+                            .into();
+                    source.skip_whitespace();
+                    // Skip the `:`.
+                    source.position += 1;
+
+                    let entry = Self::new(source, get(key.clone().into(), context)?, context)?;
+
+                    // The last of several entries with the same key wins, like it does for the value.
+                    entries.retain(|(k, _)| *k != key);
+                    entries.push((key, entry));
+                }
+            }
+            Some(b'"') => source.skip_string(),
+            _ => source.skip_literal(),
+        }
+
+        let source = if value.is_object() {
+            None
+        } else {
+            Some(source.text[start..source.position].into())
+        };
+
+        Ok(Self {
+            source,
+            value,
+            elements,
+            entries,
+        })
+    }
+}
+
+/// A cursor over a JSON text that has already been validated.
+#[derive(Debug)]
+struct JsonSource<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> JsonSource<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, position: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.position += 1;
+        }
+    }
+
+    /// Skips the separator before the next element of an array or object, and returns `true`
+    /// if the `end` delimiter was reached instead.
+    fn close(&mut self, end: u8) -> bool {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b',') => {
+                self.position += 1;
+                false
+            }
+            Some(c) if c == end => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Skips a string literal, including its quotes.
+    fn skip_string(&mut self) {
+        self.position += 1;
+        while let Some(c) = self.peek() {
+            self.position += 1;
+            match c {
+                b'"' => break,
+                b'\\' => self.position += 1,
+                _ => {}
+            }
+        }
+    }
+
+    /// Skips a number, `true`, `false` or `null`.
+    fn skip_literal(&mut self) {
+        while matches!(self.peek(), Some(c) if !matches!(c, b',' | b']' | b'}' | b' ' | b'\t' | b'\n' | b'\r'))
+        {
+            self.position += 1;
+        }
+    }
+}
@@ -327,6 +327,48 @@ fn json_stringify_pretty_print_with_string_object() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn json_stringify_typed_array() {
+    let mut context = Context::new();
+
+    let actual = forward(&mut context, r#"JSON.stringify(new Uint8Array([1, 2, 3]))"#);
+    let expected = forward(&mut context, r#"'{"0":1,"1":2,"2":3}'"#);
+    assert_eq!(actual, expected);
+
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify(new Float64Array([1.5, NaN]))"#,
+    );
+    let expected = forward(&mut context, r#"'{"0":1.5,"1":null}'"#);
+    assert_eq!(actual, expected);
+
+    let actual = forward(
+        &mut context,
+        r#"try { JSON.stringify(new BigInt64Array([1n])) } catch (e) { e.name }"#,
+    );
+    assert_eq!(actual, "\"TypeError\"");
+}
+
+#[test]
+fn json_stringify_map_and_set() {
+    let mut context = Context::new();
+
+    let actual = forward(&mut context, r#"JSON.stringify(new Map([[1, 2]]))"#);
+    let expected = forward(&mut context, r#"'{}'"#);
+    assert_eq!(actual, expected);
+
+    let actual = forward(&mut context, r#"JSON.stringify(new Set([1, 2]))"#);
+    let expected = forward(&mut context, r#"'{}'"#);
+    assert_eq!(actual, expected);
+
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify({ a: new Uint8Array(2), m: new Map(), s: new Set([1]) })"#,
+    );
+    let expected = forward(&mut context, r#"'{"a":{"0":0,"1":0},"m":{},"s":{}}'"#);
+    assert_eq!(actual, expected);
+}
+
 #[test]
 fn json_parse_array_with_reviver() {
     let mut context = Context::new();
@@ -401,6 +443,84 @@ fn json_parse_object_with_reviver() {
     assert_eq!(result, r#""{"firstname":"boa","lastname":"interpreter"}""#);
 }
 
+#[test]
+fn json_parse_reviver_big_integer_without_precision_loss() {
+    let mut context = Context::new();
+    let init = r#"
+        var parsed = JSON.parse('{"big": 9007199254740993, "small": 1}', (key, value, { source }) =>
+            typeof value === "number" && !Number.isSafeInteger(value) ? BigInt(source) : value
+        );
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "typeof parsed.big"), "\"bigint\"");
+    assert_eq!(forward(&mut context, "parsed.big"), "9007199254740993n");
+    assert_eq!(
+        forward(&mut context, "parsed.big === 9007199254740993n"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "parsed.small"), "1");
+}
+
+#[test]
+fn json_parse_reviver_source_text() {
+    let mut context = Context::new();
+    let init = r#"
+        function sources(text) {
+            let result = [];
+            JSON.parse(text, function (key, value, context) {
+                result.push(key + "=" + ("source" in context ? context.source : "-"));
+                return value;
+            });
+            return result.join(" ");
+        }
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"sources('[1, -2.50, "a\\"b", true, null]')"#
+        ),
+        r#""0=1 1=-2.50 2="a\"b" 3=true 4=null =-""#
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"sources(' {"a": {"b": 1e3}, "\\u0063": 2} ')"#
+        ),
+        r#""b=1e3 a=- c=2 =-""#
+    );
+    // Only the last of several entries with the same key is visited.
+    assert_eq!(
+        forward(&mut context, r#"sources('{"a": [1], "a": 2}')"#),
+        r#""a=2 =-""#
+    );
+}
+
+#[test]
+fn json_parse_reviver_source_text_of_replaced_values() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        JSON.parse('[[1], 2, 3]', function (key, value, context) {
+            if (key === "0") {
+                // Replaces `2` with an equal value, and `3` with a different one.
+                this[1] = 2;
+                this[2] = 4;
+            }
+            log.push(key + ":" + JSON.stringify(context));
+            return value;
+        });
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "log.join(' ')"),
+        r#""0:{"source":"1"} 0:{} 1:{"source":"2"} 2:{} :{}""#
+    );
+}
+
 #[test]
 fn json_parse_sets_prototypes() {
     let mut context = Context::new();
@@ -19,7 +19,7 @@ pub struct MapIterator {
     iterated_map: Option<JsObject>,
     map_next_index: usize,
     map_iteration_kind: PropertyNameKind,
-    lock: MapLock,
+    lock: Option<MapLock>,
 }
 
 impl MapIterator {
@@ -39,13 +39,15 @@ impl MapIterator {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         if let Some(map_obj) = map.as_object() {
-            if let Some(map) = map_obj.borrow_mut().as_map_mut() {
-                let lock = map.lock(map_obj.clone());
+            // The map must not be borrowed while the iterator is allocated, since the garbage
+            // collector may run.
+            let lock = map_obj.borrow().as_map_ref().map(|map| map.lock());
+            if let Some(lock) = lock {
                 let iter = MapIterator {
                     iterated_map: Some(map_obj.clone()),
                     map_next_index: 0,
                     map_iteration_kind: kind,
-                    lock,
+                    lock: Some(lock),
                 };
                 let map_iterator = JsObject::from_proto_and_data(
                     context.iterator_prototypes().map_iterator(),
@@ -101,6 +103,14 @@ impl MapIterator {
                 map_iterator.iterated_map = Some(obj);
                 return item;
             }
+
+            // Release the lock explicitly, so the empty entries are removed right away.
+            if let Some(lock) = map_iterator.lock.take() {
+                obj.borrow_mut()
+                    .as_map_mut()
+                    .expect("iterator should only iterate maps")
+                    .unlock(lock);
+            }
         }
 
         Ok(create_iter_result_object(
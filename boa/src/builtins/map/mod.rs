@@ -13,7 +13,7 @@
 #![allow(clippy::mutable_key_type)]
 
 use crate::{
-    builtins::{iterable::IteratorResult, BuiltIn},
+    builtins::{iterable::IteratorResult, object::group_by, Array, BuiltIn},
     context::StandardObjects,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
@@ -78,6 +78,7 @@ impl BuiltIn for Map {
             None,
             Attribute::CONFIGURABLE,
         )
+        .static_method(Self::group_by, "groupBy", 2)
         .property(
             "entries",
             entries_function.clone(),
@@ -166,6 +167,54 @@ impl Map {
         Ok(this.clone())
     }
 
+    /// `Map.groupBy( items, callbackfn )`
+    ///
+    /// Groups the elements of an iterable by the keys returned by `callbackfn`, into a new `Map`
+    /// holding an array of elements per key. Keys are compared with `SameValueZero`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-map.groupby
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/groupBy
+    pub(crate) fn group_by(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let groups be ? GroupBy(items, callbackfn, zero).
+        let groups = group_by(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            |key, _| {
+                Ok(match key {
+                    JsValue::Rational(r) if r.is_zero() => JsValue::Rational(0f64),
+                    key => key,
+                })
+            },
+            context,
+        )?;
+
+        // 2. Let map be ! Construct(%Map%).
+        // 3. For each Record { [[Key]], [[Value]] } g of groups, do
+        //     a. Let elements be CreateArrayFromList(g.[[Elements]]).
+        //     b. Let entry be the Record { [[Key]]: g.[[Key]], [[Value]]: elements }.
+        //     c. Append entry to map.[[MapData]].
+        let mut map_data = OrderedMap::with_capacity(groups.len());
+        for (key, elements) in groups {
+            let elements = Array::create_array_from_list(elements, context);
+            map_data.insert(key, elements.into());
+        }
+        let map = JsObject::from_proto_and_data(
+            context.standard_objects().map_object().prototype(),
+            ObjectData::map(map_data),
+        );
+
+        // 4. Return map.
+        Ok(map.into())
+    }
+
     /// `Map.prototype.entries()`
     ///
     /// Returns a new Iterator object that contains the [key, value] pairs for each element in the Map object in insertion order.
@@ -270,7 +319,7 @@ impl Map {
         if let Some(object) = this.as_object() {
             // 2. Perform ? RequireInternalSlot(M, [[MapData]]).
             // 3. Let entries be the List that is M.[[MapData]].
-            if let Some(map) = object.borrow_mut().as_map_mut() {
+            if let Some(map) = object.borrow().as_map_ref() {
                 // 4. Let count be 0.
                 // 5. For each Record { [[Key]], [[Value]] } p of entries, do
                 // a. If p.[[Key]] is not empty, set count to count + 1.
@@ -299,6 +348,19 @@ impl Map {
     ) -> JsResult<JsValue> {
         let key = args.get_or_undefined(0);
 
+        const JS_ZERO: &JsValue = &JsValue::Rational(0f64);
+
+        let key = match key {
+            JsValue::Rational(r) => {
+                if r.is_zero() {
+                    JS_ZERO
+                } else {
+                    key
+                }
+            }
+            _ => key,
+        };
+
         // 1. Let M be the this value.
         if let Some(object) = this.as_object() {
             // 2. Perform ? RequireInternalSlot(M, [[MapData]]).
@@ -472,11 +534,11 @@ impl Map {
         // after it has been visited and then re-added before the forEach call completes.
         // Keys that are deleted after the call to forEach begins and before being visited
         // are not visited unless the key is added again before the forEach call completes.
-        let _lock = map
-            .borrow_mut()
-            .as_map_mut()
+        let lock = map
+            .borrow()
+            .as_map_ref()
             .expect("checked that `this` was a map")
-            .lock(map.clone());
+            .lock();
 
         // 4. Let entries be the List that is M.[[MapData]].
         // 5. For each Record { [[Key]], [[Value]] } e of entries, do
@@ -489,8 +551,7 @@ impl Map {
                     map.get_index(index)
                         .map(|(k, v)| [v.clone(), k.clone(), this.clone()])
                 } else {
-                    // 6. Return undefined.
-                    return Ok(JsValue::undefined());
+                    break;
                 }
             };
 
@@ -502,6 +563,14 @@ impl Map {
 
             index += 1;
         }
+
+        map.borrow_mut()
+            .as_map_mut()
+            .expect("checked that `this` was a map")
+            .unlock(lock);
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
     }
 
     /// `Map.prototype.values()`
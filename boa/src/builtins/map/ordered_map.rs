@@ -1,13 +1,14 @@
 use crate::{
-    gc::{custom_trace, Finalize, Trace},
-    object::JsObject,
+    gc::{custom_trace, empty_trace, Finalize, Trace},
     JsValue,
 };
 use indexmap::{Equivalent, IndexMap};
 use std::{
+    cell::Cell,
     collections::hash_map::RandomState,
     fmt::Debug,
     hash::{BuildHasher, Hash, Hasher},
+    rc::Rc,
 };
 
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -37,13 +38,24 @@ impl Equivalent<MapKey> for JsValue {
 }
 
 /// A newtype wrapping indexmap::IndexMap
-#[derive(Clone)]
 pub struct OrderedMap<V, S = RandomState> {
     map: IndexMap<MapKey, Option<V>, S>,
-    lock: u32,
+    // Shared with the `MapLock`s, so they can be released without borrowing the map.
+    lock: Rc<Cell<u32>>,
     empty_count: usize,
 }
 
+impl<V: Clone, S: Clone> Clone for OrderedMap<V, S> {
+    fn clone(&self) -> Self {
+        // The locks of the iterators belong to the original map, the clone starts unlocked.
+        Self {
+            map: self.map.clone(),
+            lock: Rc::default(),
+            empty_count: self.empty_count,
+        }
+    }
+}
+
 impl<V: Trace, S: BuildHasher> Finalize for OrderedMap<V, S> {}
 unsafe impl<V: Trace, S: BuildHasher> Trace for OrderedMap<V, S> {
     custom_trace!(this, {
@@ -72,7 +84,7 @@ impl<V> OrderedMap<V> {
     pub fn new() -> Self {
         OrderedMap {
             map: IndexMap::new(),
-            lock: 0,
+            lock: Rc::default(),
             empty_count: 0,
         }
     }
@@ -80,7 +92,7 @@ impl<V> OrderedMap<V> {
     pub fn with_capacity(capacity: usize) -> Self {
         OrderedMap {
             map: IndexMap::with_capacity(capacity),
-            lock: 0,
+            lock: Rc::default(),
             empty_count: 0,
         }
     }
@@ -117,6 +129,7 @@ impl<V> OrderedMap<V> {
     ///
     /// Computes in **O(1)** time (amortized average).
     pub fn insert(&mut self, key: JsValue, value: V) -> Option<V> {
+        self.compact();
         self.map.insert(MapKey::Key(key), Some(value)).flatten()
     }
 
@@ -131,7 +144,8 @@ impl<V> OrderedMap<V> {
     ///
     /// Computes in **O(n)** time (average).
     pub fn remove(&mut self, key: &JsValue) -> Option<V> {
-        if self.lock == 0 {
+        self.compact();
+        if self.lock.get() == 0 {
             self.map.shift_remove(key).flatten()
         } else if self.map.contains_key(key) {
             self.map.insert(MapKey::Empty(self.empty_count), None);
@@ -144,10 +158,19 @@ impl<V> OrderedMap<V> {
 
     /// Removes all elements from the map and resets the counter of
     /// empty entries.
+    ///
+    /// While the map is locked, the elements are replaced by empty entries instead, so the
+    /// iterators keep their position and still visit the elements added afterwards.
     pub fn clear(&mut self) {
-        self.map.clear();
-        self.map.shrink_to_fit();
-        self.empty_count = 0
+        if self.lock.get() == 0 {
+            self.map.clear();
+            self.map.shrink_to_fit();
+            self.empty_count = 0
+        } else {
+            let len = self.map.len();
+            self.map = (0..len).map(|i| (MapKey::Empty(i), None)).collect();
+            self.empty_count = len;
+        }
     }
 
     /// Return a reference to the value stored for `key`, if it is present,
@@ -192,15 +215,24 @@ impl<V> OrderedMap<V> {
     /// Increases the lock counter and returns a lock object that will decrement the counter when dropped.
     ///
     /// This allows objects to be removed from the map during iteration without affecting the indexes until the iteration has completed.
-    pub(crate) fn lock(&mut self, map: JsObject) -> MapLock {
-        self.lock += 1;
-        MapLock(map)
+    pub(crate) fn lock(&self) -> MapLock {
+        self.lock.set(self.lock.get() + 1);
+        MapLock(self.lock.clone())
+    }
+
+    /// Releases a lock of the map and, if it was the last one, removes all empty entries.
+    ///
+    /// Iterations should release their lock through this method when they complete. A lock that
+    /// is just dropped, like when its iterator is garbage collected, leaves the empty entries
+    /// behind until the next change of the map.
+    pub(crate) fn unlock(&mut self, lock: MapLock) {
+        drop(lock);
+        self.compact();
     }
 
-    /// Decreases the lock counter and, if 0, removes all empty entries.
-    fn unlock(&mut self) {
-        self.lock -= 1;
-        if self.lock == 0 {
+    /// Removes all empty entries if the map isn't locked.
+    fn compact(&mut self) {
+        if self.lock.get() == 0 && self.empty_count != 0 {
             self.map.retain(|k, _| matches!(k, MapKey::Key(_)));
             self.empty_count = 0;
         }
@@ -208,21 +240,29 @@ impl<V> OrderedMap<V> {
 }
 
 /// Increases the lock count of the map for the lifetime of the guard. This should not be dropped until iteration has completed.
-#[derive(Debug, Trace)]
-pub(crate) struct MapLock(JsObject);
+///
+/// The guard only holds the lock counter of the map, so dropping it never borrows the map, even
+/// while the garbage collector finalizes it.
+#[derive(Debug)]
+pub(crate) struct MapLock(Rc<Cell<u32>>);
+
+impl Finalize for MapLock {}
+
+// Safety: `MapLock` does not contain any object that require trace,
+// so this is safe.
+unsafe impl Trace for MapLock {
+    empty_trace!();
+}
 
 impl Clone for MapLock {
     fn clone(&self) -> Self {
-        let mut map = self.0.borrow_mut();
-        let map = map.as_map_mut().expect("MapLock does not point to a map");
-        map.lock(self.0.clone())
+        self.0.set(self.0.get() + 1);
+        MapLock(self.0.clone())
     }
 }
 
-impl Finalize for MapLock {
-    fn finalize(&self) {
-        let mut map = self.0.borrow_mut();
-        let map = map.as_map_mut().expect("MapLock does not point to a map");
-        map.unlock();
+impl Drop for MapLock {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() - 1);
     }
 }
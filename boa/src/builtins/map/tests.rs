@@ -1,4 +1,4 @@
-use crate::{forward, Context};
+use crate::{forward, forward_val, gc::force_collect, Context};
 
 #[test]
 fn construct_empty() {
@@ -404,3 +404,163 @@ fn for_of_delete() {
     assert_eq!(forward(&mut context, "result[3][0]"), "3");
     assert_eq!(forward(&mut context, "result[3][1]"), "\"d\"");
 }
+
+#[test]
+fn clear_during_iteration() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new Map([[0, "a"], [1, "b"]]);
+        let iterator = map.keys();
+        iterator.next();
+        map.clear();
+        map.set(2, "c");
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "map.size"), "1");
+    assert_eq!(forward(&mut context, "iterator.next().value"), "2");
+    assert_eq!(forward(&mut context, "iterator.next().done"), "true");
+}
+
+#[test]
+fn same_value_zero_keys() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new Map([[NaN, "first"], [0, "zero"]]);
+        map.set(NaN, "second");
+        map.set(-0, "negative zero");
+        map.set(Number("not a number"), "third");
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "map.size"), "2");
+    assert_eq!(forward(&mut context, "map.get(NaN)"), "\"third\"");
+    assert_eq!(forward(&mut context, "map.get(+0)"), "\"negative zero\"");
+    assert_eq!(forward(&mut context, "map.has(-0)"), "true");
+    // `-0` keys are normalized to `+0`.
+    assert_eq!(
+        forward(&mut context, "Object.is([...map.keys()][1], 0)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "map.delete(-0)"), "true");
+    assert_eq!(forward(&mut context, "map.has(0)"), "false");
+}
+
+#[test]
+fn size_is_accessor() {
+    let mut context = Context::new();
+    let init = r#"
+        let descriptor = Object.getOwnPropertyDescriptor(Map.prototype, "size");
+        let map = new Map();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "typeof descriptor.get"),
+        "\"function\""
+    );
+    assert_eq!(forward(&mut context, "descriptor.set"), "undefined");
+    assert_eq!(forward(&mut context, "map.hasOwnProperty('size')"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { descriptor.get.call({}) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn size_tracks_mutations() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new Map();
+        let sizes = [map.size];
+        map.set("a", 1);
+        sizes.push(map.size);
+        map.set("b", 2);
+        sizes.push(map.size);
+        map.set("a", 3);
+        sizes.push(map.size);
+        map.delete("a");
+        sizes.push(map.size);
+        map.delete("missing");
+        sizes.push(map.size);
+        map.clear();
+        sizes.push(map.size);
+        map.set("c", 4);
+        sizes.push(map.size);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "sizes.join()"), "\"0,1,2,2,1,1,0,1\"");
+    assert_eq!(forward(&mut context, "map.has('b')"), "false");
+    assert_eq!(forward(&mut context, "map.get('c')"), "4");
+}
+
+#[test]
+fn group_by() {
+    let mut context = Context::new();
+    let init = r#"
+        let key = { name: "key" };
+        let other = { name: "key" };
+        let map = Map.groupBy([1, 2, 3, 4, 5, 6], (element, index) => {
+            if (index === 0) return -0;
+            if (element % 3 === 0) return key;
+            return element === 2 ? 0 : other;
+        });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "map instanceof Map"), "true");
+    assert_eq!(forward(&mut context, "map.size"), "3");
+    assert_eq!(forward(&mut context, "map.get(0).join()"), "\"1,2\"");
+    assert_eq!(
+        forward(&mut context, "Object.is([...map.keys()][0], 0)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "map.get(key).join()"), "\"3,6\"");
+    assert_eq!(forward(&mut context, "map.get(other).join()"), "\"4,5\"");
+    assert_eq!(
+        forward(&mut context, "map.get({ name: 'key' })"),
+        "undefined"
+    );
+}
+
+#[test]
+fn iterator_over_advancing() {
+    let mut context = Context::new();
+    let init = r#"
+        let collection = new Map([[1, "a"]]);
+        let iterator = collection.values();
+        let results = [];
+        for (let i = 0; i < 3; i++) {
+            let next = iterator.next();
+            results.push(String(next.value) + ":" + next.done);
+        }
+        collection.set(2, "b");
+        let next = iterator.next();
+        results.push(String(next.value) + ":" + next.done);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "results.join()"),
+        "\"a:false,undefined:true,undefined:true,undefined:true\""
+    );
+}
+
+#[test]
+fn collecting_iterators_does_not_borrow_the_map() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new Map([[1, "a"], [2, "b"]]);
+        (function () {
+            for (let i = 0; i < 10; i++) {
+                map.entries().next();
+            }
+        })();
+        map
+    "#;
+    let map = forward_val(&mut context, init).unwrap();
+    let map = map.as_object().unwrap();
+
+    // The locks of the collected iterators are released while the map is borrowed.
+    let _map = map.borrow();
+    force_collect();
+}
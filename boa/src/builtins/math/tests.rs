@@ -727,3 +727,82 @@ fn trunc() {
     assert_eq!(a.to_number(&mut context).unwrap(), 13_f64);
     assert_eq!(b.to_number(&mut context).unwrap(), 0_f64);
 }
+
+#[test]
+fn special_values() {
+    let mut context = Context::new();
+    let init = r#"
+        function show(f) {
+            return [NaN, 0, -0, Infinity, -Infinity]
+                .map(x => Object.is(f(x), -0) ? "-0" : String(f(x)))
+                .join();
+        }
+        "#;
+
+    eprintln!("{}", forward(&mut context, init));
+
+    for (function, expected) in [
+        ("clz32", "32,32,32,32,32"),
+        ("fround", "NaN,0,-0,Infinity,-Infinity"),
+        ("expm1", "NaN,0,-0,Infinity,-1"),
+        ("log1p", "NaN,0,-0,Infinity,NaN"),
+        ("cbrt", "NaN,0,-0,Infinity,-Infinity"),
+        ("sinh", "NaN,0,-0,Infinity,-Infinity"),
+        ("cosh", "NaN,1,1,Infinity,Infinity"),
+        ("tanh", "NaN,0,-0,1,-1"),
+    ] {
+        assert_eq!(
+            forward(&mut context, &format!("show(Math.{})", function)),
+            format!("\"{}\"", expected),
+            "Math.{}",
+            function
+        );
+    }
+
+    assert_eq!(forward(&mut context, "Math.clz32(1)"), "31");
+    assert_eq!(forward(&mut context, "Math.clz32(2 ** 32)"), "32");
+    assert_eq!(
+        forward(&mut context, "Math.fround(1.1)"),
+        "1.100000023841858"
+    );
+    assert_eq!(
+        forward(&mut context, "Math.fround(3.4028236e38)"),
+        "Infinity"
+    );
+    assert_eq!(forward(&mut context, "Math.log1p(-1)"), "-Infinity");
+    assert_eq!(
+        forward(&mut context, "Math.expm1(1e-10)"),
+        "1.00000000005e-10"
+    );
+}
+
+#[test]
+fn hypot_special_values() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "Math.hypot(3, 4)"), "5");
+    assert_eq!(
+        forward(&mut context, "Object.is(Math.hypot(-0, -0), 0)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Math.hypot(NaN, 1)"), "NaN");
+    // An infinite argument wins over NaN, wherever it appears.
+    assert_eq!(
+        forward(&mut context, "Math.hypot(NaN, -Infinity)"),
+        "Infinity"
+    );
+    assert_eq!(
+        forward(&mut context, "Math.hypot(Infinity, NaN)"),
+        "Infinity"
+    );
+    // The squares would overflow or underflow, but the norm doesn't.
+    assert_eq!(
+        forward(&mut context, "Math.hypot(1e200, 1e200)"),
+        "1.414213562373095e+200"
+    );
+    assert_eq!(
+        forward(&mut context, "Math.hypot(1e-200, 1e-200)"),
+        "1.414213562373095e-200"
+    );
+    assert_eq!(forward(&mut context, "Math.hypot.length"), "2");
+}
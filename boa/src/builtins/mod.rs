@@ -1,5 +1,7 @@
 //! Builtins live here, such as Object, String, Math, etc.
 
+#[cfg(feature = "abort_controller")]
+pub mod abort_controller;
 pub mod array;
 pub mod array_buffer;
 pub mod bigint;
@@ -8,8 +10,12 @@ pub mod boolean;
 pub mod console;
 pub mod dataview;
 pub mod date;
+#[cfg(feature = "encoding")]
+pub mod encoding;
 pub mod error;
+pub mod eval;
 pub mod function;
+pub mod generator;
 pub mod global_this;
 pub mod infinity;
 pub mod intl;
@@ -21,14 +27,21 @@ pub mod math;
 pub mod nan;
 pub mod number;
 pub mod object;
+pub mod promise;
 pub mod proxy;
 pub mod reflect;
 pub mod regexp;
 pub mod set;
 pub mod string;
+#[cfg(feature = "structured_clone")]
+pub mod structured_clone;
 pub mod symbol;
+#[cfg(feature = "timers")]
+pub mod timers;
 pub mod typed_array;
 pub mod undefined;
+pub mod weak_map;
+pub mod weak_set;
 
 pub(crate) use self::{
     array::{array_iterator::ArrayIterator, Array},
@@ -36,7 +49,11 @@ pub(crate) use self::{
     boolean::Boolean,
     dataview::DataView,
     date::Date,
-    error::{Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError, UriError},
+    error::{
+        AggregateError, Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError,
+        UriError,
+    },
+    eval::Eval,
     function::BuiltInFunctionObject,
     global_this::GlobalThis,
     infinity::Infinity,
@@ -49,6 +66,7 @@ pub(crate) use self::{
     number::Number,
     object::for_in_iterator::ForInIterator,
     object::Object as BuiltInObjectObject,
+    promise::Promise,
     proxy::Proxy,
     reflect::Reflect,
     regexp::RegExp,
@@ -61,6 +79,8 @@ pub(crate) use self::{
         Int8Array, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
     },
     undefined::Undefined,
+    weak_map::WeakMap,
+    weak_set::WeakSet,
 };
 
 use crate::{
@@ -123,6 +143,7 @@ pub fn init(context: &mut Context) {
         Infinity,
         NaN,
         GlobalThis,
+        Eval,
         BuiltInFunctionObject,
         BuiltInObjectObject,
         Math,
@@ -130,6 +151,7 @@ pub fn init(context: &mut Context) {
         Json,
         Array,
         Proxy,
+        Promise,
         ArrayBuffer,
         BigInt,
         Boolean,
@@ -138,6 +160,8 @@ pub fn init(context: &mut Context) {
         Map,
         Number,
         Set,
+        WeakMap,
+        WeakSet,
         String,
         RegExp,
         Int8Array,
@@ -159,11 +183,30 @@ pub fn init(context: &mut Context) {
         SyntaxError,
         EvalError,
         UriError,
+        AggregateError,
         Reflect
     };
 
     #[cfg(feature = "console")]
     init_builtin::<console::Console>(context);
+
+    #[cfg(feature = "abort_controller")]
+    {
+        init_builtin::<abort_controller::AbortController>(context);
+        init_builtin::<abort_controller::AbortSignal>(context);
+    }
+
+    #[cfg(feature = "encoding")]
+    {
+        init_builtin::<encoding::TextEncoder>(context);
+        init_builtin::<encoding::TextDecoder>(context);
+    }
+
+    #[cfg(feature = "structured_clone")]
+    init_builtin::<structured_clone::StructuredClone>(context);
+
+    #[cfg(feature = "timers")]
+    timers::Timers::init(context);
 }
 
 pub trait JsArgs {
@@ -24,7 +24,8 @@ use crate::{
     value::{AbstractRelation, IntegerOrInfinity, JsValue},
     BoaProfiler, Context, JsResult,
 };
-use num_traits::{float::FloatCore, Num};
+use num_bigint::BigUint;
+use num_traits::{float::FloatCore, ToPrimitive};
 
 mod conversions;
 
@@ -248,34 +249,51 @@ impl Number {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        // 1. Let this_num be ? thisNumberValue(this value).
+        // 1. Let x be ? thisNumberValue(this value).
         let this_num = Self::this_number_value(this, context)?;
-        let precision = match args.get(0) {
-            // 2. Let f be ? ToIntegerOrInfinity(fractionDigits).
-            Some(n) => match n.to_integer(context)? as i32 {
-                0..=100 => n.to_integer(context)? as usize,
-                // 4, 5. If f < 0 or f > 100, throw a RangeError exception.
-                _ => {
-                    return Err(context.construct_range_error(
-                        "toFixed() digits argument must be between 0 and 100",
-                    ))
-                }
-            },
-            // 3. If fractionDigits is undefined, then f is 0.
-            None => 0,
+
+        // 2. Let f be ? ToIntegerOrInfinity(fractionDigits).
+        // 3. Assert: If fractionDigits is undefined, then f is 0.
+        let precision = match args.get_or_undefined(0).to_integer_or_infinity(context)? {
+            IntegerOrInfinity::Integer(f) if (0..=100).contains(&f) => f as usize,
+            // 4. If f is not finite, throw a RangeError exception.
+            // 5. If f < 0 or f > 100, throw a RangeError exception.
+            _ => {
+                return Err(context
+                    .construct_range_error("toFixed() digits argument must be between 0 and 100"))
+            }
         };
-        // 6. If x is not finite, return ! Number::toString(x).
+
+        // 6. If x is not finite, return Number::toString(x).
         if !this_num.is_finite() {
-            Ok(JsValue::new(Self::to_native_string(this_num)))
-        // 10. If x ≥ 10^21, then let m be ! ToString(𝔽(x)).
-        } else if this_num >= 1.0e21 {
-            Ok(JsValue::new(f64_to_exponential(this_num)))
-        } else {
-            // Get rid of the '-' sign for -0.0 because of 9. If x < 0, then set s to "-".
-            let this_num = if this_num == 0. { 0. } else { this_num };
-            let this_fixed_num = format!("{:.*}", precision, this_num);
-            Ok(JsValue::new(this_fixed_num))
+            return Ok(JsValue::new(Self::to_native_string(this_num)));
         }
+
+        // 7. Set x to ℝ(x).
+        // 8. Let s be the empty String.
+        // 9. If x < 0, then
+        //    a. Set s to "-".
+        //    b. Set x to -x.
+        let (sign, x) = if this_num < 0.0 {
+            ("-", -this_num)
+        } else {
+            ("", this_num)
+        };
+
+        // 10. If x ≥ 10^21, then
+        //     a. Let m be ! ToString(𝔽(x)).
+        // 11. Else,
+        //     a. Let n be an integer for which n / 10^f - x is as close to zero as possible.
+        //        If there are two such n, pick the larger n.
+        //     b-e. Insert a "." between the integer and fractional digits of n.
+        let m = if x >= 1.0e21 {
+            Self::to_native_string(x)
+        } else {
+            f64_to_fixed(x, precision)
+        };
+
+        // 12. Return the string-concatenation of s and m.
+        Ok(JsValue::new(format!("{}{}", sign, m)))
     }
 
     /// `Number.prototype.toLocaleString( [locales [, options]] )`
@@ -302,30 +320,6 @@ impl Number {
         Ok(JsValue::new(this_str_num))
     }
 
-    /// flt_str_to_exp - used in to_precision
-    ///
-    /// This function traverses a string representing a number,
-    /// returning the floored log10 of this number.
-    ///
-    fn flt_str_to_exp(flt: &str) -> i32 {
-        let mut non_zero_encountered = false;
-        let mut dot_encountered = false;
-        for (i, c) in flt.chars().enumerate() {
-            if c == '.' {
-                if non_zero_encountered {
-                    return (i as i32) - 1;
-                }
-                dot_encountered = true;
-            } else if c != '0' {
-                if dot_encountered {
-                    return 1 - (i as i32);
-                }
-                non_zero_encountered = true;
-            }
-        }
-        (flt.len() as i32) - 1
-    }
-
     /// round_to_precision - used in to_precision
     ///
     /// This procedure has two roles:
@@ -449,19 +443,14 @@ impl Number {
             exponent = 0;
         // 10
         } else {
-            // Due to f64 limitations, this part differs a bit from the spec,
-            // but has the same effect. It manipulates the string constructed
-            // by `format`: digits with an optional dot between two of them.
-            suffix = format!("{:.100}", this_num);
-
-            // a: getting an exponent
-            exponent = Self::flt_str_to_exp(&suffix);
-            // b: getting relevant digits only
-            if exponent < 0 {
-                suffix = suffix.split_off((1 - exponent) as usize);
-            } else if let Some(n) = suffix.find('.') {
-                suffix.remove(n);
-            }
+            // This part differs a bit from the spec, but has the same effect:
+            // it starts from every digit of the exact value of `this_num` and
+            // rounds them to `precision` digits.
+
+            // a, b: getting an exponent and the relevant digits
+            let (digits, digits_exponent) = f64_to_exact_digits(this_num);
+            suffix = digits;
+            exponent = digits_exponent;
             // impl: having exactly `precision` digits in `suffix`
             if Self::round_to_precision(&mut suffix, precision) {
                 exponent += 1;
@@ -583,7 +572,7 @@ impl Number {
                         } else {
                             let c: u8 = frac_buf[fraction_cursor];
                             // Reconstruct digit.
-                            let digit_0 = (c as char).to_digit(10).unwrap();
+                            let digit_0 = (c as char).to_digit(radix as u32).unwrap();
                             if digit_0 + 1 >= radix as u32 {
                                 continue;
                             }
@@ -655,17 +644,19 @@ impl Number {
 
         // 2. If radix is undefined, let radixNumber be 10.
         // 3. Else, let radixNumber be ? ToInteger(radix).
-        let radix = args
-            .get(0)
-            .map(|arg| arg.to_integer(context))
-            .transpose()?
-            .map_or(10, |radix| radix as u8);
+        let radix = args.get_or_undefined(0);
+        let radix_number = if radix.is_undefined() {
+            10.0
+        } else {
+            radix.to_integer(context)?
+        };
 
         // 4. If radixNumber < 2 or radixNumber > 36, throw a RangeError exception.
-        if !(2..=36).contains(&radix) {
+        if !(2.0..=36.0).contains(&radix_number) {
             return context
                 .throw_range_error("radix must be an integer at least 2 and no greater than 36");
         }
+        let radix = radix_number as u8;
 
         // 5. If radixNumber = 10, return ! ToString(x).
         if radix == 10 {
@@ -814,7 +805,12 @@ impl Number {
             //     mathInt may be an implementation-approximated value representing the integer value that is
             //     represented by Z in radix-R notation.)
             let math_int = u64::from_str_radix(var_z, var_r as u32).map_or_else(
-                |_| f64::from_str_radix(var_z, var_r as u32).expect("invalid_float_conversion"),
+                |_| {
+                    // Too long for a u64, so round the exact value to the nearest f64.
+                    BigUint::parse_bytes(var_z.as_bytes(), var_r as u32)
+                        .and_then(|n| n.to_f64())
+                        .expect("Z only contains radix-R digits")
+                },
                 |i| i as f64,
             );
 
@@ -1147,6 +1143,52 @@ fn f64_to_exponential(n: f64) -> String {
     }
 }
 
+/// Formats a non-negative, finite float with exactly `digits` fractional digits.
+///
+/// The value is rounded on its exact binary value rather than its shortest decimal
+/// representation, with ties going away from zero, so `(0.5).toFixed(0)` is `"1"` while
+/// `(1.005).toFixed(2)` is `"1.00"`.
+fn f64_to_fixed(x: f64, digits: usize) -> String {
+    // x = mantissa * 2^exponent, so n = round(mantissa * 10^digits * 2^exponent).
+    let (mantissa, exponent, _) = x.integer_decode();
+    let scaled = BigUint::from(mantissa) * BigUint::from(10u8).pow(digits as u32);
+    let n = if exponent >= 0 {
+        scaled << exponent as usize
+    } else {
+        let shift = exponent.unsigned_abs() as usize;
+        (scaled + (BigUint::from(1u8) << (shift - 1))) >> shift
+    };
+
+    let mut m = n.to_string();
+    if digits != 0 {
+        if m.len() <= digits {
+            m.insert_str(0, &"0".repeat(digits + 1 - m.len()));
+        }
+        m.insert(m.len() - digits, '.');
+    }
+    m
+}
+
+/// Gets every decimal digit of the exact value of a positive, finite float, along with the
+/// exponent of the leading digit, such that `x = d.ddd… × 10^exponent`.
+fn f64_to_exact_digits(x: f64) -> (String, i32) {
+    // x = mantissa * 2^exponent, and 2^-k = 5^k / 10^k, so the digits of a fractional x
+    // are the digits of mantissa * 5^k.
+    let (mantissa, exponent, _) = x.integer_decode();
+    let (n, scale) = if exponent >= 0 {
+        (BigUint::from(mantissa) << exponent as usize, 0)
+    } else {
+        let k = u32::from(exponent.unsigned_abs());
+        (
+            BigUint::from(mantissa) * BigUint::from(5u8).pow(k),
+            k as i32,
+        )
+    };
+    let digits = n.to_string();
+    let exponent = digits.len() as i32 - 1 - scale;
+    (digits, exponent)
+}
+
 /// Helper function that formats a float as a ES6-style exponential number string with a given precision.
 // We can't use the same approach as in `f64_to_exponential`
 // because in cases like (0.999).toExponential(0) the result will be 1e0.
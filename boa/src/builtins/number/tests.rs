@@ -100,6 +100,56 @@ fn to_fixed() {
     assert_eq!(nan_fixed, "\"NaN\"");
 }
 
+#[test]
+fn to_fixed_rounds_exact_value() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "(0.1).toFixed(20)"),
+        "\"0.10000000000000000555\""
+    );
+    assert_eq!(forward(&mut context, "(1.005).toFixed(2)"), "\"1.00\"");
+    assert_eq!(forward(&mut context, "(1.45).toFixed(1)"), "\"1.4\"");
+    assert_eq!(forward(&mut context, "(0.5).toFixed(0)"), "\"1\"");
+    assert_eq!(forward(&mut context, "(2.5).toFixed(0)"), "\"3\"");
+    assert_eq!(forward(&mut context, "(0.25).toFixed(1)"), "\"0.3\"");
+    assert_eq!(forward(&mut context, "(0).toFixed(2)"), "\"0.00\"");
+    assert_eq!(forward(&mut context, "(-0).toFixed(2)"), "\"0.00\"");
+    assert_eq!(forward(&mut context, "(5e-324).toFixed(3)"), "\"0.000\"");
+    assert_eq!(
+        forward(&mut context, "(1e20).toFixed(2)"),
+        "\"100000000000000000000.00\""
+    );
+    assert_eq!(forward(&mut context, "(1e21).toFixed(2)"), "\"1e+21\"");
+}
+
+#[test]
+fn to_fixed_negative() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "(-1.005).toFixed(2)"), "\"-1.00\"");
+    assert_eq!(forward(&mut context, "(-2.5).toFixed(0)"), "\"-3\"");
+    assert_eq!(forward(&mut context, "(-0.001).toFixed(2)"), "\"-0.00\"");
+    assert_eq!(forward(&mut context, "(-1e21).toFixed(2)"), "\"-1e+21\"");
+}
+
+#[test]
+fn to_fixed_range_error() {
+    let mut context = Context::new();
+
+    for digits in ["-1", "101", "Infinity", "-Infinity"] {
+        assert_eq!(
+            "\"RangeError\"",
+            &forward(
+                &mut context,
+                format!("try {{ (1).toFixed({}) }} catch (e) {{ e.name }}", digits)
+            )
+        );
+    }
+    assert_eq!(forward(&mut context, "(1).toFixed(NaN)"), "\"1\"");
+    assert_eq!(forward(&mut context, "(1).toFixed(100).length"), "102");
+}
+
 #[test]
 fn to_locale_string() {
     let mut context = Context::new();
@@ -183,6 +233,35 @@ fn to_precision() {
     assert_eq!(forward(&mut context, range_error_4), expected);
 }
 
+#[test]
+fn to_precision_notation_and_rounding() {
+    let mut context = Context::new();
+
+    let cases = [
+        ("(123.456).toPrecision(4)", "123.5"),
+        ("(0.0001234).toPrecision(2)", "0.00012"),
+        ("(0).toPrecision(3)", "0.00"),
+        ("(-0).toPrecision(1)", "0"),
+        ("(-123.456).toPrecision(2)", "-1.2e+2"),
+        ("(0.000001).toPrecision(2)", "0.0000010"),
+        ("(1e-7).toPrecision(2)", "1.0e-7"),
+        ("(999999).toPrecision(3)", "1.00e+6"),
+        ("(0.125).toPrecision(2)", "0.13"),
+        ("(1.45).toPrecision(2)", "1.4"),
+        ("(1e-200).toPrecision(3)", "1.00e-200"),
+        ("(5e-324).toPrecision(1)", "5e-324"),
+        ("(1.7976931348623157e308).toPrecision(5)", "1.7977e+308"),
+    ];
+    for (src, expected) in cases {
+        assert_eq!(
+            forward(&mut context, src),
+            format!("\"{}\"", expected),
+            "{}",
+            src
+        );
+    }
+}
+
 #[test]
 fn to_string() {
     let mut context = Context::new();
@@ -367,6 +446,47 @@ fn to_string() {
     );
 }
 
+#[test]
+fn to_string_radix() {
+    let mut context = Context::new();
+
+    assert_eq!("\"ff\"", &forward(&mut context, "(255).toString(16)"));
+    assert_eq!("\"-ff\"", &forward(&mut context, "(-255).toString(16)"));
+    assert_eq!("\"101\"", &forward(&mut context, "(5).toString(2)"));
+    assert_eq!("\"z\"", &forward(&mut context, "(35).toString(36)"));
+    assert_eq!(
+        "\"21i3v9\"",
+        &forward(&mut context, "(123456789).toString(36)")
+    );
+    assert_eq!("\"0.1\"", &forward(&mut context, "(0.5).toString(2)"));
+    assert_eq!("\"0.11\"", &forward(&mut context, "(0.75).toString(2)"));
+    assert_eq!(
+        "\"255\"",
+        &forward(&mut context, "(255).toString(undefined)")
+    );
+    assert_eq!("\"ff\"", &forward(&mut context, "(255).toString(16.9)"));
+    // Rounding the last fractional digit carries over a digit above 9.
+    assert_eq!(
+        "\"273.30cgb3i2dd\"",
+        &forward(&mut context, "(943.1516034985423).toString(20)")
+    );
+}
+
+#[test]
+fn to_string_radix_range_error() {
+    let mut context = Context::new();
+
+    for radix in ["0", "1", "37", "258", "-254", "NaN", "Infinity"] {
+        assert_eq!(
+            "\"RangeError\"",
+            &forward(
+                &mut context,
+                format!("try {{ (255).toString({}) }} catch (e) {{ e.name }}", radix)
+            )
+        );
+    }
+}
+
 #[test]
 fn num_to_string_exponential() {
     let mut context = Context::new();
@@ -405,6 +525,46 @@ fn num_to_string_exponential() {
     );
 }
 
+#[test]
+fn to_string_special_values() {
+    let mut context = Context::new();
+
+    assert_eq!("\"0\"", forward(&mut context, "String(-0)"));
+    assert_eq!("\"0\"", forward(&mut context, "'' + -0"));
+    assert_eq!("\"0\"", forward(&mut context, "`${-0}`"));
+    assert_eq!("\"0,0\"", forward(&mut context, "[0, -0].join()"));
+    assert_eq!("\"Infinity\"", forward(&mut context, "String(Infinity)"));
+    assert_eq!("\"-Infinity\"", forward(&mut context, "'' + -Infinity"));
+    assert_eq!("\"NaN\"", forward(&mut context, "`${0 / 0}`"));
+}
+
+#[test]
+fn to_string_shortest_representation() {
+    let mut context = Context::new();
+
+    assert_eq!("\"0.1\"", forward(&mut context, "String(0.1)"));
+    assert_eq!(
+        "\"0.30000000000000004\"",
+        forward(&mut context, "String(0.1 + 0.2)")
+    );
+    assert_eq!("\"0.3\"", forward(&mut context, "String(0.3)"));
+    assert_eq!("\"4.35\"", forward(&mut context, "String(4.35)"));
+    assert_eq!(
+        "\"0.3333333333333333\"",
+        forward(&mut context, "String(1 / 3)")
+    );
+    assert_eq!("\"5e-324\"", forward(&mut context, "String(5e-324)"));
+    assert_eq!(
+        "\"1.7976931348623157e+308\"",
+        forward(&mut context, "String(Number.MAX_VALUE)")
+    );
+    assert_eq!(
+        "\"9007199254740992\"",
+        forward(&mut context, "String(2 ** 53)")
+    );
+    assert_eq!("\"1.23e-18\"", forward(&mut context, "String(123e-20)"));
+}
+
 #[test]
 fn value_of() {
     let mut context = Context::new();
@@ -617,6 +777,44 @@ fn parse_int_negative_varying_radix() {
     }
 }
 
+#[test]
+fn parse_int_radix_36() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseInt(\"Z\", 36)"), "35");
+    assert_eq!(&forward(&mut context, "parseInt(\"zz\", 36)"), "1295");
+    assert_eq!(&forward(&mut context, "parseInt(\"-Boa\", 36)"), "-15130");
+    assert_eq!(&forward(&mut context, "parseInt(\"1y\", 35)"), "69");
+    assert_eq!(
+        &forward(&mut context, "parseInt(\"zzzzzzzzzzzzzzzz\", 36)"),
+        "7.958661109946401e+24"
+    );
+}
+
+#[test]
+fn parse_int_letter_case() {
+    let mut context = Context::new();
+
+    for (lower, upper, radix) in [("ff", "FF", 16), ("boa", "BOA", 36), ("zz", "Zz", 36)] {
+        assert_eq!(
+            forward(&mut context, format!("parseInt(\"{}\", {})", lower, radix)),
+            forward(&mut context, format!("parseInt(\"{}\", {})", upper, radix))
+        );
+    }
+}
+
+#[test]
+fn parse_int_stops_at_invalid_digit() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseInt(\"12{4\", 36)"), "38");
+    assert_eq!(&forward(&mut context, "parseInt(\"az!\", 11)"), "10");
+    assert_eq!(&forward(&mut context, "parseInt(\"19\", 8)"), "1");
+    assert_eq!(&forward(&mut context, "parseInt(\"fg\", 16)"), "15");
+    assert_eq!(&forward(&mut context, "parseInt(\"9\", 8)"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"a\", 10)"), "NaN");
+}
+
 #[test]
 fn parse_int_malformed_str() {
     let mut context = Context::new();
@@ -648,6 +846,59 @@ fn parse_int_too_many_args() {
     assert_eq!(&forward(&mut context, "parseInt(\"100\", 10, 10)"), "100");
 }
 
+/// Shows that a "0x" prefix only switches to radix 16 when the radix is 0, undefined or 16.
+#[test]
+fn parse_int_hex_prefix_radix() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseInt(\"0x1F\")"), "31");
+    assert_eq!(&forward(&mut context, "parseInt(\"0X1f\", 0)"), "31");
+    assert_eq!(&forward(&mut context, "parseInt(\"0x1F\", 16)"), "31");
+    assert_eq!(&forward(&mut context, "parseInt(\"0x1F\", 10)"), "0");
+    assert_eq!(&forward(&mut context, "parseInt(\"-0x10\")"), "-16");
+    assert_eq!(&forward(&mut context, "parseInt(\"0x\")"), "NaN");
+}
+
+#[test]
+fn parse_int_radix_out_of_range() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 37)"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 1)"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", -1)"), "NaN");
+    // The radix goes through ToInt32, so 2^32 + 2 is radix 2.
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 4294967298)"), "2");
+}
+
+#[test]
+fn parse_int_whitespace_and_sign() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseInt(\"   -42abc\")"), "-42");
+    assert_eq!(&forward(&mut context, "parseInt(\"\\n\\t +7 \")"), "7");
+    assert_eq!(&forward(&mut context, "parseInt(\"-0\")"), "-0");
+    assert_eq!(&forward(&mut context, "parseInt(\"-\")"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"+-1\")"), "NaN");
+}
+
+#[test]
+fn parse_int_long_digit_strings() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        &forward(&mut context, "parseInt(\"123456789012345678901234567890\")"),
+        "1.2345678901234568e+29"
+    );
+    assert_eq!(
+        &forward(&mut context, "parseInt(\"18446744073709551617\")"),
+        "18446744073709552000"
+    );
+    assert_eq!(
+        &forward(&mut context, "parseInt(\"1\".repeat(400))"),
+        "Infinity"
+    );
+}
+
 #[test]
 fn parse_float_simple() {
     let mut context = Context::new();
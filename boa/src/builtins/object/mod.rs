@@ -14,7 +14,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object
 
 use crate::{
-    builtins::{map, BuiltIn, JsArgs},
+    builtins::{iterable::IteratorResult, map, BuiltIn, JsArgs},
     context::StandardObjects,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
@@ -25,6 +25,8 @@ use crate::{
     value::JsValue,
     BoaProfiler, Context, JsResult,
 };
+use indexmap::IndexMap;
+use std::hash::Hash;
 
 use super::Array;
 
@@ -58,7 +60,7 @@ impl BuiltIn for Object {
         .method(Self::property_is_enumerable, "propertyIsEnumerable", 0)
         .method(Self::to_string, "toString", 0)
         .method(Self::value_of, "valueOf", 0)
-        .method(Self::is_prototype_of, "isPrototypeOf", 0)
+        .method(Self::is_prototype_of, "isPrototypeOf", 1)
         .static_method(Self::create, "create", 2)
         .static_method(Self::set_prototype_of, "setPrototypeOf", 2)
         .static_method(Self::get_prototype_of, "getPrototypeOf", 1)
@@ -89,6 +91,7 @@ impl BuiltIn for Object {
         .static_method(Self::get_own_property_symbols, "getOwnPropertySymbols", 1)
         .static_method(Self::has_own, "hasOwn", 2)
         .static_method(Self::from_entries, "fromEntries", 1)
+        .static_method(Self::group_by, "groupBy", 2)
         .build();
 
         object.into()
@@ -198,28 +201,32 @@ impl Object {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let object = args.get_or_undefined(0).to_object(context)?;
+        // 1. Let obj be ? ToObject(O).
+        let obj = args.get_or_undefined(0).to_object(context)?;
+
+        // 2. Let ownKeys be ? obj.[[OwnPropertyKeys]]().
+        let own_keys = obj.__own_property_keys__(context)?;
+
+        // 3. Let descriptors be ! OrdinaryObjectCreate(%Object.prototype%).
         let descriptors = context.construct_object();
 
-        for key in object.borrow().properties().keys() {
-            let descriptor = {
-                let desc = object.__get_own_property__(&key, context)?;
-                Self::from_property_descriptor(desc, context)
-            };
+        // 4. For each element key of ownKeys, do
+        for key in own_keys {
+            // a. Let desc be ? obj.[[GetOwnProperty]](key).
+            let desc = obj.__get_own_property__(&key, context)?;
+
+            // b. Let descriptor be ! FromPropertyDescriptor(desc).
+            let descriptor = Self::from_property_descriptor(desc, context);
 
+            // c. If descriptor is not undefined,
+            //    perform ! CreateDataPropertyOrThrow(descriptors, key, descriptor).
             if !descriptor.is_undefined() {
-                descriptors.borrow_mut().insert(
-                    key,
-                    PropertyDescriptor::builder()
-                        .value(descriptor)
-                        .writable(true)
-                        .enumerable(true)
-                        .configurable(true),
-                );
+                descriptors.create_data_property_or_throw(key, descriptor, context)?;
             }
         }
 
-        Ok(JsValue::Object(descriptors))
+        // 5. Return descriptors.
+        Ok(descriptors.into())
     }
 
     /// The abstract operation `FromPropertyDescriptor`.
@@ -947,6 +954,127 @@ impl Object {
         // 6. Return ? AddEntriesFromIterable(obj, iterable, adder).
         map::add_entries_from_iterable(&obj, iterable, &adder.into(), context)
     }
+
+    /// `Object.groupBy( items, callbackfn )`
+    ///
+    /// Groups the elements of an iterable by the property keys returned by `callbackfn`, into a
+    /// null-prototype object holding an array of elements per key.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.groupby
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/groupBy
+    pub fn group_by(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let groups be ? GroupBy(items, callbackfn, property).
+        let groups = group_by(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            |key, context| key.to_property_key(context),
+            context,
+        )?;
+
+        // 2. Let obj be OrdinaryObjectCreate(null).
+        let obj = JsObject::from_proto_and_data(None, ObjectData::ordinary());
+
+        // 3. For each Record { [[Key]], [[Elements]] } g of groups, do
+        for (key, elements) in groups {
+            // a. Let elements be CreateArrayFromList(g.[[Elements]]).
+            let elements = Array::create_array_from_list(elements, context);
+
+            // b. Perform ! CreateDataPropertyOrThrow(obj, g.[[Key]], elements).
+            obj.create_data_property_or_throw(key, elements, context)?;
+        }
+
+        // 4. Return obj.
+        Ok(obj.into())
+    }
+}
+
+/// The abstract operation `GroupBy ( items, callbackfn, keyCoercion )`
+///
+/// Calls `callbackfn` with each element of the iterable `items` and its index, and groups the
+/// elements by the returned key after converting it with `coerce_key`. The groups keep the
+/// order in which their keys were first returned.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-groupby
+pub(crate) fn group_by<K, F>(
+    items: &JsValue,
+    callback: &JsValue,
+    coerce_key: F,
+    context: &mut Context,
+) -> JsResult<IndexMap<K, Vec<JsValue>>>
+where
+    K: Hash + Eq,
+    F: Fn(JsValue, &mut Context) -> JsResult<K>,
+{
+    // 1. Perform ? RequireObjectCoercible(items).
+    items.require_object_coercible(context)?;
+
+    // 2. If IsCallable(callbackfn) is false, throw a TypeError exception.
+    let callback = callback
+        .as_callable()
+        .ok_or_else(|| context.construct_type_error("groupBy: callback is not callable"))?;
+
+    // 3. Let groups be a new empty List.
+    let mut groups: IndexMap<K, Vec<JsValue>> = IndexMap::new();
+
+    // 4. Let iteratorRecord be ? GetIterator(items, sync).
+    let iterator_record = items.get_iterator(context, None, None)?;
+
+    // 5. Let k be 0.
+    let mut k: usize = 0;
+
+    // 6. Repeat,
+    loop {
+        // a. If k ≥ 2^53 - 1, then
+        //     i. Let error be ThrowCompletion(a newly created TypeError object).
+        //     ii. Return ? IteratorClose(iteratorRecord, error).
+        // NOTE: This can't happen in practice, as `k` can't be incremented that many times.
+
+        // b. Let next be ? IteratorStepValue(iteratorRecord).
+        let IteratorResult { value, done } = iterator_record.next(context)?;
+
+        // c. If next is done, then
+        if done {
+            // i. Return groups.
+            return Ok(groups);
+        }
+
+        // d. Let value be next.
+        // e. Let key be Completion(Call(callbackfn, undefined, « value, 𝔽(k) »)).
+        // f. IfAbruptCloseIterator(key, iteratorRecord).
+        // g. If keyCoercion is property, then
+        //     i. Set key to Completion(ToPropertyKey(key)).
+        //     ii. IfAbruptCloseIterator(key, iteratorRecord).
+        // h. Else,
+        //     i. Assert: keyCoercion is zero.
+        //     ii. If key is -0𝔽, set key to +0𝔽.
+        let key = callback
+            .call(&JsValue::undefined(), &[value.clone(), k.into()], context)
+            .and_then(|key| coerce_key(key, context));
+        let key = match key {
+            Ok(key) => key,
+            Err(error) => {
+                // Closing the iterator returns the abrupt completion it was given, or the
+                // error thrown by the iterator's `return` method.
+                return match iterator_record.close(Err(error), context) {
+                    Ok(_) => unreachable!("IteratorClose returned a normal completion"),
+                    Err(error) => Err(error),
+                };
+            }
+        };
+
+        // i. Perform AddValueToKeyedGroup(groups, key, value).
+        groups.entry(key).or_default().push(value);
+
+        // j. Set k to k + 1.
+        k += 1;
+    }
 }
 
 /// The abstract operation ObjectDefineProperties
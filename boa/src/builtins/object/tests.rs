@@ -291,6 +291,58 @@ fn get_own_property_descriptors() {
     assert_eq!(forward(&mut context, "result.b.value"), "2");
 }
 
+#[test]
+fn get_own_property_descriptors_includes_every_own_key() {
+    let mut context = Context::new();
+    let init = r#"
+        let sym = Symbol("sym");
+        let obj = { a: 1, [sym]: 2 };
+        Object.defineProperty(obj, "hidden", { value: 3, enumerable: false });
+        let result = Object.getOwnPropertyDescriptors(obj);
+        let string = Object.getOwnPropertyDescriptors("ab");
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Reflect.ownKeys(result).map(String).join()"),
+        "\"a,hidden,Symbol(sym)\""
+    );
+    assert_eq!(forward(&mut context, "result.hidden.value"), "3");
+    assert_eq!(forward(&mut context, "result.hidden.enumerable"), "false");
+    assert_eq!(forward(&mut context, "result[sym].value"), "2");
+    assert_eq!(
+        forward(&mut context, "Object.keys(string).join()"),
+        "\"0,1,length\""
+    );
+    assert_eq!(forward(&mut context, "string[1].value"), "\"b\"");
+    assert_eq!(forward(&mut context, "string[1].writable"), "false");
+}
+
+#[test]
+fn get_own_property_descriptors_clone_keeps_accessors() {
+    let mut context = Context::new();
+    let init = r#"
+        let source = {
+            value: 1,
+            get double() { return this.value * 2; },
+        };
+        let clone = Object.create(
+            Object.getPrototypeOf(source),
+            Object.getOwnPropertyDescriptors(source)
+        );
+        clone.value = 21;
+        let original = Object.getOwnPropertyDescriptor(source, "double");
+        let copied = Object.getOwnPropertyDescriptor(clone, "double");
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "clone.double"), "42");
+    assert_eq!(forward(&mut context, "source.double"), "2");
+    assert_eq!(forward(&mut context, "copied.get === original.get"), "true");
+    assert_eq!(forward(&mut context, "copied.set"), "undefined");
+    assert_eq!(forward(&mut context, "'value' in copied"), "false");
+}
+
 #[test]
 fn object_define_properties() {
     let mut context = Context::new();
@@ -321,6 +373,43 @@ fn object_is_prototype_of() {
     assert_eq!(context.eval(init).unwrap(), JsValue::new(true));
 }
 
+#[test]
+fn object_is_prototype_of_chain() {
+    let scenario = r#"
+        let grandparent = {};
+        let parent = Object.create(grandparent);
+        let child = Object.create(parent);
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("parent.isPrototypeOf(child)", "true"),
+        TestAction::TestEq("grandparent.isPrototypeOf(child)", "true"),
+        TestAction::TestEq("child.isPrototypeOf(parent)", "false"),
+        TestAction::TestEq("child.isPrototypeOf(child)", "false"),
+        TestAction::TestEq(
+            "Object.prototype.isPrototypeOf(Object.create(null))",
+            "false",
+        ),
+        TestAction::TestEq("Object.prototype.isPrototypeOf.length", "1"),
+    ]);
+}
+
+#[test]
+fn object_is_prototype_of_non_object() {
+    check_output(&[
+        TestAction::TestEq("Object.prototype.isPrototypeOf(1)", "false"),
+        TestAction::TestEq("Object.prototype.isPrototypeOf('a')", "false"),
+        TestAction::TestEq("Object.prototype.isPrototypeOf(undefined)", "false"),
+        // A primitive argument returns `false` before `this` is converted.
+        TestAction::TestEq("Object.prototype.isPrototypeOf.call(null, 1)", "false"),
+        TestAction::TestEq(
+            "Object.prototype.isPrototypeOf.call(null, {})",
+            r#"Uncaught "TypeError": "cannot convert 'null' or 'undefined' to object""#,
+        ),
+    ]);
+}
+
 #[test]
 fn object_get_own_property_names_invalid_args() {
     let error_message = r#"Uncaught "TypeError": "cannot convert 'null' or 'undefined' to object""#;
@@ -356,6 +445,32 @@ fn object_get_own_property_names() {
     ]);
 }
 
+#[test]
+fn object_get_own_property_names_index_order() {
+    check_output(&[
+        TestAction::TestEq(
+            r#"Object.getOwnPropertyNames({ "2": 0, "10": 0, "1": 0 })"#,
+            r#"[ "1", "2", "10" ]"#,
+        ),
+        TestAction::TestEq(
+            r#"Object.getOwnPropertyNames({ "b": 0, "100": 0, "a": 0, "20": 0, "4294967294": 0 })"#,
+            r#"[ "20", "100", "4294967294", "b", "a" ]"#,
+        ),
+        // Only canonical integers below 2^32 - 1 are array indices, the rest keep creation order.
+        TestAction::TestEq(
+            r#"Object.getOwnPropertyNames({ "b": 0, "4294967295": 0, "01": 0, "1": 0 })"#,
+            r#"[ "1", "b", "4294967295", "01" ]"#,
+        ),
+        TestAction::TestEq(
+            r#"
+            let o = { "1": "a", "01": "b", "+1": "c" };
+            o[1] + o["01"] + o["+1"]
+            "#,
+            r#""abc""#,
+        ),
+    ]);
+}
+
 #[test]
 fn object_get_own_property_symbols_invalid_args() {
     let error_message = r#"Uncaught "TypeError": "cannot convert 'null' or 'undefined' to object""#;
@@ -420,3 +535,131 @@ fn object_from_entries() {
         TestAction::TestEq("map[5]", "4"),
     ]);
 }
+
+#[test]
+fn object_from_entries_round_trips_object_entries() {
+    let scenario = r#"
+        let source = { a: 1, b: "two", c: [3] };
+        let copy = Object.fromEntries(Object.entries(source));
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("copy === source", "false"),
+        TestAction::TestEq("Object.getPrototypeOf(copy) === Object.prototype", "true"),
+        TestAction::TestEq("Object.keys(copy).join()", "\"a,b,c\""),
+        TestAction::TestEq("copy.a", "1"),
+        TestAction::TestEq("copy.b", "\"two\""),
+        TestAction::TestEq("copy.c === source.c", "true"),
+    ]);
+}
+
+#[test]
+fn object_from_entries_iterables() {
+    let scenario = r#"
+        let fromMap = Object.fromEntries(new Map([["x", 1], [2, "y"]]));
+        let fromArrayLike = Object.fromEntries([{ 0: "k", 1: "v", length: 2 }]);
+        let fromKeyObject = Object.fromEntries([[{ toString() { return "z"; } }, 3]]);
+        let custom = {
+            [Symbol.iterator]() {
+                let i = 0;
+                return {
+                    next() {
+                        i++;
+                        return i < 3 ? { value: ["k" + i, i], done: false } : { done: true };
+                    },
+                };
+            },
+        };
+        let fromCustom = Object.fromEntries(custom);
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("fromMap.x", "1"),
+        TestAction::TestEq("fromMap[2]", "\"y\""),
+        TestAction::TestEq("fromArrayLike.k", "\"v\""),
+        TestAction::TestEq("fromKeyObject.z", "3"),
+        TestAction::TestEq("Object.keys(fromCustom).join()", "\"k1,k2\""),
+        TestAction::TestEq("fromCustom.k2", "2"),
+    ]);
+}
+
+#[test]
+fn object_from_entries_non_object_entry() {
+    let scenario = r#"
+        let closed = false;
+        let iterable = {
+            [Symbol.iterator]() {
+                return {
+                    next() { return { value: 1, done: false }; },
+                    return() { closed = true; return {}; },
+                };
+            },
+        };
+        let error;
+        try {
+            Object.fromEntries(iterable);
+        } catch (e) {
+            error = e;
+        }
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("error instanceof TypeError", "true"),
+        TestAction::TestEq("closed", "true"),
+    ]);
+}
+
+#[test]
+fn object_group_by() {
+    let scenario = r#"
+        let calls = [];
+        let groups = Object.groupBy([1, 2, 3, 4, 5], (element, index) => {
+            calls.push(element + ":" + index);
+            return element % 2 === 0 ? "even" : "odd";
+        });
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("calls.join()", "\"1:0,2:1,3:2,4:3,5:4\""),
+        TestAction::TestEq("Object.getPrototypeOf(groups)", "null"),
+        TestAction::TestEq("Object.keys(groups).join()", "\"odd,even\""),
+        TestAction::TestEq("groups.odd.join()", "\"1,3,5\""),
+        TestAction::TestEq("groups.even.join()", "\"2,4\""),
+        TestAction::TestEq("Array.isArray(groups.even)", "true"),
+        TestAction::TestEq(
+            "Object.keys(Object.groupBy('abca', c => c === 'a' ? 1 : c)).join()",
+            "\"1,b,c\"",
+        ),
+    ]);
+}
+
+#[test]
+fn object_group_by_errors() {
+    check_output(&[
+        TestAction::TestStartsWith("Object.groupBy(null, x => x)", "Uncaught \"TypeError\": "),
+        TestAction::TestStartsWith("Object.groupBy([1], 1)", "Uncaught \"TypeError\": "),
+        TestAction::TestEq(
+            r#"
+            let closed = false;
+            let iterable = {
+                [Symbol.iterator]() {
+                    return {
+                        next() { return { value: 1, done: false }; },
+                        return() { closed = true; return {}; },
+                    };
+                },
+            };
+            try {
+                Object.groupBy(iterable, () => { throw "stop"; });
+            } catch (e) {
+                e + ":" + closed
+            }
+            "#,
+            "\"stop:true\"",
+        ),
+    ]);
+}
@@ -0,0 +1,1931 @@
+//! This module implements the global `Promise` object.
+//!
+//! A `Promise` represents the eventual completion or failure of an asynchronous operation. The
+//! reactions registered with `then` never run synchronously: settling a promise hands a job to
+//! the host through [`HostHooks::enqueue_promise_job`], and the default hooks queue it until
+//! [`Context::run_jobs`] is called.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-promise-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(doc)]
+use crate::host::HostHooks;
+use crate::{
+    builtins::{error::AggregateError, iterable::IteratorRecord, Array, BuiltIn, JsArgs},
+    context::StandardObjects,
+    gc::{Finalize, Trace},
+    host::PromiseRejectionOperation,
+    job::NativeJob,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+use gc::{Gc, GcCell};
+
+/// `IfAbruptRejectPromise ( value, capability )`
+///
+/// Rejects the promise of `capability` and returns it from the current function if `value` is
+/// an error, or unwraps it otherwise.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ifabruptrejectpromise
+macro_rules! if_abrupt_reject_promise {
+    ($value:expr, $capability:expr, $context:expr) => {
+        match $value {
+            // 1. If value is an abrupt completion, then
+            Err(error) => {
+                // a. Perform ? Call(capability.[[Reject]], undefined, « value.[[Value]] »).
+                $capability
+                    .reject
+                    .call(&JsValue::undefined(), &[error], $context)?;
+
+                // b. Return capability.[[Promise]].
+                return Ok($capability.promise.clone().into());
+            }
+            // 2. Else if value is a Completion Record, set value to value.[[Value]].
+            Ok(value) => value,
+        }
+    };
+}
+
+/// The `[[PromiseState]]` and `[[PromiseResult]]` internal slots of a promise.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum PromiseState {
+    Pending,
+    Fulfilled(JsValue),
+    Rejected(JsValue),
+}
+
+/// The `[[Type]]` of a promise reaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReactionType {
+    Fulfill,
+    Reject,
+}
+
+/// A [`PromiseReaction Record`][spec], called when a promise is settled.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisereaction-records
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ReactionRecord {
+    /// The capability of the promise that is resolved with the result of the handler, or `None`
+    /// if the handler is an internal continuation, like the ones of `await`.
+    capability: Option<PromiseCapability>,
+    #[unsafe_ignore_trace]
+    kind: ReactionType,
+    /// The handler, or `None` to pass the settled value through.
+    handler: Option<JsObject>,
+}
+
+/// A [`PromiseCapability Record`][spec], a promise along with the functions that settle it.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisecapability-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct PromiseCapability {
+    pub(crate) promise: JsObject,
+    pub(crate) resolve: JsObject,
+    pub(crate) reject: JsObject,
+}
+
+/// The resolve and reject functions passed to the executor of `NewPromiseCapability`.
+#[derive(Debug, Default, Trace, Finalize)]
+struct ResolvingFunctionsRecord {
+    resolve: JsValue,
+    reject: JsValue,
+}
+
+/// The data of the resolve and reject functions created by `CreateResolvingFunctions`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ResolvingFunctionData {
+    promise: JsObject,
+    already_resolved: Gc<GcCell<bool>>,
+}
+
+/// The data of the functions `Promise.prototype.finally` calls `then` with.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct FinallyData {
+    on_finally: JsObject,
+    constructor: JsObject,
+}
+
+/// The data of the functions called with the settled value of each promise given to
+/// `Promise.all`, `Promise.allSettled` and `Promise.any`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ResolveElementData {
+    already_called: Gc<GcCell<bool>>,
+    index: usize,
+    values: Gc<GcCell<Vec<JsValue>>>,
+    capability: PromiseCapability,
+    remaining_elements: Gc<GcCell<usize>>,
+}
+
+impl ResolveElementData {
+    /// Marks the element as settled, returning `false` if it already was.
+    fn call_once(&self) -> bool {
+        let mut already_called = self.already_called.borrow_mut();
+        !std::mem::replace(&mut *already_called, true)
+    }
+
+    /// Decrements the count of remaining elements, returning `true` if it reached zero.
+    fn finish_element(&self) -> bool {
+        let mut remaining_elements = self.remaining_elements.borrow_mut();
+        *remaining_elements -= 1;
+        *remaining_elements == 0
+    }
+}
+
+/// The internal slots of a `Promise` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Promise {
+    state: PromiseState,
+    fulfill_reactions: Vec<ReactionRecord>,
+    reject_reactions: Vec<ReactionRecord>,
+    is_handled: bool,
+}
+
+impl BuiltIn for Promise {
+    const NAME: &'static str = "Promise";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_species = FunctionBuilder::native(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .constructor(false)
+            .build();
+
+        ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().promise_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_method(Self::all, "all", 1)
+        .static_method(Self::all_settled, "allSettled", 1)
+        .static_method(Self::any, "any", 1)
+        .static_method(Self::race, "race", 1)
+        .static_method(Self::reject, "reject", 1)
+        .static_method(Self::resolve, "resolve", 1)
+        .static_method(Self::with_resolvers, "withResolvers", 0)
+        .static_accessor(
+            WellKnownSymbols::species(),
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::catch, "catch", 1)
+        .method(Self::finally, "finally", 1)
+        .method(Self::then, "then", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .into()
+    }
+}
+
+impl Promise {
+    const LENGTH: usize = 1;
+
+    /// `Promise ( executor )`
+    ///
+    /// Creates a pending promise, and calls `executor` with the functions that resolve and
+    /// reject it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-executor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/Promise
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin Promise constructor without new is forbidden",
+            );
+        }
+
+        // 2. If IsCallable(executor) is false, throw a TypeError exception.
+        let executor = match args.get_or_undefined(0).as_callable() {
+            Some(executor) => executor.clone(),
+            None => return context.throw_type_error("Promise executor is not callable"),
+        };
+
+        // 3. Let promise be ? OrdinaryCreateFromConstructor(NewTarget, "%Promise.prototype%", « [[PromiseState]], [[PromiseResult]], [[PromiseFulfillReactions]], [[PromiseRejectReactions]], [[PromiseIsHandled]] »).
+        // 4. Set promise.[[PromiseState]] to pending.
+        // 5. Set promise.[[PromiseFulfillReactions]] to a new empty List.
+        // 6. Set promise.[[PromiseRejectReactions]] to a new empty List.
+        // 7. Set promise.[[PromiseIsHandled]] to false.
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardObjects::promise_object, context)?;
+        let promise = JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::promise(Self {
+                state: PromiseState::Pending,
+                fulfill_reactions: Vec::new(),
+                reject_reactions: Vec::new(),
+                is_handled: false,
+            }),
+        );
+
+        // 8. Let resolvingFunctions be CreateResolvingFunctions(promise).
+        let (resolve, reject) = Self::create_resolving_functions(&promise, context);
+
+        // 9. Let completion be Completion(Call(executor, undefined, « resolvingFunctions.[[Resolve]], resolvingFunctions.[[Reject]] »)).
+        let completion = executor.call(
+            &JsValue::undefined(),
+            &[resolve.into(), reject.clone().into()],
+            context,
+        );
+
+        // 10. If completion is an abrupt completion, then
+        if let Err(error) = completion {
+            // a. Perform ? Call(resolvingFunctions.[[Reject]], undefined, « completion.[[Value]] »).
+            reject.call(&JsValue::undefined(), &[error], context)?;
+        }
+
+        // 11. Return promise.
+        Ok(promise.into())
+    }
+
+    /// `get Promise [ @@species ]`
+    ///
+    /// The `Promise [ @@species ]` accessor property returns the Promise constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-promise-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/@@species
+    fn get_species(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return the this value.
+        Ok(this.clone())
+    }
+
+    /// `CreateResolvingFunctions ( promise )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createresolvingfunctions
+    fn create_resolving_functions(
+        promise: &JsObject,
+        context: &mut Context,
+    ) -> (JsObject, JsObject) {
+        // 1. Let alreadyResolved be the Record { [[Value]]: false }.
+        let data = ResolvingFunctionData {
+            promise: promise.clone(),
+            already_resolved: Gc::new(GcCell::new(false)),
+        };
+
+        // 2. Let stepsResolve be the algorithm steps defined in Promise Resolve Functions.
+        // 3. Let lengthResolve be the number of non-optional parameters of the function definition in Promise Resolve Functions.
+        // 4. Let resolve be CreateBuiltinFunction(stepsResolve, lengthResolve, "", « [[Promise]], [[AlreadyResolved]] »).
+        // 5. Set resolve.[[Promise]] to promise.
+        // 6. Set resolve.[[AlreadyResolved]] to alreadyResolved.
+        let resolve =
+            FunctionBuilder::native_with_data(context, Self::resolve_function, data.clone())
+                .name("")
+                .length(1)
+                .build();
+
+        // 7. Let stepsReject be the algorithm steps defined in Promise Reject Functions.
+        // 8. Let lengthReject be the number of non-optional parameters of the function definition in Promise Reject Functions.
+        // 9. Let reject be CreateBuiltinFunction(stepsReject, lengthReject, "", « [[Promise]], [[AlreadyResolved]] »).
+        // 10. Set reject.[[Promise]] to promise.
+        // 11. Set reject.[[AlreadyResolved]] to alreadyResolved.
+        let reject = FunctionBuilder::native_with_data(context, Self::reject_function, data)
+            .name("")
+            .length(1)
+            .build();
+
+        // 12. Return the Record { [[Resolve]]: resolve, [[Reject]]: reject }.
+        (resolve, reject)
+    }
+
+    /// Promise Resolve Functions
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve-functions
+    fn resolve_function(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let F be the active function object.
+        // 2. Assert: F has a [[Promise]] internal slot whose value is an Object.
+        // 3. Let promise be F.[[Promise]].
+        // 4. Let alreadyResolved be F.[[AlreadyResolved]].
+        let data = context
+            .current_function_data::<ResolvingFunctionData>()
+            .expect("resolve functions must have a promise")
+            .clone();
+        let promise = data.promise.clone();
+
+        // 5. If alreadyResolved.[[Value]] is true, return undefined.
+        // 6. Set alreadyResolved.[[Value]] to true.
+        if std::mem::replace(&mut *data.already_resolved.borrow_mut(), true) {
+            return Ok(JsValue::undefined());
+        }
+
+        let resolution = args.get_or_undefined(0);
+        let thenable = match resolution.as_object() {
+            // 7. If SameValue(resolution, promise) is true, then
+            Some(resolution) if JsObject::equals(resolution, &promise) => {
+                // a. Let selfResolutionError be a newly created TypeError object.
+                let error = context.construct_type_error("cannot resolve a promise with itself");
+
+                // b. Perform RejectPromise(promise, selfResolutionError).
+                Self::reject_promise(&promise, error, context);
+
+                // c. Return undefined.
+                return Ok(JsValue::undefined());
+            }
+            Some(resolution) => resolution,
+            // 8. If Type(resolution) is not Object, then
+            None => {
+                // a. Perform FulfillPromise(promise, resolution).
+                Self::fulfill_promise(&promise, resolution.clone(), context);
+
+                // b. Return undefined.
+                return Ok(JsValue::undefined());
+            }
+        };
+
+        // 9. Let then be Completion(Get(resolution, "then")).
+        let then_action = match thenable.get("then", context) {
+            // 10. If then is an abrupt completion, then
+            Err(error) => {
+                // a. Perform RejectPromise(promise, then.[[Value]]).
+                Self::reject_promise(&promise, error, context);
+
+                // b. Return undefined.
+                return Ok(JsValue::undefined());
+            }
+            // 11. Let thenAction be then.[[Value]].
+            Ok(then_action) => then_action,
+        };
+
+        // 12. If IsCallable(thenAction) is false, then
+        let then_action = if let Some(then_action) = then_action.as_callable() {
+            then_action.clone()
+        } else {
+            // a. Perform FulfillPromise(promise, resolution).
+            Self::fulfill_promise(&promise, resolution.clone(), context);
+
+            // b. Return undefined.
+            return Ok(JsValue::undefined());
+        };
+
+        // 13. Let thenJobCallback be HostMakeJobCallback(thenAction).
+        // 14. Let job be NewPromiseResolveThenableJob(promise, resolution, thenJobCallback).
+        let job = Self::new_promise_resolve_thenable_job(promise, thenable.clone(), then_action);
+
+        // 15. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
+        context.host_hooks().enqueue_promise_job(job, context);
+
+        // 16. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Promise Reject Functions
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-reject-functions
+    fn reject_function(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let F be the active function object.
+        // 2. Assert: F has a [[Promise]] internal slot whose value is an Object.
+        // 3. Let promise be F.[[Promise]].
+        // 4. Let alreadyResolved be F.[[AlreadyResolved]].
+        let data = context
+            .current_function_data::<ResolvingFunctionData>()
+            .expect("reject functions must have a promise")
+            .clone();
+        let promise = data.promise.clone();
+
+        // 5. If alreadyResolved.[[Value]] is true, return undefined.
+        // 6. Set alreadyResolved.[[Value]] to true.
+        if std::mem::replace(&mut *data.already_resolved.borrow_mut(), true) {
+            return Ok(JsValue::undefined());
+        }
+
+        // 7. Perform RejectPromise(promise, reason).
+        Self::reject_promise(&promise, args.get_or_undefined(0).clone(), context);
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `FulfillPromise ( promise, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-fulfillpromise
+    fn fulfill_promise(promise: &JsObject, value: JsValue, context: &mut Context) {
+        let reactions = {
+            let mut promise = promise.borrow_mut();
+            let promise = promise
+                .as_promise_mut()
+                .expect("only promises can be fulfilled");
+
+            // 1. Assert: The value of promise.[[PromiseState]] is pending.
+            debug_assert!(matches!(promise.state, PromiseState::Pending));
+
+            // 2. Let reactions be promise.[[PromiseFulfillReactions]].
+            // 3. Set promise.[[PromiseResult]] to value.
+            // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
+            // 5. Set promise.[[PromiseRejectReactions]] to undefined.
+            // 6. Set promise.[[PromiseState]] to fulfilled.
+            promise.reject_reactions.clear();
+            promise.state = PromiseState::Fulfilled(value.clone());
+            std::mem::take(&mut promise.fulfill_reactions)
+        };
+
+        // 7. Perform TriggerPromiseReactions(reactions, value).
+        Self::trigger_promise_reactions(reactions, &value, context);
+    }
+
+    /// `RejectPromise ( promise, reason )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rejectpromise
+    fn reject_promise(promise: &JsObject, reason: JsValue, context: &mut Context) {
+        let (reactions, is_handled) = {
+            let mut promise = promise.borrow_mut();
+            let promise = promise
+                .as_promise_mut()
+                .expect("only promises can be rejected");
+
+            // 1. Assert: The value of promise.[[PromiseState]] is pending.
+            debug_assert!(matches!(promise.state, PromiseState::Pending));
+
+            // 2. Let reactions be promise.[[PromiseRejectReactions]].
+            // 3. Set promise.[[PromiseResult]] to reason.
+            // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
+            // 5. Set promise.[[PromiseRejectReactions]] to undefined.
+            // 6. Set promise.[[PromiseState]] to rejected.
+            promise.fulfill_reactions.clear();
+            promise.state = PromiseState::Rejected(reason.clone());
+            (
+                std::mem::take(&mut promise.reject_reactions),
+                promise.is_handled,
+            )
+        };
+
+        // 7. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "reject").
+        if !is_handled {
+            context.host_hooks().promise_rejection_tracker(
+                promise,
+                PromiseRejectionOperation::Reject,
+                context,
+            );
+        }
+
+        // 8. Perform TriggerPromiseReactions(reactions, reason).
+        Self::trigger_promise_reactions(reactions, &reason, context);
+    }
+
+    /// `TriggerPromiseReactions ( reactions, argument )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-triggerpromisereactions
+    fn trigger_promise_reactions(
+        reactions: Vec<ReactionRecord>,
+        argument: &JsValue,
+        context: &mut Context,
+    ) {
+        // 1. For each element reaction of reactions, do
+        for reaction in reactions {
+            // a. Let job be NewPromiseReactionJob(reaction, argument).
+            let job = Self::new_promise_reaction_job(reaction, argument.clone());
+
+            // b. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
+            context.host_hooks().enqueue_promise_job(job, context);
+        }
+
+        // 2. Return unused.
+    }
+
+    /// `NewPromiseReactionJob ( reaction, argument )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisereactionjob
+    fn new_promise_reaction_job(reaction: ReactionRecord, argument: JsValue) -> NativeJob {
+        // 1. Let job be a new Job Abstract Closure with no parameters that captures reaction and argument and performs the following steps when called:
+        NativeJob::new(move |context| {
+            // a. Let promiseCapability be reaction.[[Capability]].
+            // b. Let type be reaction.[[Type]].
+            // c. Let handler be reaction.[[Handler]].
+            let capability = &reaction.capability;
+
+            let handler_result = match &reaction.handler {
+                // d. If handler is empty, then
+                None => match reaction.kind {
+                    // i. If type is Fulfill, let handlerResult be NormalCompletion(argument).
+                    ReactionType::Fulfill => Ok(argument),
+                    // ii. Else,
+                    //     1. Assert: type is Reject.
+                    //     2. Let handlerResult be ThrowCompletion(argument).
+                    ReactionType::Reject => Err(argument),
+                },
+                // e. Else, let handlerResult be Completion(HostCallJobCallback(handler, undefined, « argument »)).
+                Some(handler) => handler.call(&JsValue::undefined(), &[argument], context),
+            };
+
+            // f. If promiseCapability is undefined, then
+            let capability = match capability {
+                // i. Assert: handlerResult is not an abrupt completion.
+                // ii. Return empty.
+                None => return handler_result.map(|_| JsValue::undefined()),
+                // g. Assert: promiseCapability is a PromiseCapability Record.
+                Some(capability) => capability,
+            };
+
+            match handler_result {
+                // h. If handlerResult is an abrupt completion, then
+                //     i. Return ? Call(promiseCapability.[[Reject]], undefined, « handlerResult.[[Value]] »).
+                Err(reason) => capability
+                    .reject
+                    .call(&JsValue::undefined(), &[reason], context),
+                // i. Else,
+                //     i. Return ? Call(promiseCapability.[[Resolve]], undefined, « handlerResult.[[Value]] »).
+                Ok(value) => capability
+                    .resolve
+                    .call(&JsValue::undefined(), &[value], context),
+            }
+        })
+    }
+
+    /// `NewPromiseResolveThenableJob ( promiseToResolve, thenable, then )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromiseresolvethenablejob
+    fn new_promise_resolve_thenable_job(
+        promise_to_resolve: JsObject,
+        thenable: JsObject,
+        then: JsObject,
+    ) -> NativeJob {
+        // 1. Let job be a new Job Abstract Closure with no parameters that captures promiseToResolve, thenable, and then and performs the following steps when called:
+        NativeJob::new(move |context| {
+            // a. Let resolvingFunctions be CreateResolvingFunctions(promiseToResolve).
+            let (resolve, reject) = Self::create_resolving_functions(&promise_to_resolve, context);
+
+            // b. Let thenCallResult be Completion(HostCallJobCallback(then, thenable, « resolvingFunctions.[[Resolve]], resolvingFunctions.[[Reject]] »)).
+            let then_call_result = then.call(
+                &thenable.into(),
+                &[resolve.into(), reject.clone().into()],
+                context,
+            );
+
+            // c. If thenCallResult is an abrupt completion, then
+            //     i. Return ? Call(resolvingFunctions.[[Reject]], undefined, « thenCallResult.[[Value]] »).
+            // d. Return ? thenCallResult.
+            match then_call_result {
+                Err(error) => reject.call(&JsValue::undefined(), &[error], context),
+                result => result,
+            }
+        })
+    }
+
+    /// `NewPromiseCapability ( C )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisecapability
+    pub(crate) fn new_promise_capability(
+        c: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<PromiseCapability> {
+        // 1. If IsConstructor(C) is false, throw a TypeError exception.
+        let c = match c.as_constructor() {
+            Some(c) => c.clone(),
+            None => {
+                return Err(context.construct_type_error("promise capability needs a constructor"))
+            }
+        };
+
+        // 2. NOTE: C is assumed to be a constructor function that supports the parameter conventions of the Promise constructor (see 27.2.3.1).
+        // 3. Let resolvingFunctions be the Record { [[Resolve]]: undefined, [[Reject]]: undefined }.
+        let resolving_functions = Gc::new(GcCell::new(ResolvingFunctionsRecord::default()));
+
+        // 4. Let executorClosure be a new Abstract Closure with parameters (resolve, reject) that captures resolvingFunctions and performs the following steps when called:
+        // 5. Let executor be CreateBuiltinFunction(executorClosure, 2, "", « »).
+        let executor = FunctionBuilder::native_with_data(
+            context,
+            Self::capability_executor,
+            resolving_functions.clone(),
+        )
+        .name("")
+        .length(2)
+        .build();
+
+        // 6. Let promise be ? Construct(C, « executor »).
+        let promise = c
+            .construct(&[executor.into()], &c.clone().into(), context)?
+            .as_object()
+            .cloned()
+            .expect("constructors must return an object");
+
+        let resolving_functions = resolving_functions.borrow();
+
+        // 7. If IsCallable(resolvingFunctions.[[Resolve]]) is false, throw a TypeError exception.
+        let resolve = match resolving_functions.resolve.as_callable() {
+            Some(resolve) => resolve.clone(),
+            None => {
+                return Err(context.construct_type_error("promise resolve function is not callable"))
+            }
+        };
+
+        // 8. If IsCallable(resolvingFunctions.[[Reject]]) is false, throw a TypeError exception.
+        let reject = match resolving_functions.reject.as_callable() {
+            Some(reject) => reject.clone(),
+            None => {
+                return Err(context.construct_type_error("promise reject function is not callable"))
+            }
+        };
+
+        // 9. Return the PromiseCapability Record { [[Promise]]: promise, [[Resolve]]: resolvingFunctions.[[Resolve]], [[Reject]]: resolvingFunctions.[[Reject]] }.
+        Ok(PromiseCapability {
+            promise,
+            resolve,
+            reject,
+        })
+    }
+
+    /// The executor created by `NewPromiseCapability`, which records the functions that
+    /// settle the new promise.
+    fn capability_executor(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let resolving_functions = context
+            .current_function_data::<Gc<GcCell<ResolvingFunctionsRecord>>>()
+            .expect("the executor must have the resolving functions")
+            .clone();
+        let mut resolving_functions = resolving_functions.borrow_mut();
+
+        // a. If resolvingFunctions.[[Resolve]] is not undefined, throw a TypeError exception.
+        // b. If resolvingFunctions.[[Reject]] is not undefined, throw a TypeError exception.
+        if !resolving_functions.resolve.is_undefined() || !resolving_functions.reject.is_undefined()
+        {
+            return context.throw_type_error("promise executor was already called");
+        }
+
+        // c. Set resolvingFunctions.[[Resolve]] to resolve.
+        // d. Set resolvingFunctions.[[Reject]] to reject.
+        resolving_functions.resolve = args.get_or_undefined(0).clone();
+        resolving_functions.reject = args.get_or_undefined(1).clone();
+
+        // e. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `PromiseResolve ( C, x )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve
+    fn promise_resolve(c: &JsObject, x: JsValue, context: &mut Context) -> JsResult<JsObject> {
+        // 1. If IsPromise(x) is true, then
+        if let Some(x) = x.as_object().filter(|x| x.is_promise()) {
+            // a. Let xConstructor be ? Get(x, "constructor").
+            let x_constructor = x.get("constructor", context)?;
+
+            // b. If SameValue(xConstructor, C) is true, return x.
+            if matches!(x_constructor.as_object(), Some(x_constructor) if JsObject::equals(x_constructor, c))
+            {
+                return Ok(x.clone());
+            }
+        }
+
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let capability = Self::new_promise_capability(&c.clone().into(), context)?;
+
+        // 3. Perform ? Call(promiseCapability.[[Resolve]], undefined, « x »).
+        capability
+            .resolve
+            .call(&JsValue::undefined(), &[x], context)?;
+
+        // 4. Return promiseCapability.[[Promise]].
+        Ok(capability.promise.clone())
+    }
+
+    /// `PerformPromiseThen ( promise, onFulfilled, onRejected [ , resultCapability ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromisethen
+    fn perform_promise_then(
+        promise: &JsObject,
+        on_fulfilled: &JsValue,
+        on_rejected: &JsValue,
+        result_capability: Option<PromiseCapability>,
+        context: &mut Context,
+    ) -> JsValue {
+        let result_promise = result_capability
+            .as_ref()
+            .map(|capability| capability.promise.clone());
+
+        // 1. Assert: IsPromise(promise) is true.
+        // 2. If resultCapability is not present, then
+        //     a. Set resultCapability to undefined.
+        // 3. If IsCallable(onFulfilled) is false, then
+        //     a. Let onFulfilledJobCallback be empty.
+        // 4. Else,
+        //     a. Let onFulfilledJobCallback be HostMakeJobCallback(onFulfilled).
+        // 5. If IsCallable(onRejected) is false, then
+        //     a. Let onRejectedJobCallback be empty.
+        // 6. Else,
+        //     a. Let onRejectedJobCallback be HostMakeJobCallback(onRejected).
+        // 7. Let fulfillReaction be the PromiseReaction { [[Capability]]: resultCapability, [[Type]]: Fulfill, [[Handler]]: onFulfilledJobCallback }.
+        let fulfill_reaction = ReactionRecord {
+            capability: result_capability.clone(),
+            kind: ReactionType::Fulfill,
+            handler: on_fulfilled.as_callable().cloned(),
+        };
+
+        // 8. Let rejectReaction be the PromiseReaction { [[Capability]]: resultCapability, [[Type]]: Reject, [[Handler]]: onRejectedJobCallback }.
+        let reject_reaction = ReactionRecord {
+            capability: result_capability,
+            kind: ReactionType::Reject,
+            handler: on_rejected.as_callable().cloned(),
+        };
+
+        let (job, track_handle) = {
+            let mut promise = promise.borrow_mut();
+            let promise = promise
+                .as_promise_mut()
+                .expect("`then` can only be performed on promises");
+
+            let job = match &promise.state {
+                // 9. If promise.[[PromiseState]] is pending, then
+                PromiseState::Pending => {
+                    // a. Append fulfillReaction as the last element of the List that is promise.[[PromiseFulfillReactions]].
+                    promise.fulfill_reactions.push(fulfill_reaction);
+
+                    // b. Append rejectReaction as the last element of the List that is promise.[[PromiseRejectReactions]].
+                    promise.reject_reactions.push(reject_reaction);
+                    None
+                }
+                // 10. Else if promise.[[PromiseState]] is fulfilled, then
+                //     a. Let value be promise.[[PromiseResult]].
+                //     b. Let fulfillJob be NewPromiseReactionJob(fulfillReaction, value).
+                PromiseState::Fulfilled(value) => Some(Self::new_promise_reaction_job(
+                    fulfill_reaction,
+                    value.clone(),
+                )),
+                // 11. Else,
+                //     a. Assert: The value of promise.[[PromiseState]] is rejected.
+                //     b. Let reason be promise.[[PromiseResult]].
+                //     c. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "handle").
+                //     d. Let rejectJob be NewPromiseReactionJob(rejectReaction, reason).
+                PromiseState::Rejected(reason) => Some(Self::new_promise_reaction_job(
+                    reject_reaction,
+                    reason.clone(),
+                )),
+            };
+
+            // 12. Set promise.[[PromiseIsHandled]] to true.
+            let track_handle =
+                matches!(promise.state, PromiseState::Rejected(_)) && !promise.is_handled;
+            promise.is_handled = true;
+
+            (job, track_handle)
+        };
+
+        // 11.c. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "handle").
+        if track_handle {
+            context.host_hooks().promise_rejection_tracker(
+                promise,
+                PromiseRejectionOperation::Handle,
+                context,
+            );
+        }
+
+        // 10.c. Perform HostEnqueuePromiseJob(fulfillJob.[[Job]], fulfillJob.[[Realm]]).
+        // 11.e. Perform HostEnqueuePromiseJob(rejectJob.[[Job]], rejectJob.[[Realm]]).
+        if let Some(job) = job {
+            context.host_hooks().enqueue_promise_job(job, context);
+        }
+
+        // 13. If resultCapability is undefined, return undefined.
+        // 14. Else, return resultCapability.[[Promise]].
+        result_promise.map_or_else(JsValue::undefined, JsValue::from)
+    }
+
+    /// The steps of `Await ( value )` that register the continuations of the suspended
+    /// execution context, which is resumed by calling `on_fulfilled` or `on_rejected` once the
+    /// awaited value settles.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#await
+    pub(crate) fn perform_await(
+        value: JsValue,
+        on_fulfilled: JsObject,
+        on_rejected: JsObject,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 2. Let promise be ? PromiseResolve(%Promise%, value).
+        let promise_constructor = context.standard_objects().promise_object().constructor();
+        let promise = Self::promise_resolve(&promise_constructor, value, context)?;
+
+        // 7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
+        Self::perform_promise_then(
+            &promise,
+            &on_fulfilled.into(),
+            &on_rejected.into(),
+            None,
+            context,
+        );
+
+        Ok(())
+    }
+
+    /// `Promise.prototype.then ( onFulfilled, onRejected )`
+    ///
+    /// Registers the callbacks called when the promise is fulfilled or rejected, and returns a
+    /// promise resolved with their result.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.then
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/then
+    pub(crate) fn then(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let promise be the this value.
+        // 2. If IsPromise(promise) is false, throw a TypeError exception.
+        let promise = match this.as_object() {
+            Some(promise) if promise.is_promise() => promise,
+            _ => {
+                return context
+                    .throw_type_error("Promise.prototype.then called on incompatible receiver")
+            }
+        };
+
+        // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
+        let c = promise.species_constructor(StandardObjects::promise_object, context)?;
+
+        // 4. Let resultCapability be ? NewPromiseCapability(C).
+        let result_capability = Self::new_promise_capability(&c.into(), context)?;
+
+        // 5. Return PerformPromiseThen(promise, onFulfilled, onRejected, resultCapability).
+        Ok(Self::perform_promise_then(
+            promise,
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            Some(result_capability),
+            context,
+        ))
+    }
+
+    /// `Promise.prototype.catch ( onRejected )`
+    ///
+    /// Registers the callback called when the promise is rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.catch
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/catch
+    pub(crate) fn catch(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let promise be the this value.
+        // 2. Return ? Invoke(promise, "then", « undefined, onRejected »).
+        this.invoke(
+            "then",
+            &[JsValue::undefined(), args.get_or_undefined(0).clone()],
+            context,
+        )
+    }
+
+    /// `Promise.prototype.finally ( onFinally )`
+    ///
+    /// Registers the callback called when the promise is settled, which doesn't change the
+    /// outcome of the promise unless it throws.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.finally
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally
+    pub(crate) fn finally(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let promise be the this value.
+        // 2. If Type(promise) is not Object, throw a TypeError exception.
+        let promise = match this.as_object() {
+            Some(promise) => promise,
+            None => {
+                return context
+                    .throw_type_error("Promise.prototype.finally called on a non-object value")
+            }
+        };
+
+        // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
+        // 4. Assert: IsConstructor(C) is true.
+        let c = promise.species_constructor(StandardObjects::promise_object, context)?;
+
+        let on_finally = args.get_or_undefined(0);
+        let (then_finally, catch_finally) = match on_finally.as_callable() {
+            // 5. If IsCallable(onFinally) is false, then
+            //     a. Let thenFinally be onFinally.
+            //     b. Let catchFinally be onFinally.
+            None => (on_finally.clone(), on_finally.clone()),
+            // 6. Else,
+            Some(on_finally) => {
+                let data = FinallyData {
+                    on_finally: on_finally.clone(),
+                    constructor: c,
+                };
+
+                // a. Let thenFinallyClosure be a new Abstract Closure with parameters (value) that captures onFinally and C and performs the following steps when called:
+                // b. Let thenFinally be CreateBuiltinFunction(thenFinallyClosure, 1, "", « »).
+                let then_finally =
+                    FunctionBuilder::native_with_data(context, Self::then_finally, data.clone())
+                        .name("")
+                        .length(1)
+                        .build();
+
+                // c. Let catchFinallyClosure be a new Abstract Closure with parameters (reason) that captures onFinally and C and performs the following steps when called:
+                // d. Let catchFinally be CreateBuiltinFunction(catchFinallyClosure, 1, "", « »).
+                let catch_finally =
+                    FunctionBuilder::native_with_data(context, Self::catch_finally, data)
+                        .name("")
+                        .length(1)
+                        .build();
+
+                (then_finally.into(), catch_finally.into())
+            }
+        };
+
+        // 7. Return ? Invoke(promise, "then", « thenFinally, catchFinally »).
+        this.invoke("then", &[then_finally, catch_finally], context)
+    }
+
+    /// The `thenFinally` closure of `Promise.prototype.finally`.
+    fn then_finally(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let data = context
+            .current_function_data::<FinallyData>()
+            .expect("thenFinally must have its data")
+            .clone();
+        let value = args.get_or_undefined(0).clone();
+
+        // i. Let result be ? Call(onFinally, undefined).
+        let result = data.on_finally.call(&JsValue::undefined(), &[], context)?;
+
+        // ii. Let promise be ? PromiseResolve(C, result).
+        let promise = Self::promise_resolve(&data.constructor, result, context)?;
+
+        // iii. Let returnValue be a new Abstract Closure with no parameters that captures value and performs the following steps when called:
+        //     1. Return value.
+        // iv. Let valueThunk be CreateBuiltinFunction(returnValue, 0, "", « »).
+        let value_thunk = FunctionBuilder::native_with_data(
+            context,
+            |_, _, context| {
+                Ok(context
+                    .current_function_data::<JsValue>()
+                    .expect("valueThunk must have a value")
+                    .clone())
+            },
+            value,
+        )
+        .name("")
+        .length(0)
+        .build();
+
+        // v. Return ? Invoke(promise, "then", « valueThunk »).
+        JsValue::from(promise).invoke("then", &[value_thunk.into()], context)
+    }
+
+    /// The `catchFinally` closure of `Promise.prototype.finally`.
+    fn catch_finally(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let data = context
+            .current_function_data::<FinallyData>()
+            .expect("catchFinally must have its data")
+            .clone();
+        let reason = args.get_or_undefined(0).clone();
+
+        // i. Let result be ? Call(onFinally, undefined).
+        let result = data.on_finally.call(&JsValue::undefined(), &[], context)?;
+
+        // ii. Let promise be ? PromiseResolve(C, result).
+        let promise = Self::promise_resolve(&data.constructor, result, context)?;
+
+        // iii. Let throwReason be a new Abstract Closure with no parameters that captures reason and performs the following steps when called:
+        //     1. Return ThrowCompletion(reason).
+        // iv. Let thrower be CreateBuiltinFunction(throwReason, 0, "", « »).
+        let thrower = FunctionBuilder::native_with_data(
+            context,
+            |_, _, context| {
+                Err(context
+                    .current_function_data::<JsValue>()
+                    .expect("thrower must have a reason")
+                    .clone())
+            },
+            reason,
+        )
+        .name("")
+        .length(0)
+        .build();
+
+        // v. Return ? Invoke(promise, "then", « thrower »).
+        JsValue::from(promise).invoke("then", &[thrower.into()], context)
+    }
+
+    /// `Promise.resolve ( x )`
+    ///
+    /// Returns a promise resolved with `x`, or `x` itself if it is already a promise created by
+    /// this constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.resolve
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve
+    pub(crate) fn resolve(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. If Type(C) is not Object, throw a TypeError exception.
+        let c = match this.as_object() {
+            Some(c) => c,
+            None => {
+                return context.throw_type_error("Promise.resolve called on a non-object value")
+            }
+        };
+
+        // 3. Return ? PromiseResolve(C, x).
+        Self::promise_resolve(c, args.get_or_undefined(0).clone(), context).map(Into::into)
+    }
+
+    /// `Promise.reject ( r )`
+    ///
+    /// Returns a promise rejected with `r`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.reject
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/reject
+    pub(crate) fn reject(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let capability = Self::new_promise_capability(this, context)?;
+
+        // 3. Perform ? Call(promiseCapability.[[Reject]], undefined, « r »).
+        capability.reject.call(
+            &JsValue::undefined(),
+            &[args.get_or_undefined(0).clone()],
+            context,
+        )?;
+
+        // 4. Return promiseCapability.[[Promise]].
+        Ok(capability.promise.clone().into())
+    }
+
+    /// `Promise.withResolvers ( )`
+    ///
+    /// Returns an object holding a new promise together with the functions that resolve and
+    /// reject it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-promise-with-resolvers/#sec-promise.withResolvers
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/withResolvers
+    pub(crate) fn with_resolvers(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let capability = Self::new_promise_capability(this, context)?;
+
+        // 3. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+        let obj = context.construct_object();
+
+        // 4. Perform ! CreateDataPropertyOrThrow(obj, "promise", promiseCapability.[[Promise]]).
+        obj.create_data_property_or_throw("promise", capability.promise.clone(), context)
+            .expect("CreateDataPropertyOrThrow cannot fail here");
+
+        // 5. Perform ! CreateDataPropertyOrThrow(obj, "resolve", promiseCapability.[[Resolve]]).
+        obj.create_data_property_or_throw("resolve", capability.resolve.clone(), context)
+            .expect("CreateDataPropertyOrThrow cannot fail here");
+
+        // 6. Perform ! CreateDataPropertyOrThrow(obj, "reject", promiseCapability.[[Reject]]).
+        obj.create_data_property_or_throw("reject", capability.reject.clone(), context)
+            .expect("CreateDataPropertyOrThrow cannot fail here");
+
+        // 7. Return obj.
+        Ok(obj.into())
+    }
+
+    /// `GetPromiseResolve ( promiseConstructor )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getpromiseresolve
+    fn get_promise_resolve(
+        promise_constructor: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let promiseResolve be ? Get(promiseConstructor, "resolve").
+        let promise_resolve = promise_constructor.get("resolve", context)?;
+
+        // 2. If IsCallable(promiseResolve) is false, throw a TypeError exception.
+        // 3. Return promiseResolve.
+        promise_resolve.as_callable().cloned().ok_or_else(|| {
+            context.construct_type_error("`resolve` of the constructor is not callable")
+        })
+    }
+
+    /// Runs the steps shared by the promise combinators: creates the capability of the
+    /// returned promise, gets the iterator of `iterable`, and calls `perform` with them.
+    ///
+    /// `perform` must close the iterator itself if it fails after the iterator is done, since
+    /// [`IteratorRecord`] doesn't track whether it is.
+    fn combinator<F>(
+        this: &JsValue,
+        iterable: &JsValue,
+        perform: F,
+        context: &mut Context,
+    ) -> JsResult<JsValue>
+    where
+        F: FnOnce(
+            &IteratorRecord,
+            &JsObject,
+            &PromiseCapability,
+            &JsObject,
+            &mut Context,
+        ) -> JsResult<JsValue>,
+    {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let capability = Self::new_promise_capability(this, context)?;
+        let c = this
+            .as_object()
+            .expect("NewPromiseCapability only accepts constructors");
+
+        // 3. Let promiseResolve be Completion(GetPromiseResolve(C)).
+        // 4. IfAbruptRejectPromise(promiseResolve, promiseCapability).
+        let promise_resolve =
+            if_abrupt_reject_promise!(Self::get_promise_resolve(c, context), capability, context);
+
+        // 5. Let iteratorRecord be Completion(GetIterator(iterable, sync)).
+        // 6. IfAbruptRejectPromise(iteratorRecord, promiseCapability).
+        let iterator_record = if_abrupt_reject_promise!(
+            iterable.get_iterator(context, None, None),
+            capability,
+            context
+        );
+
+        // 7. Let result be Completion(PerformPromiseAll(iteratorRecord, C, promiseCapability, promiseResolve)).
+        let result = perform(&iterator_record, c, &capability, &promise_resolve, context);
+
+        // 8. If result is an abrupt completion, then
+        //     a. If iteratorRecord.[[Done]] is false, set result to Completion(IteratorClose(iteratorRecord, result)).
+        //     b. IfAbruptRejectPromise(result, promiseCapability).
+        // 9. Return ? result.
+        Ok(if_abrupt_reject_promise!(result, capability, context))
+    }
+
+    /// `Promise.all ( iterable )`
+    ///
+    /// Returns a promise fulfilled with the values of all the promises of `iterable`, or
+    /// rejected with the reason of the first promise that is rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/all
+    pub(crate) fn all(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::combinator(
+            this,
+            args.get_or_undefined(0),
+            Self::perform_promise_all,
+            context,
+        )
+    }
+
+    /// `PerformPromiseAll ( iteratorRecord, constructor, resultCapability, promiseResolve )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseall
+    fn perform_promise_all(
+        iterator_record: &IteratorRecord,
+        constructor: &JsObject,
+        result_capability: &PromiseCapability,
+        promise_resolve: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let values be a new empty List.
+        let values = Gc::new(GcCell::new(Vec::new()));
+
+        // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
+        let remaining_elements = Gc::new(GcCell::new(1));
+
+        // 3. Let index be 0.
+        let mut index = 0;
+
+        // 4. Repeat,
+        loop {
+            // a. Let next be Completion(IteratorStep(iteratorRecord)).
+            // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // c. ReturnIfAbrupt(next).
+            let next = iterator_record.next(context)?;
+
+            // d. If next is false, then
+            if next.done {
+                // i. Set iteratorRecord.[[Done]] to true.
+                // ii. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                let mut remaining = remaining_elements.borrow_mut();
+                *remaining -= 1;
+
+                // iii. If remainingElementsCount.[[Value]] is 0, then
+                if *remaining == 0 {
+                    drop(remaining);
+
+                    // 1. Let valuesArray be CreateArrayFromList(values).
+                    let values_array =
+                        Array::create_array_from_list(values.borrow().iter().cloned(), context);
+
+                    // 2. Perform ? Call(resultCapability.[[Resolve]], undefined, « valuesArray »).
+                    result_capability.resolve.call(
+                        &JsValue::undefined(),
+                        &[values_array.into()],
+                        context,
+                    )?;
+                }
+
+                // iv. Return resultCapability.[[Promise]].
+                return Ok(result_capability.promise.clone().into());
+            }
+
+            // e. Let nextValue be Completion(IteratorValue(next)).
+            // f. If nextValue is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // g. ReturnIfAbrupt(nextValue).
+            // h. Append undefined to values.
+            values.borrow_mut().push(JsValue::undefined());
+
+            // i. Let nextPromise be ? Call(promiseResolve, constructor, « nextValue »).
+            let next_promise =
+                match promise_resolve.call(&constructor.clone().into(), &[next.value], context) {
+                    Ok(next_promise) => next_promise,
+                    err => return iterator_record.close(err, context),
+                };
+
+            // j. Let steps be the algorithm steps defined in Promise.all Resolve Element Functions.
+            // k. Let length be the number of non-optional parameters of the function definition in Promise.all Resolve Element Functions.
+            // l. Let onFulfilled be CreateBuiltinFunction(steps, length, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
+            // m. Set onFulfilled.[[AlreadyCalled]] to false.
+            // n. Set onFulfilled.[[Index]] to index.
+            // o. Set onFulfilled.[[Values]] to values.
+            // p. Set onFulfilled.[[Capability]] to resultCapability.
+            // q. Set onFulfilled.[[RemainingElements]] to remainingElementsCount.
+            let on_fulfilled = FunctionBuilder::native_with_data(
+                context,
+                Self::all_resolve_element,
+                ResolveElementData {
+                    already_called: Gc::new(GcCell::new(false)),
+                    index,
+                    values: values.clone(),
+                    capability: result_capability.clone(),
+                    remaining_elements: remaining_elements.clone(),
+                },
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            // r. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+            *remaining_elements.borrow_mut() += 1;
+
+            // s. Perform ? Invoke(nextPromise, "then", « onFulfilled, resultCapability.[[Reject]] »).
+            let then = next_promise.invoke(
+                "then",
+                &[on_fulfilled.into(), result_capability.reject.clone().into()],
+                context,
+            );
+            if then.is_err() {
+                return iterator_record.close(then, context);
+            }
+
+            // t. Set index to index + 1.
+            index += 1;
+        }
+    }
+
+    /// Promise.all Resolve Element Functions
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all-resolve-element-functions
+    fn all_resolve_element(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let F be the active function object.
+        let data = context
+            .current_function_data::<ResolveElementData>()
+            .expect("resolve element functions must have their data")
+            .clone();
+
+        // 2. If F.[[AlreadyCalled]] is true, return undefined.
+        // 3. Set F.[[AlreadyCalled]] to true.
+        if !data.call_once() {
+            return Ok(JsValue::undefined());
+        }
+
+        // 4. Let index be F.[[Index]].
+        // 5. Let values be F.[[Values]].
+        // 6. Let promiseCapability be F.[[Capability]].
+        // 7. Let remainingElementsCount be F.[[RemainingElements]].
+        // 8. Set values[index] to x.
+        data.values.borrow_mut()[data.index] = args.get_or_undefined(0).clone();
+
+        // 9. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+        // 10. If remainingElementsCount.[[Value]] is 0, then
+        if data.finish_element() {
+            // a. Let valuesArray be CreateArrayFromList(values).
+            let values_array =
+                Array::create_array_from_list(data.values.borrow().iter().cloned(), context);
+
+            // b. Return ? Call(promiseCapability.[[Resolve]], undefined, « valuesArray »).
+            return data.capability.resolve.call(
+                &JsValue::undefined(),
+                &[values_array.into()],
+                context,
+            );
+        }
+
+        // 11. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `Promise.allSettled ( iterable )`
+    ///
+    /// Returns a promise fulfilled with an object describing the outcome of each promise of
+    /// `iterable`, once all of them are settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/allSettled
+    pub(crate) fn all_settled(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::combinator(
+            this,
+            args.get_or_undefined(0),
+            Self::perform_promise_all_settled,
+            context,
+        )
+    }
+
+    /// `PerformPromiseAllSettled ( iteratorRecord, constructor, resultCapability, promiseResolve )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseallsettled
+    fn perform_promise_all_settled(
+        iterator_record: &IteratorRecord,
+        constructor: &JsObject,
+        result_capability: &PromiseCapability,
+        promise_resolve: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let values be a new empty List.
+        let values = Gc::new(GcCell::new(Vec::new()));
+
+        // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
+        let remaining_elements = Gc::new(GcCell::new(1));
+
+        // 3. Let index be 0.
+        let mut index = 0;
+
+        // 4. Repeat,
+        loop {
+            // a. Let next be Completion(IteratorStep(iteratorRecord)).
+            // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // c. ReturnIfAbrupt(next).
+            let next = iterator_record.next(context)?;
+
+            // d. If next is false, then
+            if next.done {
+                // i. Set iteratorRecord.[[Done]] to true.
+                // ii. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                let mut remaining = remaining_elements.borrow_mut();
+                *remaining -= 1;
+
+                // iii. If remainingElementsCount.[[Value]] is 0, then
+                if *remaining == 0 {
+                    drop(remaining);
+
+                    // 1. Let valuesArray be CreateArrayFromList(values).
+                    let values_array =
+                        Array::create_array_from_list(values.borrow().iter().cloned(), context);
+
+                    // 2. Perform ? Call(resultCapability.[[Resolve]], undefined, « valuesArray »).
+                    result_capability.resolve.call(
+                        &JsValue::undefined(),
+                        &[values_array.into()],
+                        context,
+                    )?;
+                }
+
+                // iv. Return resultCapability.[[Promise]].
+                return Ok(result_capability.promise.clone().into());
+            }
+
+            // e. Let nextValue be Completion(IteratorValue(next)).
+            // f. If nextValue is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // g. ReturnIfAbrupt(nextValue).
+            // h. Append undefined to values.
+            values.borrow_mut().push(JsValue::undefined());
+
+            // i. Let nextPromise be ? Call(promiseResolve, constructor, « nextValue »).
+            let next_promise =
+                match promise_resolve.call(&constructor.clone().into(), &[next.value], context) {
+                    Ok(next_promise) => next_promise,
+                    err => return iterator_record.close(err, context),
+                };
+
+            // j. Let stepsFulfilled be the algorithm steps defined in Promise.allSettled Resolve Element Functions.
+            // k. Let lengthFulfilled be the number of non-optional parameters of the function definition in Promise.allSettled Resolve Element Functions.
+            // l. Let onFulfilled be CreateBuiltinFunction(stepsFulfilled, lengthFulfilled, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
+            // m. Let alreadyCalled be the Record { [[Value]]: false }.
+            // n. Set onFulfilled.[[AlreadyCalled]] to alreadyCalled.
+            // o. Set onFulfilled.[[Index]] to index.
+            // p. Set onFulfilled.[[Values]] to values.
+            // q. Set onFulfilled.[[Capability]] to resultCapability.
+            // r. Set onFulfilled.[[RemainingElements]] to remainingElementsCount.
+            let data = ResolveElementData {
+                already_called: Gc::new(GcCell::new(false)),
+                index,
+                values: values.clone(),
+                capability: result_capability.clone(),
+                remaining_elements: remaining_elements.clone(),
+            };
+            let on_fulfilled = FunctionBuilder::native_with_data(
+                context,
+                Self::all_settled_resolve_element,
+                data.clone(),
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            // s. Let stepsRejected be the algorithm steps defined in Promise.allSettled Reject Element Functions.
+            // t. Let lengthRejected be the number of non-optional parameters of the function definition in Promise.allSettled Reject Element Functions.
+            // u. Let onRejected be CreateBuiltinFunction(stepsRejected, lengthRejected, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
+            // v. Set onRejected.[[AlreadyCalled]] to alreadyCalled.
+            // w. Set onRejected.[[Index]] to index.
+            // x. Set onRejected.[[Values]] to values.
+            // y. Set onRejected.[[Capability]] to resultCapability.
+            // z. Set onRejected.[[RemainingElements]] to remainingElementsCount.
+            let on_rejected =
+                FunctionBuilder::native_with_data(context, Self::all_settled_reject_element, data)
+                    .name("")
+                    .length(1)
+                    .build();
+
+            // aa. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+            *remaining_elements.borrow_mut() += 1;
+
+            // ab. Perform ? Invoke(nextPromise, "then", « onFulfilled, onRejected »).
+            let then =
+                next_promise.invoke("then", &[on_fulfilled.into(), on_rejected.into()], context);
+            if then.is_err() {
+                return iterator_record.close(then, context);
+            }
+
+            // ac. Set index to index + 1.
+            index += 1;
+        }
+    }
+
+    /// Promise.allSettled Resolve Element Functions
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled-resolve-element-functions
+    fn all_settled_resolve_element(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::all_settled_element(ReactionType::Fulfill, args.get_or_undefined(0), context)
+    }
+
+    /// Promise.allSettled Reject Element Functions
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled-reject-element-functions
+    fn all_settled_reject_element(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::all_settled_element(ReactionType::Reject, args.get_or_undefined(0), context)
+    }
+
+    /// The steps shared by the resolve and reject element functions of `Promise.allSettled`.
+    fn all_settled_element(
+        kind: ReactionType,
+        x: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let F be the active function object.
+        let data = context
+            .current_function_data::<ResolveElementData>()
+            .expect("allSettled element functions must have their data")
+            .clone();
+
+        // 2. Let alreadyCalled be F.[[AlreadyCalled]].
+        // 3. If alreadyCalled.[[Value]] is true, return undefined.
+        // 4. Set alreadyCalled.[[Value]] to true.
+        if !data.call_once() {
+            return Ok(JsValue::undefined());
+        }
+
+        // 5. Let index be F.[[Index]].
+        // 6. Let values be F.[[Values]].
+        // 7. Let promiseCapability be F.[[Capability]].
+        // 8. Let remainingElementsCount be F.[[RemainingElements]].
+        // 9. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+        let obj = context.construct_object();
+
+        // 10. Perform ! CreateDataPropertyOrThrow(obj, "status", "fulfilled").
+        // 11. Perform ! CreateDataPropertyOrThrow(obj, "value", x).
+        // or, for the reject element functions,
+        // 10. Perform ! CreateDataPropertyOrThrow(obj, "status", "rejected").
+        // 11. Perform ! CreateDataPropertyOrThrow(obj, "reason", x).
+        let (status, key) = match kind {
+            ReactionType::Fulfill => ("fulfilled", "value"),
+            ReactionType::Reject => ("rejected", "reason"),
+        };
+        obj.create_data_property_or_throw("status", status, context)
+            .expect("CreateDataPropertyOrThrow cannot fail here");
+        obj.create_data_property_or_throw(key, x.clone(), context)
+            .expect("CreateDataPropertyOrThrow cannot fail here");
+
+        // 12. Set values[index] to obj.
+        data.values.borrow_mut()[data.index] = obj.into();
+
+        // 13. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+        // 14. If remainingElementsCount.[[Value]] is 0, then
+        if data.finish_element() {
+            // a. Let valuesArray be CreateArrayFromList(values).
+            let values_array =
+                Array::create_array_from_list(data.values.borrow().iter().cloned(), context);
+
+            // b. Return ? Call(promiseCapability.[[Resolve]], undefined, « valuesArray »).
+            return data.capability.resolve.call(
+                &JsValue::undefined(),
+                &[values_array.into()],
+                context,
+            );
+        }
+
+        // 15. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `Promise.any ( iterable )`
+    ///
+    /// Returns a promise fulfilled with the value of the first promise of `iterable` that is
+    /// fulfilled, or rejected with an `AggregateError` of all the reasons if none is.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.any
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/any
+    pub(crate) fn any(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::combinator(
+            this,
+            args.get_or_undefined(0),
+            Self::perform_promise_any,
+            context,
+        )
+    }
+
+    /// `PerformPromiseAny ( iteratorRecord, constructor, resultCapability, promiseResolve )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseany
+    fn perform_promise_any(
+        iterator_record: &IteratorRecord,
+        constructor: &JsObject,
+        result_capability: &PromiseCapability,
+        promise_resolve: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let errors be a new empty List.
+        let errors = Gc::new(GcCell::new(Vec::new()));
+
+        // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
+        let remaining_elements = Gc::new(GcCell::new(1));
+
+        // 3. Let index be 0.
+        let mut index = 0;
+
+        // 4. Repeat,
+        loop {
+            // a. Let next be Completion(IteratorStep(iteratorRecord)).
+            // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // c. ReturnIfAbrupt(next).
+            let next = iterator_record.next(context)?;
+
+            // d. If next is false, then
+            if next.done {
+                // i. Set iteratorRecord.[[Done]] to true.
+                // ii. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                let mut remaining = remaining_elements.borrow_mut();
+                *remaining -= 1;
+
+                // iii. If remainingElementsCount.[[Value]] is 0, then
+                if *remaining == 0 {
+                    // 1. Let error be a newly created AggregateError object.
+                    // 2. Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: CreateArrayFromList(errors) }).
+                    let error = AggregateError::create(
+                        errors.borrow().clone(),
+                        "no promise was fulfilled",
+                        context,
+                    );
+
+                    // 3. Return ThrowCompletion(error).
+                    return Err(error.into());
+                }
+
+                // iv. Return resultCapability.[[Promise]].
+                return Ok(result_capability.promise.clone().into());
+            }
+
+            // e. Let nextValue be Completion(IteratorValue(next)).
+            // f. If nextValue is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // g. ReturnIfAbrupt(nextValue).
+            // h. Append undefined to errors.
+            errors.borrow_mut().push(JsValue::undefined());
+
+            // i. Let nextPromise be ? Call(promiseResolve, constructor, « nextValue »).
+            let next_promise =
+                match promise_resolve.call(&constructor.clone().into(), &[next.value], context) {
+                    Ok(next_promise) => next_promise,
+                    err => return iterator_record.close(err, context),
+                };
+
+            // j. Let stepsRejected be the algorithm steps defined in Promise.any Reject Element Functions.
+            // k. Let lengthRejected be the number of non-optional parameters of the function definition in Promise.any Reject Element Functions.
+            // l. Let onRejected be CreateBuiltinFunction(stepsRejected, lengthRejected, "", « [[AlreadyCalled]], [[Index]], [[Errors]], [[Capability]], [[RemainingElements]] »).
+            // m. Set onRejected.[[AlreadyCalled]] to false.
+            // n. Set onRejected.[[Index]] to index.
+            // o. Set onRejected.[[Errors]] to errors.
+            // p. Set onRejected.[[Capability]] to resultCapability.
+            // q. Set onRejected.[[RemainingElements]] to remainingElementsCount.
+            let on_rejected = FunctionBuilder::native_with_data(
+                context,
+                Self::any_reject_element,
+                ResolveElementData {
+                    already_called: Gc::new(GcCell::new(false)),
+                    index,
+                    values: errors.clone(),
+                    capability: result_capability.clone(),
+                    remaining_elements: remaining_elements.clone(),
+                },
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            // r. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+            *remaining_elements.borrow_mut() += 1;
+
+            // s. Perform ? Invoke(nextPromise, "then", « resultCapability.[[Resolve]], onRejected »).
+            let then = next_promise.invoke(
+                "then",
+                &[result_capability.resolve.clone().into(), on_rejected.into()],
+                context,
+            );
+            if then.is_err() {
+                return iterator_record.close(then, context);
+            }
+
+            // t. Set index to index + 1.
+            index += 1;
+        }
+    }
+
+    /// Promise.any Reject Element Functions
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.any-reject-element-functions
+    fn any_reject_element(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let F be the active function object.
+        let data = context
+            .current_function_data::<ResolveElementData>()
+            .expect("reject element functions must have their data")
+            .clone();
+
+        // 2. If F.[[AlreadyCalled]] is true, return undefined.
+        // 3. Set F.[[AlreadyCalled]] to true.
+        if !data.call_once() {
+            return Ok(JsValue::undefined());
+        }
+
+        // 4. Let index be F.[[Index]].
+        // 5. Let errors be F.[[Errors]].
+        // 6. Let promiseCapability be F.[[Capability]].
+        // 7. Let remainingElementsCount be F.[[RemainingElements]].
+        // 8. Set errors[index] to x.
+        data.values.borrow_mut()[data.index] = args.get_or_undefined(0).clone();
+
+        // 9. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+        // 10. If remainingElementsCount.[[Value]] is 0, then
+        if data.finish_element() {
+            // a. Let error be a newly created AggregateError object.
+            // b. Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: CreateArrayFromList(errors) }).
+            let errors = data.values.borrow().clone();
+            let error = AggregateError::create(errors, "no promise was fulfilled", context);
+
+            // c. Return ? Call(promiseCapability.[[Reject]], undefined, « error »).
+            return data
+                .capability
+                .reject
+                .call(&JsValue::undefined(), &[error.into()], context);
+        }
+
+        // 11. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `Promise.race ( iterable )`
+    ///
+    /// Returns a promise settled like the first promise of `iterable` that is settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.race
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/race
+    pub(crate) fn race(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::combinator(
+            this,
+            args.get_or_undefined(0),
+            Self::perform_promise_race,
+            context,
+        )
+    }
+
+    /// `PerformPromiseRace ( iteratorRecord, constructor, resultCapability, promiseResolve )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiserace
+    fn perform_promise_race(
+        iterator_record: &IteratorRecord,
+        constructor: &JsObject,
+        result_capability: &PromiseCapability,
+        promise_resolve: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Repeat,
+        loop {
+            // a. Let next be Completion(IteratorStep(iteratorRecord)).
+            // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // c. ReturnIfAbrupt(next).
+            let next = iterator_record.next(context)?;
+
+            // d. If next is false, then
+            if next.done {
+                // i. Set iteratorRecord.[[Done]] to true.
+                // ii. Return resultCapability.[[Promise]].
+                return Ok(result_capability.promise.clone().into());
+            }
+
+            // e. Let nextValue be Completion(IteratorValue(next)).
+            // f. If nextValue is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // g. ReturnIfAbrupt(nextValue).
+            // h. Let nextPromise be ? Call(promiseResolve, constructor, « nextValue »).
+            let next_promise =
+                match promise_resolve.call(&constructor.clone().into(), &[next.value], context) {
+                    Ok(next_promise) => next_promise,
+                    err => return iterator_record.close(err, context),
+                };
+
+            // i. Perform ? Invoke(nextPromise, "then", « resultCapability.[[Resolve]], resultCapability.[[Reject]] »).
+            let then = next_promise.invoke(
+                "then",
+                &[
+                    result_capability.resolve.clone().into(),
+                    result_capability.reject.clone().into(),
+                ],
+                context,
+            );
+            if then.is_err() {
+                return iterator_record.close(then, context);
+            }
+        }
+    }
+}
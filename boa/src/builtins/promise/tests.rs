@@ -0,0 +1,299 @@
+use crate::{
+    forward,
+    host::{HostHooks, PromiseRejectionOperation},
+    job::NativeJob,
+    object::JsObject,
+    Context,
+};
+use std::{cell::RefCell, rc::Rc};
+
+/// Evaluates `src`, runs the pending jobs and returns the value of `log` afterwards.
+fn run_and_log(context: &mut Context, src: &str) -> String {
+    forward(context, "var log = [];");
+    forward(context, src);
+    context.run_jobs().expect("no job should throw");
+    forward(context, "log.join()")
+}
+
+#[test]
+fn reactions_run_after_the_script() {
+    let mut context = Context::new();
+    let src = r#"
+        Promise.resolve(1)
+            .then(v => { log.push("then " + v); return v + 1; })
+            .then(v => { log.push("then " + v); });
+        new Promise(resolve => { log.push("executor"); resolve(); })
+            .then(() => log.push("other"));
+        log.push("sync");
+        "#;
+    forward(&mut context, "var log = [];");
+    forward(&mut context, src);
+    assert_eq!(forward(&mut context, "log.join()"), "\"executor,sync\"");
+
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"executor,sync,then 1,other,then 2\""
+    );
+}
+
+#[test]
+fn constructor() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Promise(() => {}) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { new Promise(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.prototype.toString.call(new Promise(() => {}))"
+        ),
+        "\"[object Promise]\""
+    );
+    assert_eq!(
+        forward(&mut context, "Promise[Symbol.species] === Promise"),
+        "true"
+    );
+
+    let src = r#"
+        new Promise(() => { throw "thrown"; }).catch(e => log.push(e));
+        new Promise((resolve, reject) => { resolve(1); reject(2); throw 3; })
+            .then(v => log.push(v), e => log.push("rejected " + e));
+        "#;
+    assert_eq!(run_and_log(&mut context, src), "\"thrown,1\"");
+}
+
+#[test]
+fn thenables() {
+    let mut context = Context::new();
+    let src = r#"
+        var thenable = { then(resolve) { log.push("then called"); resolve("adopted"); } };
+        Promise.resolve(thenable).then(v => log.push(v));
+        new Promise(resolve => resolve(Promise.reject("nested"))).catch(e => log.push(e));
+        var resolveP;
+        var p = new Promise(resolve => { resolveP = resolve; });
+        resolveP(p);
+        p.catch(e => log.push(e.name));
+        log.push("sync");
+        "#;
+    assert_eq!(
+        run_and_log(&mut context, src),
+        "\"sync,then called,TypeError,adopted,nested\""
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "var q = Promise.resolve(1); Promise.resolve(q) === q"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn catch_and_finally() {
+    let mut context = Context::new();
+    let src = r#"
+        Promise.reject("a")
+            .finally(() => log.push("finally"))
+            .catch(e => { log.push("caught " + e); return "b"; })
+            .finally(() => { throw "c"; })
+            .then(v => log.push(v), e => log.push("rejected " + e));
+        Promise.resolve("d")
+            .finally(() => "ignored")
+            .then(v => log.push(v));
+        "#;
+    assert_eq!(
+        run_and_log(&mut context, src),
+        "\"finally,caught a,d,rejected c\""
+    );
+}
+
+#[test]
+fn species_of_constructor_function_subclass() {
+    let mut context = Context::new();
+    let src = r#"
+        function subclass(name) {
+            function Sub(executor) {
+                log.push(name);
+                return Reflect.construct(Promise, [executor], Sub);
+            }
+            Sub.prototype = Object.create(Promise.prototype, { constructor: { value: Sub } });
+            Object.setPrototypeOf(Sub, Promise);
+            return Sub;
+        }
+        var MyPromise = subclass("MyPromise");
+        var Species = subclass("Species");
+        Object.defineProperty(MyPromise, Symbol.species, { value: Species });
+
+        var promise = new MyPromise(resolve => resolve(1));
+        var then = promise.then(v => log.push("then " + v));
+        var fin = promise.finally(() => log.push("finally"));
+        log.push(then instanceof Species, fin instanceof Species);
+        "#;
+    // The reactions of `finally` create their promises through the species as well.
+    assert_eq!(
+        run_and_log(&mut context, src),
+        "\"MyPromise,Species,Species,true,true,then 1,finally,Species,Species,Species\""
+    );
+}
+
+#[test]
+fn all_and_all_settled() {
+    let mut context = Context::new();
+    let src = r#"
+        Promise.all([1, Promise.resolve(2), { then(r) { r(3); } }])
+            .then(v => log.push("all " + v.join(" ")));
+        Promise.all([1, Promise.reject("no")])
+            .catch(e => log.push("all rejected " + e));
+        Promise.all([]).then(v => log.push("empty " + v.length));
+        Promise.allSettled([1, Promise.reject(2)]).then(v => log.push(
+            v.map(r => r.status + ":" + (r.status === "fulfilled" ? r.value : r.reason)).join(" ")
+        ));
+        "#;
+    assert_eq!(
+        run_and_log(&mut context, src),
+        "\"empty 0,all rejected no,fulfilled:1 rejected:2,all 1 2 3\""
+    );
+}
+
+#[test]
+fn any_and_race() {
+    let mut context = Context::new();
+    let src = r#"
+        Promise.any([Promise.reject(1), Promise.resolve(2)]).then(v => log.push("any " + v));
+        Promise.any([Promise.reject(1), Promise.reject(2)]).catch(e => log.push(
+            e.name + " " + e.errors.join(" ") + " " + (e instanceof AggregateError)
+        ));
+        Promise.race([new Promise(() => {}), Promise.reject("lost"), Promise.resolve("late")])
+            .catch(e => log.push("race " + e));
+        "#;
+    assert_eq!(
+        run_and_log(&mut context, src),
+        "\"any 2,AggregateError 1 2 true,race lost\""
+    );
+
+    assert_eq!(
+        run_and_log(&mut context, "Promise.all(1).catch(e => log.push(e.name));"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn with_resolvers() {
+    let mut context = Context::new();
+    let src = r#"
+        var first = Promise.withResolvers();
+        first.promise.then(v => log.push("resolved " + v));
+        first.resolve(1);
+        first.resolve(2);
+        var second = Promise.withResolvers();
+        second.promise.catch(e => log.push("rejected " + e));
+        second.reject(3);
+        log.push(Object.keys(first).join(" "));
+        log.push(first.promise instanceof Promise);
+        "#;
+    assert_eq!(
+        run_and_log(&mut context, src),
+        "\"promise resolve reject,true,resolved 1,rejected 3\""
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Promise.withResolvers.call(1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn host_enqueues_jobs() {
+    #[derive(Default)]
+    struct Queue(Rc<RefCell<Vec<NativeJob>>>);
+
+    impl HostHooks for Queue {
+        fn enqueue_promise_job(&self, job: NativeJob, _: &mut Context) {
+            self.0.borrow_mut().push(job);
+        }
+    }
+
+    let jobs = Rc::new(RefCell::new(Vec::new()));
+    let mut context = Context::with_host_hooks(Queue(jobs.clone()));
+    forward(
+        &mut context,
+        "var result; Promise.resolve(1).then(v => { result = v; });",
+    );
+    assert_eq!(jobs.borrow().len(), 1);
+
+    // The default queue of the context isn't used.
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "result"), "undefined");
+
+    let job = jobs.borrow_mut().pop().unwrap();
+    job.call(&mut context).unwrap();
+    assert_eq!(forward(&mut context, "result"), "1");
+}
+
+#[test]
+fn host_tracks_rejections() {
+    struct Tracker(Rc<RefCell<Vec<(JsObject, PromiseRejectionOperation)>>>);
+
+    impl HostHooks for Tracker {
+        fn promise_rejection_tracker(
+            &self,
+            promise: &JsObject,
+            operation: PromiseRejectionOperation,
+            _: &mut Context,
+        ) {
+            self.0.borrow_mut().push((promise.clone(), operation));
+        }
+    }
+
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let mut context = Context::with_host_hooks(Tracker(calls.clone()));
+    let src = r#"
+        var handled = new Promise((_, reject) => { globalThis.reject = reject; });
+        handled.catch(() => {});
+        reject(1);
+        var unhandled = Promise.reject(2);
+        unhandled.catch(() => {});
+        unhandled.catch(() => {});
+        var source = Promise.reject(3);
+        var derived = source.then(v => v);
+        "#;
+    forward(&mut context, src);
+    context.run_jobs().unwrap();
+
+    let names = ["handled", "unhandled", "source", "derived"];
+    let calls: Vec<_> = calls
+        .borrow()
+        .iter()
+        .map(|(promise, operation)| {
+            let name = names
+                .iter()
+                .find(|name| context.eval(name).unwrap().as_object() == Some(promise))
+                .expect("only the promises of the script are tracked");
+            (*name, *operation)
+        })
+        .collect();
+    assert_eq!(
+        calls,
+        [
+            ("unhandled", PromiseRejectionOperation::Reject),
+            ("unhandled", PromiseRejectionOperation::Handle),
+            ("source", PromiseRejectionOperation::Reject),
+            ("source", PromiseRejectionOperation::Handle),
+            ("derived", PromiseRejectionOperation::Reject),
+        ]
+    );
+}
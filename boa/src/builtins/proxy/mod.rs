@@ -10,6 +10,9 @@
 //! [spec]: https://tc39.es/ecma262/#sec-proxy-objects
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy
 
+#[cfg(test)]
+mod tests;
+
 use crate::{
     builtins::{BuiltIn, JsArgs},
     gc::{Finalize, Trace},
@@ -0,0 +1,236 @@
+use crate::{forward, Context};
+
+/// Runs `src` and returns the name of the error it throws, or `"ok"` if it doesn't throw.
+fn thrown(context: &mut Context, src: &str) -> String {
+    forward(
+        context,
+        &format!("try {{ {}; \"ok\" }} catch (e) {{ e.name }}", src),
+    )
+}
+
+#[test]
+fn traps_are_called() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        var target = function () { return "called"; };
+        var handler = {};
+        ["get", "set", "has", "deleteProperty", "ownKeys", "defineProperty",
+         "getOwnPropertyDescriptor", "getPrototypeOf", "setPrototypeOf", "isExtensible",
+         "preventExtensions", "apply", "construct"].forEach(function (name) {
+            handler[name] = function () {
+                log.push(name);
+                return Reflect[name].apply(null, arguments);
+            };
+        });
+        var proxy = new Proxy(target, handler);
+
+        proxy.a;
+        proxy.a = 1;
+        "a" in proxy;
+        delete proxy.a;
+        Object.keys(proxy);
+        Object.defineProperty(proxy, "b", { value: 1, configurable: true });
+        Object.getOwnPropertyDescriptor(proxy, "b");
+        Object.getPrototypeOf(proxy);
+        Object.setPrototypeOf(proxy, Function.prototype);
+        Object.isExtensible(proxy);
+        proxy();
+        new proxy();
+        Object.preventExtensions(proxy);
+        "#;
+    forward(&mut context, init);
+
+    for trap in [
+        "get",
+        "set",
+        "has",
+        "deleteProperty",
+        "ownKeys",
+        "defineProperty",
+        "getOwnPropertyDescriptor",
+        "getPrototypeOf",
+        "setPrototypeOf",
+        "isExtensible",
+        "preventExtensions",
+        "apply",
+        "construct",
+    ] {
+        assert_eq!(
+            forward(&mut context, &format!("log.indexOf(\"{}\") !== -1", trap)),
+            "true",
+            "the `{}` trap was not called",
+            trap
+        );
+    }
+    assert_eq!(forward(&mut context, "proxy()"), "\"called\"");
+}
+
+#[test]
+fn missing_traps_forward_to_target() {
+    let mut context = Context::new();
+    let init = r#"
+        var target = { a: 1 };
+        var proxy = new Proxy(target, {});
+        proxy.b = 2;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "proxy.a"), "1");
+    assert_eq!(forward(&mut context, "target.b"), "2");
+    assert_eq!(forward(&mut context, "\"a\" in proxy"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.keys(proxy).join()"),
+        "\"a,b\""
+    );
+}
+
+#[test]
+fn trap_keys_are_strings() {
+    let mut context = Context::new();
+    let init = r#"
+        var keys = [];
+        var proxy = new Proxy([], {
+            get(target, key) { keys.push(typeof key + " " + key); return Reflect.get(target, key); },
+            set(target, key, value) { keys.push(typeof key + " " + key); return Reflect.set(target, key, value); },
+        });
+        proxy[0] = "a";
+        proxy[0];
+        proxy.x = 1;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "keys.join()"),
+        "\"string 0,string 0,string x\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.keys(new Proxy([1, 2], { ownKeys(t) { return Reflect.ownKeys(t); } })).join()"
+        ),
+        "\"0,1\""
+    );
+}
+
+#[test]
+fn own_keys_traps_drive_enumeration() {
+    let mut context = Context::new();
+    let init = r#"
+        var proxy = new Proxy({ a: 1, b: 2 }, {
+            ownKeys() { return ["b", "c", "a", "hidden"]; },
+            getOwnPropertyDescriptor(target, key) {
+                if (key === "c") {
+                    return { value: 3, enumerable: true, configurable: true };
+                }
+                if (key === "hidden") {
+                    return { value: 4, enumerable: false, configurable: true };
+                }
+                return Reflect.getOwnPropertyDescriptor(target, key);
+            },
+        });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Object.keys(proxy).join()"),
+        "\"b,c,a\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertyNames(proxy).join()"),
+        "\"b,c,a,hidden\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(proxy, 'c').value"
+        ),
+        "3"
+    );
+}
+
+#[test]
+fn invariant_violations_throw() {
+    let mut context = Context::new();
+    let init = r#"
+        var frozen = Object.freeze({ a: 1 });
+        var sealed = Object.seal({ a: 1 });
+        var locked = Object.preventExtensions({ a: 1 });
+        "#;
+    forward(&mut context, init);
+
+    for src in [
+        // [[Get]] must report the value of a non-writable, non-configurable property.
+        "new Proxy(frozen, { get() { return 2; } }).a",
+        // [[Set]] can't succeed for a non-writable, non-configurable property.
+        "'use strict'; new Proxy(frozen, { set() { return true; } }).a = 2",
+        // [[HasProperty]] can't hide a non-configurable property.
+        "'a' in new Proxy(sealed, { has() { return false; } })",
+        // [[Delete]] can't remove a non-configurable property.
+        "delete new Proxy(sealed, { deleteProperty() { return true; } }).a",
+        // [[OwnPropertyKeys]] must list every non-configurable key.
+        "Object.keys(new Proxy(sealed, { ownKeys() { return []; } }))",
+        // [[OwnPropertyKeys]] must not contain duplicates.
+        "Object.keys(new Proxy({}, { ownKeys() { return ['a', 'a']; } }))",
+        // [[DefineOwnProperty]] can't add a property to a non-extensible target.
+        "Object.defineProperty(new Proxy(locked, { defineProperty() { return true; } }), 'b', { value: 1 })",
+        // [[GetOwnProperty]] can't report a non-configurable property as missing.
+        "Object.getOwnPropertyDescriptor(new Proxy(sealed, { getOwnPropertyDescriptor() {} }), 'a')",
+        // [[GetPrototypeOf]] must match the prototype of a non-extensible target.
+        "Object.getPrototypeOf(new Proxy(locked, { getPrototypeOf() { return Array.prototype; } }))",
+        // [[SetPrototypeOf]] can't change the prototype of a non-extensible target.
+        "Object.setPrototypeOf(new Proxy(locked, { setPrototypeOf() { return true; } }), Array.prototype)",
+        // [[IsExtensible]] must agree with the target.
+        "Object.isExtensible(new Proxy({}, { isExtensible() { return false; } }))",
+        // [[PreventExtensions]] can't report success while the target is still extensible.
+        "Object.preventExtensions(new Proxy({}, { preventExtensions() { return true; } }))",
+        // [[Call]] and [[Construct]] need a callable target.
+        "new Proxy({}, { apply() {} })()",
+        "new new Proxy(function () {}, { construct() { return 1; } })()",
+        // A trap must be callable.
+        "new Proxy({}, { get: 1 }).a",
+    ] {
+        assert_eq!(thrown(&mut context, src), "\"TypeError\"", "{}", src);
+    }
+}
+
+#[test]
+fn revocable() {
+    let mut context = Context::new();
+    let init = r#"
+        var revocable = Proxy.revocable([], {});
+        var proxy = revocable.proxy;
+        proxy.push(1);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "proxy.length"), "1");
+    assert_eq!(forward(&mut context, "Array.isArray(proxy)"), "true");
+
+    forward(&mut context, "revocable.revoke()");
+    assert_eq!(thrown(&mut context, "proxy.length"), "\"TypeError\"");
+    assert_eq!(thrown(&mut context, "proxy.a = 1"), "\"TypeError\"");
+    assert_eq!(
+        thrown(&mut context, "Array.isArray(proxy)"),
+        "\"TypeError\""
+    );
+    // Revoking again does nothing.
+    assert_eq!(thrown(&mut context, "revocable.revoke()"), "\"ok\"");
+}
+
+#[test]
+fn constructor_arguments() {
+    let mut context = Context::new();
+
+    assert_eq!(thrown(&mut context, "Proxy({}, {})"), "\"TypeError\"");
+    assert_eq!(thrown(&mut context, "new Proxy(1, {})"), "\"TypeError\"");
+    assert_eq!(thrown(&mut context, "new Proxy({}, null)"), "\"TypeError\"");
+    assert_eq!(
+        forward(&mut context, "typeof new Proxy(function () {}, {})"),
+        "\"function\""
+    );
+    assert_eq!(
+        forward(&mut context, "typeof new Proxy({}, {})"),
+        "\"object\""
+    );
+}
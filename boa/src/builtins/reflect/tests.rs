@@ -44,6 +44,57 @@ fn define_property() {
     assert_eq!(forward(&mut context, "obj.p"), "42");
 }
 
+#[test]
+fn define_property_returns_false_instead_of_throwing() {
+    let mut context = Context::new();
+
+    let init = r#"
+        let obj = {};
+        let defined = Reflect.defineProperty(obj, 'x', { value: 1 });
+        let arr = [1, 2];
+        Object.defineProperty(arr, 'length', { writable: false });
+        let trapped = new Proxy({}, { defineProperty() { return false; } });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "defined"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.defineProperty(obj, 'x', { value: 2 })"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.defineProperty(obj, 'x', { get() {} })"
+        ),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "obj.x"), "1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.defineProperty(obj, 'x', { value: 1 })"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Reflect.defineProperty(arr, 2, { value: 3 })"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "arr.length"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.defineProperty(trapped, 'a', { value: 1 })"
+        ),
+        "false"
+    );
+}
+
 #[test]
 fn delete_property() {
     let mut context = Context::new();
@@ -189,3 +240,196 @@ fn set_prototype_of() {
 
     assert_eq!(forward(&mut context, "p.name"), "\"F\"");
 }
+
+#[test]
+fn construct_new_target() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function Base() { this.base = true; }
+        function Derived() {}
+        var instance = Reflect.construct(Base, [], Derived);
+        var trapNewTarget;
+        var proxy = new Proxy(Base, {
+            construct(target, args, newTarget) {
+                trapNewTarget = newTarget;
+                return Reflect.construct(target, args, newTarget);
+            }
+        });
+        var proxied = new proxy();
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(instance) === Derived.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "instance.base"), "true");
+    assert_eq!(forward(&mut context, "trapNewTarget === proxy"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(proxied) === Base.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Reflect.construct(Base, [], Math.max) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Reflect.construct(() => {}, []) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn get_and_set_receiver() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var target = {
+            get value() { return this.name; },
+            set value(v) { this.name = v; },
+        };
+        var receiver = { name: "receiver" };
+        Reflect.set(target, "value", "set", receiver);
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Reflect.get(target, 'value', receiver)"),
+        "\"set\""
+    );
+    assert_eq!(forward(&mut context, "target.name"), "undefined");
+    assert_eq!(forward(&mut context, "receiver.name"), "\"set\"");
+}
+
+#[test]
+fn set_defines_data_properties_on_the_receiver() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var target = { x: 0 };
+        var receiver = {};
+        var result = Reflect.set(target, "x", 5, receiver);
+
+        var read_only = Object.defineProperty({}, "x", { value: 1, configurable: true });
+        var accessor = Object.defineProperty({}, "x", { get() {}, configurable: true });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "result"), "true");
+    assert_eq!(forward(&mut context, "receiver.x"), "5");
+    assert_eq!(forward(&mut context, "target.x"), "0");
+
+    // The receiver's own property decides whether the value can be defined.
+    assert_eq!(
+        forward(&mut context, "Reflect.set(target, 'x', 5, read_only)"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "Reflect.set(target, 'x', 5, accessor)"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "Reflect.set(target, 'x', 5, 1)"),
+        "false"
+    );
+}
+
+#[test]
+fn receiver_is_passed_through_proxies() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var base = { get who() { return this.name; } };
+        var forwarding = new Proxy(base, {
+            get(target, key, receiver) {
+                return Reflect.get(target, key, receiver);
+            },
+            set(target, key, value, receiver) {
+                set_receiver = receiver;
+                return Reflect.set(target, key, value, receiver);
+            },
+        });
+        var set_receiver;
+        var child = Object.create(forwarding);
+        child.name = "child";
+        child.other = 1;
+        "#;
+
+    forward(&mut context, init);
+
+    // The getter runs with the object the property was read from, not the proxy's target.
+    assert_eq!(forward(&mut context, "child.who"), "\"child\"");
+    assert_eq!(forward(&mut context, "set_receiver === child"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertyNames(child).join()"),
+        "\"name,other\""
+    );
+    assert_eq!(forward(&mut context, "'other' in base"), "false");
+}
+
+#[test]
+fn returns_booleans_and_validates_targets() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var frozen = Object.freeze({ a: 1 });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Reflect.set(frozen, 'a', 2)"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.defineProperty(frozen, 'b', { value: 1 })"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "Reflect.deleteProperty(frozen, 'a')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "Reflect.setPrototypeOf(frozen, null)"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "Reflect.setPrototypeOf.length"), "2");
+
+    for call in [
+        "Reflect.get(1, 'a')",
+        "Reflect.set('a', 'length', 1)",
+        "Reflect.has(null, 'a')",
+        "Reflect.ownKeys(undefined)",
+        "Reflect.apply({}, null, [])",
+        "Reflect.setPrototypeOf({}, 1)",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {} }} catch (e) {{ e.name }}", call)
+            ),
+            "\"TypeError\"",
+            "{}",
+            call
+        );
+    }
+}
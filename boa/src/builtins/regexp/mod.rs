@@ -61,6 +61,9 @@ pub struct RegExp {
 
     original_source: JsString,
     original_flags: JsString,
+
+    /// The names of the named capturing groups, in the order they appear in the pattern.
+    group_names: Box<[Box<str>]>,
 }
 
 // Only safe while regress::Regex doesn't implement Trace itself.
@@ -68,6 +71,20 @@ unsafe impl Trace for RegExp {
     empty_trace!();
 }
 
+impl RegExp {
+    /// Gets the source the regular expression was created with.
+    #[cfg(feature = "structured_clone")]
+    pub(crate) fn original_source(&self) -> &JsString {
+        &self.original_source
+    }
+
+    /// Gets the flags the regular expression was created with.
+    #[cfg(feature = "structured_clone")]
+    pub(crate) fn original_flags(&self) -> &JsString {
+        &self.original_flags
+    }
+}
+
 impl BuiltIn for RegExp {
     const NAME: &'static str = "RegExp";
 
@@ -197,50 +214,76 @@ impl RegExp {
         let flags = args.get_or_undefined(1);
 
         // 1. Let patternIsRegExp be ? IsRegExp(pattern).
-        let pattern_is_regexp = pattern.as_object().filter(|obj| obj.is_regexp());
+        let pattern_is_regexp = is_reg_exp(pattern, context)?;
 
         // 2. If NewTarget is undefined, then
         // 3. Else, let newTarget be NewTarget.
-        if new_target.is_undefined() {
+        let new_target = if new_target.is_undefined() {
             // a. Let newTarget be the active function object.
+            let new_target: JsValue = context
+                .standard_objects()
+                .regexp_object()
+                .constructor()
+                .into();
+
             // b. If patternIsRegExp is true and flags is undefined, then
-            if let Some(pattern) = pattern_is_regexp {
+            if let Some(pattern) = pattern.as_object().filter(|_| pattern_is_regexp) {
                 if flags.is_undefined() {
                     // i. Let patternConstructor be ? Get(pattern, "constructor").
                     let pattern_constructor = pattern.get("constructor", context)?;
                     // ii. If SameValue(newTarget, patternConstructor) is true, return pattern.
-                    if JsValue::same_value(new_target, &pattern_constructor) {
+                    if JsValue::same_value(&new_target, &pattern_constructor) {
                         return Ok(pattern.clone().into());
                     }
                 }
             }
-        }
+            new_target
+        } else {
+            new_target.clone()
+        };
 
         // 4. If Type(pattern) is Object and pattern has a [[RegExpMatcher]] internal slot, then
-        // 6. Else,
-        let (p, f) = if let Some(pattern) = pattern_is_regexp {
-            let obj = pattern.borrow();
-            let regexp = obj.as_regexp().unwrap();
-
+        let original = pattern.as_object().and_then(|obj| {
+            obj.borrow().as_regexp().map(|regexp| {
+                (
+                    regexp.original_source.clone(),
+                    regexp.original_flags.clone(),
+                )
+            })
+        });
+        let (p, f) = if let Some((source, original_flags)) = original {
             // a. Let P be pattern.[[OriginalSource]].
             // b. If flags is undefined, let F be pattern.[[OriginalFlags]].
             // c. Else, let F be flags.
             if flags.is_undefined() {
-                (
-                    JsValue::new(regexp.original_source.clone()),
-                    JsValue::new(regexp.original_flags.clone()),
-                )
+                (JsValue::new(source), JsValue::new(original_flags))
             } else {
-                (JsValue::new(regexp.original_source.clone()), flags.clone())
+                (JsValue::new(source), flags.clone())
             }
+        }
+        // 5. Else if patternIsRegExp is true, then
+        else if let Some(pattern) = pattern.as_object().filter(|_| pattern_is_regexp) {
+            // a. Let P be ? Get(pattern, "source").
+            let p = pattern.get("source", context)?;
+
+            // b. If flags is undefined, then
+            //     i. Let F be ? Get(pattern, "flags").
+            // c. Else, let F be flags.
+            let f = if flags.is_undefined() {
+                pattern.get("flags", context)?
+            } else {
+                flags.clone()
+            };
+            (p, f)
         } else {
-            // a. Let P be pattern.
-            // b. Let F be flags.
+            // 6. Else,
+            //     a. Let P be pattern.
+            //     b. Let F be flags.
             (pattern.clone(), flags.clone())
         };
 
         // 7. Let O be ? RegExpAlloc(newTarget).
-        let o = RegExp::alloc(new_target, &[], context)?;
+        let o = RegExp::alloc(&new_target, &[], context)?;
 
         // 8.Return ? RegExpInitialize(O, P, F).
         RegExp::initialize(&o, &[p, f], context)
@@ -330,7 +373,17 @@ impl RegExp {
         // 12. Set obj.[[OriginalSource]] to P.
         // 13. Set obj.[[OriginalFlags]] to F.
         // 14. Set obj.[[RegExpMatcher]] to the Abstract Closure that evaluates parseResult by applying the semantics provided in 22.2.2 using patternCharacters as the pattern's List of SourceCharacter values and F as the flag parameters.
-        let matcher = match Regex::with_flags(&p, f.as_ref()) {
+        let group_names = match capture_group_names(&p) {
+            Ok(names) => names,
+            Err(name) => {
+                return context.throw_syntax_error(format!(
+                    "duplicate capture group name '{}' in regular expression",
+                    name
+                ))
+            }
+        };
+        let matcher = match Regex::with_flags(&rename_capture_groups(&p, &group_names), f.as_ref())
+        {
             Err(error) => {
                 return Err(context
                     .construct_syntax_error(format!("failed to create matcher: {}", error.text)));
@@ -349,6 +402,7 @@ impl RegExp {
             unicode,
             original_source: p,
             original_flags: f,
+            group_names,
         };
 
         this.set_data(ObjectData::reg_exp(Box::new(regexp)));
@@ -666,8 +720,8 @@ impl RegExp {
             for c in src.chars() {
                 match c {
                     '/' => s.push_str("\\/"),
-                    '\n' => s.push_str("\\\\n"),
-                    '\r' => s.push_str("\\\\r"),
+                    '\n' => s.push_str("\\n"),
+                    '\r' => s.push_str("\\r"),
                     _ => s.push(c),
                 }
             }
@@ -864,17 +918,10 @@ impl RegExp {
             }
 
             // b. Let r be matcher(S, lastIndex).
-            // Check if last_index is a valid utf8 index into input.
-            let last_byte_index = match String::from_utf16(
-                &input.encode_utf16().take(last_index).collect::<Vec<u16>>(),
-            ) {
-                Ok(s) => s.len(),
-                Err(_) => {
-                    return Err(context.construct_type_error(
-                        "Failed to get byte index from utf16 encoded string",
-                    ))
-                }
-            };
+            // Regress searches the input from the byte index of lastIndex onwards, so the match
+            // it returns starts at the first index at or after lastIndex where the matcher
+            // succeeds.
+            let last_byte_index = utf16_to_byte_index(&input, last_index);
             let r = matcher.find_from(&input, last_byte_index).next();
 
             match r {
@@ -894,40 +941,34 @@ impl RegExp {
                 }
 
                 Some(m) => {
+                    let start = input[..m.start()].encode_utf16().count();
+
                     // c. If r is failure, then
-                    // d. Else,
-                    if m.start() != last_index {
-                        // i. If sticky is true, then
-                        if sticky {
-                            // 1. Perform ? Set(R, "lastIndex", +0𝔽, true).
-                            this.set("lastIndex", 0, true, context)?;
-
-                            // 2. Return null.
-                            return Ok(None);
-                        }
+                    // i. If sticky is true, then
+                    if start != last_index && sticky {
+                        // 1. Perform ? Set(R, "lastIndex", +0𝔽, true).
+                        this.set("lastIndex", 0, true, context)?;
 
-                        // ii. Set lastIndex to AdvanceStringIndex(S, lastIndex, fullUnicode).
-                        last_index = advance_string_index(input.clone(), last_index, unicode);
-                    } else {
-                        //i. Assert: r is a State.
-                        //ii. Set matchSucceeded to true.
-                        break m;
+                        // 2. Return null.
+                        return Ok(None);
                     }
+
+                    // d. Else,
+                    //i. Assert: r is a State.
+                    //ii. Set matchSucceeded to true.
+                    last_index = start;
+                    break m;
                 }
             }
         };
 
         // 13. Let e be r's endIndex value.
-        let mut e = match_value.end();
-
         // 14. If fullUnicode is true, then
-        if unicode {
-            // e is an index into the Input character list, derived from S, matched by matcher.
-            // Let eUTF be the smallest index into S that corresponds to the character at element e of Input.
-            // If e is greater than or equal to the number of elements in Input, then eUTF is the number of code units in S.
-            // b. Set e to eUTF.
-            e = input.split_at(e).0.encode_utf16().count();
-        }
+        //     a. Let eUTF be the smallest index into S that corresponds to the character at element e of Input.
+        //     b. Set e to eUTF.
+        // Regress matches against the code points of S in every mode, so the end of the match is
+        // always converted from a byte index into a code unit index.
+        let e = input[..match_value.end()].encode_utf16().count();
 
         // 15. If global is true or sticky is true, then
         if global || sticky {
@@ -945,7 +986,7 @@ impl RegExp {
         let a = Array::array_create(n + 1, None, context)?;
 
         // 20. Perform ! CreateDataPropertyOrThrow(A, "index", 𝔽(lastIndex)).
-        a.create_data_property_or_throw("index", match_value.start(), context)
+        a.create_data_property_or_throw("index", last_index, context)
             .unwrap();
 
         // 21. Perform ! CreateDataPropertyOrThrow(A, "input", S).
@@ -965,33 +1006,27 @@ impl RegExp {
 
         // 24. If R contains any GroupName, then
         // 25. Else,
-        let named_groups = match_value.named_groups();
-        let groups = if named_groups.clone().count() > 0 {
+        let groups = if rx.group_names.is_empty() {
+            // a. Let groups be undefined.
+            JsValue::undefined()
+        } else {
             // a. Let groups be ! OrdinaryObjectCreate(null).
-            let groups = JsValue::from(JsObject::empty());
+            let groups = JsObject::empty();
 
             // Perform 27.f here
             // f. If the ith capture of R was defined with a GroupName, then
             // i. Let s be the CapturingGroupName of the corresponding RegExpIdentifierName.
             // ii. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
-            for (name, range) in named_groups {
-                if let Some(range) = range {
-                    let value = if let Some(s) = input.get(range.clone()) {
-                        s
-                    } else {
-                        ""
-                    };
-
-                    groups
-                        .to_object(context)?
-                        .create_data_property_or_throw(name, value, context)
-                        .unwrap();
-                }
+            for (index, name) in rx.group_names.iter().enumerate() {
+                let value = match_value
+                    .named_group(&regress_group_name(index))
+                    .and_then(|range| input.get(range))
+                    .map_or_else(JsValue::undefined, JsValue::new);
+                groups
+                    .create_data_property_or_throw(&**name, value, context)
+                    .unwrap();
             }
-            groups
-        } else {
-            // a. Let groups be undefined.
-            JsValue::undefined()
+            groups.into()
         };
 
         // 26. Perform ! CreateDataPropertyOrThrow(A, "groups", groups).
@@ -1143,21 +1178,23 @@ impl RegExp {
         _: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let (body, flags) = if let Some(object) = this.as_object() {
-            let object = object.borrow();
-            let regex = object.as_regexp().ok_or_else(|| {
-                context.construct_type_error(format!(
-                    "Method RegExp.prototype.toString called on incompatible receiver {}",
-                    this.display()
-                ))
-            })?;
-            (regex.original_source.clone(), regex.original_flags.clone())
-        } else {
-            return context.throw_type_error(format!(
+        // 1. Let R be the this value.
+        // 2. If Type(R) is not Object, throw a TypeError exception.
+        let regexp = this.as_object().ok_or_else(|| {
+            context.construct_type_error(format!(
                 "Method RegExp.prototype.toString called on incompatible receiver {}",
                 this.display()
-            ));
-        };
+            ))
+        })?;
+
+        // 3. Let pattern be ? ToString(? Get(R, "source")).
+        let body = regexp.get("source", context)?.to_string(context)?;
+
+        // 4. Let flags be ? ToString(? Get(R, "flags")).
+        let flags = regexp.get("flags", context)?.to_string(context)?;
+
+        // 5. Let result be the string-concatenation of "/", pattern, "/", and flags.
+        // 6. Return result.
         Ok(format!("/{}/{}", body, flags).into())
     }
 
@@ -1744,6 +1781,32 @@ impl RegExp {
     }
 }
 
+/// `7.2.8 IsRegExp ( argument )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-isregexp
+pub(crate) fn is_reg_exp(argument: &JsValue, context: &mut Context) -> JsResult<bool> {
+    // 1. If Type(argument) is not Object, return false.
+    let argument = match argument {
+        JsValue::Object(argument) => argument,
+        _ => return Ok(false),
+    };
+
+    // 2. Let matcher be ? Get(argument, @@match).
+    let matcher = argument.get(WellKnownSymbols::r#match(), context)?;
+
+    // 3. If matcher is not undefined, return ! ToBoolean(matcher).
+    if !matcher.is_undefined() {
+        return Ok(matcher.to_boolean());
+    }
+
+    // 4. If argument has a [[RegExpMatcher]] internal slot, return true.
+    // 5. Return false.
+    Ok(argument.is_regexp())
+}
+
 /// `22.2.5.2.3 AdvanceStringIndex ( S, index, unicode )`
 ///
 /// More information:
@@ -1774,3 +1837,133 @@ fn advance_string_index(s: JsString, index: usize, unicode: bool) -> usize {
 
     index + offset as usize
 }
+
+/// Converts an index into the UTF-16 code units of `s` into a byte index into `s`.
+///
+/// An index that falls between the two halves of a surrogate pair is moved to the end of the
+/// pair, since a match can't start inside a code point of the UTF-8 string.
+fn utf16_to_byte_index(s: &str, index: usize) -> usize {
+    let mut units = 0;
+    for (byte_index, c) in s.char_indices() {
+        if units >= index {
+            return byte_index;
+        }
+        units += c.len_utf16();
+    }
+    s.len()
+}
+
+/// Returns the names of the named capturing groups of `pattern`, in the order they appear, or
+/// the first name that is used by two groups.
+///
+/// Regress accepts duplicate group names and doesn't keep the order of the groups, so the
+/// pattern is scanned for them here.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-patterns-static-semantics-early-errors
+fn capture_group_names(pattern: &str) -> Result<Box<[Box<str>]>, &str> {
+    let mut names: Vec<Box<str>> = Vec::new();
+    let mut in_class = false;
+    let mut chars = pattern.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' => in_class = true,
+            ']' => in_class = false,
+            // `(?<=` and `(?<!` are lookbehinds, not groups.
+            '(' if !in_class
+                && pattern[index + 1..].starts_with("?<")
+                && !pattern[index + 3..].starts_with(&['=', '!'][..]) =>
+            {
+                let rest = &pattern[index + 3..];
+                if let Some(end) = rest.find('>') {
+                    let name = &rest[..end];
+                    if names.iter().any(|other| &**other == name) {
+                        return Err(name);
+                    }
+                    names.push(name.into());
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(names.into_boxed_slice())
+}
+
+/// Replaces the names of the capturing groups of `pattern`, and of the backreferences to them,
+/// with the names returned by [`regress_group_name`].
+///
+/// Regress fails to parse some valid group names, like `value`, where a `u` follows another
+/// character of the name, so the pattern given to it only uses names it can parse.
+fn rename_capture_groups(pattern: &str, names: &[Box<str>]) -> String {
+    let rename = |name: &str| {
+        names
+            .iter()
+            .position(|other| &**other == name)
+            .map(regress_group_name)
+    };
+
+    let mut renamed = String::with_capacity(pattern.len());
+    let mut in_class = false;
+    let mut chars = pattern.char_indices();
+    while let Some((index, c)) = chars.next() {
+        let rest = &pattern[index..];
+        let (prefix, name) = match c {
+            '\\' if !in_class && rest.starts_with("\\k<") => ("\\k<", &rest[3..]),
+            '(' if !in_class
+                && rest.starts_with("(?<")
+                && !rest[3..].starts_with(&['=', '!'][..]) =>
+            {
+                ("(?<", &rest[3..])
+            }
+            '\\' => {
+                renamed.push(c);
+                if let Some((_, escaped)) = chars.next() {
+                    renamed.push(escaped);
+                }
+                continue;
+            }
+            '[' => {
+                in_class = true;
+                renamed.push(c);
+                continue;
+            }
+            ']' => {
+                in_class = false;
+                renamed.push(c);
+                continue;
+            }
+            _ => {
+                renamed.push(c);
+                continue;
+            }
+        };
+
+        match name
+            .find('>')
+            .and_then(|end| Some((end, rename(&name[..end])?)))
+        {
+            Some((end, new_name)) => {
+                renamed.push_str(prefix);
+                renamed.push_str(&new_name);
+                renamed.push('>');
+                // Skip the rest of the prefix, the name and the closing `>`.
+                for _ in 0..prefix.chars().count() + name[..end].chars().count() {
+                    chars.next();
+                }
+            }
+            None => renamed.push(c),
+        }
+    }
+    renamed
+}
+
+/// The name given to regress for the named capturing group at `index`, in the order the named
+/// groups appear in the pattern.
+fn regress_group_name(index: usize) -> String {
+    format!("g{}", index)
+}
@@ -59,33 +59,87 @@ fn species() {
     assert_eq!(forward(&mut context, "descriptor.configurable"), "true");
 }
 
-// TODO: uncomment this test when property getters are supported
-
-//    #[test]
-//    fn flags() {
-//        let mut context = Context::new();
-//        let init = r#"
-//                var re_gi = /test/gi;
-//                var re_sm = /test/sm;
-//                "#;
-//
-//        eprintln!("{}", forward(&mut context, init));
-//        assert_eq!(forward(&mut context, "re_gi.global"), "true");
-//        assert_eq!(forward(&mut context, "re_gi.ignoreCase"), "true");
-//        assert_eq!(forward(&mut context, "re_gi.multiline"), "false");
-//        assert_eq!(forward(&mut context, "re_gi.dotAll"), "false");
-//        assert_eq!(forward(&mut context, "re_gi.unicode"), "false");
-//        assert_eq!(forward(&mut context, "re_gi.sticky"), "false");
-//        assert_eq!(forward(&mut context, "re_gi.flags"), "gi");
-//
-//        assert_eq!(forward(&mut context, "re_sm.global"), "false");
-//        assert_eq!(forward(&mut context, "re_sm.ignoreCase"), "false");
-//        assert_eq!(forward(&mut context, "re_sm.multiline"), "true");
-//        assert_eq!(forward(&mut context, "re_sm.dotAll"), "true");
-//        assert_eq!(forward(&mut context, "re_sm.unicode"), "false");
-//        assert_eq!(forward(&mut context, "re_sm.sticky"), "false");
-//        assert_eq!(forward(&mut context, "re_sm.flags"), "ms");
-//    }
+#[test]
+fn flags() {
+    let mut context = Context::new();
+    let init = r#"
+            var re_gi = /test/gi;
+            var re_sm = /test/sm;
+            var re_uy = /test/yu;
+            "#;
+
+    eprintln!("{}", forward(&mut context, init));
+    assert_eq!(forward(&mut context, "re_gi.global"), "true");
+    assert_eq!(forward(&mut context, "re_gi.ignoreCase"), "true");
+    assert_eq!(forward(&mut context, "re_gi.multiline"), "false");
+    assert_eq!(forward(&mut context, "re_gi.dotAll"), "false");
+    assert_eq!(forward(&mut context, "re_gi.unicode"), "false");
+    assert_eq!(forward(&mut context, "re_gi.sticky"), "false");
+    assert_eq!(forward(&mut context, "re_gi.flags"), "\"gi\"");
+
+    assert_eq!(forward(&mut context, "re_sm.global"), "false");
+    assert_eq!(forward(&mut context, "re_sm.ignoreCase"), "false");
+    assert_eq!(forward(&mut context, "re_sm.multiline"), "true");
+    assert_eq!(forward(&mut context, "re_sm.dotAll"), "true");
+    assert_eq!(forward(&mut context, "re_sm.unicode"), "false");
+    assert_eq!(forward(&mut context, "re_sm.sticky"), "false");
+    assert_eq!(forward(&mut context, "re_sm.flags"), "\"ms\"");
+
+    assert_eq!(forward(&mut context, "re_uy.unicode"), "true");
+    assert_eq!(forward(&mut context, "re_uy.sticky"), "true");
+    assert_eq!(forward(&mut context, "re_uy.flags"), "\"uy\"");
+}
+
+#[test]
+fn dot_all() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "/a.b/s.test('a\\nb')"), "true");
+    assert_eq!(forward(&mut context, "/a.b/.test('a\\nb')"), "false");
+    assert_eq!(forward(&mut context, "/a.b/s.test('a\\u2028b')"), "true");
+}
+
+#[test]
+fn sticky() {
+    let mut context = Context::new();
+    let init = r#"
+        var regex = /a/y;
+        regex.lastIndex = 1;
+        "#;
+
+    eprintln!("{}", forward(&mut context, init));
+    assert_eq!(forward(&mut context, "regex.exec('bab').index"), "1");
+    assert_eq!(forward(&mut context, "regex.lastIndex"), "2");
+
+    // A match after lastIndex doesn't count, and lastIndex is reset.
+    assert_eq!(forward(&mut context, "regex.exec('babba')"), "null");
+    assert_eq!(forward(&mut context, "regex.lastIndex"), "0");
+    assert_eq!(forward(&mut context, "regex.test('ba')"), "false");
+    assert_eq!(forward(&mut context, "regex.lastIndex"), "0");
+
+    // Global sticky matching stops at the first gap.
+    assert_eq!(
+        forward(&mut context, "'aaba'.match(/a/gy).join()"),
+        "\"a,a\""
+    );
+    assert_eq!(
+        forward(&mut context, "'aaba'.replace(/a/gy, 'x')"),
+        "\"xxba\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[...'aaba'.matchAll(/a/gy)].map(m => m.index).join()"
+        ),
+        "\"0,1\""
+    );
+
+    // split ignores the sticky flag of its separator.
+    assert_eq!(
+        forward(&mut context, "'a1b2c'.split(/\\d/y).join()"),
+        "\"a,b,c\""
+    );
+}
 
 #[test]
 fn last_index() {
@@ -141,6 +195,67 @@ fn to_string() {
         "\"/\\n/g\""
     );
     assert_eq!(forward(&mut context, "/\\n/g.toString()"), "\"/\\n/g\"");
+    assert_eq!(
+        forward(&mut context, "new RegExp('a/b').toString()"),
+        "\"/a\\/b/\""
+    );
+    assert_eq!(
+        forward(&mut context, "new RegExp('').toString()"),
+        "\"/(?:)/\""
+    );
+}
+
+#[test]
+fn constructor_copies_regexp_argument() {
+    let mut context = Context::new();
+    let init = r#"
+        var original = /ab+c/gi;
+        original.lastIndex = 3;
+        var overridden = new RegExp(original, "m");
+        var inherited = new RegExp(original);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "overridden.source"), "\"ab+c\"");
+    assert_eq!(forward(&mut context, "overridden.flags"), "\"m\"");
+    assert_eq!(forward(&mut context, "overridden.lastIndex"), "0");
+    assert_eq!(forward(&mut context, "overridden === original"), "false");
+    assert_eq!(forward(&mut context, "inherited.source"), "\"ab+c\"");
+    assert_eq!(forward(&mut context, "inherited.flags"), "\"gi\"");
+    assert_eq!(forward(&mut context, "inherited === original"), "false");
+    assert_eq!(
+        forward(&mut context, "String(new RegExp(original, ''))"),
+        "\"/ab+c/\""
+    );
+}
+
+#[test]
+fn call_without_new_returns_regexp_argument() {
+    let mut context = Context::new();
+    let init = r#"
+        var original = /a/g;
+        var like = { constructor: RegExp, source: "b", flags: "i" };
+        like[Symbol.match] = true;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "RegExp(original) === original"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "RegExp(original, undefined) === original"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "RegExp(original, 'g') === original"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "RegExp(like) === like"), "true");
+    assert_eq!(
+        forward(&mut context, "new RegExp(like).toString()"),
+        "\"/b/i\""
+    );
 }
 
 #[test]
@@ -237,3 +352,192 @@ fn search() {
     assert_eq!(forward(&mut context, "/u/[Symbol.search](null)"), "1");
     assert_eq!(forward(&mut context, "/d/[Symbol.search](undefined)"), "2");
 }
+
+#[test]
+fn named_capture_groups() {
+    let mut context = Context::new();
+    let init = r#"
+        var date = /(?<year>\d{4})-(?<month>\d{2})/.exec("on 2021-03");
+        var either = /(?<a>x)|(?<b>y)/.exec("x");
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "date.groups.year"), "\"2021\"");
+    assert_eq!(forward(&mut context, "date.groups.month"), "\"03\"");
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(date.groups)"),
+        "null"
+    );
+
+    // Unmatched groups are still listed, in the order they appear in the pattern.
+    assert_eq!(
+        forward(&mut context, "Object.keys(either.groups).join()"),
+        "\"a,b\""
+    );
+    assert_eq!(forward(&mut context, "either.groups.b"), "undefined");
+    assert_eq!(
+        forward(&mut context, "'ab'.replace(/(?<x>a)|(?<y>q)/, '[$<y>]')"),
+        "\"[]b\""
+    );
+
+    assert_eq!(forward(&mut context, "/(?<c>.)\\k<c>/.test('aa')"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "'2021-03'.replace(/(?<y>\\d+)-(?<m>\\d+)/, '$<m>/$<y>')"
+        ),
+        "\"03/2021\""
+    );
+    // Regress can't parse these names itself.
+    assert_eq!(
+        forward(
+            &mut context,
+            "/(?<value>.)\\k<value>/.exec('xaa').groups.value"
+        ),
+        "\"a\""
+    );
+    assert_eq!(
+        forward(&mut context, "'ab'.replace(/(?<quux>a)/, '[$<quux>]')"),
+        "\"[a]b\""
+    );
+    assert_eq!(
+        forward(&mut context, "/(?<value>a)/.source"),
+        "\"(?<value>a)\""
+    );
+    // Lookbehinds aren't named groups.
+    assert_eq!(
+        forward(&mut context, "/(?<=a)(?<n>b)/.exec('ab').groups.n"),
+        "\"b\""
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new RegExp('(?<a>.)(?<a>.)') } catch (e) { e.name }"
+        ),
+        "\"SyntaxError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new RegExp('[(?<a>](?<a>.)').source } catch (e) { e.name }"
+        ),
+        "\"[(?<a>](?<a>.)\""
+    );
+}
+
+#[test]
+fn lookbehind() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "/(?<=\\$)\\d+/.exec('cost: $42')[0]"),
+        "\"42\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "'$4 and 5 and $6'.match(/(?<=\\$)\\d/g).join()"
+        ),
+        "\"4,6\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "'$4 and 5 and $6'.match(/(?<!\\$)\\b\\d/g).join()"
+        ),
+        "\"5\""
+    );
+
+    // The lookbehind can see the text before lastIndex, but never before the start of the input.
+    assert_eq!(forward(&mut context, "/(?<=a)b/.test('b')"), "false");
+    assert_eq!(forward(&mut context, "/(?<!a)b/.exec('b').index"), "0");
+    assert_eq!(
+        forward(
+            &mut context,
+            "var sticky = /(?<=a)b/y; sticky.lastIndex = 1; sticky.exec('ab')[0]"
+        ),
+        "\"b\""
+    );
+
+    // Alternatives of different lengths, and groups captured inside the lookbehind.
+    assert_eq!(
+        forward(&mut context, "/(?<=USD|€)\\d+/.exec('€30')[0]"),
+        "\"30\""
+    );
+    assert_eq!(
+        forward(&mut context, "/(?<=(\\w+)-)\\d/.exec('ab-1')[1]"),
+        "\"ab\""
+    );
+
+    // Indices are in UTF-16 code units, even when the input has surrogate pairs.
+    assert_eq!(
+        forward(&mut context, "/(?<=a)x/u.exec('\\u{1F600}ax').index"),
+        "3"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "/(?<=\\u{1F600})x/u.exec('a\\u{1F600}x').index"
+        ),
+        "3"
+    );
+    assert_eq!(forward(&mut context, "/x/.exec('\\u{1F600}x').index"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "var global = /x/gu; global.exec('\\u{1F600}x\\u{1F600}x'); global.lastIndex"
+        ),
+        "3"
+    );
+}
+
+#[test]
+fn string_methods_delegate_to_symbol_methods() {
+    let mut context = Context::new();
+    let init = r#"
+        var re = /b/;
+        var calls = 0;
+        re[Symbol.match] = function (s) { calls++; return ['custom', s]; };
+        re[Symbol.replace] = function (s, r) { return 'replace:' + s + ':' + r; };
+        re[Symbol.search] = function (s) { return 42; };
+        re[Symbol.split] = function (s, limit) { return ['split', s, limit]; };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "'abc'.match(re).join()"),
+        "\"custom,abc\""
+    );
+    assert_eq!(forward(&mut context, "calls"), "1");
+    assert_eq!(
+        forward(&mut context, "'abc'.replace(re, 'x')"),
+        "\"replace:abc:x\""
+    );
+    assert_eq!(forward(&mut context, "'abc'.search(re)"), "42");
+    assert_eq!(
+        forward(&mut context, "'abc'.split(re, 3).join()"),
+        "\"split,abc,3\""
+    );
+
+    // Other regexes keep using the methods on the prototype.
+    assert_eq!(forward(&mut context, "'abc'.match(/b/).index"), "1");
+}
+
+#[test]
+fn overriding_prototype_symbol_method() {
+    let mut context = Context::new();
+    let init = r#"
+        var original = RegExp.prototype[Symbol.match];
+        RegExp.prototype[Symbol.match] = function (s) { return 'overridden ' + s; };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "'abc'.match(/a/)"),
+        "\"overridden abc\""
+    );
+
+    forward(&mut context, "RegExp.prototype[Symbol.match] = original");
+    assert_eq!(forward(&mut context, "'abc'.match(/c/)[0]"), "\"c\"");
+}
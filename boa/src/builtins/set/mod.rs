@@ -21,6 +21,7 @@ use crate::{
     symbol::WellKnownSymbols,
     BoaProfiler, Context, JsResult, JsValue,
 };
+use num_traits::Zero;
 use ordered_set::OrderedSet;
 
 pub mod set_iterator;
@@ -203,11 +204,7 @@ impl Set {
 
         if let Some(object) = this.as_object() {
             if let Some(set) = object.borrow_mut().as_set_mut() {
-                set.add(if value.as_number().map(|n| n == -0f64).unwrap_or(false) {
-                    JsValue::Integer(0)
-                } else {
-                    value.clone()
-                });
+                set.add(Self::normalize_zero(value));
             } else {
                 return context.throw_type_error("'this' is not a Set");
             }
@@ -230,15 +227,12 @@ impl Set {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/clear
     pub(crate) fn clear(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         if let Some(object) = this.as_object() {
-            if object.borrow().is_set() {
-                this.set_data(ObjectData::set(OrderedSet::new()));
-                Ok(JsValue::undefined())
-            } else {
-                context.throw_type_error("'this' is not a Set")
+            if let Some(set) = object.borrow_mut().as_set_mut() {
+                set.clear();
+                return Ok(JsValue::undefined());
             }
-        } else {
-            context.throw_type_error("'this' is not a Set")
         }
+        context.throw_type_error("'this' is not a Set")
     }
 
     /// `Set.prototype.delete( value )`
@@ -261,7 +255,7 @@ impl Set {
 
         let res = if let Some(object) = this.as_object() {
             if let Some(set) = object.borrow_mut().as_set_mut() {
-                set.delete(value)
+                set.delete(&Self::normalize_zero(value))
             } else {
                 return context.throw_type_error("'this' is not a Set");
             }
@@ -334,9 +328,16 @@ impl Set {
             this_arg.clone()
         };
 
+        // Values removed during the iteration leave an empty entry behind while the set is
+        // locked, so the indexes of the values that haven't been visited yet don't change.
+        let lock = this
+            .as_object()
+            .and_then(|obj| obj.borrow().as_set_ref().map(|set| set.lock()))
+            .ok_or_else(|| context.construct_type_error("'this' is not a Set"))?;
+
         let mut index = 0;
 
-        while index < Set::get_size(this, context)? {
+        while index < Set::get_full_len(this, context)? {
             let arguments = this
                 .as_object()
                 .and_then(|obj| {
@@ -354,6 +355,12 @@ impl Set {
             index += 1;
         }
 
+        if let Some(obj) = this.as_object() {
+            if let Some(set) = obj.borrow_mut().as_set_mut() {
+                set.unlock(lock);
+            }
+        }
+
         Ok(JsValue::Undefined)
     }
 
@@ -378,7 +385,7 @@ impl Set {
             .and_then(|obj| {
                 obj.borrow()
                     .as_set_ref()
-                    .map(|set| set.contains(value).into())
+                    .map(|set| set.contains(&Self::normalize_zero(value)).into())
             })
             .ok_or_else(|| context.construct_type_error("'this' is not a Set"))
     }
@@ -421,6 +428,23 @@ impl Set {
         Set::get_size(this, context).map(JsValue::from)
     }
 
+    /// Helper function to get the number of entries of the set, including the empty entries
+    /// left behind by values removed during an iteration.
+    fn get_full_len(set: &JsValue, context: &mut Context) -> JsResult<usize> {
+        set.as_object()
+            .and_then(|obj| obj.borrow().as_set_ref().map(|set| set.full_len()))
+            .ok_or_else(|| context.construct_type_error("'this' is not a Set"))
+    }
+
+    /// Returns `value`, with `-0` replaced by `+0`, as sets compare their values with
+    /// `SameValueZero`.
+    fn normalize_zero(value: &JsValue) -> JsValue {
+        match value {
+            JsValue::Rational(r) if r.is_zero() => JsValue::Integer(0),
+            _ => value.clone(),
+        }
+    }
+
     /// Helper function to get the size of the set.
     fn get_size(set: &JsValue, context: &mut Context) -> JsResult<usize> {
         set.as_object()
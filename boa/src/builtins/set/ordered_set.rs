@@ -1,36 +1,59 @@
-use crate::gc::{custom_trace, Finalize, Trace};
-use indexmap::{
-    set::{IntoIter, Iter},
-    IndexSet,
-};
+use crate::gc::{custom_trace, empty_trace, Finalize, Trace};
+use indexmap::IndexSet;
 use std::{
+    cell::Cell,
     collections::hash_map::RandomState,
     fmt::Debug,
     hash::{BuildHasher, Hash},
+    rc::Rc,
 };
 
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+enum SetKey<V> {
+    Value(V),
+    Empty(usize), // Necessary to ensure empty values are still unique.
+}
+
 /// A newtype wrapping indexmap::IndexSet
-#[derive(Clone)]
-pub struct OrderedSet<V, S = RandomState>(IndexSet<V, S>)
+pub struct OrderedSet<V, S = RandomState>
 where
-    V: Hash + Eq;
+    V: Hash + Eq,
+{
+    inner: IndexSet<SetKey<V>, S>,
+    // Shared with the `SetLock`s, so they can be released without borrowing the set.
+    lock: Rc<Cell<u32>>,
+    empty_count: usize,
+}
+
+impl<V: Hash + Eq + Clone, S: BuildHasher + Clone> Clone for OrderedSet<V, S> {
+    fn clone(&self) -> Self {
+        // The locks of the iterators belong to the original set, the clone starts unlocked.
+        Self {
+            inner: self.inner.clone(),
+            lock: Rc::default(),
+            empty_count: self.empty_count,
+        }
+    }
+}
 
 impl<V: Eq + Hash + Trace, S: BuildHasher> Finalize for OrderedSet<V, S> {}
 unsafe impl<V: Eq + Hash + Trace, S: BuildHasher> Trace for OrderedSet<V, S> {
     custom_trace!(this, {
-        for v in this.0.iter() {
-            mark(v);
+        for v in this.inner.iter() {
+            if let SetKey::Value(v) = v {
+                mark(v);
+            }
         }
     });
 }
 
-impl<V: Hash + Eq + Debug> Debug for OrderedSet<V> {
+impl<V: Hash + Eq + Clone + Debug> Debug for OrderedSet<V> {
     fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        self.0.fmt(formatter)
+        formatter.debug_set().entries(self.iter()).finish()
     }
 }
 
-impl<V: Hash + Eq> Default for OrderedSet<V> {
+impl<V: Hash + Eq + Clone> Default for OrderedSet<V> {
     fn default() -> Self {
         Self::new()
     }
@@ -38,93 +61,179 @@ impl<V: Hash + Eq> Default for OrderedSet<V> {
 
 impl<V> OrderedSet<V>
 where
-    V: Hash + Eq,
+    V: Hash + Eq + Clone,
 {
     pub fn new() -> Self {
-        OrderedSet(IndexSet::new())
+        OrderedSet {
+            inner: IndexSet::new(),
+            lock: Rc::default(),
+            empty_count: 0,
+        }
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
-        OrderedSet(IndexSet::with_capacity(capacity))
+        OrderedSet {
+            inner: IndexSet::with_capacity(capacity),
+            lock: Rc::default(),
+            empty_count: 0,
+        }
+    }
+
+    /// Return the number of values in the set, including empty values.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn full_len(&self) -> usize {
+        self.inner.len()
     }
 
-    /// Return the number of key-value pairs in the map.
+    /// Return the number of values in the set, not including empty values.
     ///
     /// Computes in **O(1)** time.
     pub fn size(&self) -> usize {
-        self.0.len()
+        self.inner.len() - self.empty_count
     }
 
-    /// Returns true if the map contains no elements.
+    /// Returns true if the set contains no elements.
     ///
     /// Computes in **O(1)** time.
     pub fn is_empty(&self) -> bool {
-        self.0.len() == 0
+        self.size() == 0
     }
 
-    /// Insert a value pair in the set.
+    /// Insert a value in the set.
     ///
-    /// If an equivalent value already exists in the set: ???
+    /// If an equivalent value already exists in the set, the set is left unchanged and `false`
+    /// is returned.
     ///
     /// If no equivalent value existed in the set: the new value is
-    /// inserted, last in order, and false
+    /// inserted, last in order, and `true` is returned.
     ///
     /// Computes in **O(1)** time (amortized average).
     pub fn add(&mut self, value: V) -> bool {
-        self.0.insert(value)
+        self.compact();
+        self.inner.insert(SetKey::Value(value))
     }
 
     /// Delete the `value` from the set and return true if successful
     ///
-    /// Return `false` if `value` is not in map.
+    /// Return `false` if `value` is not in set.
     ///
     /// Computes in **O(n)** time (average).
     pub fn delete(&mut self, value: &V) -> bool {
-        self.0.shift_remove(value)
+        self.compact();
+        let value = SetKey::Value(value.clone());
+        if self.lock.get() == 0 {
+            self.inner.shift_remove(&value)
+        } else if self.inner.contains(&value) {
+            self.inner.insert(SetKey::Empty(self.empty_count));
+            self.empty_count += 1;
+            self.inner.swap_remove(&value)
+        } else {
+            false
+        }
+    }
+
+    /// Removes all elements from the set and resets the counter of
+    /// empty entries.
+    ///
+    /// While the set is locked, the elements are replaced by empty entries instead, so the
+    /// iterators keep their position and still visit the elements added afterwards.
+    pub fn clear(&mut self) {
+        if self.lock.get() == 0 {
+            self.inner.clear();
+            self.inner.shrink_to_fit();
+            self.empty_count = 0
+        } else {
+            let len = self.inner.len();
+            self.inner = (0..len).map(SetKey::Empty).collect();
+            self.empty_count = len;
+        }
     }
 
     /// Checks if a given value is present in the set
     ///
     /// Return `true` if `value` is present in set, false otherwise.
     ///
-    /// Computes in **O(n)** time (average).
+    /// Computes in **O(1)** time (average).
     pub fn contains(&self, value: &V) -> bool {
-        self.0.contains(value)
+        self.inner.contains(&SetKey::Value(value.clone()))
     }
 
-    /// Get a key-value pair by index
-    /// Valid indices are 0 <= index < self.len()
+    /// Get a value by index.
+    ///
+    /// Valid indices are 0 <= index < self.full_len().
+    ///
     /// Computes in O(1) time.
     pub fn get_index(&self, index: usize) -> Option<&V> {
-        self.0.get_index(index)
+        if let SetKey::Value(value) = self.inner.get_index(index)? {
+            Some(value)
+        } else {
+            None
+        }
     }
 
     /// Return an iterator over the values of the set, in their order
-    pub fn iter(&self) -> Iter<'_, V> {
-        self.0.iter()
+    pub fn iter(&self) -> impl Iterator<Item = &V> {
+        self.inner.iter().filter_map(|v| {
+            if let SetKey::Value(v) = v {
+                Some(v)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Increases the lock counter and returns a lock object that will decrement the counter when dropped.
+    ///
+    /// This allows values to be removed from the set during iteration without affecting the indexes until the iteration has completed.
+    pub(crate) fn lock(&self) -> SetLock {
+        self.lock.set(self.lock.get() + 1);
+        SetLock(self.lock.clone())
+    }
+
+    /// Releases a lock of the set and, if it was the last one, removes all empty entries.
+    ///
+    /// Iterations should release their lock through this method when they complete. A lock that
+    /// is just dropped, like when its iterator is garbage collected, leaves the empty entries
+    /// behind until the next change of the set.
+    pub(crate) fn unlock(&mut self, lock: SetLock) {
+        drop(lock);
+        self.compact();
+    }
+
+    /// Removes all empty entries if the set isn't locked.
+    fn compact(&mut self) {
+        if self.lock.get() == 0 && self.empty_count != 0 {
+            self.inner.retain(|v| matches!(v, SetKey::Value(_)));
+            self.empty_count = 0;
+        }
     }
 }
 
-impl<'a, V, S> IntoIterator for &'a OrderedSet<V, S>
-where
-    V: Hash + Eq,
-    S: BuildHasher,
-{
-    type Item = &'a V;
-    type IntoIter = Iter<'a, V>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.iter()
+/// Increases the lock count of the set for the lifetime of the guard. This should not be dropped until iteration has completed.
+///
+/// The guard only holds the lock counter of the set, so dropping it never borrows the set, even
+/// while the garbage collector finalizes it.
+#[derive(Debug)]
+pub(crate) struct SetLock(Rc<Cell<u32>>);
+
+impl Finalize for SetLock {}
+
+// Safety: `SetLock` does not contain any object that require trace,
+// so this is safe.
+unsafe impl Trace for SetLock {
+    empty_trace!();
+}
+
+impl Clone for SetLock {
+    fn clone(&self) -> Self {
+        self.0.set(self.0.get() + 1);
+        SetLock(self.0.clone())
     }
 }
 
-impl<V, S> IntoIterator for OrderedSet<V, S>
-where
-    V: Hash + Eq,
-    S: BuildHasher,
-{
-    type Item = V;
-    type IntoIter = IntoIter<V>;
-    fn into_iter(self) -> IntoIter<V> {
-        self.0.into_iter()
+impl Drop for SetLock {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() - 1);
     }
 }
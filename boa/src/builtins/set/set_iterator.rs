@@ -1,7 +1,7 @@
 use crate::{
-    builtins::Array,
     builtins::JsValue,
     builtins::{function::make_builtin_fn, iterable::create_iter_result_object},
+    builtins::{set::ordered_set::SetLock, Array},
     object::{JsObject, ObjectData},
     property::{PropertyDescriptor, PropertyNameKind},
     symbol::WellKnownSymbols,
@@ -20,6 +20,7 @@ pub struct SetIterator {
     iterated_set: JsValue,
     next_index: usize,
     iteration_kind: PropertyNameKind,
+    lock: Option<SetLock>,
 }
 
 impl SetIterator {
@@ -27,10 +28,14 @@ impl SetIterator {
 
     /// Constructs a new `SetIterator`, that will iterate over `set`, starting at index 0
     fn new(set: JsValue, kind: PropertyNameKind) -> Self {
+        let lock = set
+            .as_object()
+            .and_then(|obj| obj.borrow().as_set_ref().map(|set| set.lock()));
         SetIterator {
             iterated_set: set,
             next_index: 0,
             iteration_kind: kind,
+            lock,
         }
     }
 
@@ -88,7 +93,7 @@ impl SetIterator {
                 .and_then(|obj| obj.as_set_ref())
                 .ok_or_else(|| context.construct_type_error("'this' is not a Set"))?;
 
-            let num_entries = entries.size();
+            let num_entries = entries.full_len();
             while index < num_entries {
                 let e = entries.get_index(index);
                 index += 1;
@@ -113,7 +118,13 @@ impl SetIterator {
             }
         }
 
-        set_iterator.iterated_set = JsValue::undefined();
+        // Release the lock explicitly, so the empty entries are removed right away.
+        let set = std::mem::take(&mut set_iterator.iterated_set);
+        if let (Some(set), Some(lock)) = (set.as_object(), set_iterator.lock.take()) {
+            if let Some(set) = set.borrow_mut().as_set_mut() {
+                set.unlock(lock);
+            }
+        }
         Ok(create_iter_result_object(
             JsValue::undefined(),
             true,
@@ -1,4 +1,4 @@
-use crate::{forward, Context};
+use crate::{forward, forward_val, gc::force_collect, Context};
 
 #[test]
 fn construct_empty() {
@@ -216,6 +216,75 @@ fn for_each() {
     assert_eq!(forward(&mut context, "sizeSum"), "9");
 }
 
+#[test]
+fn for_each_live_mutation() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set([1, 2, 3]);
+        let visited = [];
+        set.forEach(function(value) {
+            visited.push(value);
+            if (value === 1) {
+                set.delete(2);
+                set.add(4);
+            }
+            if (value === 3) {
+                set.delete(1);
+                set.add(1);
+            }
+        });
+        "#;
+    forward(&mut context, init);
+    // A value deleted before being visited is skipped, one re-added after being visited is
+    // visited again, and new values are always visited.
+    assert_eq!(forward(&mut context, "visited.join()"), "\"1,3,4,1\"");
+    assert_eq!(forward(&mut context, "[...set].join()"), "\"3,4,1\"");
+}
+
+#[test]
+fn clear_during_iteration() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set([1, 2]);
+        let iterator = set.values();
+        iterator.next();
+        set.clear();
+        set.add(3);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "set.size"), "1");
+    assert_eq!(forward(&mut context, "iterator.next().value"), "3");
+    assert_eq!(forward(&mut context, "iterator.next().done"), "true");
+}
+
+#[test]
+fn same_value_zero() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set([NaN, 0, -0, Number("not a number"), "0"]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "set.size"), "3");
+    assert_eq!(forward(&mut context, "set.has(NaN)"), "true");
+    assert_eq!(forward(&mut context, "set.has(-0)"), "true");
+    assert_eq!(forward(&mut context, "Object.is([...set][1], 0)"), "true");
+    assert_eq!(forward(&mut context, "set.delete(-0)"), "true");
+    assert_eq!(forward(&mut context, "set.has(0)"), "false");
+}
+
+#[test]
+fn object_identity() {
+    let mut context = Context::new();
+    let init = r#"
+        let object = {};
+        let set = new Set([object, {}, object]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "set.size"), "2");
+    assert_eq!(forward(&mut context, "set.has(object)"), "true");
+    assert_eq!(forward(&mut context, "set.has({})"), "false");
+}
+
 #[test]
 fn recursive_display() {
     let mut context = Context::new();
@@ -246,3 +315,95 @@ fn not_a_function() {
         "\"TypeError: calling a builtin Set constructor without new is forbidden\""
     );
 }
+
+#[test]
+fn size_is_accessor() {
+    let mut context = Context::new();
+    let init = r#"
+        let descriptor = Object.getOwnPropertyDescriptor(Set.prototype, "size");
+        let set = new Set();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "typeof descriptor.get"),
+        "\"function\""
+    );
+    assert_eq!(forward(&mut context, "descriptor.set"), "undefined");
+    assert_eq!(forward(&mut context, "set.hasOwnProperty('size')"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { descriptor.get.call({}) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn size_tracks_mutations() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set();
+        let sizes = [set.size];
+        set.add("a");
+        sizes.push(set.size);
+        set.add("b");
+        sizes.push(set.size);
+        set.add("a");
+        sizes.push(set.size);
+        set.delete("a");
+        sizes.push(set.size);
+        set.delete("missing");
+        sizes.push(set.size);
+        set.clear();
+        sizes.push(set.size);
+        set.add("c");
+        sizes.push(set.size);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "sizes.join()"), "\"0,1,2,2,1,1,0,1\"");
+    assert_eq!(forward(&mut context, "set.has('b')"), "false");
+    assert_eq!(forward(&mut context, "set.has('c')"), "true");
+}
+
+#[test]
+fn iterator_over_advancing() {
+    let mut context = Context::new();
+    let init = r#"
+        let collection = new Set([1]);
+        let iterator = collection.values();
+        let results = [];
+        for (let i = 0; i < 3; i++) {
+            let next = iterator.next();
+            results.push(String(next.value) + ":" + next.done);
+        }
+        collection.add(2);
+        let next = iterator.next();
+        results.push(String(next.value) + ":" + next.done);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "results.join()"),
+        "\"1:false,undefined:true,undefined:true,undefined:true\""
+    );
+}
+
+#[test]
+fn collecting_iterators_does_not_borrow_the_set() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set([1, 2, 3]);
+        (function () {
+            for (let i = 0; i < 10; i++) {
+                set.values().next();
+            }
+        })();
+        set
+    "#;
+    let set = forward_val(&mut context, init).unwrap();
+    let set = set.as_object().unwrap();
+
+    // The locks of the collected iterators are released while the set is borrowed.
+    let _set = set.borrow();
+    force_collect();
+}
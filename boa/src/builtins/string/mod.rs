@@ -21,6 +21,7 @@ use crate::{
     builtins::{string::string_iterator::StringIterator, Array, BuiltIn, RegExp},
     object::{ConstructorBuilder, ObjectData},
     property::{Attribute, PropertyDescriptor},
+    string::NormalizationForm,
     symbol::WellKnownSymbols,
     BoaProfiler, Context, JsResult, JsString, JsValue,
 };
@@ -29,7 +30,6 @@ use std::{
     cmp::{max, min},
     string::String as StdString,
 };
-use unicode_normalization::UnicodeNormalization;
 
 use super::JsArgs;
 
@@ -122,7 +122,7 @@ impl BuiltIn for String {
         .method(Self::index_of, "indexOf", 1)
         .method(Self::last_index_of, "lastIndexOf", 1)
         .method(Self::r#match, "match", 1)
-        .method(Self::normalize, "normalize", 1)
+        .method(Self::normalize, "normalize", 0)
         .method(Self::pad_end, "padEnd", 1)
         .method(Self::pad_start, "padStart", 1)
         .method(Self::trim, "trim", 0)
@@ -1719,14 +1719,19 @@ impl String {
             f_str.as_str()
         };
 
-        match f {
-            "NFC" => Ok(JsValue::new(s.nfc().collect::<StdString>())),
-            "NFD" => Ok(JsValue::new(s.nfd().collect::<StdString>())),
-            "NFKC" => Ok(JsValue::new(s.nfkc().collect::<StdString>())),
-            "NFKD" => Ok(JsValue::new(s.nfkd().collect::<StdString>())),
-            _ => context
-                .throw_range_error("The normalization form should be one of NFC, NFD, NFKC, NFKD."),
-        }
+        let form = match f {
+            "NFC" => NormalizationForm::Nfc,
+            "NFD" => NormalizationForm::Nfd,
+            "NFKC" => NormalizationForm::Nfkc,
+            "NFKD" => NormalizationForm::Nfkd,
+            _ => {
+                return context.throw_range_error(
+                    "The normalization form should be one of NFC, NFD, NFKC, NFKD.",
+                )
+            }
+        };
+
+        Ok(s.normalize(form).into())
     }
 
     /// `String.prototype.search( regexp )`
@@ -1826,9 +1831,15 @@ pub(crate) fn get_substitution(
             let second_is_digit = second.map_or(false, |ch| ch.is_digit(10));
             // we use peek so that it is still in the iterator if not used
             let third = if second_is_digit { chars.peek() } else { None };
-            let third_is_digit = third.map_or(false, |ch| ch.is_digit(10));
-
-            match (second, third) {
+            // A two-digit index that exceeds the number of captures is reinterpreted as a
+            // one-digit index followed by a literal digit.
+            let two_digit_index = second
+                .and_then(|ch| ch.to_digit(10))
+                .zip(third.and_then(|ch| ch.to_digit(10)))
+                .map(|(tens, units)| (10 * tens + units) as usize)
+                .filter(|nn| (1..=m).contains(nn));
+
+            match (second, two_digit_index) {
                 // $$
                 (Some('$'), _) => {
                     // $
@@ -1857,22 +1868,11 @@ pub(crate) fn get_substitution(
                     }
                 }
                 // $nn
-                (Some(second), Some(third)) if second_is_digit && third_is_digit => {
+                (_, Some(nn)) => {
                     // The nnth element of captures, where nn is a two-digit decimal number in the range 01 to 99.
-                    let tens = second.to_digit(10).unwrap() as usize;
-                    let units = third.to_digit(10).unwrap() as usize;
-                    let nn = 10 * tens + units;
-
                     // If nn ≤ m and the nnth element of captures is undefined, use the empty String instead.
-                    // If nn is 00 or nn > m, no replacement is done.
-                    if nn == 0 || nn > m {
-                        result.push('$');
-                        result.push(second);
-                        result.push(*third);
-                    } else if let Some(capture) = captures.get(nn - 1) {
-                        if let Some(s) = capture.as_string() {
-                            result.push_str(s);
-                        }
+                    if let Some(s) = captures[nn - 1].as_string() {
+                        result.push_str(s);
                     }
 
                     chars.next();
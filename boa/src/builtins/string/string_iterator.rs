@@ -36,7 +36,7 @@ impl StringIterator {
         let string_iterator = string_iterator
             .as_mut()
             .and_then(|obj| obj.as_string_iterator_mut())
-            .ok_or_else(|| context.construct_type_error("`this` is not an ArrayIterator"))?;
+            .ok_or_else(|| context.construct_type_error("`this` is not a StringIterator"))?;
 
         if string_iterator.string.is_undefined() {
             return Ok(create_iter_result_object(
@@ -67,12 +67,12 @@ impl StringIterator {
         Ok(create_iter_result_object(result_string, false, context))
     }
 
-    /// Create the %ArrayIteratorPrototype% object
+    /// Create the %StringIteratorPrototype% object
     ///
     /// More information:
     ///  - [ECMA reference][spec]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-%arrayiteratorprototype%-object
+    /// [spec]: https://tc39.es/ecma262/#sec-%stringiteratorprototype%-object
     pub(crate) fn create_prototype(
         iterator_prototype: JsObject,
         context: &mut Context,
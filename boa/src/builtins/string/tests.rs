@@ -281,6 +281,60 @@ fn replace_substitutions() {
     assert_eq!(forward(&mut context, "no_sub"), "\"one $_ three\"");
 }
 
+#[test]
+fn replace_string_pattern_substitutions() {
+    let mut context = Context::new();
+
+    for (call, expected) in [
+        ("'abc'.replace('b', '[$$]')", "a[$]c"),
+        ("'abc'.replace('b', '[$&]')", "a[b]c"),
+        ("'abc'.replace('b', '[$`]')", "a[a]c"),
+        ("'abc'.replace('b', \"[$']\")", "a[c]c"),
+        // Without captures, `$n` and `$<` are left as they are.
+        ("'abc'.replace('b', '[$1|$<x>]')", "a[$1|$<x>]c"),
+        ("'abc'.replace('b', '[$|$0|$_]')", "a[$|$0|$_]c"),
+        ("'x'.replace('x', '$$$&$')", "$x$"),
+    ] {
+        assert_eq!(
+            forward(&mut context, call),
+            format!("\"{}\"", expected),
+            "{}",
+            call
+        );
+    }
+}
+
+#[test]
+fn replace_capture_substitutions() {
+    let mut context = Context::new();
+    let init = r#"
+        var date = /(?<year>\d+)-(?<month>\d+)/;
+        var eleven = /(a)(b)(c)(d)(e)(f)(g)(h)(i)(j)(k)/;
+        "#;
+
+    forward(&mut context, init);
+
+    for (call, expected) in [
+        ("'abc'.replace(/(b)/, '[$1|$01|$2|$00]')", "a[b|b|$2|$00]c"),
+        ("'abc'.replace(/(x)?b/, '[$1]')", "a[]c"),
+        // `$10` with a single capture is `$1` followed by a literal `0`.
+        ("'abc'.replace(/(b)/, '[$10]')", "a[b0]c"),
+        ("'abcdefghijkl'.replace(eleven, '$11-$10-$12')", "k-j-a2l"),
+        (
+            "'2021-07'.replace(date, '$<month>/$<year>/$<day>')",
+            "07/2021/",
+        ),
+        ("'2021-07'.replace(date, '$<year')", "$<year"),
+    ] {
+        assert_eq!(
+            forward(&mut context, call),
+            format!("\"{}\"", expected),
+            "{}",
+            call
+        );
+    }
+}
+
 #[test]
 fn replace_with_function() {
     let mut context = Context::new();
@@ -560,6 +614,65 @@ fn test_match() {
     assert_eq!(forward(&mut context, "result4[0]"), "\"B\"");
 }
 
+#[test]
+fn match_non_global_returns_match_object() {
+    let mut context = Context::new();
+    let init = r#"
+        var named = "abc abd".match(/a(b)(?<last>.)/);
+        var plain = "abc abd".match(/a(b)./);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "named.length"), "3");
+    assert_eq!(forward(&mut context, "named[0]"), "\"abc\"");
+    assert_eq!(forward(&mut context, "named[1]"), "\"b\"");
+    assert_eq!(forward(&mut context, "named.index"), "0");
+    assert_eq!(forward(&mut context, "named.input"), "\"abc abd\"");
+    assert_eq!(forward(&mut context, "named.groups.last"), "\"c\"");
+    assert_eq!(forward(&mut context, "plain.length"), "2");
+    assert_eq!(forward(&mut context, "plain.groups"), "undefined");
+    assert_eq!(forward(&mut context, "'x'.match(/y/)"), "null");
+}
+
+#[test]
+fn match_global_returns_all_matches() {
+    let mut context = Context::new();
+    let init = r#"
+        var re = /a(b)(?<last>.)/g;
+        re.lastIndex = 5;
+        var all = "abc abd".match(re);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "all.length"), "2");
+    assert_eq!(forward(&mut context, "all[0]"), "\"abc\"");
+    assert_eq!(forward(&mut context, "all[1]"), "\"abd\"");
+    assert_eq!(forward(&mut context, "all.index"), "undefined");
+    assert_eq!(forward(&mut context, "all.groups"), "undefined");
+    assert_eq!(forward(&mut context, "re.lastIndex"), "0");
+    assert_eq!(forward(&mut context, "'x'.match(/y/g)"), "null");
+    assert_eq!(forward(&mut context, "'aaa'.match(/a*?/g).length"), "4");
+}
+
+#[test]
+fn match_coerces_argument_to_regexp() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "'a.b'.match('.')[0]"), "\"a\"");
+    assert_eq!(forward(&mut context, "'a.b'.match('.').index"), "0");
+    assert_eq!(forward(&mut context, "'1 2'.match(2).index"), "2");
+    assert_eq!(forward(&mut context, "'null'.match(null)[0]"), "\"null\"");
+    assert_eq!(forward(&mut context, "'abc'.match()[0]"), "\"\"");
+    assert_eq!(forward(&mut context, "'abc'.match().index"), "0");
+    assert_eq!(
+        forward(
+            &mut context,
+            "var m = {}; m[Symbol.match] = function (s) { return 'custom ' + s; }; 'q'.match(m)"
+        ),
+        "\"custom q\""
+    );
+}
+
 #[test]
 fn trim() {
     let mut context = Context::new();
@@ -1037,6 +1150,42 @@ fn empty_iter() {
     assert_eq!(forward(&mut context, "next.done"), "true");
 }
 
+#[test]
+fn iter_over_advancing() {
+    let mut context = Context::new();
+    let init = r#"
+        let iter = "a😀"[Symbol.iterator]();
+        let results = [];
+        for (let i = 0; i < 4; i++) {
+            let next = iter.next();
+            results.push(next.value === undefined ? "undefined" : next.value.length);
+            results.push(next.done);
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "results.join()"),
+        "\"1,false,2,false,undefined,true,undefined,true\""
+    );
+}
+
+#[test]
+fn iter_next_on_incompatible_receiver() {
+    let mut context = Context::new();
+    let init = r#"
+        let next = ""[Symbol.iterator]().next;
+        try {
+            next.call([][Symbol.iterator]());
+        } catch (e) {
+            e.name + ": " + e.message
+        }
+    "#;
+    assert_eq!(
+        forward(&mut context, init),
+        "\"TypeError: `this` is not a StringIterator\""
+    );
+}
+
 #[test]
 fn ascii_iter() {
     let mut context = Context::new();
@@ -1144,3 +1293,52 @@ fn search() {
     assert_eq!(forward(&mut context, "'aa'.search(/a/g)"), "0");
     assert_eq!(forward(&mut context, "'ba'.search(/a/)"), "1");
 }
+
+#[test]
+fn normalize() {
+    let mut context = Context::new();
+
+    // Canonical composition and decomposition.
+    assert_eq!(
+        forward(&mut context, "'e\\u0301'.normalize('NFC') === '\\u00e9'"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "'\\u00e9'.normalize('NFD') === 'e\\u0301'"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "'e\\u0301'.normalize() === '\\u00e9'"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "'e\\u0301'.normalize(undefined).length"),
+        "1"
+    );
+
+    // Ligatures are only decomposed by the compatibility forms.
+    assert_eq!(
+        forward(&mut context, "'\\ufb01'.normalize('NFKD')"),
+        "\"fi\""
+    );
+    assert_eq!(
+        forward(&mut context, "'\\ufb01'.normalize('NFKC')"),
+        "\"fi\""
+    );
+    assert_eq!(
+        forward(&mut context, "'\\ufb01'.normalize('NFC') === '\\ufb01'"),
+        "true"
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { 'a'.normalize('nfc') } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.prototype.normalize.length"),
+        "0"
+    );
+}
@@ -0,0 +1,673 @@
+//! This module implements the global `structuredClone` function.
+//!
+//! `structuredClone` deep-copies a value with the structured clone algorithm of the HTML
+//! specification. The value is first serialized into a list of records, one per object, which
+//! keeps the identity of the objects that appear several times in the value, and cycles. The
+//! records are then deserialized into new objects.
+//!
+//! The `ArrayBuffer`s of the transfer list are not copied: their contents are moved to the clone,
+//! and the originals are detached. This only happens once the whole value was serialized, so a
+//! value that can't be cloned leaves the transfer list untouched.
+//!
+//! More information:
+//!  - [HTML specification][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#safe-passing-of-structured-data
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/structuredClone
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        array_buffer::ArrayBuffer, date::Date, iterable::iterable_to_list,
+        map::ordered_map::OrderedMap, set::ordered_set::OrderedSet, typed_array::TypedArrayName,
+        Array, BuiltIn, JsArgs, RegExp,
+    },
+    object::{FunctionBuilder, JsObject, Object, ObjectData, ObjectKind},
+    property::{Attribute, PropertyDescriptor, PropertyNameKind},
+    BoaProfiler, Context, JsBigInt, JsResult, JsString, JsValue,
+};
+use gc::GcCell;
+use rustc_hash::FxHashMap;
+
+/// The names of the errors that keep their type when cloned. Any other error becomes an `Error`.
+const ERROR_NAMES: [&str; 7] = [
+    "Error",
+    "EvalError",
+    "RangeError",
+    "ReferenceError",
+    "SyntaxError",
+    "TypeError",
+    "URIError",
+];
+
+/// A serialized value: either a primitive, or the index of the record of an object.
+#[derive(Debug, Clone)]
+enum Serialized {
+    Primitive(JsValue),
+    Object(usize),
+}
+
+/// The serialized form of an object.
+#[derive(Debug)]
+enum Record {
+    Boolean(bool),
+    Number(f64),
+    BigInt(JsBigInt),
+    String(JsString),
+    Date(Date),
+    RegExp {
+        source: JsString,
+        flags: JsString,
+    },
+    ArrayBuffer(Vec<u8>),
+    /// An `ArrayBuffer` of the transfer list, whose contents are moved once the serialization is
+    /// done.
+    Transferred,
+    TypedArray {
+        name: TypedArrayName,
+        buffer: usize,
+        byte_offset: usize,
+        array_length: usize,
+    },
+    DataView {
+        buffer: usize,
+        byte_offset: usize,
+        byte_length: usize,
+    },
+    Map(Vec<(Serialized, Serialized)>),
+    Set(Vec<Serialized>),
+    Error {
+        name: &'static str,
+        message: Option<JsString>,
+    },
+    Array {
+        length: usize,
+        properties: Vec<(JsString, Serialized)>,
+    },
+    Object(Vec<(JsString, Serialized)>),
+}
+
+/// What the serializer needs to know about an object, read while it is borrowed.
+enum Shape {
+    Leaf(Record),
+    TypedArray {
+        name: TypedArrayName,
+        buffer: JsObject,
+        byte_offset: usize,
+        array_length: usize,
+    },
+    DataView {
+        buffer: JsObject,
+        byte_offset: usize,
+        byte_length: usize,
+    },
+    Map(Vec<(JsValue, JsValue)>),
+    Set(Vec<JsValue>),
+    Error,
+    Array,
+    Object,
+}
+
+/// Serializes the objects of a value, remembering the ones already serialized.
+#[derive(Debug, Default)]
+struct Serializer {
+    /// Maps the address of every serialized object to the index of its record.
+    memory: FxHashMap<*const GcCell<Object>, usize>,
+    /// Keeps the serialized objects alive, so their addresses can't be reused by new objects
+    /// while the serialization runs user code.
+    objects: Vec<JsObject>,
+    records: Vec<Record>,
+}
+
+impl Serializer {
+    /// Serializes `value`.
+    fn serialize(&mut self, value: &JsValue, context: &mut Context) -> JsResult<Serialized> {
+        match value {
+            JsValue::Symbol(_) => Err(data_clone_error("Symbol values can't be cloned", context)),
+            JsValue::Object(object) => {
+                Ok(Serialized::Object(self.serialize_object(object, context)?))
+            }
+            _ => Ok(Serialized::Primitive(value.clone())),
+        }
+    }
+
+    /// Reserves the record of `object`, and returns its index.
+    fn reserve(&mut self, object: &JsObject) -> usize {
+        let index = self.records.len();
+        self.memory.insert(object.as_ref(), index);
+        self.objects.push(object.clone());
+        self.records.push(Record::Object(Vec::new()));
+        index
+    }
+
+    /// Returns the index of the record of `object`, if it was already serialized.
+    fn lookup(&self, object: &JsObject) -> Option<usize> {
+        self.memory.get(&(object.as_ref() as *const _)).copied()
+    }
+
+    /// Serializes `object`, returning the index of its record.
+    fn serialize_object(&mut self, object: &JsObject, context: &mut Context) -> JsResult<usize> {
+        if let Some(index) = self.lookup(object) {
+            return Ok(index);
+        }
+
+        let index = self.reserve(object);
+        let record = match Self::shape(object, context)? {
+            Shape::Leaf(record) => record,
+            Shape::TypedArray {
+                name,
+                buffer,
+                byte_offset,
+                array_length,
+            } => Record::TypedArray {
+                name,
+                buffer: self.serialize_object(&buffer, context)?,
+                byte_offset,
+                array_length,
+            },
+            Shape::DataView {
+                buffer,
+                byte_offset,
+                byte_length,
+            } => Record::DataView {
+                buffer: self.serialize_object(&buffer, context)?,
+                byte_offset,
+                byte_length,
+            },
+            Shape::Map(entries) => {
+                let mut serialized = Vec::with_capacity(entries.len());
+                for (key, value) in entries {
+                    serialized.push((
+                        self.serialize(&key, context)?,
+                        self.serialize(&value, context)?,
+                    ));
+                }
+                Record::Map(serialized)
+            }
+            Shape::Set(values) => {
+                let mut serialized = Vec::with_capacity(values.len());
+                for value in values {
+                    serialized.push(self.serialize(&value, context)?);
+                }
+                Record::Set(serialized)
+            }
+            Shape::Error => {
+                let name = object.get("name", context)?;
+                let name = ERROR_NAMES
+                    .iter()
+                    .find(
+                        |error| matches!(name.as_string(), Some(name) if name.as_str() == **error),
+                    )
+                    .copied()
+                    .unwrap_or("Error");
+                let message = match object.__get_own_property__(&"message".into(), context)? {
+                    Some(desc) if desc.is_data_descriptor() => {
+                        Some(desc.expect_value().to_string(context)?)
+                    }
+                    _ => None,
+                };
+                Record::Error { name, message }
+            }
+            Shape::Array => {
+                let length = object
+                    .__get_own_property__(&"length".into(), context)?
+                    .and_then(|desc| desc.value().and_then(JsValue::as_number))
+                    .expect("arrays must have a length") as usize;
+                Record::Array {
+                    length,
+                    properties: self.serialize_properties(object, context)?,
+                }
+            }
+            Shape::Object => Record::Object(self.serialize_properties(object, context)?),
+        };
+        self.records[index] = record;
+
+        Ok(index)
+    }
+
+    /// Reads the shape of `object`, throwing a `DataCloneError` if it can't be cloned.
+    fn shape(object: &JsObject, context: &mut Context) -> JsResult<Shape> {
+        let borrowed = object.borrow();
+        let shape = match borrowed.kind() {
+            ObjectKind::Boolean(boolean) => Shape::Leaf(Record::Boolean(*boolean)),
+            ObjectKind::Number(number) => Shape::Leaf(Record::Number(*number)),
+            ObjectKind::BigInt(bigint) => Shape::Leaf(Record::BigInt(bigint.clone())),
+            ObjectKind::String(string) => Shape::Leaf(Record::String(string.clone())),
+            ObjectKind::Date(date) => Shape::Leaf(Record::Date(*date)),
+            ObjectKind::RegExp(regexp) => Shape::Leaf(Record::RegExp {
+                source: regexp.original_source().clone(),
+                flags: regexp.original_flags().clone(),
+            }),
+            ObjectKind::ArrayBuffer(buffer) => match &buffer.array_buffer_data {
+                Some(data) => Shape::Leaf(Record::ArrayBuffer(data.clone())),
+                None => {
+                    return Err(data_clone_error(
+                        "a detached ArrayBuffer can't be cloned",
+                        context,
+                    ))
+                }
+            },
+            ObjectKind::IntegerIndexed(typed_array) => match typed_array.viewed_array_buffer() {
+                Some(buffer) if !typed_array.is_detached() => Shape::TypedArray {
+                    name: typed_array.typed_array_name(),
+                    buffer: buffer.clone(),
+                    byte_offset: typed_array.byte_offset(),
+                    array_length: typed_array.array_length(),
+                },
+                _ => {
+                    return Err(data_clone_error(
+                        "a typed array with a detached buffer can't be cloned",
+                        context,
+                    ))
+                }
+            },
+            ObjectKind::DataView(view) => {
+                let buffer = view.viewed_array_buffer();
+                let detached = buffer
+                    .borrow()
+                    .as_array_buffer()
+                    .map_or(true, ArrayBuffer::is_detached_buffer);
+                if detached {
+                    return Err(data_clone_error(
+                        "a DataView with a detached buffer can't be cloned",
+                        context,
+                    ));
+                }
+                Shape::DataView {
+                    buffer: buffer.clone(),
+                    byte_offset: view.byte_offset(),
+                    byte_length: view.byte_length(),
+                }
+            }
+            ObjectKind::Map(map) => Shape::Map(
+                map.iter()
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect(),
+            ),
+            ObjectKind::Set(set) => Shape::Set(set.iter().cloned().collect()),
+            ObjectKind::Error => Shape::Error,
+            ObjectKind::Array => Shape::Array,
+            ObjectKind::Ordinary => Shape::Object,
+            kind => {
+                let message = format!("{} objects can't be cloned", kind);
+                return Err(data_clone_error(message, context));
+            }
+        };
+
+        Ok(shape)
+    }
+
+    /// Serializes the own enumerable string properties of `object`.
+    fn serialize_properties(
+        &mut self,
+        object: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<Vec<(JsString, Serialized)>> {
+        let keys = object.enumerable_own_property_names(PropertyNameKind::Key, context)?;
+        let mut properties = Vec::with_capacity(keys.len());
+        for key in keys {
+            let key = key
+                .as_string()
+                .cloned()
+                .expect("enumerable own property names must be strings");
+
+            // A getter may have deleted the property.
+            if object.has_own_property(key.clone(), context)? {
+                let value = object.get(key.clone(), context)?;
+                properties.push((key, self.serialize(&value, context)?));
+            }
+        }
+        Ok(properties)
+    }
+}
+
+/// Creates the objects of serialized records, remembering the ones already created.
+#[derive(Debug)]
+struct Deserializer {
+    records: Vec<Option<Record>>,
+    memory: Vec<Option<JsObject>>,
+}
+
+impl Deserializer {
+    /// Deserializes `value`.
+    fn deserialize(&mut self, value: Serialized, context: &mut Context) -> JsResult<JsValue> {
+        match value {
+            Serialized::Primitive(value) => Ok(value),
+            Serialized::Object(index) => Ok(self.deserialize_object(index, context)?.into()),
+        }
+    }
+
+    /// Returns the object of the record at `index`, creating it if it doesn't exist yet.
+    fn deserialize_object(&mut self, index: usize, context: &mut Context) -> JsResult<JsObject> {
+        if let Some(object) = &self.memory[index] {
+            return Ok(object.clone());
+        }
+        let record = self.records[index]
+            .take()
+            .expect("a record is only deserialized once");
+
+        let object = match record {
+            Record::Boolean(boolean) => JsValue::new(boolean).to_object(context)?,
+            Record::Number(number) => JsValue::new(number).to_object(context)?,
+            Record::BigInt(bigint) => JsValue::new(bigint).to_object(context)?,
+            Record::String(string) => JsValue::new(string).to_object(context)?,
+            Record::Date(date) => {
+                let prototype = context.standard_objects().date_object().prototype();
+                JsObject::from_proto_and_data(prototype, ObjectData::date(date))
+            }
+            Record::RegExp { source, flags } => {
+                RegExp::create(source.into(), flags.into(), context)?
+                    .as_object()
+                    .cloned()
+                    .expect("RegExpCreate must return an object")
+            }
+            Record::ArrayBuffer(data) => {
+                let prototype = context.standard_objects().array_buffer_object().prototype();
+                JsObject::from_proto_and_data(
+                    prototype,
+                    ObjectData::array_buffer(ArrayBuffer {
+                        array_buffer_byte_length: data.len(),
+                        array_buffer_data: Some(data),
+                        array_buffer_detach_key: JsValue::undefined(),
+                    }),
+                )
+            }
+            Record::Transferred => unreachable!("transferred buffers must have been moved"),
+            Record::TypedArray {
+                name,
+                buffer,
+                byte_offset,
+                array_length,
+            } => {
+                let buffer = self.deserialize_object(buffer, context)?;
+                let constructor = name.standard_constructor()(context.standard_objects())
+                    .constructor()
+                    .into();
+                let args = [buffer.into(), byte_offset.into(), array_length.into()];
+                context
+                    .construct(&constructor, &args)?
+                    .as_object()
+                    .cloned()
+                    .expect("typed array constructors must return an object")
+            }
+            Record::DataView {
+                buffer,
+                byte_offset,
+                byte_length,
+            } => {
+                let buffer = self.deserialize_object(buffer, context)?;
+                let constructor = context
+                    .standard_objects()
+                    .data_view_object()
+                    .constructor()
+                    .into();
+                let args = [buffer.into(), byte_offset.into(), byte_length.into()];
+                context
+                    .construct(&constructor, &args)?
+                    .as_object()
+                    .cloned()
+                    .expect("the DataView constructor must return an object")
+            }
+            Record::Map(entries) => {
+                let prototype = context.standard_objects().map_object().prototype();
+                let map =
+                    JsObject::from_proto_and_data(prototype, ObjectData::map(OrderedMap::new()));
+                self.memory[index] = Some(map.clone());
+                for (key, value) in entries {
+                    let key = self.deserialize(key, context)?;
+                    let value = self.deserialize(value, context)?;
+                    map.borrow_mut()
+                        .as_map_mut()
+                        .expect("the object must be a map")
+                        .insert(key, value);
+                }
+                map
+            }
+            Record::Set(values) => {
+                let prototype = context.standard_objects().set_object().prototype();
+                let set =
+                    JsObject::from_proto_and_data(prototype, ObjectData::set(OrderedSet::new()));
+                self.memory[index] = Some(set.clone());
+                for value in values {
+                    let value = self.deserialize(value, context)?;
+                    set.borrow_mut()
+                        .as_set_mut()
+                        .expect("the object must be a set")
+                        .add(value);
+                }
+                set
+            }
+            Record::Error { name, message } => {
+                let prototype = match name {
+                    "EvalError" => context.standard_objects().eval_error_object(),
+                    "RangeError" => context.standard_objects().range_error_object(),
+                    "ReferenceError" => context.standard_objects().reference_error_object(),
+                    "SyntaxError" => context.standard_objects().syntax_error_object(),
+                    "TypeError" => context.standard_objects().type_error_object(),
+                    "URIError" => context.standard_objects().uri_error_object(),
+                    _ => context.standard_objects().error_object(),
+                }
+                .prototype();
+                let error = JsObject::from_proto_and_data(prototype, ObjectData::error());
+                if let Some(message) = message {
+                    error.insert_property(
+                        "message",
+                        PropertyDescriptor::builder()
+                            .value(message)
+                            .writable(true)
+                            .enumerable(false)
+                            .configurable(true),
+                    );
+                }
+                error
+            }
+            Record::Array { length, properties } => {
+                let array = Array::array_create(length, None, context)?;
+                self.memory[index] = Some(array.clone());
+                self.deserialize_properties(&array, properties, context)?;
+                array
+            }
+            Record::Object(properties) => {
+                let object = context.construct_object();
+                self.memory[index] = Some(object.clone());
+                self.deserialize_properties(&object, properties, context)?;
+                object
+            }
+        };
+        self.memory[index] = Some(object.clone());
+
+        Ok(object)
+    }
+
+    /// Defines the deserialized `properties` on `object`.
+    fn deserialize_properties(
+        &mut self,
+        object: &JsObject,
+        properties: Vec<(JsString, Serialized)>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        for (key, value) in properties {
+            let value = self.deserialize(value, context)?;
+            object.create_data_property_or_throw(key, value, context)?;
+        }
+        Ok(())
+    }
+}
+
+/// Creates a `DataCloneError`, an `Error` named `DataCloneError` as Boa has no `DOMException`.
+fn data_clone_error<M>(message: M, context: &mut Context) -> JsValue
+where
+    M: Into<Box<str>>,
+{
+    let error = context.construct_error(message);
+    if let Some(error) = error.as_object() {
+        error.insert_property(
+            "name",
+            PropertyDescriptor::builder()
+                .value("DataCloneError")
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+        );
+    }
+    error
+}
+
+/// Deep-copies `value` with the structured clone algorithm, moving the contents of the
+/// `ArrayBuffer`s of `transfer` to the clone and detaching them.
+///
+/// More information:
+///  - [HTML specification][spec]
+///
+/// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structuredserializewithtransfer
+pub(crate) fn structured_clone(
+    value: &JsValue,
+    transfer: &[JsObject],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let mut serializer = Serializer::default();
+
+    // StructuredSerializeWithTransfer ( value, transferList )
+    // 1. Let memory be an empty map.
+    // 2. For each transferable of transferList:
+    for transferable in transfer {
+        // a. If transferable has neither an [[ArrayBufferData]] internal slot nor a [[Detached]] internal slot, then throw a "DataCloneError" DOMException.
+        if !transferable.is_array_buffer() {
+            return Err(data_clone_error(
+                "only ArrayBuffer objects can be transferred",
+                context,
+            ));
+        }
+
+        // c. If memory[transferable] exists, then throw a "DataCloneError" DOMException.
+        if serializer.lookup(transferable).is_some() {
+            return Err(data_clone_error(
+                "an ArrayBuffer can't be transferred twice",
+                context,
+            ));
+        }
+
+        // d. Set memory[transferable] to { [[Type]]: an uninitialized value }.
+        let index = serializer.reserve(transferable);
+        serializer.records[index] = Record::Transferred;
+    }
+
+    // 3. Let serialized be ? StructuredSerializeInternal(value, false, memory).
+    let serialized = serializer.serialize(value, context)?;
+
+    // 4. Let transferDataHolders be a new empty List.
+    // 5. For each transferable of transferList:
+    for (index, transferable) in transfer.iter().enumerate() {
+        let mut transferable = transferable.borrow_mut();
+        let buffer = transferable
+            .as_array_buffer_mut()
+            .expect("the transfer list must only contain ArrayBuffer objects");
+
+        // a. If transferable has an [[ArrayBufferData]] internal slot and IsDetachedBuffer(transferable) is true, then throw a "DataCloneError" DOMException.
+        let data = match &buffer.array_buffer_data {
+            Some(data) => data.clone(),
+            None => {
+                return Err(data_clone_error(
+                    "a detached ArrayBuffer can't be transferred",
+                    context,
+                ))
+            }
+        };
+
+        // e. Otherwise:
+        //     i. Let arrayBufferData be transferable.[[ArrayBufferData]].
+        //     ii. Let arrayBufferByteLength be transferable.[[ArrayBufferByteLength]].
+        //     iii. Perform ? DetachArrayBuffer(transferable).
+        //     iv. Set dataHolder.[[ArrayBufferData]] to arrayBufferData.
+        //     v. Set dataHolder.[[ArrayBufferByteLength]] to arrayBufferByteLength.
+        buffer.detach(&JsValue::undefined(), context)?;
+        serializer.records[index] = Record::ArrayBuffer(data);
+    }
+
+    // StructuredDeserializeWithTransfer ( serializeWithTransferResult, targetRealm )
+    let mut deserializer = Deserializer {
+        memory: vec![None; serializer.records.len()],
+        records: serializer.records.into_iter().map(Some).collect(),
+    };
+    deserializer.deserialize(serialized, context)
+}
+
+/// JavaScript `structuredClone` implementation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StructuredClone;
+
+impl BuiltIn for StructuredClone {
+    const NAME: &'static str = "structuredClone";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        FunctionBuilder::native(context, Self::structured_clone)
+            .name(Self::NAME)
+            .length(1)
+            .constructor(false)
+            .build()
+            .into()
+    }
+}
+
+impl StructuredClone {
+    /// `structuredClone( value [, { transfer }] )`
+    ///
+    /// Deep-copies `value`, moving the contents of the `ArrayBuffer`s of `transfer` to the
+    /// clone.
+    ///
+    /// More information:
+    ///  - [HTML specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#dom-structuredclone
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/structuredClone
+    pub(crate) fn structured_clone(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        if args.is_empty() {
+            return context.throw_type_error("structuredClone: 1 argument required, but 0 present");
+        }
+
+        let options = args.get_or_undefined(1);
+        let transfer = match options {
+            JsValue::Undefined | JsValue::Null => JsValue::undefined(),
+            JsValue::Object(options) => options.get("transfer", context)?,
+            _ => return context.throw_type_error("structuredClone: options must be an object"),
+        };
+
+        let transfer = match transfer {
+            JsValue::Undefined => Vec::new(),
+            JsValue::Object(_) => {
+                let mut objects = Vec::new();
+                for transferable in iterable_to_list(context, transfer, None)? {
+                    match transferable.as_object() {
+                        Some(transferable) => objects.push(transferable.clone()),
+                        None => {
+                            return context.throw_type_error(
+                                "structuredClone: the transfer list must only contain objects",
+                            )
+                        }
+                    }
+                }
+                objects
+            }
+            _ => {
+                return context
+                    .throw_type_error("structuredClone: the transfer list must be iterable")
+            }
+        };
+
+        structured_clone(&args[0], &transfer, context)
+    }
+}
@@ -0,0 +1,302 @@
+use crate::{forward, gc::force_collect, Context, JsResult, JsValue};
+
+#[test]
+fn clones_nested_structures() {
+    let mut context = Context::new();
+    let init = r#"
+        var inner = { n: 1 };
+        var sparse = [];
+        sparse.length = 3;
+        sparse[1] = "x";
+        var original = {
+            inner,
+            map: new Map([["a", inner], [inner, sparse]]),
+            set: new Set([inner, 2]),
+            bytes: new Int16Array([1, -2, 3]),
+            date: new Date(5),
+            re: /a+/gi,
+            error: new RangeError("bad"),
+        };
+        original.self = original;
+        var clone = structuredClone(original);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "clone === original"), "false");
+    assert_eq!(forward(&mut context, "clone.self === clone"), "true");
+    assert_eq!(forward(&mut context, "clone.inner === inner"), "false");
+    assert_eq!(forward(&mut context, "clone.inner.n"), "1");
+
+    assert_eq!(forward(&mut context, "clone.map instanceof Map"), "true");
+    assert_eq!(forward(&mut context, "clone.map === original.map"), "false");
+    assert_eq!(
+        forward(&mut context, "clone.map.get('a') === clone.inner"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "clone.map.get(clone.inner).length"),
+        "3"
+    );
+    assert_eq!(
+        forward(&mut context, "0 in clone.map.get(clone.inner)"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "clone.map.get(clone.inner)[1]"),
+        "\"x\""
+    );
+    assert_eq!(forward(&mut context, "clone.set.has(clone.inner)"), "true");
+    assert_eq!(forward(&mut context, "clone.set.has(2)"), "true");
+
+    assert_eq!(
+        forward(&mut context, "clone.bytes instanceof Int16Array"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "clone.bytes.join()"), "\"1,-2,3\"");
+    assert_eq!(
+        forward(&mut context, "clone.bytes.buffer === original.bytes.buffer"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "clone.date.getTime()"), "5");
+    assert_eq!(
+        forward(&mut context, "clone.re.source + clone.re.flags"),
+        "\"a+gi\""
+    );
+    assert_eq!(
+        forward(&mut context, "clone.error instanceof RangeError"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "clone.error.message"), "\"bad\"");
+
+    assert_eq!(forward(&mut context, "structuredClone(1)"), "1");
+    assert_eq!(forward(&mut context, "structuredClone('a')"), "\"a\"");
+}
+
+#[test]
+fn clone_of_cyclic_graph_shares_no_references() {
+    let mut context = Context::new();
+    let init = r#"
+        function reachable(root) {
+            let seen = new Set();
+            let stack = [root];
+            while (stack.length > 0) {
+                let value = stack.pop();
+                if (typeof value !== "object" || value === null || seen.has(value)) {
+                    continue;
+                }
+                seen.add(value);
+                if (value instanceof Map) {
+                    value.forEach((v, k) => { stack.push(k); stack.push(v); });
+                } else if (value instanceof Set) {
+                    value.forEach(v => stack.push(v));
+                }
+                if (ArrayBuffer.isView(value)) {
+                    stack.push(value.buffer);
+                }
+                Object.keys(value).forEach(key => stack.push(value[key]));
+            }
+            return seen;
+        }
+        var a = { name: "a" };
+        var b = { name: "b", a };
+        a.b = b;
+        var list = [a, b];
+        list.push(list);
+        var bytes = new Uint8Array([1, 2, 3]);
+        var graph = {
+            a,
+            b,
+            list,
+            index: new Map([[a, b], ["list", list]]),
+            members: new Set([a, list]),
+            bytes,
+            view: new Uint8Array(bytes.buffer, 1),
+            when: new Date(0),
+            pattern: /x/g,
+        };
+        graph.self = graph;
+        var clone = structuredClone(graph);
+        var originals = reachable(graph);
+        var copies = reachable(clone);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "copies.size === originals.size"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "[...copies].some(o => originals.has(o))"),
+        "false"
+    );
+
+    // Cycles and shared references are rebuilt inside the clone.
+    for check in [
+        "clone.self === clone",
+        "clone.a.b === clone.b",
+        "clone.b.a === clone.a",
+        "clone.list[0] === clone.a",
+        "clone.list[2] === clone.list",
+        "clone.index.get(clone.a) === clone.b",
+        "clone.index.get('list') === clone.list",
+        "clone.members.has(clone.a)",
+        "clone.members.has(clone.list)",
+        "clone.view.buffer === clone.bytes.buffer",
+    ] {
+        assert_eq!(forward(&mut context, check), "true", "{}", check);
+    }
+}
+
+#[test]
+fn transfers_array_buffers() {
+    let mut context = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var view = new Uint8Array(buffer, 2, 4);
+        view[0] = 7;
+        var clone = structuredClone({ buffer, view }, { transfer: [buffer] });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "buffer.byteLength"), "0");
+    assert_eq!(forward(&mut context, "view.length"), "0");
+    assert_eq!(
+        forward(&mut context, "try { buffer.slice(0) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut context, "clone.buffer.byteLength"), "8");
+    assert_eq!(
+        forward(&mut context, "clone.view.buffer === clone.buffer"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "clone.view.byteOffset"), "2");
+    assert_eq!(forward(&mut context, "clone.view[0]"), "7");
+
+    // A transferred buffer is moved even if the value doesn't reference it.
+    assert_eq!(
+        forward(
+            &mut context,
+            "var other = new ArrayBuffer(4); structuredClone(1, { transfer: [other] }); other.byteLength"
+        ),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { structuredClone(buffer) } catch (e) { e.name }"
+        ),
+        "\"DataCloneError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { structuredClone(1, { transfer: [buffer] }) } catch (e) { e.name }"
+        ),
+        "\"DataCloneError\""
+    );
+}
+
+#[test]
+fn throws_data_clone_error() {
+    let mut context = Context::new();
+
+    for value in [
+        "function () {}",
+        "Symbol()",
+        "new WeakMap()",
+        "{ f() {} }",
+        "[Symbol.iterator]",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!(
+                    "try {{ structuredClone({}) }} catch (e) {{ e.name }}",
+                    value
+                )
+            ),
+            "\"DataCloneError\"",
+            "{}",
+            value
+        );
+    }
+
+    // Nothing is detached when the value can't be cloned.
+    let src = r#"
+        var buffer = new ArrayBuffer(2);
+        try { structuredClone(function () {}, { transfer: [buffer] }) } catch (e) {}
+        buffer.byteLength
+        "#;
+    assert_eq!(forward(&mut context, src), "2");
+
+    for options in ["{ transfer: [buffer, buffer] }", "{ transfer: [{}] }"] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!(
+                    "try {{ structuredClone(1, {}) }} catch (e) {{ e.name }}",
+                    options
+                )
+            ),
+            "\"DataCloneError\""
+        );
+    }
+    for options in ["{ transfer: 1 }", "{ transfer: [1] }", "1"] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!(
+                    "try {{ structuredClone(1, {}) }} catch (e) {{ e.name }}",
+                    options
+                )
+            ),
+            "\"TypeError\""
+        );
+    }
+}
+
+#[test]
+fn dates_use_the_intrinsic_prototype() {
+    let mut context = Context::new();
+    let init = r#"
+        var date = new Date(7);
+        globalThis.Date = undefined;
+        var clone = structuredClone(date);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(clone) === Object.getPrototypeOf(date)"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "clone.getTime()"), "7");
+}
+
+#[test]
+fn collected_objects_are_not_confused_with_new_ones() {
+    fn gc(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        force_collect();
+        Ok(JsValue::undefined())
+    }
+
+    let mut context = Context::new();
+    context.register_global_function("gc", 0, gc).unwrap();
+    let init = r#"
+        var holder = {
+            first: { n: 1 },
+            get second() {
+                delete holder.first;
+                gc();
+                return { n: 2 };
+            },
+        };
+        var clone = structuredClone(holder);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "clone.first.n"), "1");
+    assert_eq!(forward(&mut context, "clone.second.n"), "2");
+}
@@ -0,0 +1,188 @@
+//! This module implements the global `setTimeout` and `clearTimeout` functions.
+//!
+//! Boa has no event loop of its own, so timers are only registered in a queue stored in the
+//! [`Context`]. The host decides when time passes and fires the due callbacks by calling
+//! [`Context::run_timers`] with its current clock.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `setTimeout` specification][spec]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#timers
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/setTimeout
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{function::NativeFunctionSignature, JsArgs},
+    object::{FunctionBuilder, JsObject},
+    property::PropertyDescriptor,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+/// A callback registered with `setTimeout`.
+#[derive(Debug)]
+struct Timer {
+    id: u32,
+    deadline: u64,
+    callback: JsObject,
+    args: Vec<JsValue>,
+}
+
+/// The timer queue of a `Context`.
+#[derive(Debug, Default)]
+pub(crate) struct Timers {
+    next_id: u32,
+    now: u64,
+    queue: Vec<Timer>,
+}
+
+impl Timers {
+    /// Registers the `setTimeout` and `clearTimeout` global functions.
+    pub(crate) fn init(context: &mut Context) {
+        let _timer = BoaProfiler::global().start_event("timers", "init");
+
+        let global = context.global_object();
+        for (name, function, length) in [
+            (
+                "setTimeout",
+                Self::set_timeout as NativeFunctionSignature,
+                1,
+            ),
+            ("clearTimeout", Self::clear_timeout, 0),
+        ] {
+            let function = FunctionBuilder::native(context, function)
+                .name(name)
+                .length(length)
+                .constructor(false)
+                .build();
+            global.insert_property(
+                name,
+                PropertyDescriptor::builder()
+                    .value(function)
+                    .writable(true)
+                    .enumerable(false)
+                    .configurable(true),
+            );
+        }
+    }
+
+    /// `setTimeout( handler [, timeout [, arguments...]] )`
+    ///
+    /// Schedules `handler` to be called with `arguments` once `timeout` milliseconds have passed
+    /// since the last time the host ran the timers, and returns the timer id.
+    ///
+    /// More information:
+    ///  - [WHATWG `setTimeout` specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#dom-settimeout
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/setTimeout
+    pub(crate) fn set_timeout(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let callback = match args.get_or_undefined(0).as_callable() {
+            Some(callback) => callback.clone(),
+            None => return context.throw_type_error("setTimeout: handler is not callable"),
+        };
+        // Negative timeouts are clamped to zero.
+        let timeout = args.get_or_undefined(1).to_i32(context)?.max(0) as u64;
+        let args = args.get(2..).unwrap_or_default().to_vec();
+
+        let timers = context.timers_mut();
+        let id = timers.allocate_id();
+        let deadline = timers.now.saturating_add(timeout);
+        timers.queue.push(Timer {
+            id,
+            deadline,
+            callback,
+            args,
+        });
+
+        Ok(id.into())
+    }
+
+    /// `clearTimeout( id )`
+    ///
+    /// Cancels a timer previously registered with `setTimeout`. Unknown ids are ignored.
+    ///
+    /// More information:
+    ///  - [WHATWG `clearTimeout` specification][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#dom-cleartimeout
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/clearTimeout
+    pub(crate) fn clear_timeout(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let id = args.get_or_undefined(0).to_u32(context)?;
+        context.timers_mut().queue.retain(|timer| timer.id != id);
+        Ok(JsValue::undefined())
+    }
+
+    /// Returns a fresh timer id, wrapping around on overflow.
+    ///
+    /// Zero is never handed out, and neither is the id of a timer that is still pending.
+    fn allocate_id(&mut self) -> u32 {
+        loop {
+            self.next_id = self.next_id.wrapping_add(1);
+            let id = self.next_id;
+            if id != 0 && self.queue.iter().all(|timer| timer.id != id) {
+                return id;
+            }
+        }
+    }
+
+    /// Returns the earliest deadline of the registered timers.
+    pub(crate) fn next_deadline(&self) -> Option<u64> {
+        self.queue.iter().map(|timer| timer.deadline).min()
+    }
+
+    /// Advances the clock to `now` and fires every timer that is due, in deadline order.
+    ///
+    /// Timers registered by the fired callbacks are only considered by the next call, even if
+    /// their deadline has already passed, so a callback that keeps rescheduling itself can't
+    /// starve the host. If a callback throws, the error is returned and the timers that
+    /// haven't fired yet stay in the queue.
+    ///
+    /// The job queue is drained before and after each callback, so promise reactions queued
+    /// by the script or by a callback run before the next timer fires.
+    pub(crate) fn run(context: &mut Context, now: u64) -> JsResult<usize> {
+        let timers = context.timers_mut();
+        timers.now = timers.now.max(now);
+        let now = timers.now;
+
+        let mut due: Vec<_> = timers
+            .queue
+            .iter()
+            .filter(|timer| timer.deadline <= now)
+            .map(|timer| (timer.deadline, timer.id))
+            .collect();
+        // Timers with the same deadline fire in registration order.
+        due.sort_unstable();
+
+        let mut fired = 0;
+        for (_, id) in due {
+            context.run_jobs()?;
+
+            // A previous callback or job may have cleared this timer.
+            let queue = &mut context.timers_mut().queue;
+            let timer = match queue.iter().position(|timer| timer.id == id) {
+                Some(index) => queue.remove(index),
+                None => continue,
+            };
+            timer
+                .callback
+                .call(&JsValue::undefined(), &timer.args, context)?;
+            context.run_jobs()?;
+            fired += 1;
+        }
+
+        Ok(fired)
+    }
+}
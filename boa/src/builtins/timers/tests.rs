@@ -0,0 +1,152 @@
+use crate::{forward, Context};
+
+#[test]
+fn fires_due_timers_in_order() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        setTimeout(function() { log.push("b"); }, 20);
+        setTimeout(function(x, y) { log.push(x + y); }, 10, "a", "1");
+        setTimeout(function() { log.push("a2"); }, 10);
+        setTimeout(function() { log.push("c"); }, 30);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(context.next_timer_deadline(), Some(10));
+    assert_eq!(context.run_timers(5).unwrap(), 0);
+    assert_eq!(forward(&mut context, "log.join()"), "\"\"");
+
+    assert_eq!(context.run_timers(20).unwrap(), 3);
+    assert_eq!(forward(&mut context, "log.join()"), "\"a1,a2,b\"");
+
+    assert_eq!(context.run_timers(100).unwrap(), 1);
+    assert_eq!(forward(&mut context, "log.join()"), "\"a1,a2,b,c\"");
+    assert_eq!(context.next_timer_deadline(), None);
+}
+
+#[test]
+fn timeout_is_relative_to_last_run() {
+    let mut context = Context::new();
+    forward(&mut context, "var fired = 0;");
+    context.run_timers(100).unwrap();
+
+    forward(&mut context, "setTimeout(function() { fired++; }, 10);");
+    assert_eq!(context.next_timer_deadline(), Some(110));
+    assert_eq!(context.run_timers(105).unwrap(), 0);
+    assert_eq!(context.run_timers(110).unwrap(), 1);
+    assert_eq!(forward(&mut context, "fired"), "1");
+}
+
+#[test]
+fn clear_timeout() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        var first = setTimeout(function() { log.push("first"); }, 0);
+        var second = setTimeout(function() { log.push("second"); }, 0);
+        setTimeout(function() { clearTimeout(second); log.push("clearer"); }, 0);
+        var third = setTimeout(function() { log.push("third"); }, 0);
+        clearTimeout(first);
+        clearTimeout(12345);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "first !== second"), "true");
+    // `second` is registered before the callback clearing it, so it still fires.
+    assert_eq!(context.run_timers(0).unwrap(), 3);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"second,clearer,third\""
+    );
+
+    forward(
+        &mut context,
+        r#"
+        var late = setTimeout(function() { log.push("late"); }, 0);
+        setTimeout(function() { clearTimeout(late); }, 0);
+        "#,
+    );
+    forward(&mut context, "clearTimeout(late)");
+    assert_eq!(context.run_timers(0).unwrap(), 1);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"second,clearer,third\""
+    );
+}
+
+#[test]
+fn timers_scheduled_while_running_wait_for_next_run() {
+    let mut context = Context::new();
+    let init = r#"
+        var count = 0;
+        function tick() {
+            count++;
+            setTimeout(tick, 0);
+        }
+        setTimeout(tick, 0);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(context.run_timers(0).unwrap(), 1);
+    assert_eq!(context.run_timers(0).unwrap(), 1);
+    assert_eq!(forward(&mut context, "count"), "2");
+}
+
+#[test]
+fn throwing_callback_keeps_remaining_timers() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        setTimeout(function() { throw "boom"; }, 0);
+        setTimeout(function() { log.push("after"); }, 0);
+        "#;
+    forward(&mut context, init);
+
+    assert!(context.run_timers(0).is_err());
+    assert_eq!(forward(&mut context, "log.length"), "0");
+    assert_eq!(context.run_timers(0).unwrap(), 1);
+    assert_eq!(forward(&mut context, "log.join()"), "\"after\"");
+}
+
+#[test]
+fn non_callable_handler() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { setTimeout('code', 0) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn microtasks_run_before_and_between_timers() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        setTimeout(function() {
+            log.push("first");
+            Promise.resolve().then(function() { log.push("first job"); });
+        }, 0);
+        setTimeout(function() { log.push("second"); }, 0);
+        Promise.resolve().then(function() { log.push("script job"); });
+        "#;
+    context.eval(init).unwrap();
+
+    assert_eq!(context.run_timers(0).unwrap(), 2);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"script job,first,first job,second\""
+    );
+}
+
+#[test]
+fn ids_wrap_around_without_reusing_pending_timers() {
+    let mut context = Context::new();
+    forward(&mut context, "var first = setTimeout(function() {}, 100);");
+    context.timers_mut().next_id = u32::MAX;
+
+    assert_eq!(forward(&mut context, "setTimeout(function() {}, 0)"), "2");
+    assert_eq!(forward(&mut context, "first"), "1");
+}
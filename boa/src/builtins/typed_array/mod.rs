@@ -34,6 +34,8 @@ use num_traits::{Signed, Zero};
 use std::cmp::Ordering;
 
 pub mod integer_indexed_object;
+#[cfg(test)]
+mod tests;
 
 macro_rules! typed_array {
     ($ty:ident, $name:literal, $global_object_name:ident) => {
@@ -292,6 +294,11 @@ impl TypedArray {
             0,
             Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
         )
+        .property(
+            "values",
+            values_function.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
         .property(
             WellKnownSymbols::iterator(),
             values_function,
@@ -337,6 +344,8 @@ impl TypedArray {
         .method(Self::filter, "filter", 1)
         .method(Self::find, "find", 1)
         .method(Self::findindex, "findIndex", 1)
+        .method(Self::find_last, "findLast", 1)
+        .method(Self::find_last_index, "findLastIndex", 1)
         .method(Self::foreach, "forEach", 1)
         .method(Self::includes, "includes", 1)
         .method(Self::index_of, "indexOf", 1)
@@ -352,7 +361,6 @@ impl TypedArray {
         .method(Self::some, "some", 1)
         .method(Self::sort, "sort", 1)
         .method(Self::subarray, "subarray", 2)
-        .method(Self::values, "values", 0)
         // 23.2.3.29 %TypedArray%.prototype.toString ( )
         // The initial value of the %TypedArray%.prototype.toString data property is the same
         // built-in function object as the Array.prototype.toString method defined in 23.1.3.30.
@@ -1194,6 +1202,128 @@ impl TypedArray {
         Ok((-1).into())
     }
 
+    /// `%TypedArray%.prototype.findLast ( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.findlast
+    fn find_last(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let obj = this
+            .as_object()
+            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj_borrow = obj.borrow();
+        let o = obj_borrow
+            .as_typed_array()
+            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        if o.is_detached() {
+            return Err(context.construct_type_error("Buffer of the typed array is detached"));
+        }
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = o.array_length();
+
+        // 4. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = match args.get_or_undefined(0).as_object() {
+            Some(obj) if obj.is_callable() => obj,
+            _ => {
+                return context.throw_type_error(
+                    "TypedArray.prototype.findLast called with non-callable predicate function",
+                )
+            }
+        };
+
+        // 5. Let k be len - 1.
+        // 6. Repeat, while k ≥ 0,
+        for k in (0..len).rev() {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = obj.get(k, context).expect("Get cannot fail here");
+
+            // c. Let testResult be ! ToBoolean(? Call(predicate, thisArg, « kValue, 𝔽(k), O »)).
+            // d. If testResult is true, return kValue.
+            if predicate
+                .call(
+                    args.get_or_undefined(1),
+                    &[k_value.clone(), k.into(), this.clone()],
+                    context,
+                )?
+                .to_boolean()
+            {
+                return Ok(k_value);
+            }
+
+            // e. Set k to k - 1.
+        }
+
+        // 7. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `%TypedArray%.prototype.findLastIndex ( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.findlastindex
+    fn find_last_index(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let obj = this
+            .as_object()
+            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj_borrow = obj.borrow();
+        let o = obj_borrow
+            .as_typed_array()
+            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        if o.is_detached() {
+            return Err(context.construct_type_error("Buffer of the typed array is detached"));
+        }
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = o.array_length();
+
+        // 4. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = match args.get_or_undefined(0).as_object() {
+            Some(obj) if obj.is_callable() => obj,
+            _ => return context.throw_type_error(
+                "TypedArray.prototype.findLastIndex called with non-callable predicate function",
+            ),
+        };
+
+        // 5. Let k be len - 1.
+        // 6. Repeat, while k ≥ 0,
+        for k in (0..len).rev() {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = obj.get(k, context).expect("Get cannot fail here");
+
+            // c. Let testResult be ! ToBoolean(? Call(predicate, thisArg, « kValue, 𝔽(k), O »)).
+            // d. If testResult is true, return 𝔽(k).
+            if predicate
+                .call(
+                    args.get_or_undefined(1),
+                    &[k_value, k.into(), this.clone()],
+                    context,
+                )?
+                .to_boolean()
+            {
+                return Ok(k.into());
+            }
+
+            // e. Set k to k - 1.
+        }
+
+        // 7. Return -1𝔽.
+        Ok((-1).into())
+    }
+
     /// `23.2.3.13 %TypedArray%.prototype.forEach ( callbackfn [ , thisArg ] )`
     ///
     /// More information:
@@ -2636,18 +2766,7 @@ impl TypedArray {
         // 8. Sort items using an implementation-defined sequence of calls to SortCompare.
         // If any such call returns an abrupt completion, stop before performing any further
         // calls to SortCompare or steps in this algorithm and return that completion.
-        let mut sort_err = Ok(());
-        items.sort_by(|x, y| {
-            if sort_err.is_ok() {
-                sort_compare(x, y, compare_fn, context).unwrap_or_else(|err| {
-                    sort_err = Err(err);
-                    Ordering::Equal
-                })
-            } else {
-                Ordering::Equal
-            }
-        });
-        sort_err?;
+        Array::sort_items(&mut items, |x, y| sort_compare(x, y, compare_fn, context))?;
 
         // 9. Let j be 0.
         // 10. Repeat, while j < itemCount,
@@ -2818,19 +2937,7 @@ impl TypedArray {
         context: &mut Context,
     ) -> JsResult<JsObject> {
         // 1. Let defaultConstructor be the intrinsic object listed in column one of Table 73 for exemplar.[[TypedArrayName]].
-        let default_constructor = match typed_array_name {
-            TypedArrayName::Int8Array => StandardObjects::typed_int8_array_object,
-            TypedArrayName::Uint8Array => StandardObjects::typed_uint8_array_object,
-            TypedArrayName::Uint8ClampedArray => StandardObjects::typed_uint8clamped_array_object,
-            TypedArrayName::Int16Array => StandardObjects::typed_int16_array_object,
-            TypedArrayName::Uint16Array => StandardObjects::typed_uint16_array_object,
-            TypedArrayName::Int32Array => StandardObjects::typed_int32_array_object,
-            TypedArrayName::Uint32Array => StandardObjects::typed_uint32_array_object,
-            TypedArrayName::BigInt64Array => StandardObjects::typed_bigint64_array_object,
-            TypedArrayName::BigUint64Array => StandardObjects::typed_biguint64_array_object,
-            TypedArrayName::Float32Array => StandardObjects::typed_float32_array_object,
-            TypedArrayName::Float64Array => StandardObjects::typed_float64_array_object,
-        };
+        let default_constructor = typed_array_name.standard_constructor();
 
         // 2. Let constructor be ? SpeciesConstructor(exemplar, defaultConstructor).
         let constructor = exemplar.species_constructor(default_constructor, context)?;
@@ -3314,6 +3421,24 @@ unsafe impl Trace for TypedArrayName {
 }
 
 impl TypedArrayName {
+    /// Gets the getter of the standard constructor of the given typed array name.
+    #[inline]
+    pub(crate) fn standard_constructor(self) -> fn(&StandardObjects) -> &StandardConstructor {
+        match self {
+            Self::Int8Array => StandardObjects::typed_int8_array_object,
+            Self::Uint8Array => StandardObjects::typed_uint8_array_object,
+            Self::Uint8ClampedArray => StandardObjects::typed_uint8clamped_array_object,
+            Self::Int16Array => StandardObjects::typed_int16_array_object,
+            Self::Uint16Array => StandardObjects::typed_uint16_array_object,
+            Self::Int32Array => StandardObjects::typed_int32_array_object,
+            Self::Uint32Array => StandardObjects::typed_uint32_array_object,
+            Self::BigInt64Array => StandardObjects::typed_bigint64_array_object,
+            Self::BigUint64Array => StandardObjects::typed_biguint64_array_object,
+            Self::Float32Array => StandardObjects::typed_float32_array_object,
+            Self::Float64Array => StandardObjects::typed_float64_array_object,
+        }
+    }
+
     /// Gets the element size of the given typed array name, as per the [spec].
     ///
     /// [spec]: https://tc39.es/ecma262/#table-the-typedarray-constructors
@@ -0,0 +1,450 @@
+use crate::{builtins::JsArgs, forward, Context, JsResult, JsValue};
+
+/// Registers a global `detach(buffer)` function, since scripts have no way to detach an
+/// `ArrayBuffer` on their own.
+fn register_detach(context: &mut Context) {
+    fn detach(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let buffer = args
+            .get_or_undefined(0)
+            .as_object()
+            .cloned()
+            .ok_or_else(|| context.construct_type_error("not an ArrayBuffer"))?;
+        let mut buffer = buffer.borrow_mut();
+        let buffer = buffer
+            .as_array_buffer_mut()
+            .ok_or_else(|| context.construct_type_error("not an ArrayBuffer"))?;
+        buffer.detach(&JsValue::undefined(), context)?;
+        Ok(JsValue::undefined())
+    }
+
+    context
+        .register_global_function("detach", 1, detach)
+        .expect("could not register the detach function");
+}
+
+#[test]
+fn iterators_are_typed_array_methods() {
+    let mut context = Context::new();
+    let init = r#"
+        var TypedArrayPrototype = Object.getPrototypeOf(Uint8Array.prototype);
+        "#;
+    forward(&mut context, init);
+
+    for method in ["keys", "values", "entries"] {
+        assert_eq!(
+            forward(
+                &mut context,
+                format!(
+                    "TypedArrayPrototype.{0} !== Array.prototype.{0} && Uint8Array.prototype.{0} === TypedArrayPrototype.{0}",
+                    method
+                )
+            ),
+            "true"
+        );
+    }
+    assert_eq!(
+        forward(
+            &mut context,
+            "TypedArrayPrototype[Symbol.iterator] === TypedArrayPrototype.values"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn iterate_entries() {
+    let mut context = Context::new();
+    let init = r#"
+        var array = new Uint8Array([10, 20, 300]);
+        var entries = [];
+        for (var [index, value] of array.entries()) {
+            entries.push(index + ":" + value);
+        }
+        var keys = [];
+        for (var key of array.keys()) {
+            keys.push(key);
+        }
+        var values = [];
+        for (var value of array) {
+            values.push(value);
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "entries.join()"),
+        "\"0:10,1:20,2:44\""
+    );
+    assert_eq!(forward(&mut context, "keys.join()"), "\"0,1,2\"");
+    assert_eq!(forward(&mut context, "values.join()"), "\"10,20,44\"");
+}
+
+#[test]
+fn iteration_reads_through_buffer() {
+    let mut context = Context::new();
+    let init = r#"
+        var array = new Uint8Array(3);
+        var iterator = array.values();
+        iterator.next();
+        new DataView(array.buffer).setUint8(1, 42);
+        var second = iterator.next().value;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "second"), "42");
+}
+
+#[test]
+fn detach_mid_iteration() {
+    let mut context = Context::new();
+    register_detach(&mut context);
+    let init = r#"
+        var array = new Uint8Array([1, 2, 3]);
+        var iterator = array.entries();
+        var first = iterator.next().value.join();
+        detach(array.buffer);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "first"), "\"0,1\"");
+    assert_eq!(
+        forward(&mut context, "try { iterator.next() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { array.entries() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut context, "array.length"), "0");
+}
+
+#[test]
+fn detach_in_for_of() {
+    let mut context = Context::new();
+    register_detach(&mut context);
+    let init = r#"
+        var array = new Uint8Array([1, 2, 3]);
+        var seen = [];
+        var error;
+        try {
+            for (var value of array) {
+                seen.push(value);
+                detach(array.buffer);
+            }
+        } catch (e) {
+            error = e;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "seen.join()"), "\"1\"");
+    assert_eq!(forward(&mut context, "error instanceof TypeError"), "true");
+}
+
+#[test]
+fn sort() {
+    let mut context = Context::new();
+    let init = r#"
+        var array = new Float64Array([10, 9, 1.5, -0, NaN, 0, -Infinity, 100]);
+        var buffer = array.buffer;
+        var sorted = array.sort();
+        "#;
+    forward(&mut context, init);
+
+    // Numbers are sorted numerically, not by their string representation.
+    assert_eq!(
+        forward(&mut context, "array.join()"),
+        "\"-Infinity,0,0,1.5,9,10,100,NaN\""
+    );
+    assert_eq!(forward(&mut context, "Object.is(array[1], -0)"), "true");
+    assert_eq!(forward(&mut context, "sorted === array"), "true");
+    assert_eq!(
+        forward(&mut context, "new Float64Array(buffer)[0]"),
+        "-Infinity"
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Float64Array([1, 3, 2]).sort((a, b) => b - a).join()"
+        ),
+        "\"3,2,1\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { array.sort(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn sort_detached() {
+    let mut context = Context::new();
+    register_detach(&mut context);
+    let init = r#"
+        var array = new Float64Array([3, 1, 2]);
+        var error;
+        try {
+            array.sort(function (a, b) {
+                detach(array.buffer);
+                return a - b;
+            });
+        } catch (e) {
+            error = e;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "error.name"), "\"TypeError\"");
+    assert_eq!(
+        forward(&mut context, "try { array.sort() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn view_accessors() {
+    let mut context = Context::new();
+    register_detach(&mut context);
+    let init = r#"
+        var buffer = new ArrayBuffer(16);
+        var array = new Int16Array(buffer, 4, 3);
+        var view = new DataView(buffer, 2, 10);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "buffer.byteLength"), "16");
+    assert_eq!(forward(&mut context, "array.buffer === buffer"), "true");
+    assert_eq!(forward(&mut context, "array.byteOffset"), "4");
+    assert_eq!(forward(&mut context, "array.byteLength"), "6");
+    assert_eq!(forward(&mut context, "array.length"), "3");
+    assert_eq!(forward(&mut context, "view.buffer === buffer"), "true");
+    assert_eq!(forward(&mut context, "view.byteOffset"), "2");
+    assert_eq!(forward(&mut context, "view.byteLength"), "10");
+    assert_eq!(
+        forward(&mut context, "new DataView(buffer, 6).byteLength"),
+        "10"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            [
+                Object.getPrototypeOf(Int8Array.prototype),
+                DataView.prototype,
+                ArrayBuffer.prototype,
+            ].every(function (proto) {
+                var desc = Object.getOwnPropertyDescriptor(proto, "byteLength");
+                return typeof desc.get === "function" && desc.set === undefined;
+            })
+            "#
+        ),
+        "true"
+    );
+
+    forward(&mut context, "detach(buffer)");
+
+    assert_eq!(forward(&mut context, "buffer.byteLength"), "0");
+    assert_eq!(forward(&mut context, "array.buffer === buffer"), "true");
+    assert_eq!(forward(&mut context, "array.byteOffset"), "0");
+    assert_eq!(forward(&mut context, "array.byteLength"), "0");
+    assert_eq!(forward(&mut context, "array.length"), "0");
+    assert_eq!(forward(&mut context, "view.buffer === buffer"), "true");
+    assert_eq!(
+        forward(&mut context, "try { view.byteLength } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { view.byteOffset } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn element_conversion() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Uint8ClampedArray([300, -5, 1.5, 2.5, 0.5, NaN, Infinity]).join()"
+        ),
+        "\"255,0,2,2,0,0,255\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint8Array([300, -5, 1.5]).join()"),
+        "\"44,251,1\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Int8Array([128, 255, -129]).join()"),
+        "\"-128,-1,127\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint16Array([-1, 65536]).join()"),
+        "\"65535,0\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Float32Array([1.1])[0]"),
+        "1.100000023841858"
+    );
+}
+
+#[test]
+fn elements_round_trip_through_buffer() {
+    let mut context = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(16);
+        var bytes = new Uint8Array(buffer);
+        bytes.set([1, 2, 3, 4, 5, 6, 7, 8]);
+        var words = new Uint32Array(buffer);
+        var word = words[0];
+        words[0] = 0;
+        words[0] = word;
+        new Float64Array(buffer, 8, 1)[0] = -0.1;
+        "#;
+    forward(&mut context, init);
+
+    // Whatever the byte order of the host is, an element reads back the bytes it was made of.
+    assert_eq!(
+        forward(&mut context, "bytes.subarray(0, 4).join()"),
+        "\"1,2,3,4\""
+    );
+    assert_eq!(forward(&mut context, "new Float64Array(buffer)[1]"), "-0.1");
+    assert_eq!(
+        forward(&mut context, "new Uint32Array(buffer)[0] === word"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var a = new Int8Array(2); a[5] = 1; [a[5], a.length].join()"
+        ),
+        "\",2\""
+    );
+}
+
+#[test]
+fn reduce() {
+    let mut context = Context::new();
+    register_detach(&mut context);
+    forward(&mut context, "var array = new Int32Array([1, 2, 3]);");
+
+    assert_eq!(
+        forward(&mut context, "array.reduce((sum, x) => sum + x)"),
+        "6"
+    );
+    assert_eq!(
+        forward(&mut context, "array.reduceRight((acc, x) => acc + '' + x)"),
+        "\"321\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Int32Array([]).reduce((sum, x) => sum + x, 5)"
+        ),
+        "5"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "array.reduce((acc, x) => { array[2] = 10; return acc + x; })"
+        ),
+        "13"
+    );
+
+    for src in [
+        "new Int32Array([]).reduce((sum, x) => sum + x)",
+        "new Int32Array([]).reduceRight((sum, x) => sum + x)",
+        "Int32Array.prototype.reduce.call([1, 2], (sum, x) => sum + x)",
+        "detach(array.buffer); array.reduce((sum, x) => sum + x, 0)",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {} }} catch (e) {{ e.name }}", src)
+            ),
+            "\"TypeError\"",
+            "{}",
+            src
+        );
+    }
+}
+
+#[test]
+fn find_last() {
+    let mut context = Context::new();
+    forward(&mut context, "var array = new Int16Array([1, 2, 3, 4, 5]);");
+
+    assert_eq!(
+        forward(&mut context, "array.findLast(x => x % 2 === 0)"),
+        "4"
+    );
+    assert_eq!(
+        forward(&mut context, "array.findLastIndex(x => x % 2 === 0)"),
+        "3"
+    );
+    assert_eq!(
+        forward(&mut context, "array.findLast(x => x > 5)"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "array.findLastIndex(x => x > 5)"),
+        "-1"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var indices = []; array.findLast((x, i) => { indices.push(i); }); indices.join()"
+        ),
+        "\"4,3,2,1,0\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Int16Array.prototype.findLast.call([1], x => x) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn at() {
+    let mut context = Context::new();
+    forward(&mut context, "var array = new Int16Array([1, -2, 3]);");
+
+    assert_eq!(forward(&mut context, "array.at(0)"), "1");
+    assert_eq!(forward(&mut context, "array.at(-1)"), "3");
+    assert_eq!(forward(&mut context, "array.at(-3)"), "1");
+    assert_eq!(forward(&mut context, "array.at(-4)"), "undefined");
+    assert_eq!(forward(&mut context, "array.at(3)"), "undefined");
+    assert_eq!(forward(&mut context, "array.at(-Infinity)"), "undefined");
+    assert_eq!(forward(&mut context, "array.at('-2.5')"), "-2");
+    assert_eq!(
+        forward(&mut context, "new Float32Array([0.5, 1.5]).at(-1)"),
+        "1.5"
+    );
+}
+
+#[test]
+fn at_detached() {
+    let mut context = Context::new();
+    register_detach(&mut context);
+    let init = r#"
+        var array = new Uint8Array([1, 2, 3]);
+        var other = new Uint8Array([4, 5, 6]);
+        var index = { valueOf() { detach(other.buffer); return -1; } };
+        "#;
+    forward(&mut context, init);
+
+    // Detaching while the index is converted makes the read come back empty.
+    assert_eq!(forward(&mut context, "other.at(index)"), "undefined");
+    assert_eq!(
+        forward(&mut context, "try { other.at(0) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+
+    forward(&mut context, "detach(array.buffer)");
+    assert_eq!(
+        forward(&mut context, "try { array.at(-1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
@@ -0,0 +1,253 @@
+//! This module implements the global `WeakMap` object.
+//!
+//! A `WeakMap` is a collection of key/value pairs whose keys are objects that are held weakly:
+//! an entry doesn't keep its key alive, and is dropped once its key is garbage collected. The
+//! entries are stored inside the key objects, tagged with the [`WeakCollectionId`] of the map.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakmap-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap
+
+use crate::{
+    builtins::{map::add_entries_from_iterable, BuiltIn, JsArgs},
+    context::StandardObjects,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+        WeakCollection, WeakCollectionId,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakMap;
+
+impl BuiltIn for WeakMap {
+    const NAME: &'static str = "WeakMap";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_map_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::delete, "delete", 1)
+        .method(Self::get, "get", 1)
+        .method(Self::has, "has", 1)
+        .method(Self::set, "set", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .into()
+    }
+}
+
+impl WeakMap {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `WeakMap ( [ iterable ] )`
+    ///
+    /// Constructor for `WeakMap` objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap-iterable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/WeakMap
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin WeakMap constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let map be ? OrdinaryCreateFromConstructor(NewTarget, "%WeakMap.prototype%", « [[WeakMapData]] »).
+        // 3. Set map.[[WeakMapData]] to a new empty List.
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardObjects::weak_map_object, context)?;
+        let map = JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::weak_map(WeakCollection::new(context.weak_collections().clone())),
+        );
+
+        // 4. If iterable is either undefined or null, return map.
+        let iterable = match args.get_or_undefined(0) {
+            val if !val.is_null_or_undefined() => val,
+            _ => return Ok(map.into()),
+        };
+
+        // 5. Let adder be ? Get(map, "set").
+        let adder = map.get("set", context)?;
+
+        // 6. Return ? AddEntriesFromIterable(map, iterable, adder).
+        add_entries_from_iterable(&map, iterable, &adder, context)
+    }
+
+    /// Returns the id of the `WeakMap` `this`, or throws a `TypeError` naming `method`.
+    fn this_id(this: &JsValue, method: &str, context: &mut Context) -> JsResult<WeakCollectionId> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_weak_map())
+            .ok_or_else(|| {
+                context.construct_type_error(format!(
+                    "WeakMap.prototype.{} called on incompatible receiver",
+                    method
+                ))
+            })
+    }
+
+    /// `WeakMap.prototype.delete ( key )`
+    ///
+    /// Removes the entry of `key`, returning `true` if there was one.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete
+    pub(crate) fn delete(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let id = Self::this_id(this, "delete", context)?;
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If CanBeHeldWeakly(key) is false, return false.
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
+        //         i. Set p.[[Key]] to empty.
+        //         ii. Set p.[[Value]] to empty.
+        //         iii. Return true.
+        // 6. Return false.
+        Ok(match args.get_or_undefined(0).as_object() {
+            Some(key) => key.borrow_mut().weak_entries_mut().remove(id).into(),
+            None => false.into(),
+        })
+    }
+
+    /// `WeakMap.prototype.get ( key )`
+    ///
+    /// Returns the value associated with `key`, or `undefined` if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get
+    pub(crate) fn get(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let id = Self::this_id(this, "get", context)?;
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If CanBeHeldWeakly(key) is false, return undefined.
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return p.[[Value]].
+        // 6. Return undefined.
+        Ok(args
+            .get_or_undefined(0)
+            .as_object()
+            .and_then(|key| key.borrow().weak_entries().get(id))
+            .unwrap_or_default())
+    }
+
+    /// `WeakMap.prototype.has ( key )`
+    ///
+    /// Returns `true` if there is an entry for `key`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has
+    pub(crate) fn has(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let id = Self::this_id(this, "has", context)?;
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If CanBeHeldWeakly(key) is false, return false.
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return true.
+        // 6. Return false.
+        Ok(args
+            .get_or_undefined(0)
+            .as_object()
+            .map(|key| key.borrow().weak_entries().contains(id))
+            .unwrap_or_default()
+            .into())
+    }
+
+    /// `WeakMap.prototype.set ( key, value )`
+    ///
+    /// Associates `value` with the object `key`, and returns the `WeakMap`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set
+    pub(crate) fn set(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let id = Self::this_id(this, "set", context)?;
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If CanBeHeldWeakly(key) is false, throw a TypeError exception.
+        let key = match args.get_or_undefined(0).as_object() {
+            Some(key) => key,
+            None => return context.throw_type_error("WeakMap key must be an object"),
+        };
+
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
+        //         i. Set p.[[Value]] to value.
+        //         ii. Return M.
+        // 6. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+        // 7. Append p to entries.
+        key.borrow_mut().weak_entries_mut().insert(
+            id,
+            args.get_or_undefined(1).clone(),
+            context.weak_collections(),
+        );
+
+        // 8. Return M.
+        Ok(this.clone())
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{
+    forward,
+    gc::{force_collect, Finalize, Trace},
+    object::{JsObject, ObjectData},
+    property::Attribute,
+    Context,
+};
+use std::{cell::Cell, rc::Rc};
+
+/// A native object that records when it is finalized by the garbage collector.
+#[derive(Debug, Trace)]
+struct Tracked(#[unsafe_ignore_trace] Rc<Cell<bool>>);
+
+impl Finalize for Tracked {
+    fn finalize(&self) {
+        self.0.set(true);
+    }
+}
+
+#[test]
+fn get_set_has_delete() {
+    let mut context = Context::new();
+    let init = r#"
+        var key = {};
+        var other = {};
+        var map = new WeakMap([[key, "value"]]);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "map.get(key)"), "\"value\"");
+    assert_eq!(forward(&mut context, "map.has(key)"), "true");
+    assert_eq!(forward(&mut context, "map.has(other)"), "false");
+    assert_eq!(forward(&mut context, "map.get(other)"), "undefined");
+    assert_eq!(forward(&mut context, "map.set(other, 1) === map"), "true");
+    assert_eq!(forward(&mut context, "map.set(other, 2).get(other)"), "2");
+    assert_eq!(forward(&mut context, "map.delete(key)"), "true");
+    assert_eq!(forward(&mut context, "map.delete(key)"), "false");
+    assert_eq!(forward(&mut context, "map.has(key)"), "false");
+    assert_eq!(forward(&mut context, "new WeakMap().has(other)"), "false");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(map)"),
+        "\"[object WeakMap]\""
+    );
+}
+
+#[test]
+fn non_object_keys() {
+    let mut context = Context::new();
+    forward(&mut context, "var map = new WeakMap();");
+
+    assert_eq!(
+        forward(&mut context, "try { map.set(1, 1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { map.set(Symbol(), 1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new WeakMap([['a', 1]]) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut context, "map.get('a')"), "undefined");
+    assert_eq!(forward(&mut context, "map.has(null)"), "false");
+    assert_eq!(forward(&mut context, "map.delete(1)"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { WeakMap.prototype.has.call(new Map(), {}) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { WeakMap() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn not_iterable() {
+    let mut context = Context::new();
+    forward(&mut context, "var map = new WeakMap();");
+
+    // Which entries are still alive depends on the garbage collector, so they can't be listed.
+    assert_eq!(
+        forward(&mut context, "typeof WeakMap.prototype[Symbol.iterator]"),
+        "\"undefined\""
+    );
+    for member in ["size", "forEach", "keys", "values", "entries", "clear"] {
+        assert_eq!(
+            forward(&mut context, &format!("'{}' in map", member)),
+            "false"
+        );
+    }
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { [...map]; 'iterable' } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn entries_are_collected_with_their_key() {
+    let mut context = Context::new();
+    let collected = Rc::new(Cell::new(false));
+    let value = JsObject::from_proto_and_data(
+        None,
+        ObjectData::native_object(Box::new(Tracked(collected.clone()))),
+    );
+    context.register_global_property("value", value, Attribute::all());
+    let init = r#"
+        var map = new WeakMap();
+        var key = {};
+        map.set(key, value);
+        delete globalThis.value;
+        "#;
+    forward(&mut context, init);
+
+    // The key is still alive, so its entry is kept.
+    force_collect();
+    assert!(!collected.get());
+    assert_eq!(forward(&mut context, "map.has(key)"), "true");
+
+    // Once the key is gone, nothing can reach its entry anymore.
+    forward(&mut context, "key = undefined;");
+    force_collect();
+    assert!(collected.get());
+    assert_eq!(forward(&mut context, "map.has({})"), "false");
+}
+
+#[test]
+fn entries_do_not_keep_their_key_alive() {
+    let mut context = Context::new();
+    let collected = Rc::new(Cell::new(false));
+    let key = JsObject::from_proto_and_data(
+        None,
+        ObjectData::native_object(Box::new(Tracked(collected.clone()))),
+    );
+    context.register_global_property("key", key, Attribute::all());
+    let init = r#"
+        var map = new WeakMap();
+        // The value references the key, which must not keep either of them alive.
+        map.set(key, { key: key });
+        delete globalThis.key;
+        "#;
+    forward(&mut context, init);
+
+    force_collect();
+    assert!(collected.get());
+}
+
+#[test]
+fn values_are_collected_with_their_map() {
+    let mut context = Context::new();
+    let collected = Rc::new(Cell::new(false));
+    let value = JsObject::from_proto_and_data(
+        None,
+        ObjectData::native_object(Box::new(Tracked(collected.clone()))),
+    );
+    context.register_global_property("value", value, Attribute::all());
+    let init = r#"
+        var key = {};
+        var map = new WeakMap([[key, value]]);
+        var other = new WeakMap([[key, "other"]]);
+        delete globalThis.value;
+        "#;
+    forward(&mut context, init);
+
+    force_collect();
+    assert!(!collected.get());
+
+    // The first collection finalizes the map, the next one drops its entry from the key.
+    forward(&mut context, "map = undefined;");
+    force_collect();
+    force_collect();
+    assert!(collected.get());
+    assert_eq!(forward(&mut context, "other.get(key)"), "\"other\"");
+}
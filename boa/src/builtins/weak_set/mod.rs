@@ -0,0 +1,243 @@
+//! This module implements the global `WeakSet` object.
+//!
+//! A `WeakSet` is a collection of objects that are held weakly: being in the set doesn't keep
+//! an object alive, and it's removed from the set once it is garbage collected. The membership
+//! is stored inside the objects themselves, tagged with the [`WeakCollectionId`] of the set.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakset-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet
+
+use crate::{
+    builtins::{BuiltIn, JsArgs},
+    context::StandardObjects,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+        WeakCollection, WeakCollectionId,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakSet;
+
+impl BuiltIn for WeakSet {
+    const NAME: &'static str = "WeakSet";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_set_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::add, "add", 1)
+        .method(Self::delete, "delete", 1)
+        .method(Self::has, "has", 1)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .into()
+    }
+}
+
+impl WeakSet {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `WeakSet ( [ iterable ] )`
+    ///
+    /// Constructor for `WeakSet` objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset-iterable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/WeakSet
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin WeakSet constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let set be ? OrdinaryCreateFromConstructor(NewTarget, "%WeakSet.prototype%", « [[WeakSetData]] »).
+        // 3. Set set.[[WeakSetData]] to a new empty List.
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardObjects::weak_set_object, context)?;
+        let set = JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::weak_set(WeakCollection::new(context.weak_collections().clone())),
+        );
+
+        // 4. If iterable is either undefined or null, return set.
+        let iterable = args.get_or_undefined(0);
+        if iterable.is_null_or_undefined() {
+            return Ok(set.into());
+        }
+
+        // 5. Let adder be ? Get(set, "add").
+        // 6. If IsCallable(adder) is false, throw a TypeError exception.
+        let adder = set.get("add", context)?;
+        let adder = adder.as_callable().ok_or_else(|| {
+            context.construct_type_error("property `add` of `NewTarget` is not callable")
+        })?;
+
+        // 7. Let iteratorRecord be ? GetIterator(iterable).
+        let iterator_record = iterable.get_iterator(context, None, None)?;
+
+        // 8. Repeat,
+        loop {
+            // a. Let next be ? IteratorStep(iteratorRecord).
+            let next = iterator_record.next(context)?;
+
+            // b. If next is false, return set.
+            if next.done {
+                return Ok(set.into());
+            }
+
+            // c. Let nextValue be ? IteratorValue(next).
+            // d. Let status be Call(adder, set, « nextValue »).
+            // e. IfAbruptCloseIterator(status, iteratorRecord).
+            let status = adder.call(&set.clone().into(), &[next.value], context);
+            if status.is_err() {
+                return iterator_record.close(status, context);
+            }
+        }
+    }
+
+    /// Returns the id of the `WeakSet` `this`, or throws a `TypeError` naming `method`.
+    fn this_id(this: &JsValue, method: &str, context: &mut Context) -> JsResult<WeakCollectionId> {
+        // 1. Let S be the this value.
+        // 2. Perform ? RequireInternalSlot(S, [[WeakSetData]]).
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_weak_set())
+            .ok_or_else(|| {
+                context.construct_type_error(format!(
+                    "WeakSet.prototype.{} called on incompatible receiver",
+                    method
+                ))
+            })
+    }
+
+    /// `WeakSet.prototype.add ( value )`
+    ///
+    /// Adds the object `value` to the set, and returns the `WeakSet`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/add
+    pub(crate) fn add(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let id = Self::this_id(this, "add", context)?;
+
+        // 3. If CanBeHeldWeakly(value) is false, throw a TypeError exception.
+        let value = match args.get_or_undefined(0).as_object() {
+            Some(value) => value,
+            None => return context.throw_type_error("WeakSet value must be an object"),
+        };
+
+        // 4. Let entries be the List that is S.[[WeakSetData]].
+        // 5. For each element e of entries, do
+        //     a. If e is not empty and SameValue(e, value) is true, then
+        //         i. Return S.
+        // 6. Append value to entries.
+        value.borrow_mut().weak_entries_mut().insert(
+            id,
+            JsValue::undefined(),
+            context.weak_collections(),
+        );
+
+        // 7. Return S.
+        Ok(this.clone())
+    }
+
+    /// `WeakSet.prototype.delete ( value )`
+    ///
+    /// Removes `value` from the set, returning `true` if it was in it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/delete
+    pub(crate) fn delete(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let id = Self::this_id(this, "delete", context)?;
+
+        // 3. If CanBeHeldWeakly(value) is false, return false.
+        // 4. Let entries be the List that is S.[[WeakSetData]].
+        // 5. For each element e of entries, do
+        //     a. If e is not empty and SameValue(e, value) is true, then
+        //         i. Replace the element of entries whose value is e with an element whose value is empty.
+        //         ii. Return true.
+        // 6. Return false.
+        Ok(match args.get_or_undefined(0).as_object() {
+            Some(value) => value.borrow_mut().weak_entries_mut().remove(id).into(),
+            None => false.into(),
+        })
+    }
+
+    /// `WeakSet.prototype.has ( value )`
+    ///
+    /// Returns `true` if `value` is in the set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/has
+    pub(crate) fn has(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let id = Self::this_id(this, "has", context)?;
+
+        // 3. Let entries be the List that is S.[[WeakSetData]].
+        // 4. If CanBeHeldWeakly(value) is false, return false.
+        // 5. For each element e of entries, do
+        //     a. If e is not empty and SameValue(e, value) is true, return true.
+        // 6. Return false.
+        Ok(args
+            .get_or_undefined(0)
+            .as_object()
+            .map(|value| value.borrow().weak_entries().contains(id))
+            .unwrap_or_default()
+            .into())
+    }
+}
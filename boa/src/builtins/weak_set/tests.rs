@@ -0,0 +1,173 @@
+use crate::{
+    forward,
+    gc::{force_collect, Finalize, GcStrategy, Trace},
+    object::{JsObject, ObjectData},
+    property::Attribute,
+    Context,
+};
+use std::{cell::Cell, rc::Rc};
+
+/// A native object that records when it is finalized by the garbage collector.
+#[derive(Debug, Trace)]
+struct Tracked(#[unsafe_ignore_trace] Rc<Cell<bool>>);
+
+impl Finalize for Tracked {
+    fn finalize(&self) {
+        self.0.set(true);
+    }
+}
+
+#[test]
+fn add_has_delete() {
+    let mut context = Context::new();
+    let init = r#"
+        var a = {};
+        var b = [];
+        var set = new WeakSet([a]);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "set.has(a)"), "true");
+    assert_eq!(forward(&mut context, "set.has(b)"), "false");
+    assert_eq!(forward(&mut context, "set.add(b) === set"), "true");
+    assert_eq!(forward(&mut context, "set.has(b)"), "true");
+    assert_eq!(forward(&mut context, "set.delete(a)"), "true");
+    assert_eq!(forward(&mut context, "set.delete(a)"), "false");
+    assert_eq!(forward(&mut context, "set.has(a)"), "false");
+    assert_eq!(forward(&mut context, "new WeakSet().has(b)"), "false");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(set)"),
+        "\"[object WeakSet]\""
+    );
+}
+
+#[test]
+fn non_object_values() {
+    let mut context = Context::new();
+    forward(&mut context, "var set = new WeakSet();");
+
+    assert_eq!(
+        forward(&mut context, "try { set.add('a') } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new WeakSet([1]) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut context, "set.has(1)"), "false");
+    assert_eq!(forward(&mut context, "set.delete(undefined)"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { WeakSet.prototype.add.call(new WeakMap(), {}) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn not_iterable() {
+    let mut context = Context::new();
+    forward(&mut context, "var set = new WeakSet();");
+
+    assert_eq!(
+        forward(&mut context, "typeof WeakSet.prototype[Symbol.iterator]"),
+        "\"undefined\""
+    );
+    for member in ["size", "forEach", "keys", "values", "entries", "clear"] {
+        assert_eq!(
+            forward(&mut context, &format!("'{}' in set", member)),
+            "false"
+        );
+    }
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { [...set]; 'iterable' } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn values_are_held_weakly() {
+    let mut context = Context::new();
+    let collected = Rc::new(Cell::new(false));
+    let value = JsObject::from_proto_and_data(
+        None,
+        ObjectData::native_object(Box::new(Tracked(collected.clone()))),
+    );
+    context.register_global_property("value", value, Attribute::all());
+    forward(&mut context, "var set = new WeakSet([value]);");
+
+    force_collect();
+    assert!(!collected.get());
+    assert_eq!(forward(&mut context, "set.has(value)"), "true");
+
+    forward(&mut context, "delete globalThis.value;");
+    force_collect();
+    assert!(collected.get());
+}
+
+/// Creates a context with the given strategy and a global `value` held weakly by the global
+/// `set`. The returned flag is set once `value` is finalized.
+///
+/// There is no `WeakRef` in the engine yet, so a `WeakSet` stands in for the weak holder.
+fn weakly_held_value(strategy: GcStrategy) -> (Context, Rc<Cell<bool>>) {
+    let mut context = Context::with_gc_strategy(strategy);
+    let collected = Rc::new(Cell::new(false));
+    let value = JsObject::from_proto_and_data(
+        None,
+        ObjectData::native_object(Box::new(Tracked(collected.clone()))),
+    );
+    context.register_global_property("value", value, Attribute::all());
+    forward(&mut context, "var set = new WeakSet([value]);");
+    (context, collected)
+}
+
+#[test]
+fn incremental_strategy_collects_after_jobs() {
+    let (mut context, collected) = weakly_held_value(GcStrategy::Incremental);
+
+    context.run_jobs().unwrap();
+    assert!(!collected.get());
+    assert_eq!(forward(&mut context, "set.has(value)"), "true");
+
+    forward(
+        &mut context,
+        "Promise.resolve().then(() => { delete globalThis.value; });",
+    );
+    context.run_jobs().unwrap();
+    assert!(collected.get());
+}
+
+#[cfg(feature = "timers")]
+#[test]
+fn incremental_strategy_collects_after_timers() {
+    let (mut context, collected) = weakly_held_value(GcStrategy::Incremental);
+
+    forward(
+        &mut context,
+        "setTimeout(() => { delete globalThis.value; }, 0);",
+    );
+    assert_eq!(context.run_timers(0).unwrap(), 1);
+    assert!(collected.get());
+}
+
+#[test]
+fn stop_the_world_strategy_leaves_collection_to_the_allocator() {
+    let (mut context, collected) = weakly_held_value(GcStrategy::StopTheWorld);
+
+    forward(
+        &mut context,
+        "Promise.resolve().then(() => { delete globalThis.value; });",
+    );
+    context.run_jobs().unwrap();
+    assert!(!collected.get());
+
+    force_collect();
+    assert!(collected.get());
+}
@@ -4,8 +4,9 @@ use crate::{
     builtins::function::ThisMode,
     syntax::ast::{
         node::{
-            Declaration, GetConstField, GetField, MethodDefinitionKind, PropertyDefinition,
-            PropertyName, StatementList,
+            declaration::BindingPatternTypeObject, Block, Call, Declaration, DeclarationPattern,
+            GetConstField, GetField, MethodDefinitionKind, PropertyDefinition, PropertyName,
+            StatementList,
         },
         op::{AssignOp, BinOp, BitOp, CompOp, LogOp, NumOp, UnaryOp},
         Const, Node,
@@ -13,7 +14,7 @@ use crate::{
     vm::{CodeBlock, Opcode},
     JsBigInt, JsString, JsValue,
 };
-use std::collections::HashMap;
+use std::{collections::HashMap, mem::size_of};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum Literal {
@@ -33,6 +34,17 @@ struct JumpControlInfo {
     start_address: u32,
     is_loop: bool,
     breaks: Vec<Label>,
+    /// The number of scopes that a jump out of the loop or switch has to exit.
+    scope_depth: usize,
+}
+
+/// A scope that has to be exited before jumping out of it with `break` or `continue`.
+#[derive(Debug, Clone)]
+enum ExitScope {
+    /// A `try` block, or a `catch` block with a `finally` block after it.
+    Try { finally: Option<Block> },
+    /// A block with its own environment.
+    Environment,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -50,6 +62,7 @@ pub struct ByteCompiler {
     names_map: HashMap<JsString, u32>,
     functions_map: HashMap<JsString, u32>,
     jump_info: Vec<JumpControlInfo>,
+    exit_scopes: Vec<ExitScope>,
     top_level: bool,
 }
 
@@ -65,6 +78,7 @@ impl ByteCompiler {
             names_map: HashMap::new(),
             functions_map: HashMap::new(),
             jump_info: Vec::new(),
+            exit_scopes: Vec::new(),
             top_level: true,
         }
     }
@@ -233,6 +247,7 @@ impl ByteCompiler {
             start_address,
             is_loop: true,
             breaks: Vec::new(),
+            scope_depth: self.exit_scopes.len(),
         })
     }
 
@@ -254,6 +269,7 @@ impl ByteCompiler {
             start_address,
             is_loop: false,
             breaks: Vec::new(),
+            scope_depth: self.exit_scopes.len(),
         })
     }
 
@@ -268,6 +284,31 @@ impl ByteCompiler {
         }
     }
 
+    /// Exits the scopes entered since `depth`, before a jump out of them.
+    ///
+    /// This ends the `try` statements, running their `finally` blocks, and pops the
+    /// environments of the blocks.
+    fn compile_scope_exits(&mut self, depth: usize) {
+        let scopes = self.exit_scopes.split_off(depth);
+        for (i, scope) in scopes.iter().enumerate().rev() {
+            match scope {
+                ExitScope::Try { finally } => {
+                    self.emit_opcode(Opcode::TryEnd);
+                    if let Some(finally) = finally {
+                        // The `finally` block is only in the scopes outside of it.
+                        self.exit_scopes.extend_from_slice(&scopes[..i]);
+                        for node in finally.items() {
+                            self.compile_stmt(node, false);
+                        }
+                        self.exit_scopes.truncate(depth);
+                    }
+                }
+                ExitScope::Environment => self.emit_opcode(Opcode::PopEnvironment),
+            }
+        }
+        self.exit_scopes.extend(scopes);
+    }
+
     #[inline]
     fn compile_access<'a>(&mut self, node: &'a Node) -> Access<'a> {
         match node {
@@ -337,6 +378,75 @@ impl ByteCompiler {
     }
 
     #[inline]
+    /// Binds the identifiers of `pattern` to the parts of the value on top of the stack,
+    /// declaring them with `def`, and pops the value.
+    fn compile_declaration_pattern(&mut self, pattern: &DeclarationPattern, def: Opcode) {
+        match pattern {
+            DeclarationPattern::Object(pattern) => {
+                for binding in pattern.bindings() {
+                    match binding {
+                        BindingPatternTypeObject::Empty => {}
+                        BindingPatternTypeObject::SingleName {
+                            ident,
+                            property_name,
+                            default_init,
+                        } => {
+                            self.emit_opcode(Opcode::Dup);
+                            let index = self.get_or_insert_name(property_name);
+                            self.emit(Opcode::GetPropertyByName, &[index]);
+                            if let Some(init) = default_init {
+                                self.compile_pattern_default(init);
+                            }
+
+                            let index = self.get_or_insert_name(ident);
+                            self.emit(def, &[index]);
+                            self.emit(Opcode::InitLexical, &[index]);
+                        }
+                        BindingPatternTypeObject::BindingPattern {
+                            ident: property_name,
+                            pattern,
+                            default_init,
+                        } => {
+                            self.emit_opcode(Opcode::Dup);
+                            let index = self.get_or_insert_name(property_name);
+                            self.emit(Opcode::GetPropertyByName, &[index]);
+                            if let Some(init) = default_init {
+                                self.compile_pattern_default(init);
+                            }
+
+                            self.compile_declaration_pattern(pattern, def);
+                        }
+                        BindingPatternTypeObject::RestProperty { .. } => {
+                            self.emit_push_literal(Literal::String(
+                                "rest properties in binding patterns are not supported yet".into(),
+                            ));
+                            self.emit_opcode(Opcode::ThrowSyntaxError);
+                        }
+                    }
+                }
+                self.emit_opcode(Opcode::Pop);
+            }
+            DeclarationPattern::Array(_) => {
+                self.emit_opcode(Opcode::Pop);
+                self.emit_push_literal(Literal::String(
+                    "array binding patterns are not supported yet".into(),
+                ));
+                self.emit_opcode(Opcode::ThrowSyntaxError);
+            }
+        }
+    }
+
+    /// Replaces the value on top of the stack with the value of `init` if it is `undefined`.
+    fn compile_pattern_default(&mut self, init: &Node) {
+        self.emit_opcode(Opcode::Dup);
+        self.emit_opcode(Opcode::PushUndefined);
+        self.emit_opcode(Opcode::StrictEq);
+        let skip = self.jump_if_false();
+        self.emit_opcode(Opcode::Pop);
+        self.compile_expr(init, true);
+        self.patch_jump(skip);
+    }
+
     pub fn compile_statement_list(&mut self, list: &StatementList, use_expr: bool) {
         for (i, node) in list.items().iter().enumerate() {
             if i + 1 == list.items().len() {
@@ -348,6 +458,68 @@ impl ByteCompiler {
         }
     }
 
+    /// Compiles a script that returns its completion value, like the code of an `eval`.
+    ///
+    /// The completion value is kept at the bottom of the stack of the frame, and replaced by the
+    /// value of every expression statement.
+    pub fn compile_script(&mut self, list: &StatementList) {
+        self.emit_opcode(Opcode::PushUndefined);
+        for node in list.items() {
+            self.compile_completion(node);
+        }
+        self.emit_opcode(Opcode::Return);
+    }
+
+    /// Compiles a statement of a script, updating its completion value.
+    fn compile_completion(&mut self, node: &Node) {
+        match node {
+            Node::Block(block) => {
+                for node in block.items() {
+                    self.compile_completion(node);
+                }
+            }
+            Node::If(node) => {
+                // An `if` statement is `undefined` unless its branch has a value.
+                self.emit_opcode(Opcode::Pop);
+                self.emit_opcode(Opcode::PushUndefined);
+
+                self.compile_expr(node.cond(), true);
+                let jelse = self.jump_if_false();
+                self.compile_completion(node.body());
+
+                match node.else_node() {
+                    None => self.patch_jump(jelse),
+                    Some(else_body) => {
+                        let exit = self.jump();
+                        self.patch_jump(jelse);
+                        self.compile_completion(else_body);
+                        self.patch_jump(exit);
+                    }
+                }
+            }
+            Node::VarDeclList(_)
+            | Node::LetDeclList(_)
+            | Node::ConstDeclList(_)
+            | Node::WhileLoop(_)
+            | Node::DoWhileLoop(_)
+            | Node::Continue(_)
+            | Node::Break(_)
+            | Node::Throw(_)
+            | Node::Try(_)
+            | Node::Switch(_)
+            | Node::FunctionDecl(_)
+            | Node::AsyncFunctionDecl(_)
+            | Node::GeneratorDecl(_)
+            | Node::Return(_)
+            | Node::Empty => self.compile_stmt(node, false),
+            expr => {
+                self.compile_expr(expr, true);
+                self.emit_opcode(Opcode::Swap);
+                self.emit_opcode(Opcode::Pop);
+            }
+        }
+    }
+
     #[inline]
     pub fn compile_expr(&mut self, expr: &Node, use_expr: bool) {
         match expr {
@@ -374,7 +546,7 @@ impl ByteCompiler {
                         self.emit(Opcode::Inc, &[]);
 
                         let access = self.compile_access(unary.target());
-                        self.access_set(access, None, use_expr);
+                        self.access_set(access, None, true);
                         None
                     }
                     UnaryOp::DecrementPre => {
@@ -382,7 +554,7 @@ impl ByteCompiler {
                         self.emit(Opcode::Dec, &[]);
 
                         let access = self.compile_access(unary.target());
-                        self.access_set(access, None, use_expr);
+                        self.access_set(access, None, true);
                         None
                     }
                     UnaryOp::IncrementPost => {
@@ -392,10 +564,6 @@ impl ByteCompiler {
                         let access = self.compile_access(unary.target());
                         self.access_set(access, None, false);
 
-                        if !use_expr {
-                            self.emit(Opcode::Pop, &[]);
-                        }
-
                         None
                     }
                     UnaryOp::DecrementPost => {
@@ -405,10 +573,6 @@ impl ByteCompiler {
                         let access = self.compile_access(unary.target());
                         self.access_set(access, None, false);
 
-                        if !use_expr {
-                            self.emit(Opcode::Pop, &[]);
-                        }
-
                         None
                     }
                     UnaryOp::Delete => match unary.target() {
@@ -508,13 +672,11 @@ impl ByteCompiler {
                             LogOp::And => {
                                 let exit = self.jump_with_custom_opcode(Opcode::LogicalAnd);
                                 self.compile_expr(binary.rhs(), true);
-                                self.emit(Opcode::ToBoolean, &[]);
                                 self.patch_jump(exit);
                             }
                             LogOp::Or => {
                                 let exit = self.jump_with_custom_opcode(Opcode::LogicalOr);
                                 self.compile_expr(binary.rhs(), true);
-                                self.emit(Opcode::ToBoolean, &[]);
                                 self.patch_jump(exit);
                             }
                             LogOp::Coalesce => {
@@ -545,7 +707,6 @@ impl ByteCompiler {
                             AssignOp::BoolAnd => {
                                 let exit = self.jump_with_custom_opcode(Opcode::LogicalAnd);
                                 self.compile_expr(binary.rhs(), true);
-                                self.emit(Opcode::ToBoolean, &[]);
                                 self.patch_jump(exit);
 
                                 None
@@ -553,7 +714,6 @@ impl ByteCompiler {
                             AssignOp::BoolOr => {
                                 let exit = self.jump_with_custom_opcode(Opcode::LogicalOr);
                                 self.compile_expr(binary.rhs(), true);
-                                self.emit(Opcode::ToBoolean, &[]);
                                 self.patch_jump(exit);
 
                                 None
@@ -766,30 +926,42 @@ impl ByteCompiler {
             }
             Node::FunctionExpr(_function) => self.function(expr, use_expr),
             Node::ArrowFunctionDecl(_function) => self.function(expr, use_expr),
-            Node::Call(call) => {
-                for arg in call.args().iter().rev() {
-                    self.compile_expr(arg, true);
+            Node::AsyncFunctionExpr(_function) => self.function(expr, use_expr),
+            Node::AsyncArrowFunctionDecl(_function) => self.function(expr, use_expr),
+            Node::GeneratorExpr(_function) => self.function(expr, use_expr),
+            Node::Yield(r#yield) => {
+                match r#yield.expr() {
+                    Some(expr) => self.compile_expr(expr, true),
+                    None => self.emit_opcode(Opcode::PushUndefined),
                 }
-                match call.expr() {
-                    Node::GetConstField(field) => {
-                        self.compile_expr(field.obj(), true);
-                        self.emit(Opcode::Dup, &[]);
-                        let index = self.get_or_insert_name(field.field());
-                        self.emit(Opcode::GetPropertyByName, &[index]);
-                    }
-                    Node::GetField(field) => {
-                        self.compile_expr(field.obj(), true);
-                        self.emit(Opcode::Dup, &[]);
-                        self.compile_expr(field.field(), true);
-                        self.emit(Opcode::Swap, &[]);
-                        self.emit(Opcode::GetPropertyByValue, &[]);
-                    }
-                    expr => {
-                        self.emit(Opcode::This, &[]);
-                        self.compile_expr(expr, true);
-                    }
+
+                if r#yield.delegate() {
+                    self.emit_opcode(Opcode::InitIterator);
+                    self.emit_opcode(Opcode::PushUndefined);
+                    let start_address = self.next_opcode_location();
+                    let exit = self.jump_with_custom_opcode(Opcode::GeneratorNextDelegate);
+                    self.emit(Opcode::Jump, &[start_address]);
+                    self.patch_jump(exit);
+                } else {
+                    self.emit_opcode(Opcode::Yield);
+                    self.emit_opcode(Opcode::GeneratorNext);
+                }
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::AwaitExpr(expr) => {
+                self.compile_expr(expr.expr(), true);
+                self.emit(Opcode::Await, &[]);
+                self.emit_opcode(Opcode::GeneratorNext);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
                 }
-                self.emit(Opcode::Call, &[call.args().len() as u32]);
+            }
+            Node::Call(call) => {
+                self.compile_call(call, false);
 
                 if !use_expr {
                     self.emit(Opcode::Pop, &[]);
@@ -799,6 +971,83 @@ impl ByteCompiler {
         }
     }
 
+    /// Compiles a call, leaving its result on the stack.
+    ///
+    /// A call in `tail` position reuses the frame of the running function when it can.
+    fn compile_call(&mut self, call: &Call, tail: bool) {
+        for arg in call.args().iter().rev() {
+            self.compile_expr(arg, true);
+        }
+        match call.expr() {
+            Node::GetConstField(field) => {
+                self.compile_expr(field.obj(), true);
+                self.emit(Opcode::Dup, &[]);
+                let index = self.get_or_insert_name(field.field());
+                self.emit(Opcode::GetPropertyByName, &[index]);
+            }
+            Node::GetField(field) => {
+                self.compile_expr(field.obj(), true);
+                self.emit(Opcode::Dup, &[]);
+                self.compile_expr(field.field(), true);
+                self.emit(Opcode::Swap, &[]);
+                self.emit(Opcode::GetPropertyByValue, &[]);
+            }
+            expr => {
+                self.emit(Opcode::This, &[]);
+                self.compile_expr(expr, true);
+            }
+        }
+
+        // A call through the plain `eval` name may be a direct eval, which only the VM can
+        // tell once it knows the function. A direct eval runs in the scope of the caller, so
+        // it can't reuse its frame.
+        let opcode = match call.expr() {
+            Node::Identifier(ident) if ident.as_ref() == "eval" => Opcode::CallEval,
+            _ if tail => Opcode::TailCall,
+            _ => Opcode::Call,
+        };
+        self.emit(opcode, &[call.args().len() as u32]);
+    }
+
+    /// Compiles the expression of a `return` statement, with its calls in tail position.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-hascallintailposition
+    fn compile_tail_expr(&mut self, expr: &Node) {
+        match expr {
+            Node::Call(call) => self.compile_call(call, true),
+            Node::ConditionalOp(op) => {
+                self.compile_expr(op.cond(), true);
+                let jelse = self.jump_if_false();
+                self.compile_tail_expr(op.if_true());
+                let exit = self.jump();
+                self.patch_jump(jelse);
+                self.compile_tail_expr(op.if_false());
+                self.patch_jump(exit);
+            }
+            Node::BinOp(binary) => match binary.op() {
+                BinOp::Log(op) => {
+                    self.compile_expr(binary.lhs(), true);
+                    let exit = match op {
+                        LogOp::And => self.jump_with_custom_opcode(Opcode::LogicalAnd),
+                        LogOp::Or => self.jump_with_custom_opcode(Opcode::LogicalOr),
+                        LogOp::Coalesce => self.jump_with_custom_opcode(Opcode::Coalesce),
+                    };
+                    self.compile_tail_expr(binary.rhs());
+                    self.patch_jump(exit);
+                }
+                BinOp::Comma => {
+                    self.compile_expr(binary.lhs(), false);
+                    self.compile_tail_expr(binary.rhs());
+                }
+                _ => self.compile_expr(expr, true),
+            },
+            expr => self.compile_expr(expr, true),
+        }
+    }
+
     #[inline]
     pub fn compile_stmt(&mut self, node: &Node, use_expr: bool) {
         match node {
@@ -922,30 +1171,32 @@ impl ByteCompiler {
                 self.pop_loop_control_info();
             }
             Node::Continue(node) => {
-                let label = self.jump();
-                let mut items = self.jump_info.iter_mut().rev().filter(|info| info.is_loop);
+                let mut items = self.jump_info.iter().rev().filter(|info| info.is_loop);
                 let target = if node.label().is_none() {
                     items.next()
                 } else {
                     items.find(|info| info.label.as_deref() == node.label())
                 }
-                .expect("continue target")
-                .start_address;
+                .expect("continue target");
+                let (target, scope_depth) = (target.start_address, target.scope_depth);
 
+                self.compile_scope_exits(scope_depth);
+                let label = self.jump();
                 self.patch_jump_with_target(label, target);
             }
             Node::Break(node) => {
-                let label = self.jump();
-                if node.label().is_none() {
-                    self.jump_info.last_mut().unwrap().breaks.push(label);
+                let index = if node.label().is_none() {
+                    self.jump_info.len() - 1
                 } else {
-                    for info in self.jump_info.iter_mut().rev() {
-                        if info.label.as_deref() == node.label() {
-                            info.breaks.push(label);
-                            break;
-                        }
-                    }
-                }
+                    self.jump_info
+                        .iter()
+                        .rposition(|info| info.label.as_deref() == node.label())
+                        .expect("break target")
+                };
+
+                self.compile_scope_exits(self.jump_info[index].scope_depth);
+                let label = self.jump();
+                self.jump_info[index].breaks.push(label);
             }
             Node::Block(block) => {
                 for node in block.items() {
@@ -956,6 +1207,84 @@ impl ByteCompiler {
                 self.compile_expr(throw.expr(), true);
                 self.emit(Opcode::Throw, &[]);
             }
+            Node::Try(t) => {
+                let start = self.jump_with_custom_opcode(Opcode::TryStart);
+                // `TryStart` has a second operand, for the address of the `finally` block.
+                self.emit_u32(0);
+                let finally_address = Label {
+                    index: start.index + size_of::<u32>() as u32,
+                };
+
+                self.exit_scopes.push(ExitScope::Try {
+                    finally: t.finally().cloned(),
+                });
+                for node in t.block().items() {
+                    self.compile_stmt(node, false);
+                }
+                self.exit_scopes.pop();
+                self.emit_opcode(Opcode::TryEnd);
+
+                let mut exits = vec![self.jump()];
+
+                if let Some(catch) = t.catch() {
+                    self.patch_jump(start);
+
+                    if t.finally().is_some() {
+                        self.exit_scopes.push(ExitScope::Try {
+                            finally: t.finally().cloned(),
+                        });
+                    }
+
+                    self.emit_opcode(Opcode::PushDeclarativeEnvironment);
+                    self.exit_scopes.push(ExitScope::Environment);
+                    match catch.parameter() {
+                        Some(Declaration::Identifier { ident, .. }) => {
+                            let index = self.get_or_insert_name(ident.as_ref());
+                            self.emit(Opcode::DefLet, &[index]);
+                            self.emit(Opcode::InitLexical, &[index]);
+                        }
+                        Some(Declaration::Pattern(pattern)) => {
+                            self.compile_declaration_pattern(pattern, Opcode::DefLet);
+                        }
+                        None => self.emit_opcode(Opcode::Pop),
+                    }
+                    for node in catch.block().items() {
+                        self.compile_stmt(node, false);
+                    }
+                    self.exit_scopes.pop();
+                    self.emit_opcode(Opcode::PopEnvironment);
+
+                    if t.finally().is_some() {
+                        self.exit_scopes.pop();
+                        self.emit_opcode(Opcode::TryEnd);
+                    }
+
+                    exits.push(self.jump());
+                } else {
+                    // A `try` statement without a `catch` block has no catch address.
+                    self.patch_jump_with_target(start, 0);
+                }
+
+                if let Some(finally) = t.finally() {
+                    // The copy of the `finally` block that runs after an exception or a
+                    // return, and throws or returns again.
+                    self.patch_jump(finally_address);
+                    for node in finally.items() {
+                        self.compile_stmt(node, false);
+                    }
+                    self.emit_opcode(Opcode::FinallyEnd);
+                }
+
+                for label in exits {
+                    self.patch_jump(label);
+                }
+
+                if let Some(finally) = t.finally() {
+                    for node in finally.items() {
+                        self.compile_stmt(node, false);
+                    }
+                }
+            }
             Node::Switch(switch) => {
                 let start_address = self.next_opcode_location();
                 self.push_switch_control_info(None, start_address);
@@ -984,11 +1313,21 @@ impl ByteCompiler {
                 self.pop_switch_control_info();
             }
             Node::FunctionDecl(_function) => self.function(node, false),
+            Node::AsyncFunctionDecl(_function) => self.function(node, false),
+            Node::GeneratorDecl(_function) => self.function(node, false),
             Node::Return(ret) => {
-                if let Some(expr) = ret.expr() {
-                    self.compile_expr(expr, true);
-                } else {
-                    self.emit(Opcode::PushUndefined, &[]);
+                // Calls in tail position of strict mode code reuse the frame of the function,
+                // unless the function has more to do after they return.
+                let tail_calls = self.code_block.strict
+                    && !self.top_level
+                    && !self.code_block.is_async
+                    && !self.code_block.is_generator
+                    && self.exit_scopes.is_empty();
+
+                match ret.expr() {
+                    Some(expr) if tail_calls => self.compile_tail_expr(expr),
+                    Some(expr) => self.compile_expr(expr, true),
+                    None => self.emit(Opcode::PushUndefined, &[]),
                 }
                 self.emit(Opcode::Return, &[]);
             }
@@ -1005,46 +1344,119 @@ impl ByteCompiler {
             Arrow,
         }
 
-        let (kind, name, parameters, body) = match function {
+        let (kind, is_async, is_generator, name, parameters, body) = match function {
             Node::FunctionDecl(function) => (
                 FunctionKind::Declaration,
+                false,
+                false,
                 Some(function.name()),
                 function.parameters(),
-                function.body(),
+                function.body().items(),
             ),
             Node::FunctionExpr(function) => (
                 FunctionKind::Expression,
+                false,
+                false,
                 function.name(),
                 function.parameters(),
-                function.body(),
+                function.body().items(),
             ),
             Node::ArrowFunctionDecl(function) => (
                 FunctionKind::Arrow,
+                false,
+                false,
                 None,
                 function.params(),
-                function.body(),
+                function.body().items(),
+            ),
+            Node::AsyncFunctionDecl(function) => (
+                FunctionKind::Declaration,
+                true,
+                false,
+                Some(function.name()),
+                function.parameters(),
+                function.body().items(),
+            ),
+            Node::AsyncFunctionExpr(function) => (
+                FunctionKind::Expression,
+                true,
+                false,
+                function.name(),
+                function.parameters(),
+                function.body().items(),
+            ),
+            Node::AsyncArrowFunctionDecl(function) => (
+                FunctionKind::Arrow,
+                true,
+                false,
+                None,
+                function.params(),
+                function.body().items(),
+            ),
+            Node::GeneratorDecl(function) => (
+                FunctionKind::Declaration,
+                false,
+                true,
+                Some(function.name()),
+                function.parameters(),
+                function.body().items(),
+            ),
+            Node::GeneratorExpr(function) => (
+                FunctionKind::Expression,
+                false,
+                true,
+                function.name(),
+                function.parameters(),
+                function.body().items(),
             ),
             _ => unreachable!(),
         };
 
+        // A function is strict if its body is, or if it is defined in strict code.
+        let strict = self.code_block.strict
+            || match function {
+                Node::FunctionDecl(function) => function.body().strict(),
+                Node::FunctionExpr(function) => function.body().strict(),
+                Node::ArrowFunctionDecl(function) => function.body().strict(),
+                Node::AsyncFunctionDecl(function) => function.body().strict(),
+                Node::AsyncFunctionExpr(function) => function.body().strict(),
+                Node::AsyncArrowFunctionDecl(function) => function.body().strict(),
+                Node::GeneratorDecl(function) => function.body().strict(),
+                Node::GeneratorExpr(function) => function.body().strict(),
+                _ => unreachable!(),
+            };
+
         let length = parameters.len() as u32;
-        let mut code = CodeBlock::new(name.unwrap_or("").into(), length, false, true);
+        let mut code = CodeBlock::new(name.unwrap_or("").into(), length, strict, true);
 
         if let FunctionKind::Arrow = kind {
             code.constructor = false;
             code.this_mode = ThisMode::Lexical;
         }
 
+        // Async functions are not constructors, their calls return a promise instead.
+        if is_async {
+            code.constructor = false;
+            code.is_async = true;
+        }
+
+        // Generator functions are not constructors either, their calls return a generator.
+        if is_generator {
+            code.constructor = false;
+            code.is_generator = true;
+        }
+
         let mut compiler = ByteCompiler {
             code_block: code,
             literals_map: HashMap::new(),
             names_map: HashMap::new(),
             functions_map: HashMap::new(),
             jump_info: Vec::new(),
+            exit_scopes: Vec::new(),
             top_level: false,
         };
 
-        for node in body.items() {
+        for node in body {
             compiler.compile_stmt(node, false);
         }
 
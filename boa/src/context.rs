@@ -3,22 +3,26 @@
 use crate::{
     builtins::{
         self,
-        function::{Function, NativeFunctionSignature, ThisMode},
+        error::CustomError,
+        function::{Captures, Function, NativeFunctionSignature, ThisMode},
         intrinsics::IntrinsicObjects,
         iterable::IteratorPrototypes,
         typed_array::TypedArray,
     },
     class::{Class, ClassBuilder},
     exec::Interpreter,
+    gc::{force_collect, GcStrategy},
+    host::{DefaultHooks, HostHooks},
+    job::{FutureJob, NativeJob},
     object::PROTOTYPE,
-    object::{FunctionBuilder, JsObject, ObjectData},
+    object::{FunctionBuilder, JsObject, NativeObject, ObjectData, WeakCollections},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
     syntax::{
         ast::{
             node::{
-                statement_list::RcStatementList, Call, FormalParameter, Identifier, New,
-                StatementList,
+                statement_list::RcStatementList, Call, FormalParameter, FunctionDecl, Identifier,
+                New, StatementList,
             },
             Const, Node,
         },
@@ -26,10 +30,19 @@ use crate::{
     },
     BoaProfiler, Executable, JsResult, JsString, JsValue,
 };
+use std::{
+    collections::VecDeque,
+    rc::Rc,
+    task::Poll,
+    time::{Duration, Instant},
+};
 
 #[cfg(feature = "console")]
 use crate::builtins::console::Console;
 
+#[cfg(feature = "timers")]
+use crate::builtins::timers::Timers;
+
 #[cfg(feature = "vm")]
 use crate::vm::Vm;
 
@@ -79,6 +92,10 @@ impl StandardConstructor {
 #[derive(Debug, Clone)]
 pub struct StandardObjects {
     object: StandardConstructor,
+    #[cfg(feature = "abort_controller")]
+    abort_controller: StandardConstructor,
+    #[cfg(feature = "abort_controller")]
+    abort_signal: StandardConstructor,
     proxy: StandardConstructor,
     function: StandardConstructor,
     array: StandardConstructor,
@@ -88,6 +105,7 @@ pub struct StandardObjects {
     string: StandardConstructor,
     regexp: StandardConstructor,
     symbol: StandardConstructor,
+    date: StandardConstructor,
     error: StandardConstructor,
     type_error: StandardConstructor,
     reference_error: StandardConstructor,
@@ -111,12 +129,24 @@ pub struct StandardObjects {
     typed_float64_array: StandardConstructor,
     array_buffer: StandardConstructor,
     data_view: StandardConstructor,
+    #[cfg(feature = "encoding")]
+    text_decoder: StandardConstructor,
+    #[cfg(feature = "encoding")]
+    text_encoder: StandardConstructor,
+    weak_map: StandardConstructor,
+    weak_set: StandardConstructor,
+    promise: StandardConstructor,
+    aggregate_error: StandardConstructor,
 }
 
 impl Default for StandardObjects {
     fn default() -> Self {
         Self {
             object: StandardConstructor::default(),
+            #[cfg(feature = "abort_controller")]
+            abort_controller: StandardConstructor::default(),
+            #[cfg(feature = "abort_controller")]
+            abort_signal: StandardConstructor::default(),
             proxy: StandardConstructor::default(),
             function: StandardConstructor::default(),
             array: StandardConstructor::default(),
@@ -135,6 +165,7 @@ impl Default for StandardObjects {
             )),
             regexp: StandardConstructor::default(),
             symbol: StandardConstructor::default(),
+            date: StandardConstructor::default(),
             error: StandardConstructor::default(),
             type_error: StandardConstructor::default(),
             reference_error: StandardConstructor::default(),
@@ -158,6 +189,14 @@ impl Default for StandardObjects {
             typed_float64_array: StandardConstructor::default(),
             array_buffer: StandardConstructor::default(),
             data_view: StandardConstructor::default(),
+            #[cfg(feature = "encoding")]
+            text_decoder: StandardConstructor::default(),
+            #[cfg(feature = "encoding")]
+            text_encoder: StandardConstructor::default(),
+            weak_map: StandardConstructor::default(),
+            weak_set: StandardConstructor::default(),
+            promise: StandardConstructor::default(),
+            aggregate_error: StandardConstructor::default(),
         }
     }
 }
@@ -168,6 +207,18 @@ impl StandardObjects {
         &self.object
     }
 
+    #[cfg(feature = "abort_controller")]
+    #[inline]
+    pub fn abort_controller_object(&self) -> &StandardConstructor {
+        &self.abort_controller
+    }
+
+    #[cfg(feature = "abort_controller")]
+    #[inline]
+    pub fn abort_signal_object(&self) -> &StandardConstructor {
+        &self.abort_signal
+    }
+
     #[inline]
     pub fn proxy_object(&self) -> &StandardConstructor {
         &self.proxy
@@ -213,6 +264,11 @@ impl StandardObjects {
         &self.symbol
     }
 
+    #[inline]
+    pub fn date_object(&self) -> &StandardConstructor {
+        &self.date
+    }
+
     #[inline]
     pub fn error_object(&self) -> &StandardConstructor {
         &self.error
@@ -327,6 +383,38 @@ impl StandardObjects {
     pub fn data_view_object(&self) -> &StandardConstructor {
         &self.data_view
     }
+
+    #[cfg(feature = "encoding")]
+    #[inline]
+    pub fn text_decoder_object(&self) -> &StandardConstructor {
+        &self.text_decoder
+    }
+
+    #[cfg(feature = "encoding")]
+    #[inline]
+    pub fn text_encoder_object(&self) -> &StandardConstructor {
+        &self.text_encoder
+    }
+
+    #[inline]
+    pub fn weak_map_object(&self) -> &StandardConstructor {
+        &self.weak_map
+    }
+
+    #[inline]
+    pub fn weak_set_object(&self) -> &StandardConstructor {
+        &self.weak_set
+    }
+
+    #[inline]
+    pub fn promise_object(&self) -> &StandardConstructor {
+        &self.promise
+    }
+
+    #[inline]
+    pub fn aggregate_error_object(&self) -> &StandardConstructor {
+        &self.aggregate_error
+    }
 }
 
 /// Internal representation of the strict mode types.
@@ -337,6 +425,28 @@ pub(crate) enum StrictType {
     Function,
 }
 
+/// The way the evaluation of a script completed, returned by [`Context::eval_completion`].
+#[derive(Debug, Clone)]
+pub enum Completion {
+    /// The script ran to the end, producing this value.
+    Normal(JsValue),
+    /// The script threw this value and nothing caught it.
+    Throw(JsValue),
+    /// The script couldn't be run at all, e.g. because it failed to parse. Holds the error
+    /// message.
+    Error(String),
+}
+
+impl From<JsResult<JsValue>> for Completion {
+    #[inline]
+    fn from(result: JsResult<JsValue>) -> Self {
+        match result {
+            Ok(value) => Self::Normal(value),
+            Err(value) => Self::Throw(value),
+        }
+    }
+}
+
 /// Javascript context. It is the primary way to interact with the runtime.
 ///
 /// `Context`s constructed in a thread share the same runtime, therefore it
@@ -390,6 +500,10 @@ pub struct Context {
     #[cfg(feature = "console")]
     console: Console,
 
+    /// Registered `setTimeout` callbacks.
+    #[cfg(feature = "timers")]
+    timers: Timers,
+
     /// Cached iterator prototypes.
     iterator_prototypes: IteratorPrototypes,
 
@@ -405,6 +519,33 @@ pub struct Context {
     /// Whether or not strict mode is active.
     strict: StrictType,
 
+    /// The host hooks installed by the embedder.
+    host_hooks: Rc<dyn HostHooks>,
+
+    /// The jobs waiting for the host to run them.
+    job_queue: VecDeque<NativeJob>,
+
+    /// The futures of async native functions that haven't completed yet.
+    future_jobs: Vec<FutureJob>,
+
+    /// The data of the native function that is currently running.
+    function_data: Option<Captures>,
+
+    /// The functions that are currently being called, the innermost one last.
+    call_stack: Vec<JsObject>,
+
+    /// When garbage is collected.
+    gc_strategy: GcStrategy,
+
+    /// The registry of the `WeakMap` and `WeakSet` objects created in this context.
+    weak_collections: Rc<WeakCollections>,
+
+    /// The instant at which [`Context::run_with_timeout`] aborts the running script.
+    deadline: Option<Instant>,
+
+    /// How many more checks of the deadline can pass before the clock is read again.
+    deadline_countdown: u32,
+
     #[cfg(feature = "vm")]
     pub(crate) vm: Vm,
 }
@@ -418,11 +559,22 @@ impl Default for Context {
             executor,
             #[cfg(feature = "console")]
             console: Console::default(),
+            #[cfg(feature = "timers")]
+            timers: Timers::default(),
             iterator_prototypes: IteratorPrototypes::default(),
             typed_array_constructor: StandardConstructor::default(),
             standard_objects: Default::default(),
             intrinsic_objects: IntrinsicObjects::default(),
             strict: StrictType::Off,
+            host_hooks: Rc::new(DefaultHooks),
+            job_queue: VecDeque::new(),
+            future_jobs: Vec::new(),
+            function_data: None,
+            call_stack: Vec::new(),
+            gc_strategy: GcStrategy::default(),
+            weak_collections: Rc::default(),
+            deadline: None,
+            deadline_countdown: 0,
             #[cfg(feature = "vm")]
             vm: Vm {
                 frame: None,
@@ -458,11 +610,147 @@ impl Context {
         Default::default()
     }
 
+    /// Create a new `Context` that uses the given [`HostHooks`].
+    #[inline]
+    pub fn with_host_hooks<H>(hooks: H) -> Self
+    where
+        H: HostHooks + 'static,
+    {
+        Self {
+            host_hooks: Rc::new(hooks),
+            ..Self::default()
+        }
+    }
+
+    /// Create a new `Context` that schedules garbage collection with the given [`GcStrategy`].
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{gc::GcStrategy, Context};
+    /// let context = Context::with_gc_strategy(GcStrategy::Incremental);
+    ///
+    /// assert_eq!(context.gc_strategy(), GcStrategy::Incremental);
+    /// ```
+    #[inline]
+    pub fn with_gc_strategy(gc_strategy: GcStrategy) -> Self {
+        Self {
+            gc_strategy,
+            ..Self::default()
+        }
+    }
+
+    /// Returns how the context schedules garbage collection.
+    #[inline]
+    pub fn gc_strategy(&self) -> GcStrategy {
+        self.gc_strategy
+    }
+
+    /// Collects garbage if the [`GcStrategy`] of the context is [`GcStrategy::Incremental`].
+    #[inline]
+    fn collect_garbage_after_jobs(&self) {
+        if self.gc_strategy == GcStrategy::Incremental {
+            force_collect();
+        }
+    }
+
+    /// Returns the registry of the weak collections of the context.
+    #[inline]
+    pub(crate) fn weak_collections(&self) -> &Rc<WeakCollections> {
+        &self.weak_collections
+    }
+
+    /// Return the host hooks of the context.
+    #[inline]
+    pub(crate) fn host_hooks(&self) -> Rc<dyn HostHooks> {
+        self.host_hooks.clone()
+    }
+
     #[inline]
     pub fn executor(&mut self) -> &mut Interpreter {
         &mut self.executor
     }
 
+    /// Returns the data of the native function that is currently running, if it was created with
+    /// [`FunctionBuilder::native_with_data`] and the data is of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data is currently mutably borrowed.
+    #[inline]
+    pub fn current_function_data<T>(&self) -> Option<gc::GcCellRef<'_, T>>
+    where
+        T: NativeObject,
+    {
+        self.function_data.as_ref()?.downcast_ref::<T>()
+    }
+
+    /// Runs a native function `f` with `data` as the current function data.
+    #[inline]
+    pub(crate) fn with_function_data<F>(
+        &mut self,
+        data: Option<Captures>,
+        f: F,
+    ) -> JsResult<JsValue>
+    where
+        F: FnOnce(&mut Self) -> JsResult<JsValue>,
+    {
+        let outer_data = std::mem::replace(&mut self.function_data, data);
+        let result = f(self);
+        self.function_data = outer_data;
+        result
+    }
+
+    /// Runs `f` with `function` on top of the call stack.
+    #[inline]
+    pub(crate) fn with_call_frame<F>(&mut self, function: &JsObject, f: F) -> JsResult<JsValue>
+    where
+        F: FnOnce(&mut Self) -> JsResult<JsValue>,
+    {
+        self.call_stack.push(function.clone());
+        let result = f(self);
+        self.call_stack.pop();
+        result
+    }
+
+    /// Replaces the function on top of the call stack, when a tail call reuses the frame of the
+    /// running function.
+    #[cfg(feature = "vm")]
+    #[inline]
+    pub(crate) fn replace_call_frame(&mut self, function: JsObject) {
+        if let Some(top) = self.call_stack.last_mut() {
+            *top = function;
+        }
+    }
+
+    /// Captures the names of the callers of the running function, innermost first.
+    ///
+    /// When `until` is given, the frames above its innermost call and that call itself are
+    /// left out, and nothing is captured if it isn't on the stack.
+    pub(crate) fn capture_stack_trace(&self, until: Option<&JsObject>) -> Vec<JsString> {
+        let frames = self.call_stack.iter().rev().skip(1);
+        let frames: Vec<_> = match until {
+            Some(until) => frames
+                .skip_while(|function| !JsObject::equals(function, until))
+                .skip(1)
+                .collect(),
+            None => frames.collect(),
+        };
+
+        frames
+            .into_iter()
+            .map(|function| {
+                // Read the own `name` directly, so capturing a trace never runs a getter.
+                function
+                    .borrow()
+                    .properties()
+                    .get(&"name".into())
+                    .and_then(|name| name.value()?.as_string().cloned())
+                    .filter(|name| !name.is_empty())
+                    .unwrap_or_else(|| "<anonymous>".into())
+            })
+            .collect()
+    }
+
     /// A helper function for getting an immutable reference to the `console` object.
     #[cfg(feature = "console")]
     pub(crate) fn console(&self) -> &Console {
@@ -476,6 +764,13 @@ impl Context {
         &mut self.console
     }
 
+    /// A helper function for getting a mutable reference to the timer queue.
+    #[cfg(feature = "timers")]
+    #[inline]
+    pub(crate) fn timers_mut(&mut self) -> &mut Timers {
+        &mut self.timers
+    }
+
     /// Returns if strict mode is currently active.
     #[inline]
     pub fn strict(&self) -> bool {
@@ -523,19 +818,62 @@ impl Context {
         )
     }
 
-    /// <https://tc39.es/ecma262/#sec-call>
+    /// Calls the function `f` with the given `this` value and arguments.
+    ///
+    /// This is the way to call back into JavaScript from Rust. A `TypeError` is thrown if `f`
+    /// is not callable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{Context, JsValue};
+    /// let mut context = Context::new();
+    ///
+    /// let add = context.eval("(a, b) => a + b").unwrap();
+    /// let result = context
+    ///     .call(&add, &JsValue::undefined(), &[1.into(), 2.into()])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(result.as_number(), Some(3.0));
+    /// ```
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-call
     #[inline]
-    pub(crate) fn call(
-        &mut self,
-        f: &JsValue,
-        this: &JsValue,
-        args: &[JsValue],
-    ) -> JsResult<JsValue> {
+    pub fn call(&mut self, f: &JsValue, this: &JsValue, args: &[JsValue]) -> JsResult<JsValue> {
         f.as_callable()
             .ok_or_else(|| self.construct_type_error("Value is not callable"))
             .and_then(|obj| obj.call(this, args, self))
     }
 
+    /// Constructs a new object with the constructor `f` and the given arguments, like the
+    /// JavaScript expression `new f(...args)`.
+    ///
+    /// A `TypeError` is thrown if `f` is not a constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// let array = context.eval("Array").unwrap();
+    /// let result = context.construct(&array, &[3.into()]).unwrap();
+    ///
+    /// assert!(result.is_object());
+    /// ```
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-construct
+    #[inline]
+    pub fn construct(&mut self, f: &JsValue, args: &[JsValue]) -> JsResult<JsValue> {
+        f.as_constructor()
+            .ok_or_else(|| self.construct_type_error("Value is not a constructor"))
+            .and_then(|obj| obj.construct(args, f, self))
+    }
+
     /// Return the global object.
     #[inline]
     pub fn global_object(&self) -> JsObject {
@@ -703,13 +1041,19 @@ impl Context {
     }
 
     /// Utility to create a function Value for Function Declarations, Arrow Functions or Function Expressions
+    ///
+    /// The calls of a `generator` function return a generator, whose prototype is the `prototype`
+    /// of the function.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn create_function<N, P>(
         &mut self,
         name: N,
         params: P,
         mut body: StatementList,
         constructor: bool,
+        generator: bool,
         this_mode: ThisMode,
+        source: Option<&str>,
     ) -> JsResult<JsValue>
     where
         N: Into<JsString>,
@@ -718,34 +1062,49 @@ impl Context {
         let name = name.into();
         let function_prototype = self.standard_objects().function_object().prototype();
 
-        // Every new function has a prototype property pre-made
-        let prototype = self.construct_object();
-
         // If a function is defined within a strict context, it is strict.
         if self.strict() {
             body.set_strict(true);
         }
 
-        let params = params.into();
+        let params: Box<[FormalParameter]> = params.into();
         let params_len = params.len();
+        let contains_arguments =
+            params.iter().any(FormalParameter::contains_arguments) || body.contains_arguments();
         let func = Function::Ordinary {
             constructor,
             this_mode,
             body: RcStatementList::from(body),
             params,
             environment: self.get_current_environment().clone(),
+            source: source.map(Into::into),
+            contains_arguments,
+            generator,
         };
 
         let function =
             JsObject::from_proto_and_data(function_prototype, ObjectData::function(func));
 
-        // Set constructor field to the newly created Value (function object)
-        let constructor = PropertyDescriptor::builder()
-            .value(function.clone())
-            .writable(true)
-            .enumerable(false)
-            .configurable(true);
-        prototype.define_property_or_throw("constructor", constructor, self)?;
+        // Every new function has a prototype property pre-made. The one of a generator function
+        // is the prototype of the generators it returns, which has no `constructor`.
+        // <https://tc39.es/ecma262/#sec-runtime-semantics-instantiategeneratorfunctionobject>
+        let prototype = if generator {
+            JsObject::from_proto_and_data(
+                self.iterator_prototypes().generator(),
+                ObjectData::ordinary(),
+            )
+        } else {
+            let prototype = self.construct_object();
+
+            // Set constructor field to the newly created Value (function object)
+            let constructor = PropertyDescriptor::builder()
+                .value(function.clone())
+                .writable(true)
+                .enumerable(false)
+                .configurable(true);
+            prototype.define_property_or_throw("constructor", constructor, self)?;
+            prototype
+        };
 
         let prototype = PropertyDescriptor::builder()
             .value(prototype)
@@ -771,6 +1130,42 @@ impl Context {
         Ok(function.into())
     }
 
+    /// Creates the function object of a dynamic function, like the ones created by the
+    /// `Function` constructor, whose scope is the global environment.
+    #[cfg(not(feature = "vm"))]
+    pub(crate) fn create_dynamic_function(&mut self, function: FunctionDecl) -> JsResult<JsObject> {
+        let global_environment = self.get_global_environment();
+        self.push_environment(global_environment);
+        let result = self.create_function(
+            "anonymous",
+            function.parameters(),
+            function.body().clone(),
+            true,
+            false,
+            ThisMode::Global,
+            function.source(),
+        );
+        self.pop_environment();
+
+        Ok(result?
+            .as_object()
+            .expect("create_function must return an object")
+            .clone())
+    }
+
+    /// Creates the function object of a dynamic function, like the ones created by the
+    /// `Function` constructor, whose scope is the global environment.
+    #[cfg(feature = "vm")]
+    pub(crate) fn create_dynamic_function(&mut self, function: FunctionDecl) -> JsResult<JsObject> {
+        let mut compiler =
+            crate::bytecompiler::ByteCompiler::new(JsString::new("anonymous"), self.strict());
+        compiler.function(&Node::FunctionDecl(function), true);
+        let code = compiler.finish().functions[0].clone();
+
+        let environment = self.get_global_environment();
+        Ok(crate::vm::JsVmFunction::new(code, environment, self))
+    }
+
     /// Register a global native function.
     ///
     /// This is more efficient that creating a closure function, since this does not allocate,
@@ -853,6 +1248,59 @@ impl Context {
         Ok(())
     }
 
+    /// Register a global accessor property, with a native getter and setter.
+    ///
+    /// This is useful to expose host state that has to be read or validated every time it is
+    /// accessed. Either function can be left out, in which case reading the property returns
+    /// `undefined`, or assigning to it is ignored (or throws in strict mode).
+    ///
+    /// The getter and setter are named `get <name>` and `set <name>`, and the property is bound
+    /// to the global object with `non-enumerable` and `configurable` attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa::{Context, JsResult, JsValue};
+    /// fn version(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    ///     Ok("1.0".into())
+    /// }
+    ///
+    /// let mut context = Context::new();
+    /// context.register_global_accessor("version", Some(version), None);
+    ///
+    /// assert_eq!(context.eval("version").unwrap(), "1.0".into());
+    /// ```
+    pub fn register_global_accessor(
+        &mut self,
+        name: &str,
+        getter: Option<NativeFunctionSignature>,
+        setter: Option<NativeFunctionSignature>,
+    ) {
+        let getter = getter.map(|getter| {
+            FunctionBuilder::native(self, getter)
+                .name(format!("get {}", name))
+                .length(0)
+                .constructor(false)
+                .build()
+        });
+        let setter = setter.map(|setter| {
+            FunctionBuilder::native(self, setter)
+                .name(format!("set {}", name))
+                .length(1)
+                .constructor(false)
+                .build()
+        });
+
+        self.global_object().insert_property(
+            name,
+            PropertyDescriptor::builder()
+                .maybe_get(getter)
+                .maybe_set(setter)
+                .enumerable(false)
+                .configurable(true),
+        );
+    }
+
     /// <https://tc39.es/ecma262/#sec-hasproperty>
     #[inline]
     pub(crate) fn has_property(&mut self, obj: &JsValue, key: &PropertyKey) -> JsResult<bool> {
@@ -917,6 +1365,45 @@ impl Context {
         Ok(())
     }
 
+    /// Register a global error type named `name`, and return its constructor.
+    ///
+    /// The errors it constructs inherit from `Error.prototype`, so the host can throw them into
+    /// JavaScript and have them caught as `instanceof Error`. Extra properties can be added to
+    /// the constructed errors before throwing them.
+    ///
+    /// # Example
+    /// ```
+    /// use boa::{Context, JsValue};
+    ///
+    /// let mut context = Context::new();
+    /// let http_error = context.register_error_type("HttpError");
+    ///
+    /// let error = http_error
+    ///     .construct(&["not found".into()], &http_error.clone().into(), &mut context)
+    ///     .unwrap();
+    /// error
+    ///     .as_object()
+    ///     .unwrap()
+    ///     .set("status", 404, true, &mut context)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     context.eval("new HttpError('gone') instanceof Error").unwrap(),
+    ///     JsValue::new(true)
+    /// );
+    /// ```
+    #[inline]
+    pub fn register_error_type(&mut self, name: &str) -> JsObject {
+        let constructor = CustomError::create(name, self);
+        let property = PropertyDescriptor::builder()
+            .value(constructor.clone())
+            .writable(true)
+            .enumerable(false)
+            .configurable(true);
+        self.global_object().insert(name, property);
+        constructor
+    }
+
     /// Register a global property.
     ///
     /// # Example
@@ -967,6 +1454,9 @@ impl Context {
 
     /// Evaluates the given code.
     ///
+    /// A script that fails to parse throws a `SyntaxError`, use [`Context::eval_completion`] to
+    /// tell it apart from the exceptions thrown while running.
+    ///
     /// # Examples
     /// ```
     ///# use boa::Context;
@@ -977,10 +1467,104 @@ impl Context {
     /// assert!(value.is_number());
     /// assert_eq!(value.as_number().unwrap(), 4.0);
     /// ```
+    #[inline]
+    pub fn eval<T: AsRef<[u8]>>(&mut self, src: T) -> JsResult<JsValue> {
+        match self.eval_completion(src) {
+            Completion::Normal(value) => Ok(value),
+            Completion::Throw(value) => Err(value),
+            Completion::Error(message) => self.throw_syntax_error(message),
+        }
+    }
+
+    /// Evaluates the given code like [`Context::eval`], throwing an `Error` if it still runs
+    /// after `timeout`.
+    ///
+    /// The deadline is checked while the script runs, so it also aborts infinite loops.
+    ///
+    /// # Catching the timeout
+    ///
+    /// The timeout is an ordinary `Error` that the script can catch, for example to clean up
+    /// in a `finally` block. The deadline stays in place once it has passed, so the script is
+    /// interrupted by a new error every time the deadline is checked again. A script that
+    /// catches every one of those errors and keeps looping is not stopped; the timeout only
+    /// bounds scripts that let the error propagate.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    ///# use std::time::Duration;
+    /// let mut context = Context::new();
+    ///
+    /// let result = context.run_with_timeout("while (true) {}", Duration::from_millis(10));
+    /// assert!(result.is_err());
+    ///
+    /// let result = context.run_with_timeout("1 + 1", Duration::from_secs(1));
+    /// assert_eq!(result.unwrap(), 2.into());
+    /// ```
+    pub fn run_with_timeout<T: AsRef<[u8]>>(
+        &mut self,
+        src: T,
+        timeout: Duration,
+    ) -> JsResult<JsValue> {
+        let deadline = Instant::now() + timeout;
+        let previous = self.deadline.replace(match self.deadline {
+            Some(previous) if previous < deadline => previous,
+            _ => deadline,
+        });
+        self.deadline_countdown = 0;
+
+        let result = self.eval(src);
+
+        self.deadline = previous;
+        result
+    }
+
+    /// Throws if the deadline of [`Context::run_with_timeout`] has passed.
+    ///
+    /// Reading the clock is much slower than evaluating most nodes or instructions, so it is
+    /// only read once every `DEADLINE_CHECK_INTERVAL` calls.
+    #[inline]
+    pub(crate) fn check_deadline(&mut self) -> JsResult<()> {
+        const DEADLINE_CHECK_INTERVAL: u32 = 1024;
+
+        let deadline = match self.deadline {
+            Some(deadline) => deadline,
+            None => return Ok(()),
+        };
+        if self.deadline_countdown > 0 {
+            self.deadline_countdown -= 1;
+            return Ok(());
+        }
+        if Instant::now() < deadline {
+            self.deadline_countdown = DEADLINE_CHECK_INTERVAL;
+            return Ok(());
+        }
+
+        // The error is constructed by running nodes, which would check the deadline again.
+        self.deadline = None;
+        let error = self.construct_error("script execution timed out");
+        self.deadline = Some(deadline);
+
+        // A script catching the error gets to run its handler before the next check throws again.
+        self.deadline_countdown = DEADLINE_CHECK_INTERVAL;
+        Err(error)
+    }
+
+    /// Evaluates the given code and returns how the evaluation completed.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{Completion, Context};
+    /// let mut context = Context::new();
+    ///
+    /// assert!(matches!(context.eval_completion("1 + 3"), Completion::Normal(_)));
+    /// assert!(matches!(context.eval_completion("throw 1"), Completion::Throw(_)));
+    /// assert!(matches!(context.eval_completion("1 +"), Completion::Error(_)));
+    /// ```
     #[cfg(not(feature = "vm"))]
     #[allow(clippy::unit_arg, clippy::drop_copy)]
     #[inline]
-    pub fn eval<T: AsRef<[u8]>>(&mut self, src: T) -> JsResult<JsValue> {
+    pub fn eval_completion<T: AsRef<[u8]>>(&mut self, src: T) -> Completion {
         let main_timer = BoaProfiler::global().start_event("Main", "Main");
         let src_bytes: &[u8] = src.as_ref();
 
@@ -988,41 +1572,41 @@ impl Context {
             .parse_all()
             .map_err(|e| e.to_string());
 
-        let execution_result = match parsing_result {
+        let completion = match parsing_result {
             Ok(statement_list) => {
                 if statement_list.strict() {
                     self.set_strict_mode_global();
                 }
-                statement_list.run(self)
+                statement_list.run(self).into()
             }
-            Err(e) => self.throw_syntax_error(e),
+            Err(e) => Completion::Error(e),
         };
 
         // The main_timer needs to be dropped before the BoaProfiler is.
         drop(main_timer);
         BoaProfiler::global().drop();
 
-        execution_result
+        completion
     }
 
-    /// Evaluates the given code by compiling down to bytecode, then interpreting the bytecode into a value
+    /// Evaluates the given code by compiling down to bytecode, then interpreting the bytecode,
+    /// and returns how the evaluation completed.
     ///
     /// # Examples
     /// ```
-    ///# use boa::Context;
+    ///# use boa::{Completion, Context};
     /// let mut context = Context::new();
     ///
-    /// let value = context.eval("1 + 3").unwrap();
-    ///
-    /// assert!(value.is_number());
-    /// assert_eq!(value.as_number().unwrap(), 4.0);
+    /// assert!(matches!(context.eval_completion("1 + 3"), Completion::Normal(_)));
+    /// assert!(matches!(context.eval_completion("throw 1"), Completion::Throw(_)));
+    /// assert!(matches!(context.eval_completion("1 +"), Completion::Error(_)));
     /// ```
     #[cfg(feature = "vm")]
     #[allow(clippy::unit_arg, clippy::drop_copy)]
-    pub fn eval<T: AsRef<[u8]>>(&mut self, src: T) -> JsResult<JsValue> {
+    pub fn eval_completion<T: AsRef<[u8]>>(&mut self, src: T) -> Completion {
         use gc::Gc;
 
-        use crate::vm::CallFrame;
+        use crate::vm::{CallFrame, CompletionType};
 
         let main_timer = BoaProfiler::global().start_event("Main", "Main");
         let src_bytes: &[u8] = src.as_ref();
@@ -1033,10 +1617,13 @@ impl Context {
 
         let statement_list = match parsing_result {
             Ok(statement_list) => statement_list,
-            Err(e) => return self.throw_syntax_error(e),
+            Err(e) => return Completion::Error(e),
         };
 
-        let mut compiler = crate::bytecompiler::ByteCompiler::new(JsString::new("<main>"), false);
+        let mut compiler = crate::bytecompiler::ByteCompiler::new(
+            JsString::new("<main>"),
+            statement_list.strict(),
+        );
         compiler.compile_statement_list(&statement_list, true);
         let code_block = compiler.finish();
 
@@ -1051,15 +1638,156 @@ impl Context {
             pc: 0,
             fp,
             exit_on_return: true,
-            environment,
+            environment: environment.clone(),
+            promise_capability: None,
+            handlers: Vec::new(),
+            resumed_with: CompletionType::Normal,
+            yielded: false,
         });
         let result = self.run();
 
+        // An exception thrown in a block scope leaves the environment of the block as the
+        // current one.
+        self.pop_environment();
+        self.push_environment(environment);
+
         // The main_timer needs to be dropped before the BoaProfiler is.
         drop(main_timer);
         BoaProfiler::global().drop();
 
-        result
+        result.into()
+    }
+
+    /// Fires the `setTimeout` callbacks that are due at the host time `now`, in milliseconds,
+    /// and returns how many were run.
+    ///
+    /// The timeout passed to `setTimeout` is relative to the latest `now` given to this method
+    /// (starting at `0`), and the clock never moves backwards. Callbacks scheduled while the
+    /// timers are running are left for the next call. Pending jobs, like promise reactions,
+    /// are run before each callback and after it, so a zero-delay timer never overtakes them.
+    /// Hosts are expected to call this once the script has finished running, and can use
+    /// [`Context::next_timer_deadline`] to know when the next call is needed.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// context.eval("var fired = false; setTimeout(() => { fired = true; }, 10);").unwrap();
+    ///
+    /// assert_eq!(context.run_timers(5).unwrap(), 0);
+    /// assert_eq!(context.run_timers(10).unwrap(), 1);
+    /// assert_eq!(context.eval("fired").unwrap().as_boolean(), Some(true));
+    /// ```
+    #[cfg(feature = "timers")]
+    pub fn run_timers(&mut self, now: u64) -> JsResult<usize> {
+        let fired = Timers::run(self, now)?;
+        if fired > 0 {
+            self.collect_garbage_after_jobs();
+        }
+        Ok(fired)
+    }
+
+    /// Returns the host time, in milliseconds, at which the earliest pending `setTimeout`
+    /// callback becomes due, or `None` if no timers are registered.
+    #[cfg(feature = "timers")]
+    #[inline]
+    pub fn next_timer_deadline(&self) -> Option<u64> {
+        self.timers.next_deadline()
+    }
+
+    /// Pushes a job to the end of the job queue, to be run by [`Context::run_jobs`].
+    #[inline]
+    pub fn enqueue_job(&mut self, job: NativeJob) {
+        self.job_queue.push_back(job);
+    }
+
+    /// Pushes a future to the futures polled by [`Context::run_jobs`].
+    #[inline]
+    pub(crate) fn enqueue_future_job(&mut self, job: FutureJob) {
+        self.future_jobs.push(job);
+    }
+
+    /// Returns `true` if some futures of async native functions haven't completed yet, see
+    /// [`FunctionBuilder::async_native`].
+    #[inline]
+    pub fn has_pending_futures(&self) -> bool {
+        !self.future_jobs.is_empty()
+    }
+
+    /// Runs the queued jobs, like the reactions of settled promises, until the queue is empty.
+    ///
+    /// Jobs enqueued by the running jobs are run by the same call. If a job throws, the error is
+    /// returned and the jobs that haven't run yet stay in the queue. Hosts are expected to call
+    /// this once the script has finished running.
+    ///
+    /// Once the queue is empty, the pending futures of async native functions are polled, and
+    /// the promises of the completed ones are settled. This repeats until no future completes,
+    /// so hosts have to call this again while [`Context::has_pending_futures`] is `true`.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// context.eval("var resolved; Promise.resolve(1).then(v => { resolved = v; });").unwrap();
+    /// assert!(context.eval("resolved").unwrap().is_undefined());
+    ///
+    /// context.run_jobs().unwrap();
+    /// assert_eq!(context.eval("resolved").unwrap(), 1.into());
+    /// ```
+    pub fn run_jobs(&mut self) -> JsResult<()> {
+        let mut ran = false;
+        loop {
+            while let Some(job) = self.job_queue.pop_front() {
+                job.call(self)?;
+                ran = true;
+            }
+
+            let mut completed = Vec::new();
+            for mut future in std::mem::take(&mut self.future_jobs) {
+                match future.poll() {
+                    Poll::Ready(job) => completed.push(job),
+                    Poll::Pending => self.future_jobs.push(future),
+                }
+            }
+            if completed.is_empty() {
+                if ran {
+                    self.collect_garbage_after_jobs();
+                }
+                return Ok(());
+            }
+            for job in completed {
+                job.call(self)?;
+            }
+        }
+    }
+
+    /// Deep-copies `value` like the `structuredClone` global function, moving the contents of
+    /// the `ArrayBuffer` objects of `transfer` to the clone and detaching them.
+    ///
+    /// Values that can't be cloned, like functions, throw a `DataCloneError`.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{property::Attribute, Context};
+    /// let mut context = Context::new();
+    ///
+    /// let value = context.eval("var original = { list: [1, 2] }; original").unwrap();
+    /// let clone = context.structured_clone(&value, &[]).unwrap();
+    /// context.register_global_property("clone", clone, Attribute::all());
+    ///
+    /// assert_eq!(context.eval("clone.list[1]").unwrap(), 2.into());
+    /// assert_eq!(context.eval("clone.list === original.list").unwrap(), false.into());
+    /// ```
+    #[cfg(feature = "structured_clone")]
+    #[inline]
+    pub fn structured_clone(
+        &mut self,
+        value: &JsValue,
+        transfer: &[JsObject],
+    ) -> JsResult<JsValue> {
+        builtins::structured_clone::structured_clone(value, transfer, self)
     }
 
     /// Return the cached iterator prototypes.
@@ -128,6 +128,16 @@ impl Context {
             .recursive_initialize_binding(name, value, self)
     }
 
+    /// Returns the outermost environment of the stack, the global environment of the realm.
+    pub(crate) fn get_global_environment(&self) -> Environment {
+        self.realm
+            .environment
+            .environment_stack
+            .front()
+            .expect("Could not get the global environment")
+            .clone()
+    }
+
     /// When neededing to clone an environment (linking it with another environnment)
     /// cloning is more suited. The GC will remove the env once nothing is linking to it anymore
     pub(crate) fn get_current_environment(&mut self) -> Environment {
@@ -0,0 +1,1250 @@
+//! The evaluation of the body of a generator function by the tree-walker.
+//!
+//! The tree-walker evaluates nodes by recursion on the native stack, which can't be kept aside
+//! while a generator is suspended. Instead, a `yield` unwinds the evaluation of the body, and
+//! every node it leaves on the way records how far it got in a [`Progress`]. When the generator
+//! is resumed, the body is evaluated again from the top, and every node that recorded its
+//! progress goes straight back to the child it was evaluating, down to the `yield`, which then
+//! evaluates to the value the generator was resumed with.
+//!
+//! Nodes that don't contain a `yield` are run as usual, with [`Executable::run`]. A `yield` can be
+//! in any statement, declaration, operand, argument or element of an array, object or template
+//! literal. Anywhere else, like in the default value of a binding pattern or the target of an
+//! increment, it throws a `SyntaxError`.
+
+use crate::{
+    builtins::{
+        iterable::{create_iter_result_object, iterable_to_list, IteratorRecord},
+        Array, Eval, JsArgs,
+    },
+    context::StrictType,
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        lexical_environment::Environment,
+    },
+    exec::{CompletionType, Executable, InterpreterState, TailCall},
+    gc::{custom_trace, Finalize, Trace},
+    object::JsObject,
+    property::PropertyKey,
+    syntax::ast::{
+        node::{
+            template::TemplateElement, BinOp, Block, Call, ConditionalOp, DeclarationList,
+            DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, If, New, Node, Object, PropertyDefinition,
+            PropertyName, RcStatementList, Switch, TemplateLit, Try, UnaryOp, WhileLoop, Yield,
+        },
+        op::{self, LogOp},
+    },
+    Context, JsResult, JsValue,
+};
+
+/// Records `$progress` for the node being evaluated if `$result` is a `yield` unwinding it, or
+/// evaluates to `$result` otherwise.
+macro_rules! suspend {
+    ($self:ident, $result:expr, $progress:expr) => {
+        match $result {
+            Err(Interrupt::Yield(result)) => {
+                $self.progress.push($progress);
+                return Err(Interrupt::Yield(result));
+            }
+            result => result,
+        }
+    };
+}
+
+/// The body of a generator function run by the tree-walker, along with the progress of its
+/// evaluation while it is suspended.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct GeneratorBody {
+    body: RcStatementList,
+
+    /// The environment of the function call.
+    environment: Environment,
+
+    /// The progress of the nodes the body is suspended in, innermost first.
+    progress: Vec<Progress>,
+}
+
+impl GeneratorBody {
+    /// Creates the body of a generator function, which runs in the `environment` of its call.
+    pub(crate) fn new(body: RcStatementList, environment: Environment) -> Self {
+        Self {
+            body,
+            environment,
+            progress: Vec::new(),
+        }
+    }
+
+    /// Resumes the evaluation of the body, with `completion` and `value` as the result of the
+    /// `yield` it is suspended at, if any.
+    ///
+    /// Returns the iterator result object of the generator, along with the body again if it is
+    /// suspended at a `yield`.
+    pub(crate) fn resume(
+        mut self,
+        completion: CompletionType,
+        value: JsValue,
+        context: &mut Context,
+    ) -> (JsResult<JsValue>, Option<Self>) {
+        let body = self.body.clone();
+        context.push_environment(self.environment.clone());
+
+        let strict_before = context.strict_type();
+        match strict_before {
+            StrictType::Off if body.strict() => context.set_strict(StrictType::Function),
+            StrictType::Function if !body.strict() => context.set_strict_mode_off(),
+            _ => {}
+        }
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        let result = Evaluator {
+            context,
+            progress: &mut self.progress,
+            received: Some((completion, value)),
+        }
+        .statements(body.items());
+
+        context.set_strict(strict_before);
+        context.pop_environment();
+
+        match result {
+            Err(Interrupt::Yield(result)) => (Ok(result), Some(self)),
+            Err(Interrupt::Return(value)) => {
+                (Ok(create_iter_result_object(value, true, context)), None)
+            }
+            Err(Interrupt::Throw(error)) => (Err(error), None),
+            Ok(()) | Err(Interrupt::Break(_)) | Err(Interrupt::Continue(_)) => (
+                Ok(create_iter_result_object(
+                    JsValue::undefined(),
+                    true,
+                    context,
+                )),
+                None,
+            ),
+        }
+    }
+}
+
+/// The reason the evaluation of a node stopped before its end.
+#[derive(Debug, Finalize)]
+enum Interrupt {
+    /// A `yield`, with the iterator result object it produces, which suspends the generator.
+    Yield(JsValue),
+    Return(JsValue),
+    Throw(JsValue),
+    Break(Option<Box<str>>),
+    Continue(Option<Box<str>>),
+}
+
+// `Trace` is implemented by hand, as the derived implementation also implements `Drop`, which
+// would forbid moving out of the interrupts and the progress while matching them.
+unsafe impl Trace for Interrupt {
+    custom_trace!(this, {
+        match this {
+            Self::Yield(value) | Self::Return(value) | Self::Throw(value) => mark(value),
+            Self::Break(_) | Self::Continue(_) => {}
+        }
+    });
+}
+
+impl From<JsValue> for Interrupt {
+    fn from(error: JsValue) -> Self {
+        Self::Throw(error)
+    }
+}
+
+/// The result of the evaluation of a node in the body of a generator.
+type Completion<T> = Result<T, Interrupt>;
+
+/// How far a node got in its evaluation when a `yield` in it suspended the generator.
+#[derive(Debug, Finalize)]
+enum Progress {
+    /// At the `yield`.
+    Yield,
+
+    /// At a `yield*`, delegating to the iterator.
+    Delegate(IteratorRecord),
+
+    /// Evaluating the child with the given index, for nodes that have no other state.
+    Child(usize),
+
+    /// Running the statement with the given index of a statement list.
+    Statements(usize),
+
+    /// In the environment of a block, which is taken off the environment stack meanwhile.
+    Scope(Environment),
+
+    /// At the given step of a loop.
+    Loop(LoopStep),
+
+    /// In the body of a `for-of` or `for-in` loop, with its iterator and the environment of the
+    /// iteration.
+    Iteration(IteratorRecord, Environment),
+
+    /// At the given step of a `try` statement, with the abrupt completion of the `try` or `catch`
+    /// block that the `finally` block runs for, if any.
+    Try(TryStep, Option<Interrupt>),
+
+    /// In the case with the given index of a `switch` statement, whose body runs if a case
+    /// matched, with the value of the discriminant.
+    Switch {
+        value: JsValue,
+        case: usize,
+        matched: bool,
+    },
+
+    /// Evaluating the operands of an expression, with the values of the operands before.
+    Values(Vec<JsValue>),
+
+    /// Evaluating the element with the given index of an argument list or array literal, with
+    /// the values of the elements before.
+    List(usize, Vec<JsValue>),
+
+    /// Evaluating the property with the given index of an object literal, with the object and
+    /// the key of the property once it is known.
+    Object(JsObject, usize, Option<PropertyKey>),
+
+    /// Evaluating the element with the given index of a template literal, with the string of the
+    /// elements before.
+    Template(usize, String),
+}
+
+unsafe impl Trace for Progress {
+    custom_trace!(this, {
+        match this {
+            Self::Yield
+            | Self::Child(_)
+            | Self::Statements(_)
+            | Self::Loop(_)
+            | Self::Template(..) => {}
+            Self::Delegate(iterator) => mark(iterator),
+            Self::Scope(environment) => mark(environment),
+            Self::Iteration(iterator, environment) => {
+                mark(iterator);
+                mark(environment);
+            }
+            Self::Try(_, completion) => mark(completion),
+            Self::Switch { value, .. } => mark(value),
+            Self::Values(values) | Self::List(_, values) => mark(values),
+            Self::Object(object, _, key) => {
+                mark(object);
+                mark(key);
+            }
+        }
+    });
+}
+
+/// The steps of a loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LoopStep {
+    Init,
+    Condition,
+    Body,
+    Update,
+}
+
+/// The steps of a `try` statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TryStep {
+    Block,
+    Catch,
+    Finally,
+}
+
+/// Panics on the progress recorded by a different kind of node than the one being resumed.
+fn unexpected(progress: Progress) -> ! {
+    unreachable!("a generator resumed in a node that recorded {:?}", progress)
+}
+
+/// Returns `true` if a `break` or `continue` to `target` applies to a loop labelled `label`.
+fn targets(target: Option<&str>, label: Option<&str>) -> bool {
+    target.map_or(true, |target| label == Some(target))
+}
+
+/// Closes the `iterator` of a `for-of` loop exited by `interrupt`, which is replaced by the error
+/// of closing the iterator, if any.
+fn close_iterator(
+    iterator: &IteratorRecord,
+    interrupt: Interrupt,
+    context: &mut Context,
+) -> Interrupt {
+    let completion = match interrupt {
+        Interrupt::Throw(ref error) => Err(error.clone()),
+        _ => Ok(JsValue::undefined()),
+    };
+    match iterator.close(completion, context) {
+        Ok(_) => interrupt,
+        Err(error) => Interrupt::Throw(error),
+    }
+}
+
+/// Evaluates the nodes of a generator body, going back to the `yield` it was suspended at.
+struct Evaluator<'a> {
+    context: &'a mut Context,
+
+    /// The progress of the nodes left to resume, outermost last.
+    progress: &'a mut Vec<Progress>,
+
+    /// The completion the generator is resumed with, until the `yield` it was suspended at takes
+    /// it.
+    received: Option<(CompletionType, JsValue)>,
+}
+
+impl Evaluator<'_> {
+    /// Returns `true` if the node being evaluated is resumed, rather than evaluated afresh.
+    fn resuming(&self) -> bool {
+        !self.progress.is_empty()
+    }
+
+    /// Takes the progress of the node being resumed, or `None` if it is evaluated afresh.
+    fn resume(&mut self) -> Option<Progress> {
+        self.progress.pop()
+    }
+
+    /// Runs `node`, which doesn't contain a `yield`, turning the state it leaves the interpreter
+    /// in into an interrupt.
+    fn run<E>(&mut self, node: &E) -> Completion<JsValue>
+    where
+        E: Executable + ?Sized,
+    {
+        let value = node.run(self.context);
+        let state = self.context.executor().take_current_state();
+        let value = value?;
+        match state {
+            InterpreterState::Executing => Ok(value),
+            InterpreterState::Return => {
+                let value = TailCall::complete(value, self.context);
+                self.context
+                    .executor()
+                    .set_current_state(InterpreterState::Executing);
+                Err(Interrupt::Return(value?))
+            }
+            InterpreterState::Break(label) => Err(Interrupt::Break(label)),
+            InterpreterState::Continue(label) => Err(Interrupt::Continue(label)),
+        }
+    }
+
+    /// Throws for a `yield` that the evaluation can't be suspended at.
+    fn unsupported<T>(&mut self) -> Completion<T> {
+        Err(self
+            .context
+            .construct_syntax_error("this use of `yield` requires the `vm` feature")
+            .into())
+    }
+
+    /// Takes the completion the generator is resumed with.
+    fn received(&mut self) -> (CompletionType, JsValue) {
+        self.received
+            .take()
+            .expect("a generator is only resumed once at a `yield`")
+    }
+
+    fn statements(&mut self, items: &[Node]) -> Completion<()> {
+        let mut index = match self.resume() {
+            Some(Progress::Statements(index)) => index,
+            None => 0,
+            Some(progress) => unexpected(progress),
+        };
+        while let Some(item) = items.get(index) {
+            suspend!(self, self.statement(item), Progress::Statements(index))?;
+            index += 1;
+        }
+        Ok(())
+    }
+
+    fn statement(&mut self, node: &Node) -> Completion<()> {
+        if !node.contains_yield() {
+            return self.run(node).map(drop);
+        }
+
+        match node {
+            Node::Block(block) => self.block(block),
+            Node::If(if_smt) => self.if_statement(if_smt),
+            Node::WhileLoop(while_loop) => self.while_loop(while_loop),
+            Node::DoWhileLoop(do_while) => self.do_while_loop(do_while),
+            Node::ForLoop(for_loop) => self.for_loop(for_loop),
+            Node::ForOfLoop(for_of) => self.for_of_loop(for_of),
+            Node::ForInLoop(for_in) => self.for_in_loop(for_in),
+            Node::Try(try_node) => self.try_statement(try_node),
+            Node::Switch(switch) => self.switch(switch),
+            Node::Return(ret) => {
+                let value = match ret.expr() {
+                    Some(expr) => self.expression(expr)?,
+                    None => JsValue::undefined(),
+                };
+                Err(Interrupt::Return(value))
+            }
+            Node::Throw(throw) => Err(Interrupt::Throw(self.expression(throw.expr())?)),
+            Node::VarDeclList(list) | Node::LetDeclList(list) | Node::ConstDeclList(list) => {
+                self.declarations(list)
+            }
+            _ => self.expression(node).map(drop),
+        }
+    }
+
+    /// Evaluates `f` in a new declarative environment, or in the one it was suspended in.
+    fn scope<T, F>(&mut self, f: F) -> Completion<T>
+    where
+        F: FnOnce(&mut Self) -> Completion<T>,
+    {
+        match self.resume() {
+            Some(Progress::Scope(environment)) => self.context.push_environment(environment),
+            None => {
+                let env = self.context.get_current_environment();
+                self.context
+                    .push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+            }
+            Some(progress) => unexpected(progress),
+        }
+
+        let result = f(self);
+
+        let environment = self
+            .context
+            .pop_environment()
+            .expect("the environment of the scope was pushed");
+        if let Err(Interrupt::Yield(_)) = result {
+            self.progress.push(Progress::Scope(environment));
+        }
+        result
+    }
+
+    fn block(&mut self, block: &Block) -> Completion<()> {
+        self.scope(|this| this.statements(block.items()))
+    }
+
+    fn if_statement(&mut self, if_smt: &If) -> Completion<()> {
+        let branch = match self.resume() {
+            Some(Progress::Child(0)) | None => {
+                let cond = suspend!(self, self.expression(if_smt.cond()), Progress::Child(0))?;
+                if cond.to_boolean() {
+                    1
+                } else {
+                    2
+                }
+            }
+            Some(Progress::Child(branch)) => branch,
+            Some(progress) => unexpected(progress),
+        };
+
+        let node = if branch == 1 {
+            Some(if_smt.body())
+        } else {
+            if_smt.else_node()
+        };
+        if let Some(node) = node {
+            suspend!(self, self.statement(node), Progress::Child(branch))?;
+        }
+        Ok(())
+    }
+
+    /// Takes the step a loop was suspended at, or returns `first` if it is evaluated afresh.
+    fn loop_step(&mut self, first: LoopStep) -> LoopStep {
+        match self.resume() {
+            Some(Progress::Loop(step)) => step,
+            None => first,
+            Some(progress) => unexpected(progress),
+        }
+    }
+
+    /// Runs the `body` of a loop labelled `label`, returning `false` if it breaks out of the loop.
+    fn loop_body(&mut self, body: &Node, label: Option<&str>) -> Completion<bool> {
+        match self.statement(body) {
+            Ok(()) => Ok(true),
+            Err(Interrupt::Break(target)) if targets(target.as_deref(), label) => Ok(false),
+            Err(Interrupt::Continue(target)) if targets(target.as_deref(), label) => Ok(true),
+            Err(interrupt) => Err(interrupt),
+        }
+    }
+
+    fn while_loop(&mut self, while_loop: &WhileLoop) -> Completion<()> {
+        let mut step = self.loop_step(LoopStep::Condition);
+        loop {
+            if step == LoopStep::Condition {
+                let cond = suspend!(
+                    self,
+                    self.expression(while_loop.cond()),
+                    Progress::Loop(step)
+                )?;
+                if !cond.to_boolean() {
+                    return Ok(());
+                }
+                step = LoopStep::Body;
+            } else {
+                let body = self.loop_body(while_loop.body(), while_loop.label());
+                if !suspend!(self, body, Progress::Loop(step))? {
+                    return Ok(());
+                }
+                step = LoopStep::Condition;
+            }
+        }
+    }
+
+    fn do_while_loop(&mut self, do_while: &DoWhileLoop) -> Completion<()> {
+        let mut step = self.loop_step(LoopStep::Body);
+        loop {
+            if step == LoopStep::Body {
+                let body = self.loop_body(do_while.body(), do_while.label());
+                if !suspend!(self, body, Progress::Loop(step))? {
+                    return Ok(());
+                }
+                step = LoopStep::Condition;
+            } else {
+                let cond = suspend!(self, self.expression(do_while.cond()), Progress::Loop(step))?;
+                if !cond.to_boolean() {
+                    return Ok(());
+                }
+                step = LoopStep::Body;
+            }
+        }
+    }
+
+    fn for_loop(&mut self, for_loop: &ForLoop) -> Completion<()> {
+        self.scope(|this| {
+            let bindings = for_loop.per_iteration_bindings();
+            let mut step = this.loop_step(LoopStep::Init);
+            loop {
+                match step {
+                    LoopStep::Init => {
+                        if let Some(init) = for_loop.init() {
+                            suspend!(this, this.statement(init), Progress::Loop(step))?;
+                        }
+                        ForLoop::create_per_iteration_environment(&bindings, this.context)?;
+                        step = LoopStep::Condition;
+                    }
+                    LoopStep::Condition => {
+                        if let Some(cond) = for_loop.condition() {
+                            let cond = suspend!(this, this.expression(cond), Progress::Loop(step))?;
+                            if !cond.to_boolean() {
+                                return Ok(());
+                            }
+                        }
+                        step = LoopStep::Body;
+                    }
+                    LoopStep::Body => {
+                        let body = this.loop_body(for_loop.body(), for_loop.label());
+                        if !suspend!(this, body, Progress::Loop(step))? {
+                            return Ok(());
+                        }
+                        ForLoop::create_per_iteration_environment(&bindings, this.context)?;
+                        step = LoopStep::Update;
+                    }
+                    LoopStep::Update => {
+                        if let Some(final_expr) = for_loop.final_expr() {
+                            suspend!(this, this.expression(final_expr), Progress::Loop(step))?;
+                        }
+                        step = LoopStep::Condition;
+                    }
+                }
+            }
+        })
+    }
+
+    fn for_of_loop(&mut self, for_of: &ForOfLoop) -> Completion<()> {
+        let (iterator, environment) = match self.resume() {
+            Some(Progress::Iteration(iterator, environment)) => (iterator, Some(environment)),
+            Some(Progress::Child(_)) | None => {
+                let iterable =
+                    suspend!(self, self.expression(for_of.iterable()), Progress::Child(0))?;
+                (iterable.get_iterator(self.context, None, None)?, None)
+            }
+            Some(progress) => unexpected(progress),
+        };
+
+        self.iterate(
+            iterator,
+            environment,
+            true,
+            for_of.body(),
+            for_of.label(),
+            |value, context| for_of.bind_variable(value, context),
+        )
+    }
+
+    fn for_in_loop(&mut self, for_in: &ForInLoop) -> Completion<()> {
+        let (iterator, environment) = match self.resume() {
+            Some(Progress::Iteration(iterator, environment)) => (iterator, Some(environment)),
+            Some(Progress::Child(_)) | None => {
+                let object = suspend!(self, self.expression(for_in.expr()), Progress::Child(0))?;
+                if object.is_null_or_undefined() {
+                    return Ok(());
+                }
+                (ForInLoop::iterator(object, self.context)?, None)
+            }
+            Some(progress) => unexpected(progress),
+        };
+
+        self.iterate(
+            iterator,
+            environment,
+            false,
+            for_in.body(),
+            for_in.label(),
+            |value, context| for_in.bind_variable(value, context),
+        )
+    }
+
+    /// Runs the `body` of a `for-of` or `for-in` loop labelled `label` for every value of
+    /// `iterator`, which `bind` binds in the environment of the iteration, starting with the
+    /// `environment` of the iteration the loop was suspended in, if any.
+    ///
+    /// The iterator is closed when the loop is exited early if `close` is set.
+    fn iterate<F>(
+        &mut self,
+        iterator: IteratorRecord,
+        mut environment: Option<Environment>,
+        close: bool,
+        body: &Node,
+        label: Option<&str>,
+        bind: F,
+    ) -> Completion<()>
+    where
+        F: Fn(JsValue, &mut Context) -> JsResult<()>,
+    {
+        loop {
+            if let Some(environment) = environment.take() {
+                self.context.push_environment(environment);
+            } else {
+                let next = iterator.next(self.context)?;
+                if next.done {
+                    return Ok(());
+                }
+
+                let env = self.context.get_current_environment();
+                self.context
+                    .push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+                if let Err(error) = bind(next.value, self.context) {
+                    self.context.pop_environment();
+                    let interrupt = Interrupt::Throw(error);
+                    return Err(if close {
+                        close_iterator(&iterator, interrupt, self.context)
+                    } else {
+                        interrupt
+                    });
+                }
+            }
+
+            let result = self.loop_body(body, label);
+            let iteration_environment = self
+                .context
+                .pop_environment()
+                .expect("the environment of the iteration was pushed");
+
+            match result {
+                Ok(true) => {}
+                Ok(false) => {
+                    if close {
+                        iterator.close(Ok(JsValue::undefined()), self.context)?;
+                    }
+                    return Ok(());
+                }
+                Err(Interrupt::Yield(result)) => {
+                    self.progress
+                        .push(Progress::Iteration(iterator, iteration_environment));
+                    return Err(Interrupt::Yield(result));
+                }
+                Err(interrupt) if close => {
+                    return Err(close_iterator(&iterator, interrupt, self.context))
+                }
+                Err(interrupt) => return Err(interrupt),
+            }
+        }
+    }
+
+    fn try_statement(&mut self, try_node: &Try) -> Completion<()> {
+        let (mut step, mut completion) = match self.resume() {
+            Some(Progress::Try(step, completion)) => (step, completion),
+            None => (TryStep::Block, None),
+            Some(progress) => unexpected(progress),
+        };
+
+        loop {
+            match step {
+                TryStep::Block => match self.block(try_node.block()) {
+                    Err(Interrupt::Yield(result)) => {
+                        self.progress.push(Progress::Try(step, None));
+                        return Err(Interrupt::Yield(result));
+                    }
+                    Err(Interrupt::Throw(error)) if try_node.catch().is_some() => {
+                        completion = Some(Interrupt::Throw(error));
+                        step = TryStep::Catch;
+                    }
+                    result => {
+                        completion = result.err();
+                        step = TryStep::Finally;
+                    }
+                },
+                TryStep::Catch => {
+                    let catch = try_node
+                        .catch()
+                        .expect("only a `try` statement with a catch clause catches");
+                    // The error is only there when the catch clause is entered, not resumed.
+                    let error = match completion.take() {
+                        Some(Interrupt::Throw(error)) => Some(error),
+                        _ => None,
+                    };
+
+                    let result = self.scope(|this| {
+                        if let Some(error) = error {
+                            catch.bind_parameter(error, this.context)?;
+                        }
+                        this.block(catch.block())
+                    });
+
+                    match result {
+                        Err(Interrupt::Yield(result)) => {
+                            self.progress.push(Progress::Try(step, None));
+                            return Err(Interrupt::Yield(result));
+                        }
+                        result => {
+                            completion = result.err();
+                            step = TryStep::Finally;
+                        }
+                    }
+                }
+                TryStep::Finally => {
+                    if let Some(finally) = try_node.finally() {
+                        match self.block(finally) {
+                            Err(Interrupt::Yield(result)) => {
+                                self.progress.push(Progress::Try(step, completion));
+                                return Err(Interrupt::Yield(result));
+                            }
+                            result => result?,
+                        }
+                    }
+                    return completion.map_or(Ok(()), Err);
+                }
+            }
+        }
+    }
+
+    fn switch(&mut self, switch: &Switch) -> Completion<()> {
+        // A `switch` suspended with the index past the last case is in the default clause.
+        let (value, mut index, mut matched) = match self.resume() {
+            Some(Progress::Switch {
+                value,
+                case,
+                matched,
+            }) => (value, case, matched),
+            Some(Progress::Child(_)) | None => {
+                let value = suspend!(self, self.expression(switch.val()), Progress::Child(0))?;
+                (value, 0, false)
+            }
+            Some(progress) => unexpected(progress),
+        };
+
+        while let Some(case) = switch.cases().get(index) {
+            if !matched {
+                let cond = suspend!(
+                    self,
+                    self.expression(case.condition()),
+                    Progress::Switch {
+                        value: value.clone(),
+                        case: index,
+                        matched,
+                    }
+                )?;
+                matched = value.strict_equals(&cond);
+            }
+
+            if matched {
+                match self.statements(case.body().items()) {
+                    Err(Interrupt::Yield(result)) => {
+                        self.progress.push(Progress::Switch {
+                            value,
+                            case: index,
+                            matched,
+                        });
+                        return Err(Interrupt::Yield(result));
+                    }
+                    Err(Interrupt::Break(None)) => return Ok(()),
+                    result => result?,
+                }
+            }
+            index += 1;
+        }
+
+        if let (false, Some(default)) = (matched, switch.default()) {
+            match self.statements(default) {
+                Err(Interrupt::Yield(result)) => {
+                    self.progress.push(Progress::Switch {
+                        value,
+                        case: index,
+                        matched,
+                    });
+                    return Err(Interrupt::Yield(result));
+                }
+                Err(Interrupt::Break(None)) => return Ok(()),
+                result => result?,
+            }
+        }
+        Ok(())
+    }
+
+    fn declarations(&mut self, list: &DeclarationList) -> Completion<()> {
+        let mut index = match self.resume() {
+            Some(Progress::Child(index)) => index,
+            None => 0,
+            Some(progress) => unexpected(progress),
+        };
+
+        while let Some(decl) = list.as_ref().get(index) {
+            let value = match decl.init() {
+                Some(init) => suspend!(self, self.expression(init), Progress::Child(index))?,
+                None if list.is_const() => {
+                    return Err(self
+                        .context
+                        .construct_syntax_error("missing = in const declaration")
+                        .into())
+                }
+                None => JsValue::undefined(),
+            };
+            list.declare(decl, value, self.context)?;
+            index += 1;
+        }
+        Ok(())
+    }
+
+    fn expression(&mut self, node: &Node) -> Completion<JsValue> {
+        if !node.contains_yield() {
+            return self.run(node);
+        }
+
+        match node {
+            Node::Yield(node) => self.r#yield(node),
+            Node::Assign(assign) if !assign.lhs().contains_yield() => {
+                let value = self.expression(assign.rhs())?;
+                assign.assign(value.clone(), self.context)?;
+                Ok(value)
+            }
+            Node::BinOp(bin_op) => self.bin_op(bin_op),
+            Node::UnaryOp(unary_op)
+                if matches!(
+                    unary_op.op(),
+                    op::UnaryOp::Minus
+                        | op::UnaryOp::Plus
+                        | op::UnaryOp::Not
+                        | op::UnaryOp::Tilde
+                        | op::UnaryOp::Void
+                        | op::UnaryOp::TypeOf
+                ) =>
+            {
+                let value = self.expression(unary_op.target())?;
+                Ok(UnaryOp::apply(unary_op.op(), value, self.context)?)
+            }
+            Node::ConditionalOp(conditional) => self.conditional(conditional),
+            Node::Call(call) if !call.expr().contains_yield() => self.call(call),
+            Node::New(new) if !new.expr().contains_yield() => self.new_expression(new),
+            Node::ArrayDecl(array) => {
+                let values = self.list(array.as_ref(), Vec::new())?;
+                let array = Array::new_array(self.context);
+                Array::add_to_array_object(&array, &values, self.context)?;
+                Ok(array)
+            }
+            Node::Object(object) => self.object(object),
+            Node::TemplateLit(template) => self.template(template),
+            Node::GetConstField(get_const_field) => {
+                let obj = self.expression(get_const_field.obj())?;
+                let obj = obj.to_object(self.context)?;
+                Ok(obj.get(get_const_field.field(), self.context)?)
+            }
+            Node::GetField(get_field) => {
+                let values = self.values(&[get_field.obj(), get_field.field()])?;
+                let obj = values[0].to_object(self.context)?;
+                let key = values[1].to_property_key(self.context)?;
+                Ok(obj.get(key, self.context)?)
+            }
+            _ => self.unsupported(),
+        }
+    }
+
+    fn r#yield(&mut self, node: &Yield) -> Completion<JsValue> {
+        match self.resume() {
+            Some(Progress::Yield) => {
+                return match self.received() {
+                    (CompletionType::Normal, value) => Ok(value),
+                    (CompletionType::Return, value) => Err(Interrupt::Return(value)),
+                    (CompletionType::Throw, value) => Err(Interrupt::Throw(value)),
+                }
+            }
+            Some(Progress::Delegate(iterator)) => {
+                let (completion, received) = self.received();
+                return self.delegate(iterator, completion, received);
+            }
+            Some(Progress::Child(_)) | None => {}
+            Some(progress) => unexpected(progress),
+        }
+
+        let value = match node.expr() {
+            Some(expr) => suspend!(self, self.expression(expr), Progress::Child(0))?,
+            None => JsValue::undefined(),
+        };
+
+        if node.delegate() {
+            let iterator = value.get_iterator(self.context, None, None)?;
+            self.delegate(iterator, CompletionType::Normal, JsValue::undefined())
+        } else {
+            self.progress.push(Progress::Yield);
+            Err(Interrupt::Yield(create_iter_result_object(
+                value,
+                false,
+                self.context,
+            )))
+        }
+    }
+
+    /// Passes the completion a `yield*` is resumed with on to the `iterator` it delegates to,
+    /// yielding the result of the iterator until it is done.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator-function-definitions-runtime-semantics-evaluation
+    fn delegate(
+        &mut self,
+        iterator: IteratorRecord,
+        completion: CompletionType,
+        received: JsValue,
+    ) -> Completion<JsValue> {
+        let iterator_object = iterator.iterator_object().clone();
+        let inner_result = match completion {
+            // a. If received.[[Type]] is normal, then
+            // i. Let innerResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]], « received.[[Value]] »).
+            CompletionType::Normal => {
+                self.context
+                    .call(iterator.next_function(), &iterator_object, &[received])?
+            }
+            // b. Else if received.[[Type]] is throw, then
+            CompletionType::Throw => {
+                // i. Let throw be ? GetMethod(iterator, "throw").
+                // ii. If throw is not undefined, then
+                if let Some(throw) = iterator_object.get_method("throw", self.context)? {
+                    // 1. Let innerResult be ? Call(throw, iterator, « received.[[Value]] »).
+                    throw.call(&iterator_object, &[received], self.context)?
+                } else {
+                    // iii. Else,
+                    // 4. Perform ? IteratorClose(iteratorRecord, closeCompletion).
+                    iterator.close(Ok(JsValue::undefined()), self.context)?;
+                    // 6. Throw a TypeError exception.
+                    return Err(self
+                        .context
+                        .construct_type_error(
+                            "the iterator delegated to by yield* has no throw method",
+                        )
+                        .into());
+                }
+            }
+            // c. Else,
+            CompletionType::Return => {
+                // ii. Let return be ? GetMethod(iterator, "return").
+                // iii. If return is undefined, then
+                // 1. Return Completion(received).
+                // iv. Let innerReturnResult be ? Call(return, iterator, « received.[[Value]] »).
+                match iterator_object.get_method("return", self.context)? {
+                    Some(r#return) => r#return.call(&iterator_object, &[received], self.context)?,
+                    None => return Err(Interrupt::Return(received)),
+                }
+            }
+        };
+
+        // If Type(innerResult) is not Object, throw a TypeError exception.
+        if !inner_result.is_object() {
+            return Err(self
+                .context
+                .construct_type_error("iterator result is not an object")
+                .into());
+        }
+
+        // Let done be ? IteratorComplete(innerResult).
+        if inner_result.get_field("done", self.context)?.to_boolean() {
+            // Return ? IteratorValue(innerResult).
+            let value = inner_result.get_field("value", self.context)?;
+            if completion == CompletionType::Return {
+                return Err(Interrupt::Return(value));
+            }
+            Ok(value)
+        } else {
+            // Set received to GeneratorYield(innerResult).
+            self.progress.push(Progress::Delegate(iterator));
+            Err(Interrupt::Yield(inner_result))
+        }
+    }
+
+    /// Evaluates the operands `nodes` of an expression in order.
+    fn values(&mut self, nodes: &[&Node]) -> Completion<Vec<JsValue>> {
+        let mut values = match self.resume() {
+            Some(Progress::Values(values)) => values,
+            None => Vec::with_capacity(nodes.len()),
+            Some(progress) => unexpected(progress),
+        };
+        while let Some(node) = nodes.get(values.len()) {
+            let value = suspend!(self, self.expression(node), Progress::Values(values))?;
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    /// Evaluates the elements `nodes` of an argument list or array literal in order, spreading
+    /// the `...` elements, after the `values` evaluated before them.
+    fn list(&mut self, nodes: &[Node], values: Vec<JsValue>) -> Completion<Vec<JsValue>> {
+        let (mut index, mut values) = match self.resume() {
+            Some(Progress::List(index, values)) => (index, values),
+            None => (0, values),
+            Some(progress) => unexpected(progress),
+        };
+        while let Some(node) = nodes.get(index) {
+            if let Node::Spread(spread) = node {
+                let iterable = suspend!(
+                    self,
+                    self.expression(spread.val()),
+                    Progress::List(index, values)
+                )?;
+                values.extend(iterable_to_list(self.context, iterable, None)?);
+            } else {
+                let value = suspend!(self, self.expression(node), Progress::List(index, values))?;
+                values.push(value);
+            }
+            index += 1;
+        }
+        Ok(values)
+    }
+
+    fn bin_op(&mut self, bin_op: &BinOp) -> Completion<JsValue> {
+        match bin_op.op() {
+            op::BinOp::Num(_) | op::BinOp::Bit(_) | op::BinOp::Comp(_) => {
+                let values = self.values(&[bin_op.lhs(), bin_op.rhs()])?;
+                Ok(BinOp::apply(
+                    bin_op.op(),
+                    &values[0],
+                    &values[1],
+                    self.context,
+                )?)
+            }
+            op::BinOp::Log(_) | op::BinOp::Comma => {
+                if !matches!(self.resume(), Some(Progress::Child(1))) {
+                    let left = suspend!(self, self.expression(bin_op.lhs()), Progress::Child(0))?;
+                    let short_circuits = match bin_op.op() {
+                        op::BinOp::Log(LogOp::And) => !left.to_boolean(),
+                        op::BinOp::Log(LogOp::Or) => left.to_boolean(),
+                        op::BinOp::Log(LogOp::Coalesce) => !left.is_null_or_undefined(),
+                        _ => false,
+                    };
+                    if short_circuits {
+                        return Ok(left);
+                    }
+                }
+                suspend!(self, self.expression(bin_op.rhs()), Progress::Child(1))
+            }
+            op::BinOp::Assign(_) if bin_op.lhs().contains_yield() => self.unsupported(),
+            op::BinOp::Assign(op) => {
+                // The value of the left hand side, followed by the object it is a property of.
+                let values = match self.resume() {
+                    Some(Progress::Values(values)) => values,
+                    None => match bin_op.lhs() {
+                        Node::Identifier(name) => {
+                            vec![self.context.get_binding_value(name.as_ref())?]
+                        }
+                        Node::GetConstField(get_const_field) => {
+                            let obj = self.run(get_const_field.obj())?;
+                            vec![obj.get_field(get_const_field.field(), self.context)?, obj]
+                        }
+                        _ => return Ok(JsValue::undefined()),
+                    },
+                    Some(progress) => unexpected(progress),
+                };
+
+                let x = values[0].clone();
+                let value = if BinOp::short_circuits(op, &x) {
+                    x
+                } else {
+                    let y = suspend!(
+                        self,
+                        self.expression(bin_op.rhs()),
+                        Progress::Values(values)
+                    )?;
+                    BinOp::apply_assign(op, x, y, self.context)?
+                };
+
+                match bin_op.lhs() {
+                    Node::Identifier(name) => self.context.set_mutable_binding(
+                        name.as_ref(),
+                        value.clone(),
+                        self.context.strict(),
+                    )?,
+                    Node::GetConstField(get_const_field) => {
+                        values[1].set_field(
+                            get_const_field.field(),
+                            value.clone(),
+                            false,
+                            self.context,
+                        )?;
+                    }
+                    _ => {}
+                }
+                Ok(value)
+            }
+        }
+    }
+
+    fn conditional(&mut self, conditional: &ConditionalOp) -> Completion<JsValue> {
+        let branch = match self.resume() {
+            Some(Progress::Child(0)) | None => {
+                let cond = suspend!(
+                    self,
+                    self.expression(conditional.cond()),
+                    Progress::Child(0)
+                )?;
+                if cond.to_boolean() {
+                    1
+                } else {
+                    2
+                }
+            }
+            Some(Progress::Child(branch)) => branch,
+            Some(progress) => unexpected(progress),
+        };
+
+        let node = if branch == 1 {
+            conditional.if_true()
+        } else {
+            conditional.if_false()
+        };
+        suspend!(self, self.expression(node), Progress::Child(branch))
+    }
+
+    fn call(&mut self, call: &Call) -> Completion<JsValue> {
+        // The function and the `this` value come before the arguments.
+        let values = if self.resuming() {
+            Vec::new()
+        } else {
+            let (this, function) = Call::evaluate_callee(call.expr(), self.context)?;
+            vec![function, this]
+        };
+        let mut values = self.list(call.args(), values)?;
+        let args = values.split_off(2);
+        let (function, this) = (&values[0], &values[1]);
+
+        let result = if call.is_direct_eval(function, self.context) {
+            Eval::perform_eval(
+                args.get_or_undefined(0),
+                self.context.strict(),
+                true,
+                self.context,
+            )
+        } else {
+            self.context.call(function, this, &args)
+        };
+
+        // unset the early return flag
+        self.context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        Ok(result?)
+    }
+
+    fn new_expression(&mut self, new: &New) -> Completion<JsValue> {
+        // The constructor comes before the arguments.
+        let values = if self.resuming() {
+            Vec::new()
+        } else {
+            vec![self.run(new.expr())?]
+        };
+        let mut args = self.list(new.args(), values)?;
+        let constructor = args.remove(0);
+
+        let result = constructor
+            .as_constructor()
+            .ok_or_else(|| {
+                self.context
+                    .construct_type_error(format!("{} is not a constructor", new.expr()))
+            })
+            .and_then(|cons| cons.construct(&args, &cons.clone().into(), self.context));
+
+        // unset the early return flag
+        self.context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        Ok(result?)
+    }
+
+    fn object(&mut self, object: &Object) -> Completion<JsValue> {
+        let (obj, mut index, mut key) = match self.resume() {
+            Some(Progress::Object(obj, index, key)) => (obj, index, key),
+            None => (self.context.construct_object(), 0, None),
+            Some(progress) => unexpected(progress),
+        };
+
+        while let Some(property) = object.properties().get(index) {
+            if !property.contains_yield() {
+                Object::define_property(&obj, property, self.context)?;
+                index += 1;
+                continue;
+            }
+
+            match property {
+                PropertyDefinition::Property(name, value) => {
+                    let property_key = match (key.take(), name) {
+                        (Some(key), _) => key,
+                        (None, PropertyName::Literal(name)) => name.clone().into(),
+                        (None, PropertyName::Computed(node)) => {
+                            let name = suspend!(
+                                self,
+                                self.expression(node),
+                                Progress::Object(obj, index, None)
+                            )?;
+                            name.to_property_key(self.context)?
+                        }
+                    };
+                    let value = suspend!(
+                        self,
+                        self.expression(value),
+                        Progress::Object(obj, index, Some(property_key))
+                    )?;
+                    obj.create_data_property_or_throw(property_key, value, self.context)?;
+                }
+                PropertyDefinition::SpreadObject(node) => {
+                    let value = suspend!(
+                        self,
+                        self.expression(node),
+                        Progress::Object(obj, index, None)
+                    )?;
+                    if !value.is_null_or_undefined() {
+                        obj.copy_data_properties::<String>(&value, vec![], self.context)?;
+                    }
+                }
+                _ => return self.unsupported(),
+            }
+            index += 1;
+        }
+
+        Ok(obj.into())
+    }
+
+    fn template(&mut self, template: &TemplateLit) -> Completion<JsValue> {
+        let (mut index, mut string) = match self.resume() {
+            Some(Progress::Template(index, string)) => (index, string),
+            None => (0, String::new()),
+            Some(progress) => unexpected(progress),
+        };
+
+        while let Some(element) = template.elements().get(index) {
+            match element {
+                TemplateElement::String(s) => string.push_str(s),
+                TemplateElement::Expr(node) => {
+                    let value = suspend!(
+                        self,
+                        self.expression(node),
+                        Progress::Template(index, string)
+                    )?;
+                    string.push_str(&value.to_string(self.context)?);
+                }
+            }
+            index += 1;
+        }
+
+        Ok(string.into())
+    }
+}
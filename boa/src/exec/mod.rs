@@ -1,9 +1,12 @@
 //! Execution of the AST, this is where the interpreter actually runs
 
+mod generator;
 #[cfg(test)]
 mod tests;
 
-use crate::{Context, JsResult, JsValue};
+pub(crate) use generator::GeneratorBody;
+
+use crate::{builtins::function::Function, Context, JsResult, JsValue};
 
 pub trait Executable {
     /// Runs this executable in the given context.
@@ -18,11 +21,128 @@ pub(crate) enum InterpreterState {
     Continue(Option<Box<str>>),
 }
 
+/// The type of a completion record, which tells how the evaluation of a statement ended.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-completion-record-specification-type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CompletionType {
+    Normal,
+    Return,
+    Throw,
+}
+
+/// A call in tail position of strict mode code.
+///
+/// When the callee is an ordinary function, the `return` statement defers the call to the
+/// function call that is returning, which runs it in a loop instead of growing the native stack.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-preparefortailcall
+#[derive(Debug)]
+pub(crate) struct TailCall {
+    pub(crate) function: JsValue,
+    pub(crate) this: JsValue,
+    pub(crate) args: Vec<JsValue>,
+}
+
+impl TailCall {
+    /// Creates a new tail call, looking through bound functions and the `Function.prototype`
+    /// methods `call` and `apply` to the function they end up calling.
+    pub(crate) fn new(
+        mut function: JsValue,
+        mut this: JsValue,
+        mut args: Vec<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let call = context.intrinsics().function_prototype_call();
+        let apply = context.intrinsics().function_prototype_apply();
+
+        while let Some(object) = function.as_object().cloned() {
+            if object == call && this.is_callable() {
+                // `Function.prototype.call ( thisArg, ...args )`
+                let mut args_iter = std::mem::take(&mut args).into_iter();
+                function = std::mem::replace(&mut this, args_iter.next().unwrap_or_default());
+                args = args_iter.collect();
+            } else if object == apply && this.is_callable() {
+                // `Function.prototype.apply ( thisArg, argArray )`
+                let arg_array = args.get(1).cloned().unwrap_or_default();
+                let arg_list = if arg_array.is_null_or_undefined() {
+                    Vec::new()
+                } else {
+                    arg_array.create_list_from_array_like(&[], context)?
+                };
+                let this_arg = std::mem::take(&mut args).into_iter().next();
+                function = std::mem::replace(&mut this, this_arg.unwrap_or_default());
+                args = arg_list;
+            } else if let Some((target, bound_this, mut bound_args)) =
+                object.borrow().as_bound_function().map(|bound_function| {
+                    (
+                        bound_function.target_function().clone(),
+                        bound_function.this().clone(),
+                        bound_function.args().to_vec(),
+                    )
+                })
+            {
+                bound_args.extend(args);
+                function = target.into();
+                this = bound_this;
+                args = bound_args;
+            } else {
+                break;
+            }
+        }
+
+        Ok(Self {
+            function,
+            this,
+            args,
+        })
+    }
+
+    /// Returns `true` if the callee is an ordinary function, so the call can be deferred.
+    pub(crate) fn is_deferrable(&self) -> bool {
+        self.function.as_object().map_or(false, |function| {
+            matches!(
+                function.borrow().as_function(),
+                Some(Function::Ordinary { .. })
+            )
+        })
+    }
+
+    /// Runs the call right away.
+    pub(crate) fn run(self, context: &mut Context) -> JsResult<JsValue> {
+        context.call(&self.function, &self.this, &self.args)
+    }
+
+    /// Runs the tail call deferred by a `return` statement, if any, in place of `value`.
+    ///
+    /// This is needed where the `return` statement turns out not to be in tail position, like
+    /// inside a `try` block.
+    pub(crate) fn complete(value: JsValue, context: &mut Context) -> JsResult<JsValue> {
+        if let Some(tail_call) = context.executor().take_tail_call() {
+            let result = tail_call.run(context);
+            context
+                .executor()
+                .set_current_state(InterpreterState::Return);
+            result
+        } else {
+            Ok(value)
+        }
+    }
+}
+
 /// A Javascript intepreter
 #[derive(Debug)]
 pub struct Interpreter {
     /// the current state of the interpreter.
     state: InterpreterState,
+    /// The tail call deferred by the last `return` statement.
+    tail_call: Option<TailCall>,
 }
 
 impl Default for Interpreter {
@@ -36,6 +156,7 @@ impl Interpreter {
     pub fn new() -> Self {
         Self {
             state: InterpreterState::Executing,
+            tail_call: None,
         }
     }
 
@@ -48,4 +169,20 @@ impl Interpreter {
     pub(crate) fn get_current_state(&self) -> &InterpreterState {
         &self.state
     }
+
+    /// Takes the current state, leaving the interpreter executing.
+    #[inline]
+    pub(crate) fn take_current_state(&mut self) -> InterpreterState {
+        std::mem::replace(&mut self.state, InterpreterState::Executing)
+    }
+
+    #[inline]
+    pub(crate) fn set_tail_call(&mut self, tail_call: TailCall) {
+        self.tail_call = Some(tail_call)
+    }
+
+    #[inline]
+    pub(crate) fn take_tail_call(&mut self) -> Option<TailCall> {
+        self.tail_call.take()
+    }
 }
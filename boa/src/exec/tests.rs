@@ -1,5 +1,6 @@
 use crate::{
-    builtins::Number, check_output, exec, forward, forward_val, Context, JsValue, TestAction,
+    builtins::Number, check_output, exec, forward, forward_val, Completion, Context, JsValue,
+    TestAction,
 };
 
 #[test]
@@ -731,6 +732,45 @@ mod in_operator {
         assert_eq!(&exec(sym_in_object), "true");
     }
 
+    #[test]
+    fn inherited_symbol_in_object() {
+        let sym_in_object = r#"
+            var sym = Symbol('hi');
+            var o = Object.create({ [sym]: 'hello' });
+            [sym in o, Symbol('hi') in o].join()
+        "#;
+        assert_eq!(&exec(sym_in_object), "\"true,false\"");
+    }
+
+    #[test]
+    fn key_is_converted_with_to_property_key() {
+        let key_in_object = r#"
+            var key = { toString() { return 'a'; } };
+            key in { a: 1 }
+        "#;
+        assert_eq!(&exec(key_in_object), "true");
+    }
+
+    #[test]
+    fn proxy_has_trap() {
+        let scenario = r#"
+            var keys = [];
+            var proxy = new Proxy({}, {
+                has(target, key) {
+                    keys.push(String(key));
+                    return key === 'x';
+                }
+            });
+            var results = ['x' in proxy, 1 in proxy, 'x' in Object.create(proxy)];
+        "#;
+
+        check_output(&[
+            TestAction::Execute(scenario),
+            TestAction::TestEq("results.join()", "\"true,false,true\""),
+            TestAction::TestEq("keys.join()", "\"x,1,x\""),
+        ]);
+    }
+
     #[test]
     fn should_type_error_when_rhs_not_object() {
         let scenario = r#"
@@ -1366,6 +1406,43 @@ fn assign_to_object_decl() {
     assert_eq!(forward(&mut context, "{a: 3} = {a: 5};"), ERR_MSG);
 }
 
+#[test]
+fn syntax_error_reports_position() {
+    let mut context = Context::new();
+
+    let cases = [
+        ("let a = 1;\nlet b = ;", "at line 2, col 9"),
+        ("foo(", "unexpected end of input at line 1, col 5"),
+        (
+            "if (a) {\n  b;\n",
+            "unexpected end of input at line 3, col 1",
+        ),
+        (
+            "let s =\n  'abc",
+            "unterminated string literal at position: 2:3",
+        ),
+        (
+            "let s = `abc",
+            "unterminated template literal at position: 1:9",
+        ),
+    ];
+    for (src, position) in cases {
+        let result = forward(&mut context, src);
+        assert!(
+            result.starts_with("Uncaught \"SyntaxError\": ") && result.contains(position),
+            "{:?} gave {}",
+            src,
+            result
+        );
+    }
+
+    let message = forward(
+        &mut context,
+        "try { eval('1 +\\n  )') } catch (e) { e.message }",
+    );
+    assert!(message.contains("at line 2, col 3"), "{}", message);
+}
+
 #[test]
 fn multiline_str_concat() {
     let scenario = r#"
@@ -1488,6 +1565,21 @@ fn test_strict_mode_reserved_name() {
     }
 }
 
+#[test]
+fn test_strict_mode_eval_and_arguments_references() {
+    // Only binding `eval` and `arguments` is an error in strict mode code, referencing them isn't.
+    let mut context = Context::new();
+    let scenario = r#"
+        'use strict';
+        function f() {
+            return eval("1 + 1") + arguments.length;
+        }
+        f(1, 2);
+        "#;
+
+    assert_eq!(forward(&mut context, scenario), "4");
+}
+
 #[test]
 fn test_strict_mode_func_decl_in_block() {
     // Checks that a function declaration in a block is an error in
@@ -1531,3 +1623,331 @@ fn test_empty_statement() {
     "#;
     assert_eq!(&exec(src), "10");
 }
+
+#[test]
+fn eval_completion_normal() {
+    let mut context = Context::new();
+
+    match context.eval_completion("var x = 40; x + 2") {
+        Completion::Normal(value) => assert_eq!(value.as_number(), Some(42.0)),
+        completion => panic!("expected a normal completion, got {:?}", completion),
+    }
+}
+
+#[test]
+fn eval_completion_throw() {
+    let mut context = Context::new();
+
+    match context.eval_completion("throw { code: 7 }") {
+        Completion::Throw(value) => {
+            let code = value
+                .as_object()
+                .expect("thrown value should be an object")
+                .get("code", &mut context)
+                .unwrap();
+            assert_eq!(code.as_number(), Some(7.0));
+        }
+        completion => panic!("expected a throw completion, got {:?}", completion),
+    }
+
+    match context.eval_completion("null.x") {
+        Completion::Throw(value) => {
+            assert!(value
+                .to_string(&mut context)
+                .unwrap()
+                .starts_with("TypeError"));
+        }
+        completion => panic!("expected a throw completion, got {:?}", completion),
+    }
+}
+
+#[test]
+fn eval_completion_error() {
+    let mut context = Context::new();
+
+    assert!(matches!(
+        context.eval_completion("let = ;"),
+        Completion::Error(_)
+    ));
+    // A failed parse doesn't run anything.
+    assert!(matches!(
+        context.eval_completion("var ran = true; let = ;"),
+        Completion::Error(_)
+    ));
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.prototype.hasOwnProperty.call(globalThis, 'ran')"
+        ),
+        "false"
+    );
+
+    // `eval` still reports parse failures as a thrown `SyntaxError`.
+    let error = context.eval("let = ;").unwrap_err();
+    assert!(error
+        .to_string(&mut context)
+        .unwrap()
+        .starts_with("SyntaxError"));
+}
+
+#[test]
+fn strict_tail_calls_do_not_grow_the_stack() {
+    let scenario = r#"
+        "use strict";
+        function isEven(n) {
+            if (n === 0) {
+                return true;
+            }
+            return isOdd(n - 1);
+        }
+        function isOdd(n) {
+            if (n === 0) {
+                return false;
+            }
+            return isEven(n - 1);
+        }
+        isEven(1e6);
+        "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn strict_tail_calls_through_call_apply_and_bind() {
+    let scenario = r#"
+        "use strict";
+        function count(n) {
+            if (n === 0) {
+                return "done";
+            }
+            switch (n % 3) {
+                case 0:
+                    return count.call(null, n - 1);
+                case 1:
+                    return count.apply(null, [n - 1]);
+                default:
+                    return count.bind(null)(n - 1);
+            }
+        }
+        count(1e5);
+        "#;
+
+    assert_eq!(&exec(scenario), "\"done\"");
+}
+
+#[test]
+fn strict_tail_calls_in_operators_do_not_grow_the_stack() {
+    let forms = [
+        ("conditional consequent", "n > 0 ? f(n - 1) : 'never'"),
+        ("conditional alternative", "n < 0 ? 'never' : f(n - 1)"),
+        ("logical and", "n > 0 && f(n - 1)"),
+        ("logical or", "n < 0 || f(n - 1)"),
+        ("coalesce", "null ?? f(n - 1)"),
+        ("comma", "n, f(n - 1)"),
+    ];
+
+    for (form, expression) in forms {
+        let scenario = format!(
+            r#"
+            "use strict";
+            function f(n) {{
+                if (n === 0) {{
+                    return "done";
+                }}
+                return {};
+            }}
+            f(1e5);
+            "#,
+            expression
+        );
+
+        assert_eq!(&exec(&scenario), "\"done\"", "{}", form);
+    }
+}
+
+#[test]
+fn strict_direct_eval_in_tail_position() {
+    let scenario = r#"
+        "use strict";
+        function f() {
+            var x = 1;
+            return eval("x");
+        }
+        function g(c) {
+            var y = 2;
+            return c ? eval("y") : 0;
+        }
+        f() + g(true);
+        "#;
+
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn tail_calls_in_try_blocks_are_caught() {
+    let scenario = r#"
+        "use strict";
+        function thrower() {
+            throw "thrown";
+        }
+        function caller() {
+            try {
+                return thrower();
+            } catch (e) {
+                return "caught " + e;
+            }
+        }
+        function constructed() {
+            return { tail: true };
+        }
+        function Constructor() {
+            return constructed();
+        }
+        caller() + " " + new Constructor().tail;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"caught thrown true\"");
+}
+
+#[test]
+fn return_in_function_called_from_native_code() {
+    let scenario = r#"
+        var log = [];
+        var object = {
+            get getter() { return "get"; },
+        };
+        var value = object.getter;
+        log.push(value);
+        var proxy = new Proxy({}, { get: function () { return "trap"; } });
+        var trapped = proxy.a;
+        log.push(trapped);
+        log.join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"get,trap\"");
+}
+
+#[test]
+fn run_with_timeout_aborts_infinite_loops() {
+    use std::time::Duration;
+
+    let mut context = Context::new();
+    let error = context
+        .run_with_timeout("while (true) {}", Duration::from_millis(20))
+        .expect_err("an infinite loop must time out");
+    assert_eq!(
+        error
+            .to_string(&mut context)
+            .expect("the error can be converted to a string")
+            .as_str(),
+        "Error: script execution timed out"
+    );
+
+    // The timeout can be caught by the script.
+    let src = r#"
+        var message = "";
+        try { while (true) {} } catch (e) { message = e.message; }
+        message
+    "#;
+    assert_eq!(
+        context
+            .run_with_timeout(src, Duration::from_millis(20))
+            .expect("the timeout must be catchable"),
+        "script execution timed out".into()
+    );
+
+    // Once the deadline has passed, the script keeps being interrupted.
+    let src = r#"
+        var caught = 0;
+        while (true) {
+            try { while (true) {} } catch (e) { caught++; }
+            if (caught === 3) { throw "done"; }
+        }
+    "#;
+    assert_eq!(
+        context
+            .run_with_timeout(src, Duration::from_millis(20))
+            .expect_err("the script rethrows"),
+        "done".into()
+    );
+
+    // The deadline doesn't outlive the call.
+    assert_eq!(
+        forward(&mut context, "var i = 0; while (i < 10000) { i++; } i"),
+        "10000"
+    );
+}
+
+#[test]
+fn register_global_accessor_validates_in_setter() {
+    use crate::{builtins::JsArgs, JsResult};
+    use std::cell::RefCell;
+
+    thread_local! {
+        static THEME: RefCell<String> = RefCell::new(String::from("light"));
+    }
+
+    fn get_theme(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(THEME.with(|theme| theme.borrow().clone()).into())
+    }
+
+    fn set_theme(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let theme = args.get_or_undefined(0).to_string(context)?;
+        if theme.as_str() != "light" && theme.as_str() != "dark" {
+            return context.throw_range_error(format!("invalid theme '{}'", theme));
+        }
+        THEME.with(|current| *current.borrow_mut() = theme.to_string());
+        Ok(JsValue::undefined())
+    }
+
+    let mut context = Context::new();
+    context.register_global_accessor("theme", Some(get_theme), Some(set_theme));
+    context.register_global_accessor("readOnly", Some(get_theme), None);
+
+    assert_eq!(forward(&mut context, "theme"), "\"light\"");
+    forward(&mut context, "theme = 'dark'");
+    assert_eq!(forward(&mut context, "theme"), "\"dark\"");
+    assert_eq!(THEME.with(|theme| theme.borrow().clone()), "dark");
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { theme = 'blue' } catch (e) { e.message }"
+        ),
+        "\"invalid theme 'blue'\""
+    );
+    assert_eq!(forward(&mut context, "theme"), "\"dark\"");
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "var d = Object.getOwnPropertyDescriptor(globalThis, 'theme'); [d.get.name, d.set.name, d.enumerable, d.configurable].join()"
+        ),
+        "\"get theme,set theme,false,true\""
+    );
+
+    // Without a setter, assignments are ignored.
+    forward(&mut context, "readOnly = 'light'");
+    assert_eq!(forward(&mut context, "readOnly"), "\"dark\"");
+}
+
+#[cfg(not(feature = "vm"))]
+#[test]
+fn async_functions_require_the_vm() {
+    let scenarios = [
+        "async function f() { await 1; }",
+        "var f = async function () { await 1; };",
+        "var f = async () => await 1;",
+        "var object = { async f() { await 1; } };",
+    ];
+    for scenario in scenarios {
+        let src = format!(
+            "try {{ {} 'evaluated' }} catch (e) {{ e.name + ': ' + e.message }}",
+            scenario
+        );
+        assert_eq!(
+            &exec(&src),
+            "\"SyntaxError: async functions require the `vm` feature\""
+        );
+    }
+}
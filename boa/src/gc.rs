@@ -8,3 +8,27 @@ pub use gc::{
     custom_trace, force_collect, unsafe_empty_trace as empty_trace, Finalize, GcCellRef as Ref,
     GcCellRefMut as RefMut, Trace,
 };
+
+/// How a [`Context`][crate::Context] schedules garbage collection.
+///
+/// The `gc` crate has no incremental marking, so every collection is a full, stop-the-world
+/// collection. What a strategy changes is when collections happen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GcStrategy {
+    /// Collect only when the allocator decides the heap has grown enough, which can pause a
+    /// running script for a whole collection.
+    StopTheWorld,
+
+    /// Also run a full collection at event-loop boundaries: once
+    /// [`Context::run_jobs`][crate::Context::run_jobs] has drained the job queue, and once
+    /// `Context::run_timers` has fired its callbacks, if any job or callback ran. The collection
+    /// itself is not incremental, but it happens between jobs, while the heap is still small,
+    /// rather than in the middle of a script.
+    Incremental,
+}
+
+impl Default for GcStrategy {
+    fn default() -> Self {
+        Self::StopTheWorld
+    }
+}
@@ -0,0 +1,110 @@
+//! Host hooks that let an embedder customize implementation-defined behaviour.
+//!
+//! The ECMAScript specification leaves some operations to the host environment, which Boa
+//! exposes through the [`HostHooks`] trait. Every method has a default implementation that
+//! follows the behaviour the specification recommends, so an embedder only needs to override
+//! the hooks it cares about and install them with [`Context::with_host_hooks`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-host-hooks-summary
+
+use crate::{job::NativeJob, object::JsObject, Context, JsResult, JsValue};
+use std::fmt;
+
+/// Customizable host hooks of a [`Context`].
+///
+/// # Examples
+///
+/// Forbidding the compilation of strings into code:
+///
+/// ```
+///# use boa::{host::HostHooks, Context, JsResult};
+/// struct NoDynamicCode;
+///
+/// impl HostHooks for NoDynamicCode {
+///     fn ensure_can_compile_strings(&self, context: &mut Context) -> JsResult<()> {
+///         Err(context.construct_eval_error("code generation from strings is disallowed"))
+///     }
+/// }
+///
+/// let mut context = Context::with_host_hooks(NoDynamicCode);
+/// assert!(context.eval("new Function('return 1')").is_err());
+/// ```
+pub trait HostHooks {
+    /// [`HostEnsureCanCompileStrings ( calleeRealm )`][spec]
+    ///
+    /// Called before a string is compiled into code at runtime, like in `eval` or the
+    /// `Function` constructor. Returning an error aborts the compilation and throws the error.
+    ///
+    /// The default implementation allows all compilations.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostensurecancompilestrings
+    fn ensure_can_compile_strings(&self, _context: &mut Context) -> JsResult<()> {
+        Ok(())
+    }
+
+    /// [`HostEnqueuePromiseJob ( job, realm )`][spec]
+    ///
+    /// Called with the jobs that run the reactions of a settled promise, and that resolve a
+    /// promise with a thenable. The host must run every job once the execution stack is empty,
+    /// in the order they were enqueued, which lets an embedder drive them from its own event loop.
+    ///
+    /// The default implementation pushes the job to the job queue of the context, which is
+    /// drained by [`Context::run_jobs`].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostenqueuepromisejob
+    fn enqueue_promise_job(&self, job: NativeJob, context: &mut Context) {
+        context.enqueue_job(job);
+    }
+
+    /// [`HostPromiseRejectionTracker ( promise, operation )`][spec]
+    ///
+    /// Called with [`PromiseRejectionOperation::Reject`] when a promise is rejected without any
+    /// handlers, and with [`PromiseRejectionOperation::Handle`] when a handler is later added to
+    /// such a promise. This lets an embedder report unhandled rejections.
+    ///
+    /// The default implementation does nothing.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+    fn promise_rejection_tracker(
+        &self,
+        _promise: &JsObject,
+        _operation: PromiseRejectionOperation,
+        _context: &mut Context,
+    ) {
+    }
+
+    /// [`report the exception`][spec]
+    ///
+    /// Called with an exception thrown by a callback that the engine invoked on behalf of the
+    /// host, like an `AbortSignal` event listener, when there is no script to propagate it to.
+    ///
+    /// The default implementation ignores the exception.
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/webappapis.html#report-the-exception
+    fn report_exception(&self, _error: &JsValue, _context: &mut Context) {}
+}
+
+/// The operation passed to [`HostHooks::promise_rejection_tracker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromiseRejectionOperation {
+    /// A promise was rejected without any handlers.
+    Reject,
+
+    /// A handler was added to a rejected promise that had none.
+    Handle,
+}
+
+impl fmt::Debug for dyn HostHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HostHooks")
+    }
+}
+
+/// The host hooks used by [`Context::new`], which keep every default implementation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultHooks;
+
+impl HostHooks for DefaultHooks {}
@@ -0,0 +1,106 @@
+//! Jobs that run after the currently running script, like the reactions of a `Promise`.
+//!
+//! The ECMAScript specification calls the work that must happen once the execution stack is
+//! empty a [`Job`][spec]. Boa hands every job to the host through
+//! [`HostHooks::enqueue_promise_job`], whose default implementation pushes it to the job
+//! queue of the [`Context`], drained by [`Context::run_jobs`].
+//!
+//! Native functions created with [`FunctionBuilder::async_native`] also leave a [`FutureJob`]
+//! behind, which [`Context::run_jobs`] polls until the future completes.
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-jobs
+
+#[cfg(doc)]
+use crate::{host::HostHooks, object::FunctionBuilder};
+use crate::{Context, JsResult, JsValue};
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{self, Poll, Wake, Waker},
+};
+
+/// The closure run by a [`NativeJob`].
+type JobFn = dyn FnOnce(&mut Context) -> JsResult<JsValue>;
+
+/// A job that runs some native code with access to a [`Context`].
+///
+/// # Examples
+///
+/// ```
+///# use boa::{job::NativeJob, Context};
+/// let mut context = Context::new();
+/// context.eval("var ran = false;").unwrap();
+/// context.enqueue_job(NativeJob::new(|context| context.eval("ran = true;")));
+///
+/// assert_eq!(context.eval("ran").unwrap(), false.into());
+/// context.run_jobs().unwrap();
+/// assert_eq!(context.eval("ran").unwrap(), true.into());
+/// ```
+pub struct NativeJob {
+    f: Box<JobFn>,
+}
+
+impl fmt::Debug for NativeJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NativeJob")
+    }
+}
+
+impl NativeJob {
+    /// Creates a new job from a closure.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnOnce(&mut Context) -> JsResult<JsValue> + 'static,
+    {
+        Self { f: Box::new(f) }
+    }
+
+    /// Runs the job.
+    pub fn call(self, context: &mut Context) -> JsResult<JsValue> {
+        (self.f)(context)
+    }
+}
+
+/// A future that produces the [`NativeJob`] to run once it completes.
+///
+/// Futures are polled by [`Context::run_jobs`] with a waker that does nothing, so the host has
+/// to call it again to make progress on the futures that are still pending.
+pub(crate) struct FutureJob {
+    future: Pin<Box<dyn Future<Output = NativeJob>>>,
+}
+
+impl fmt::Debug for FutureJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FutureJob")
+    }
+}
+
+impl FutureJob {
+    /// Creates a new job from a future.
+    pub(crate) fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = NativeJob> + 'static,
+    {
+        Self {
+            future: Box::pin(future),
+        }
+    }
+
+    /// Polls the future once, returning the job it produced if it completed.
+    pub(crate) fn poll(&mut self) -> Poll<NativeJob> {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        self.future
+            .as_mut()
+            .poll(&mut task::Context::from_waker(&waker))
+    }
+}
+
+/// A waker that does nothing, the pending futures are polled again by the next call to
+/// [`Context::run_jobs`] instead.
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
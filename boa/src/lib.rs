@@ -4,6 +4,7 @@ This is an experimental Javascript lexer, parser and compiler written in Rust. C
 # Crate Features
  - **serde** - Enables serialization and deserialization of the AST (Abstract Syntax Tree).
  - **console** - Enables `boa`s WHATWG `console` object implementation.
+ - **timers** - Enables the `setTimeout` and `clearTimeout` global functions, see `Context::run_timers`.
  - **profiler** - Enables profiling with measureme (this is mostly internal).
 
 **/
@@ -48,6 +49,8 @@ pub mod context;
 pub mod environment;
 pub mod exec;
 pub mod gc;
+pub mod host;
+pub mod job;
 pub mod object;
 pub mod profiler;
 pub mod property;
@@ -74,7 +77,11 @@ pub(crate) use crate::{exec::Executable, profiler::BoaProfiler};
 // Export things to root level
 #[doc(inline)]
 pub use crate::{
-    bigint::JsBigInt, context::Context, string::JsString, symbol::JsSymbol, value::JsValue,
+    bigint::JsBigInt,
+    context::{Completion, Context},
+    string::JsString,
+    symbol::JsSymbol,
+    value::JsValue,
 };
 
 use crate::syntax::{
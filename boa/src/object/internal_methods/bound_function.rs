@@ -36,26 +36,30 @@ fn bound_function_exotic_call(
     arguments_list: &[JsValue],
     context: &mut Context,
 ) -> JsResult<JsValue> {
-    let obj = obj.borrow();
-    let bound_function = obj
-        .as_bound_function()
-        .expect("bound function exotic method should only be callable from bound function objects");
-
-    // 1. Let target be F.[[BoundTargetFunction]].
-    let target = bound_function.target_function();
-
-    // 2. Let boundThis be F.[[BoundThis]].
-    let bound_this = bound_function.this();
-
-    // 3. Let boundArgs be F.[[BoundArguments]].
-    let bound_args = bound_function.args();
+    // The bound function must not stay borrowed while the target runs, since the target can
+    // access it.
+    let (target, bound_this, bound_args) = {
+        let obj = obj.borrow();
+        let bound_function = obj.as_bound_function().expect(
+            "bound function exotic method should only be callable from bound function objects",
+        );
+
+        // 1. Let target be F.[[BoundTargetFunction]].
+        // 2. Let boundThis be F.[[BoundThis]].
+        // 3. Let boundArgs be F.[[BoundArguments]].
+        (
+            bound_function.target_function().clone(),
+            bound_function.this().clone(),
+            bound_function.args().to_vec(),
+        )
+    };
 
     // 4. Let args be the list-concatenation of boundArgs and argumentsList.
-    let mut args = bound_args.to_vec();
+    let mut args = bound_args;
     args.extend_from_slice(arguments_list);
 
     // 5. Return ? Call(target, boundThis, args).
-    target.call(bound_this, &args, context)
+    target.call(&bound_this, &args, context)
 }
 
 /// Internal method `[[Construct]]` for Bound Function Exotic Objects
@@ -72,21 +76,23 @@ fn bound_function_exotic_construct(
     new_target: &JsValue,
     context: &mut Context,
 ) -> JsResult<JsValue> {
-    let object = obj.borrow();
-    let bound_function = object
-        .as_bound_function()
-        .expect("bound function exotic method should only be callable from bound function objects");
-
-    // 1. Let target be F.[[BoundTargetFunction]].
-    let target = bound_function.target_function();
-
-    // 2. Assert: IsConstructor(target) is true.
-
-    // 3. Let boundArgs be F.[[BoundArguments]].
-    let bound_args = bound_function.args();
+    let (target, bound_args) = {
+        let object = obj.borrow();
+        let bound_function = object.as_bound_function().expect(
+            "bound function exotic method should only be callable from bound function objects",
+        );
+
+        // 1. Let target be F.[[BoundTargetFunction]].
+        // 2. Assert: IsConstructor(target) is true.
+        // 3. Let boundArgs be F.[[BoundArguments]].
+        (
+            bound_function.target_function().clone(),
+            bound_function.args().to_vec(),
+        )
+    };
 
     // 4. Let args be the list-concatenation of boundArgs and argumentsList.
-    let mut args = bound_args.to_vec();
+    let mut args = bound_args;
     args.extend_from_slice(arguments_list);
 
     // 5. If SameValue(F, newTarget) is true, set newTarget to target.
@@ -1,10 +1,13 @@
 use crate::{
-    builtins::function::{Captures, ClosureFunctionSignature, Function, NativeFunctionSignature},
+    builtins::{
+        function::{Captures, ClosureFunctionSignature, Function, NativeFunctionSignature},
+        generator::{Generator, GeneratorContext},
+    },
     environment::{
         function_environment_record::{BindingStatus, FunctionEnvironmentRecord},
         lexical_environment::Environment,
     },
-    exec::{Executable, InterpreterState},
+    exec::{Executable, GeneratorBody, InterpreterState, TailCall},
     object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
     syntax::ast::node::RcStatementList,
     Context, JsResult, JsValue,
@@ -46,7 +49,30 @@ fn function_call(
     args: &[JsValue],
     context: &mut Context,
 ) -> JsResult<JsValue> {
-    call_construct(obj, this, args, context, false)
+    // A tail call deferred by an outer function must wait until its caller runs it.
+    let outer_tail_call = context.executor().take_tail_call();
+
+    let mut result = context.with_call_frame(obj, |context| {
+        call_construct(obj, this, args, context, false)
+    });
+
+    // Calls in tail position are deferred to here, so the native stack stays the same size
+    // however long the chain of tail calls is.
+    while let Some(tail_call) = context.executor().take_tail_call() {
+        let function = tail_call
+            .function
+            .as_object()
+            .expect("only calls to ordinary functions are deferred");
+        result = context.with_call_frame(function, |context| {
+            call_construct(function, &tail_call.this, &tail_call.args, context, false)
+        });
+    }
+
+    if let Some(outer_tail_call) = outer_tail_call {
+        context.executor().set_tail_call(outer_tail_call);
+    }
+
+    result
 }
 
 /// Construct an instance of this object with the specified arguments.
@@ -63,7 +89,9 @@ fn function_construct(
     new_target: &JsValue,
     context: &mut Context,
 ) -> JsResult<JsValue> {
-    call_construct(obj, new_target, args, context, true)
+    context.with_call_frame(obj, |context| {
+        call_construct(obj, new_target, args, context, true)
+    })
 }
 
 /// Internal implementation of [`call`](#method.call) and [`construct`](#method.construct).
@@ -89,13 +117,14 @@ pub(super) fn call_construct(
     /// This is needed for the call method since we cannot mutate the function itself since we
     /// already borrow it so we get the function body clone it then drop the borrow and run the body
     enum FunctionBody {
-        BuiltInFunction(NativeFunctionSignature),
-        BuiltInConstructor(NativeFunctionSignature),
+        BuiltInFunction(NativeFunctionSignature, Option<Captures>),
+        BuiltInConstructor(NativeFunctionSignature, Option<Captures>),
         Closure {
             function: Box<dyn ClosureFunctionSignature>,
             captures: Captures,
         },
         Ordinary(RcStatementList),
+        Generator(RcStatementList),
     }
 
     let this_function_object = obj.clone();
@@ -110,11 +139,12 @@ pub(super) fn call_construct(
                 Function::Native {
                     function,
                     constructor,
+                    data,
                 } => {
                     if *constructor || construct {
-                        FunctionBody::BuiltInConstructor(*function)
+                        FunctionBody::BuiltInConstructor(*function, data.clone())
                     } else {
-                        FunctionBody::BuiltInFunction(*function)
+                        FunctionBody::BuiltInFunction(*function, data.clone())
                     }
                 }
                 Function::Closure {
@@ -129,6 +159,9 @@ pub(super) fn call_construct(
                     body,
                     params,
                     environment,
+                    contains_arguments,
+                    generator,
+                    ..
                 } => {
                     let this = if construct {
                         // If the prototype of the constructor is not an object, then use the default object
@@ -192,7 +225,11 @@ pub(super) fn call_construct(
                     // - If there are default parameters or if lexical names and function names do not contain `arguments` (10.2.11.18)
                     //
                     // https://tc39.es/ecma262/#sec-functiondeclarationinstantiation
-                    if !this_mode.is_lexical()
+                    //
+                    // Diverge from the spec by also skipping it when nothing in the function can
+                    // observe it, which avoids the allocation on most calls.
+                    if *contains_arguments
+                        && !this_mode.is_lexical()
                         && !arguments_in_parameter_names
                         && (has_parameter_expressions
                             || (!body.lexically_declared_names().contains("arguments")
@@ -259,7 +296,11 @@ pub(super) fn call_construct(
                         context.push_environment(second_env);
                     }
 
-                    FunctionBody::Ordinary(body.clone())
+                    if *generator {
+                        FunctionBody::Generator(body.clone())
+                    } else {
+                        FunctionBody::Ordinary(body.clone())
+                    }
                 }
                 #[cfg(feature = "vm")]
                 Function::VmOrdinary { .. } => {
@@ -272,18 +313,39 @@ pub(super) fn call_construct(
     };
 
     match body {
-        FunctionBody::BuiltInConstructor(function) if construct => {
-            function(this_target, args, context)
+        FunctionBody::BuiltInConstructor(function, data) if construct => {
+            context.with_function_data(data, |context| function(this_target, args, context))
         }
-        FunctionBody::BuiltInConstructor(function) => {
-            function(&JsValue::undefined(), args, context)
+        FunctionBody::BuiltInConstructor(function, data) => context
+            .with_function_data(data, |context| {
+                function(&JsValue::undefined(), args, context)
+            }),
+        FunctionBody::BuiltInFunction(function, data) => {
+            context.with_function_data(data, |context| function(this_target, args, context))
         }
-        FunctionBody::BuiltInFunction(function) => function(this_target, args, context),
-        FunctionBody::Closure { function, captures } => {
-            (function)(this_target, args, captures, context)
+        FunctionBody::Closure { function, captures } => context
+            .with_function_data(None, |context| {
+                (function)(this_target, args, captures, context)
+            }),
+        FunctionBody::Generator(body) => {
+            // The body runs in the environment of the call when the generator is resumed.
+            let environment = context.get_current_environment();
+            if has_parameter_expressions {
+                context.pop_environment();
+            }
+            context.pop_environment();
+
+            let generator_context = GeneratorContext::Body(GeneratorBody::new(body, environment));
+            Generator::create(obj, generator_context, context).map(Into::into)
         }
         FunctionBody::Ordinary(body) => {
             let result = body.run(context);
+            // Unset the early return flag, so a `return` in a function called from native code,
+            // like a getter or a proxy trap, doesn't also end the statements of the caller.
+            let returned = context.executor().get_current_state() == &InterpreterState::Return;
+            context
+                .executor()
+                .set_current_state(InterpreterState::Executing);
             let this = context.get_this_binding();
 
             if has_parameter_expressions {
@@ -292,9 +354,12 @@ pub(super) fn call_construct(
             context.pop_environment();
 
             if construct {
+                // Constructors need the result of a deferred tail call to check it is an object.
+                let result = result.and_then(|value| TailCall::complete(value, context));
+
                 // https://tc39.es/ecma262/#sec-ecmascript-function-objects-construct-argumentslist-newtarget
                 // 12. If result.[[Type]] is return, then
-                if context.executor().get_current_state() == &InterpreterState::Return {
+                if returned {
                     // a. If Type(result.[[Value]]) is Object, return NormalCompletion(result.[[Value]]).
                     if let Ok(v) = &result {
                         if v.is_object() {
@@ -308,7 +373,7 @@ pub(super) fn call_construct(
 
                 // 14. Return ? constructorEnv.GetThisBinding().
                 this
-            } else if context.executor().get_current_state() == &InterpreterState::Return {
+            } else if returned {
                 result
             } else {
                 result?;
@@ -52,6 +52,7 @@ impl JsObject {
             prototype: prototype.into(),
             extensible: true,
             properties: Default::default(),
+            weak_entries: Default::default(),
         })
     }
 
@@ -313,6 +314,17 @@ impl JsObject {
         self.borrow().is_map()
     }
 
+    /// Checks if it is a `Promise` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_promise(&self) -> bool {
+        self.borrow().is_promise()
+    }
+
     /// Checks if it's a `String` object.
     ///
     /// # Panics
@@ -624,6 +636,10 @@ impl JsObject {
     /// - [EcmaScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-iscallable
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
     #[inline]
     #[track_caller]
     pub fn is_callable(&self) -> bool {
@@ -636,6 +652,10 @@ impl JsObject {
     /// - [EcmaScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-isconstructor
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
     #[inline]
     #[track_caller]
     pub fn is_constructor(&self) -> bool {
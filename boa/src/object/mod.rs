@@ -5,10 +5,14 @@ use crate::{
         array::array_iterator::ArrayIterator,
         array_buffer::ArrayBuffer,
         function::arguments::{Arguments, MappedArguments},
-        function::{BoundFunction, Captures, Function, NativeFunctionSignature},
+        function::{
+            AsyncFunctionSignature, BoundFunction, Captures, Function, NativeFunctionSignature,
+        },
+        generator::Generator,
         map::map_iterator::MapIterator,
         map::ordered_map::OrderedMap,
         object::for_in_iterator::ForInIterator,
+        promise::Promise,
         proxy::Proxy,
         regexp::regexp_string_iterator::RegExpStringIterator,
         set::ordered_set::OrderedSet,
@@ -19,9 +23,15 @@ use crate::{
     },
     context::StandardConstructor,
     gc::{Finalize, Trace},
+    job::{FutureJob, NativeJob},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     Context, JsBigInt, JsResult, JsString, JsSymbol, JsValue,
 };
+
+#[cfg(feature = "abort_controller")]
+use crate::builtins::abort_controller::{AbortController, AbortSignal};
+#[cfg(feature = "encoding")]
+use crate::builtins::encoding::{TextDecoder, TextEncoder};
 use std::{
     any::Any,
     fmt::{self, Debug, Display},
@@ -31,6 +41,7 @@ use std::{
 pub use jsobject::{JsObject, RecursionLimiter, Ref, RefMut};
 pub use operations::IntegrityLevel;
 pub use property_map::*;
+pub use weak_entries::{WeakCollection, WeakCollectionId};
 
 use self::internal_methods::{
     arguments::ARGUMENTS_EXOTIC_INTERNAL_METHODS,
@@ -55,6 +66,10 @@ pub(crate) mod internal_methods;
 mod jsobject;
 mod operations;
 mod property_map;
+mod weak_entries;
+
+pub(crate) use self::weak_entries::WeakCollections;
+use self::weak_entries::WeakEntries;
 
 /// Static `prototype`, usually set on constructors as a key to point to their respective prototype object.
 pub static PROTOTYPE: &str = "prototype";
@@ -95,6 +110,8 @@ pub struct Object {
     prototype: JsPrototype,
     /// Whether it can have new properties added to it.
     extensible: bool,
+    /// The entries of the weak collections this object is a key of.
+    weak_entries: WeakEntries,
 }
 
 /// Defines the kind of an object and its internal methods
@@ -107,6 +124,10 @@ pub struct ObjectData {
 /// Defines the different types of objects.
 #[derive(Debug, Trace, Finalize)]
 pub enum ObjectKind {
+    #[cfg(feature = "abort_controller")]
+    AbortController(AbortController),
+    #[cfg(feature = "abort_controller")]
+    AbortSignal(AbortSignal),
     Array,
     ArrayIterator(ArrayIterator),
     ArrayBuffer(ArrayBuffer),
@@ -129,14 +150,58 @@ pub enum ObjectKind {
     Error,
     Ordinary,
     Proxy(Proxy),
+    Promise(Promise),
     Date(Date),
     Global,
     Arguments(Arguments),
     NativeObject(Box<dyn NativeObject>),
     IntegerIndexed(IntegerIndexed),
+    #[cfg(feature = "encoding")]
+    TextDecoder(TextDecoder),
+    #[cfg(feature = "encoding")]
+    TextEncoder(TextEncoder),
+    WeakMap(WeakCollection),
+    WeakSet(WeakCollection),
+    Generator(Generator),
 }
 
 impl ObjectData {
+    /// Create the `AbortController` object data
+    #[cfg(feature = "abort_controller")]
+    pub fn abort_controller(abort_controller: AbortController) -> Self {
+        Self {
+            kind: ObjectKind::AbortController(abort_controller),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `AbortSignal` object data
+    #[cfg(feature = "abort_controller")]
+    pub fn abort_signal(abort_signal: AbortSignal) -> Self {
+        Self {
+            kind: ObjectKind::AbortSignal(abort_signal),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `TextDecoder` object data
+    #[cfg(feature = "encoding")]
+    pub fn text_decoder(text_decoder: TextDecoder) -> Self {
+        Self {
+            kind: ObjectKind::TextDecoder(text_decoder),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `TextEncoder` object data
+    #[cfg(feature = "encoding")]
+    pub fn text_encoder(text_encoder: TextEncoder) -> Self {
+        Self {
+            kind: ObjectKind::TextEncoder(text_encoder),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `Array` object data and reference its exclusive internal methods
     pub fn array() -> Self {
         Self {
@@ -265,6 +330,38 @@ impl ObjectData {
         }
     }
 
+    /// Create the `Promise` object data
+    pub fn promise(promise: Promise) -> Self {
+        Self {
+            kind: ObjectKind::Promise(promise),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Generator` object data
+    pub fn generator(generator: Generator) -> Self {
+        Self {
+            kind: ObjectKind::Generator(generator),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `WeakMap` object data
+    pub fn weak_map(collection: WeakCollection) -> Self {
+        Self {
+            kind: ObjectKind::WeakMap(collection),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `WeakSet` object data
+    pub fn weak_set(collection: WeakCollection) -> Self {
+        Self {
+            kind: ObjectKind::WeakSet(collection),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `String` object data and reference its exclusive internal methods
     pub fn string(string: JsString) -> Self {
         Self {
@@ -375,6 +472,10 @@ impl ObjectData {
 impl Display for ObjectKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(match self {
+            #[cfg(feature = "abort_controller")]
+            Self::AbortController(_) => "AbortController",
+            #[cfg(feature = "abort_controller")]
+            Self::AbortSignal(_) => "AbortSignal",
             Self::Array => "Array",
             Self::ArrayIterator(_) => "ArrayIterator",
             Self::ArrayBuffer(_) => "ArrayBuffer",
@@ -393,6 +494,7 @@ impl Display for ObjectKind {
             Self::Error => "Error",
             Self::Ordinary => "Ordinary",
             Self::Proxy(_) => "Proxy",
+            Self::Promise(_) => "Promise",
             Self::Boolean(_) => "Boolean",
             Self::Number(_) => "Number",
             Self::BigInt(_) => "BigInt",
@@ -401,7 +503,14 @@ impl Display for ObjectKind {
             Self::Arguments(_) => "Arguments",
             Self::NativeObject(_) => "NativeObject",
             Self::IntegerIndexed(_) => "TypedArray",
+            #[cfg(feature = "encoding")]
+            Self::TextDecoder(_) => "TextDecoder",
+            #[cfg(feature = "encoding")]
+            Self::TextEncoder(_) => "TextEncoder",
             Self::DataView(_) => "DataView",
+            Self::WeakMap(_) => "WeakMap",
+            Self::WeakSet(_) => "WeakSet",
+            Self::Generator(_) => "Generator",
         })
     }
 }
@@ -424,6 +533,7 @@ impl Default for Object {
             properties: PropertyMap::default(),
             prototype: None,
             extensible: true,
+            weak_entries: WeakEntries::default(),
         }
     }
 }
@@ -434,6 +544,66 @@ impl Object {
         &self.data.kind
     }
 
+    #[cfg(feature = "abort_controller")]
+    #[inline]
+    pub fn as_abort_controller(&self) -> Option<&AbortController> {
+        match &self.data {
+            ObjectData {
+                kind: ObjectKind::AbortController(abort_controller),
+                ..
+            } => Some(abort_controller),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "abort_controller")]
+    #[inline]
+    pub fn as_abort_signal(&self) -> Option<&AbortSignal> {
+        match &self.data {
+            ObjectData {
+                kind: ObjectKind::AbortSignal(abort_signal),
+                ..
+            } => Some(abort_signal),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "abort_controller")]
+    #[inline]
+    pub fn as_abort_signal_mut(&mut self) -> Option<&mut AbortSignal> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::AbortSignal(abort_signal),
+                ..
+            } => Some(abort_signal),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "encoding")]
+    #[inline]
+    pub fn as_text_decoder(&self) -> Option<&TextDecoder> {
+        match &self.data {
+            ObjectData {
+                kind: ObjectKind::TextDecoder(text_decoder),
+                ..
+            } => Some(text_decoder),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "encoding")]
+    #[inline]
+    pub fn as_text_encoder(&self) -> Option<&TextEncoder> {
+        match &self.data {
+            ObjectData {
+                kind: ObjectKind::TextEncoder(text_encoder),
+                ..
+            } => Some(text_encoder),
+            _ => None,
+        }
+    }
+
     /// Checks if it an `Array` object.
     #[inline]
     pub fn is_array(&self) -> bool {
@@ -669,6 +839,85 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn is_promise(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::Promise(_),
+                ..
+            }
+        )
+    }
+
+    #[inline]
+    pub fn as_promise(&self) -> Option<&Promise> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Promise(ref promise),
+                ..
+            } => Some(promise),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_promise_mut(&mut self) -> Option<&mut Promise> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::Promise(promise),
+                ..
+            } => Some(promise),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_generator(&self) -> Option<&Generator> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Generator(ref generator),
+                ..
+            } => Some(generator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_generator_mut(&mut self) -> Option<&mut Generator> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::Generator(generator),
+                ..
+            } => Some(generator),
+            _ => None,
+        }
+    }
+
+    /// Returns the id of the `WeakMap` this object is, if it is one.
+    #[inline]
+    pub fn as_weak_map(&self) -> Option<WeakCollectionId> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::WeakMap(ref collection),
+                ..
+            } => Some(collection.id()),
+            _ => None,
+        }
+    }
+
+    /// Returns the id of the `WeakSet` this object is, if it is one.
+    #[inline]
+    pub fn as_weak_set(&self) -> Option<WeakCollectionId> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::WeakSet(ref collection),
+                ..
+            } => Some(collection.id()),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn as_set_iterator_mut(&mut self) -> Option<&mut SetIterator> {
         match &mut self.data {
@@ -1137,6 +1386,18 @@ impl Object {
         &self.properties
     }
 
+    /// Returns the entries of the weak collections this object is a key of.
+    #[inline]
+    pub(crate) fn weak_entries(&self) -> &WeakEntries {
+        &self.weak_entries
+    }
+
+    /// Returns the entries of the weak collections this object is a key of, mutably.
+    #[inline]
+    pub(crate) fn weak_entries_mut(&mut self) -> &mut WeakEntries {
+        &mut self.weak_entries
+    }
+
     /// Helper function for property insertion.
     #[inline]
     pub(crate) fn insert<K, P>(&mut self, key: K, property: P) -> Option<PropertyDescriptor>
@@ -1252,6 +1513,65 @@ impl<'context> FunctionBuilder<'context> {
             function: Some(Function::Native {
                 function,
                 constructor: false,
+                data: None,
+            }),
+            name: JsString::default(),
+            length: 0,
+        }
+    }
+
+    /// Create a new `FunctionBuilder` for creating a native function backed by `data`.
+    ///
+    /// While the function runs, it can read the data of its own instance through
+    /// [`Context::current_function_data`], so one native function can back many function
+    /// objects with different state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa::{
+    /// #     gc::{Finalize, Trace},
+    /// #     object::FunctionBuilder,
+    /// #     Context, JsResult, JsValue,
+    /// # };
+    /// #[derive(Debug, Trace, Finalize)]
+    /// struct Greeting(String);
+    ///
+    /// fn greet(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    ///     let greeting = context
+    ///         .current_function_data::<Greeting>()
+    ///         .map(|greeting| greeting.0.clone())
+    ///         .unwrap_or_default();
+    ///     Ok(greeting.into())
+    /// }
+    ///
+    /// let mut context = Context::new();
+    /// for (name, greeting) in [("hello", "Hello!"), ("bonjour", "Bonjour !")] {
+    ///     let function =
+    ///         FunctionBuilder::native_with_data(&mut context, greet, Greeting(greeting.into()))
+    ///             .name(name)
+    ///             .build();
+    ///     context.register_global_property(name, function, Default::default());
+    /// }
+    ///
+    /// assert_eq!(context.eval("hello()").unwrap(), "Hello!".into());
+    /// assert_eq!(context.eval("bonjour()").unwrap(), "Bonjour !".into());
+    /// ```
+    #[inline]
+    pub fn native_with_data<D>(
+        context: &'context mut Context,
+        function: NativeFunctionSignature,
+        data: D,
+    ) -> Self
+    where
+        D: NativeObject,
+    {
+        Self {
+            context,
+            function: Some(Function::Native {
+                function,
+                constructor: false,
+                data: Some(Captures::new(data)),
             }),
             name: JsString::default(),
             length: 0,
@@ -1296,11 +1616,10 @@ impl<'context> FunctionBuilder<'context> {
             context,
             function: Some(Function::Closure {
                 function: Box::new(move |this, args, captures: Captures, context| {
-                    let mut captures = captures.as_mut_any();
-                    let captures = captures.downcast_mut::<C>().ok_or_else(|| {
+                    let mut captures = captures.downcast_mut::<C>().ok_or_else(|| {
                         context.construct_type_error("cannot downcast `Captures` to given type")
                     })?;
-                    function(this, args, captures, context)
+                    function(this, args, &mut captures, context)
                 }),
                 constructor: false,
                 captures: Captures::new(captures),
@@ -1310,6 +1629,71 @@ impl<'context> FunctionBuilder<'context> {
         }
     }
 
+    /// Create a new `FunctionBuilder` for creating a native function that runs asynchronously.
+    ///
+    /// Calling the function calls `function` and immediately returns a pending promise. The
+    /// future returned by `function` is driven by [`Context::run_jobs`], which settles the
+    /// promise with the output of the future once it completes.
+    ///
+    /// The futures are polled with a waker that does nothing, so a future that isn't complete
+    /// when the job queue runs out of jobs is only polled again by the next call to
+    /// [`Context::run_jobs`]. The host has to keep ticking the job queue, for example from its
+    /// event loop, while [`Context::has_pending_futures`] is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa::{object::FunctionBuilder, property::Attribute, Context, JsValue};
+    /// let mut context = Context::new();
+    /// let function = FunctionBuilder::async_native(&mut context, |_, args, _| {
+    ///     let value = args.get(0).cloned().unwrap_or_default();
+    ///     async move { Ok(value) }
+    /// })
+    /// .name("later")
+    /// .length(1)
+    /// .build();
+    /// context.register_global_property("later", function, Attribute::all());
+    ///
+    /// context.eval("var result; later(1).then(v => { result = v; });").unwrap();
+    /// assert_eq!(context.eval("result").unwrap(), JsValue::undefined());
+    ///
+    /// while {
+    ///     context.run_jobs().unwrap();
+    ///     context.has_pending_futures()
+    /// } {}
+    /// assert_eq!(context.eval("result").unwrap(), 1.into());
+    /// ```
+    #[inline]
+    pub fn async_native<F, Fut>(context: &'context mut Context, function: F) -> Self
+    where
+        F: AsyncFunctionSignature<Fut> + Copy,
+        Fut: std::future::Future<Output = JsResult<JsValue>> + 'static,
+    {
+        Self::closure(context, move |this, args, context| {
+            let promise_constructor = context.standard_objects().promise_object().constructor();
+            let capability = Promise::new_promise_capability(&promise_constructor.into(), context)
+                .expect("%Promise% can always create a capability");
+
+            let future = function(this, args, context);
+            let promise = capability.promise.clone();
+            context.enqueue_future_job(FutureJob::new(async move {
+                let result = future.await;
+                NativeJob::new(move |context| match result {
+                    Ok(value) => capability
+                        .resolve
+                        .call(&JsValue::undefined(), &[value], context),
+                    Err(reason) => {
+                        capability
+                            .reject
+                            .call(&JsValue::undefined(), &[reason], context)
+                    }
+                })
+            }));
+
+            Ok(promise.into())
+        })
+    }
+
     /// Specify the name property of object function object.
     ///
     /// The default is `""` (empty string).
@@ -1781,6 +2165,7 @@ impl<'context> ConstructorBuilder<'context> {
         let function = Function::Native {
             function: self.constructor_function,
             constructor: self.constructor,
+            data: None,
         };
 
         let length = PropertyDescriptor::builder()
@@ -7,6 +7,7 @@ use crate::{
     value::Type,
     Context, JsResult, JsValue,
 };
+use std::collections::HashSet;
 
 /// Object integrity level.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -104,7 +105,7 @@ impl JsObject {
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-deletepropertyorthrow
+    /// [spec]: https://tc39.es/ecma262/#sec-createdataproperty
     pub fn create_data_property<K, V>(
         &self,
         key: K,
@@ -127,14 +128,43 @@ impl JsObject {
         self.__define_own_property__(key.into(), new_desc.into(), context)
     }
 
-    // todo: CreateMethodProperty
+    /// Create method property
+    ///
+    /// Defines a writable, non-enumerable and configurable data property, the way the methods of
+    /// builtin objects are defined.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createmethodproperty
+    pub fn create_method_property<K, V>(
+        &self,
+        key: K,
+        value: V,
+        context: &mut Context,
+    ) -> JsResult<bool>
+    where
+        K: Into<PropertyKey>,
+        V: Into<JsValue>,
+    {
+        // 1. Assert: Type(O) is Object.
+        // 2. Assert: IsPropertyKey(P) is true.
+        // 3. Let newDesc be the PropertyDescriptor { [[Value]]: V, [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }.
+        let new_desc = PropertyDescriptor::builder()
+            .value(value)
+            .writable(true)
+            .enumerable(false)
+            .configurable(true);
+        // 4. Return ? O.[[DefineOwnProperty]](P, newDesc).
+        self.__define_own_property__(key.into(), new_desc.into(), context)
+    }
 
     /// Create data property or throw
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-deletepropertyorthrow
+    /// [spec]: https://tc39.es/ecma262/#sec-createdatapropertyorthrow
     pub fn create_data_property_or_throw<K, V>(
         &self,
         key: K,
@@ -416,6 +446,54 @@ impl JsObject {
         Ok(true)
     }
 
+    /// Freezes this object and, recursively, every object stored in its own enumerable data
+    /// properties, like calling `Object.freeze` on the whole object graph.
+    ///
+    /// Every object is frozen once, so cyclic graphs are fine. Accessor properties are not
+    /// followed, as that would run their getters. Returns `false` if one of the objects refused
+    /// to be frozen, like a `Proxy` whose trap returns `false`.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{object::IntegrityLevel, Context};
+    /// let mut context = Context::new();
+    ///
+    /// let config = context.eval("({ server: { port: 8080 } })").unwrap();
+    /// let config = config.as_object().unwrap();
+    /// assert!(config.deep_freeze(&mut context).unwrap());
+    ///
+    /// let server = config.get("server", &mut context).unwrap();
+    /// let server = server.as_object().unwrap();
+    /// assert!(server
+    ///     .test_integrity_level(IntegrityLevel::Frozen, &mut context)
+    ///     .unwrap());
+    /// ```
+    pub fn deep_freeze(&self, context: &mut Context) -> JsResult<bool> {
+        let mut visited = HashSet::new();
+        let mut pending = vec![self.clone()];
+        let mut frozen = true;
+
+        while let Some(object) = pending.pop() {
+            if !visited.insert(object.as_ref() as *const _ as usize) {
+                continue;
+            }
+
+            for key in object.__own_property_keys__(context)? {
+                if let Some(desc) = object.__get_own_property__(&key, context)? {
+                    if desc.expect_enumerable() {
+                        if let Some(JsValue::Object(value)) = desc.value() {
+                            pending.push(value.clone());
+                        }
+                    }
+                }
+            }
+
+            frozen &= object.set_integrity_level(IntegrityLevel::Frozen, context)?;
+        }
+
+        Ok(frozen)
+    }
+
     #[inline]
     pub(crate) fn length_of_array_like(&self, context: &mut Context) -> JsResult<usize> {
         // 1. Assert: Type(obj) is Object.
@@ -591,9 +669,18 @@ impl JsValue {
     /// More information:
     /// - [EcmaScript reference][spec]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-getmethod
+    /// # Examples
+    /// ```
+    ///# use boa::{Context, JsValue};
+    /// let mut context = Context::new();
+    ///
+    /// let to_fixed = JsValue::new(5).get_v("toFixed", &mut context).unwrap();
+    /// assert!(to_fixed.is_callable());
+    /// ```
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getv
     #[inline]
-    pub(crate) fn get_v<K>(&self, key: K, context: &mut Context) -> JsResult<JsValue>
+    pub fn get_v<K>(&self, key: K, context: &mut Context) -> JsResult<JsValue>
     where
         K: Into<PropertyKey>,
     {
@@ -604,6 +691,45 @@ impl JsValue {
         o.__get__(&key.into(), self.clone(), context)
     }
 
+    /// Sets the value of a specific property of an ECMAScript language value, like an assignment
+    /// to a property of the value does. If the value is not an object, the property is set using
+    /// a wrapper object appropriate for the type of the value, with the value itself as the
+    /// receiver, so only setters found on the prototype chain can observe the assignment.
+    ///
+    /// Returns `false` if the property could not be set, or throws a `TypeError` instead if
+    /// `throw` is `true`.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-putvalue
+    #[inline]
+    pub fn set_v<K, V>(
+        &self,
+        key: K,
+        value: V,
+        throw: bool,
+        context: &mut Context,
+    ) -> JsResult<bool>
+    where
+        K: Into<PropertyKey>,
+        V: Into<JsValue>,
+    {
+        let key = key.into();
+        // a. Let baseObj be ? ToObject(V.[[Base]]).
+        let o = self.to_object(context)?;
+        // b. Let succeeded be ? baseObj.[[Set]](V.[[ReferencedName]], W, GetThisValue(V)).
+        let success = o.__set__(key.clone(), value.into(), self.clone(), context)?;
+        // c. If succeeded is false and V.[[Strict]] is true, throw a TypeError exception.
+        if !success && throw {
+            return Err(
+                context.construct_type_error(format!("cannot set non-writable property: {}", key))
+            );
+        }
+        // d. Return.
+        Ok(success)
+    }
+
     /// Abstract operation `GetMethod ( V, P )`
     ///
     /// Retrieves the value of a specific property, when the value of the property is expected to be a function.
@@ -1,4 +1,4 @@
-use crate::{check_output, exec, TestAction};
+use crate::{check_output, exec, Context, TestAction};
 
 #[test]
 fn ordinary_has_instance_nonobject_prototype() {
@@ -44,3 +44,130 @@ fn object_properties_return_order() {
         ),
     ]);
 }
+
+#[test]
+fn object_kind_predicates() {
+    let mut context = Context::new();
+    let mut object = |src: &str| {
+        context
+            .eval(src)
+            .unwrap()
+            .as_object()
+            .cloned()
+            .expect("source should evaluate to an object")
+    };
+
+    let ordinary = object("({})");
+    assert!(ordinary.is_ordinary());
+    assert!(!ordinary.is_callable());
+    assert!(!ordinary.is_constructor());
+    assert!(!ordinary.is_array());
+    assert!(!ordinary.is_error());
+
+    let array = object("[1, 2, 3]");
+    assert!(array.is_array());
+    assert!(!array.is_ordinary());
+    assert!(!array.is_callable());
+
+    let function = object("(function f() {})");
+    assert!(function.is_callable());
+    assert!(function.is_constructor());
+    assert!(!function.is_ordinary());
+
+    let arrow = object("(() => {})");
+    assert!(arrow.is_callable());
+    assert!(!arrow.is_constructor());
+
+    let constructor = object("Array");
+    assert!(constructor.is_callable());
+    assert!(constructor.is_constructor());
+
+    let builtin = object("Math.max");
+    assert!(builtin.is_callable());
+    assert!(!builtin.is_constructor());
+
+    let error = object("new TypeError('oops')");
+    assert!(error.is_error());
+    assert!(!error.is_ordinary());
+    assert!(!error.is_callable());
+
+    let proxy = object("new Proxy([], {})");
+    assert!(!proxy.is_array());
+    assert!(!proxy.is_ordinary());
+    assert!(!proxy.is_callable());
+}
+
+#[test]
+fn deep_freeze() {
+    let mut context = Context::new();
+    let config = context
+        .eval(
+            r#"
+            var config = {
+                server: { host: "localhost", ports: [80, 443] },
+                get computed() { return {}; },
+            };
+            config.server.config = config;
+            config
+            "#,
+        )
+        .unwrap();
+    let config = config.as_object().unwrap();
+
+    assert!(config.deep_freeze(&mut context).unwrap());
+
+    for check in [
+        "Object.isFrozen(config)",
+        "Object.isFrozen(config.server)",
+        "Object.isFrozen(config.server.ports)",
+        "!Object.isFrozen(config.computed)",
+        "'use strict'; try { config.server.host = 'remote'; false } catch (e) { e instanceof TypeError }",
+        "try { config.server.ports.push(8080); false } catch (e) { e instanceof TypeError }",
+    ] {
+        assert_eq!(context.eval(check).unwrap().as_boolean(), Some(true), "{}", check);
+    }
+    assert_eq!(
+        context
+            .eval("config.server.ports.length")
+            .unwrap()
+            .as_number(),
+        Some(2.0)
+    );
+}
+
+#[test]
+fn create_data_and_method_properties() {
+    let mut context = Context::new();
+    let object = context.construct_object();
+    let method = context.eval("(function method() {})").unwrap();
+
+    assert!(object
+        .create_data_property_or_throw("data", 1, &mut context)
+        .unwrap());
+    assert!(object
+        .create_method_property("method", method, &mut context)
+        .unwrap());
+
+    let data = object
+        .__get_own_property__(&"data".into(), &mut context)
+        .unwrap()
+        .unwrap();
+    assert!(data.expect_writable() && data.expect_enumerable() && data.expect_configurable());
+
+    let method = object
+        .__get_own_property__(&"method".into(), &mut context)
+        .unwrap()
+        .unwrap();
+    assert!(method.expect_writable() && method.expect_configurable());
+    assert!(!method.expect_enumerable());
+
+    // Neither can redefine a non-configurable property.
+    let frozen = context.eval("Object.freeze({ data: 1 })").unwrap();
+    let frozen = frozen.as_object().unwrap();
+    assert!(frozen
+        .create_data_property_or_throw("data", 2, &mut context)
+        .is_err());
+    assert!(!frozen
+        .create_method_property("data", 2, &mut context)
+        .unwrap());
+}
@@ -0,0 +1,232 @@
+//! Storage for the entries of the weak collections, `WeakMap` and `WeakSet`.
+//!
+//! The garbage collector has no weak references, so the weak collections store their entries
+//! inverted: an entry lives inside its key object, tagged with the [`WeakCollectionId`] of the
+//! collection it belongs to, and the collection itself only knows its id. An entry is then only
+//! reachable through its key and is dropped together with it when the key is collected, and the
+//! value of an entry is kept alive by its key alone, as the specification requires.
+//!
+//! A collection doesn't know its keys, so its entries can't be dropped when it is collected.
+//! Instead, the finalizer of the collection records its id in the [`WeakCollections`] registry
+//! of its context, and the entries of a key are pruned against that registry whenever they are
+//! accessed, and whenever the garbage collector traces the key. The values of a collected
+//! `WeakMap` are then released by the collection that follows.
+
+use crate::{
+    gc::{empty_trace, Finalize, Trace},
+    JsValue,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::{
+    cell::RefCell,
+    collections::hash_map::Entry,
+    rc::Rc,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Identifies a weak collection in the entries of its keys.
+///
+/// Ids are never reused, so an entry can't be mistaken for an entry of a newer collection once
+/// its own collection has been collected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Finalize)]
+pub struct WeakCollectionId(u64);
+
+unsafe impl Trace for WeakCollectionId {
+    empty_trace!();
+}
+
+impl WeakCollectionId {
+    /// Returns a new, unique id.
+    fn new() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// The internal state of a `WeakMap` or `WeakSet` object.
+///
+/// Finalizing it marks the collection as collected in the registry of its context.
+#[derive(Debug, Trace)]
+pub struct WeakCollection {
+    id: WeakCollectionId,
+    #[unsafe_ignore_trace]
+    registry: Rc<WeakCollections>,
+}
+
+impl WeakCollection {
+    /// Creates a new, empty collection that is tracked by `registry`.
+    pub(crate) fn new(registry: Rc<WeakCollections>) -> Self {
+        Self {
+            id: WeakCollectionId::new(),
+            registry,
+        }
+    }
+
+    /// Returns the id of the collection.
+    #[inline]
+    pub fn id(&self) -> WeakCollectionId {
+        self.id
+    }
+}
+
+impl Finalize for WeakCollection {
+    fn finalize(&self) {
+        self.registry.collection_dropped(self.id);
+    }
+}
+
+/// The registry of the weak collections of a context.
+///
+/// It counts the entries every collection has in its keys, and remembers the collections that
+/// have been garbage collected until all of their entries have been dropped.
+#[derive(Debug, Default)]
+pub(crate) struct WeakCollections(RefCell<Registry>);
+
+#[derive(Debug, Default)]
+struct Registry {
+    /// The number of entries stored in keys, for every collection that has any.
+    entries: FxHashMap<WeakCollectionId, usize>,
+
+    /// The collections that have been garbage collected while they still had entries.
+    dead: FxHashSet<WeakCollectionId>,
+}
+
+impl WeakCollections {
+    /// Returns `true` if the collection `id` has been garbage collected.
+    fn is_dead(&self, id: WeakCollectionId) -> bool {
+        self.0.borrow().dead.contains(&id)
+    }
+
+    /// Records that the collection `id` has been garbage collected.
+    fn collection_dropped(&self, id: WeakCollectionId) {
+        let mut registry = self.0.borrow_mut();
+        if registry.entries.contains_key(&id) {
+            registry.dead.insert(id);
+        }
+    }
+
+    /// Records that an entry of the collection `id` has been stored in a key.
+    fn entry_added(&self, id: WeakCollectionId) {
+        *self.0.borrow_mut().entries.entry(id).or_default() += 1;
+    }
+
+    /// Records that an entry of the collection `id` has been dropped.
+    fn entry_removed(&self, id: WeakCollectionId) {
+        let mut registry = self.0.borrow_mut();
+        if let Entry::Occupied(mut count) = registry.entries.entry(id) {
+            *count.get_mut() -= 1;
+            if *count.get() == 0 {
+                count.remove();
+                registry.dead.remove(&id);
+            }
+        }
+    }
+}
+
+/// An entry of a weak collection, stored in its key.
+#[derive(Debug)]
+struct WeakEntry {
+    value: JsValue,
+    registry: Rc<WeakCollections>,
+}
+
+/// The entries of the weak collections an object is a key of.
+#[derive(Debug, Default)]
+pub(crate) struct WeakEntries(RefCell<FxHashMap<WeakCollectionId, WeakEntry>>);
+
+unsafe impl Trace for WeakEntries {
+    unsafe fn trace(&self) {
+        // The garbage collector reaches every live key, so this is where the entries of the
+        // collections that have been collected are dropped from the keys that are never accessed
+        // again. The values they held are then no longer marked, and are collected.
+        self.prune();
+        for entry in self.0.borrow().values() {
+            entry.value.trace();
+        }
+    }
+
+    unsafe fn root(&self) {
+        for entry in self.0.borrow().values() {
+            entry.value.root();
+        }
+    }
+
+    unsafe fn unroot(&self) {
+        for entry in self.0.borrow().values() {
+            entry.value.unroot();
+        }
+    }
+
+    fn finalize_glue(&self) {
+        Finalize::finalize(self);
+        for entry in self.0.borrow().values() {
+            entry.value.finalize_glue();
+        }
+    }
+}
+
+impl Finalize for WeakEntries {
+    fn finalize(&self) {
+        // The key is being collected, and its entries with it.
+        for (id, entry) in self.0.borrow().iter() {
+            entry.registry.entry_removed(*id);
+        }
+    }
+}
+
+impl WeakEntries {
+    /// Drops the entries of the collections that have been garbage collected.
+    fn prune(&self) {
+        self.0.borrow_mut().retain(|id, entry| {
+            let dead = entry.registry.is_dead(*id);
+            if dead {
+                entry.registry.entry_removed(*id);
+            }
+            !dead
+        });
+    }
+
+    /// Returns the value of the entry of the collection `id`, if there is one.
+    pub(crate) fn get(&self, id: WeakCollectionId) -> Option<JsValue> {
+        self.prune();
+        self.0.borrow().get(&id).map(|entry| entry.value.clone())
+    }
+
+    /// Returns `true` if the collection `id` has an entry for this object.
+    pub(crate) fn contains(&self, id: WeakCollectionId) -> bool {
+        self.prune();
+        self.0.borrow().contains_key(&id)
+    }
+
+    /// Inserts or replaces the entry of the collection `id`, which is tracked by `registry`.
+    pub(crate) fn insert(
+        &mut self,
+        id: WeakCollectionId,
+        value: JsValue,
+        registry: &Rc<WeakCollections>,
+    ) {
+        self.prune();
+        match self.0.get_mut().entry(id) {
+            Entry::Occupied(mut entry) => entry.get_mut().value = value,
+            Entry::Vacant(entry) => {
+                registry.entry_added(id);
+                entry.insert(WeakEntry {
+                    value,
+                    registry: registry.clone(),
+                });
+            }
+        }
+    }
+
+    /// Removes the entry of the collection `id`, returning `true` if there was one.
+    pub(crate) fn remove(&mut self, id: WeakCollectionId) -> bool {
+        self.prune();
+        match self.0.get_mut().remove(&id) {
+            Some(entry) => {
+                entry.registry.entry_removed(id);
+                true
+            }
+            None => false,
+        }
+    }
+}
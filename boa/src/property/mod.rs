@@ -480,10 +480,26 @@ pub enum PropertyKey {
     Index(u32),
 }
 
+/// Parses `string` as an [array index][spec], i.e. the canonical decimal representation of an
+/// integer in the range `0..2^32 - 1`.
+///
+/// Strings such as `"01"`, `"+1"` or `"4294967295"` are not array indices and are kept as
+/// string keys, so they don't collide with real indices and keep their creation order.
+///
+/// [spec]: https://tc39.es/ecma262/#array-index
+fn parse_array_index(string: &str) -> Option<u32> {
+    if !string.bytes().all(|byte| byte.is_ascii_digit())
+        || (string.len() > 1 && string.starts_with('0'))
+    {
+        return None;
+    }
+    string.parse().ok().filter(|index| *index != u32::MAX)
+}
+
 impl From<JsString> for PropertyKey {
     #[inline]
     fn from(string: JsString) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_array_index(&string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string)
@@ -494,7 +510,7 @@ impl From<JsString> for PropertyKey {
 impl From<&str> for PropertyKey {
     #[inline]
     fn from(string: &str) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_array_index(string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string.into())
@@ -505,7 +521,7 @@ impl From<&str> for PropertyKey {
 impl From<String> for PropertyKey {
     #[inline]
     fn from(string: String) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_array_index(&string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string.into())
@@ -516,7 +532,7 @@ impl From<String> for PropertyKey {
 impl From<Box<str>> for PropertyKey {
     #[inline]
     fn from(string: Box<str>) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_array_index(&string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string.into())
@@ -548,13 +564,7 @@ impl From<&PropertyKey> for JsValue {
         match property_key {
             PropertyKey::String(ref string) => string.clone().into(),
             PropertyKey::Symbol(ref symbol) => symbol.clone().into(),
-            PropertyKey::Index(index) => {
-                if let Ok(integer) = i32::try_from(*index) {
-                    JsValue::new(integer)
-                } else {
-                    JsValue::new(*index)
-                }
-            }
+            PropertyKey::Index(index) => index.to_string().into(),
         }
     }
 }
@@ -565,13 +575,7 @@ impl From<PropertyKey> for JsValue {
         match property_key {
             PropertyKey::String(ref string) => string.clone().into(),
             PropertyKey::Symbol(ref symbol) => symbol.clone().into(),
-            PropertyKey::Index(index) => {
-                if let Ok(integer) = i32::try_from(index) {
-                    JsValue::new(integer)
-                } else {
-                    JsValue::new(index)
-                }
-            }
+            PropertyKey::Index(index) => index.to_string().into(),
         }
     }
 }
@@ -590,56 +594,55 @@ impl From<u16> for PropertyKey {
 
 impl From<u32> for PropertyKey {
     fn from(value: u32) -> Self {
-        PropertyKey::Index(value)
+        if value == u32::MAX {
+            PropertyKey::String(JsString::from(value.to_string()))
+        } else {
+            PropertyKey::Index(value)
+        }
     }
 }
 
 impl From<usize> for PropertyKey {
     fn from(value: usize) -> Self {
-        if let Ok(index) = u32::try_from(value) {
-            PropertyKey::Index(index)
-        } else {
-            PropertyKey::String(JsString::from(value.to_string()))
+        match u32::try_from(value) {
+            Ok(index) if index != u32::MAX => PropertyKey::Index(index),
+            _ => PropertyKey::String(JsString::from(value.to_string())),
         }
     }
 }
 
 impl From<i64> for PropertyKey {
     fn from(value: i64) -> Self {
-        if let Ok(index) = u32::try_from(value) {
-            PropertyKey::Index(index)
-        } else {
-            PropertyKey::String(JsString::from(value.to_string()))
+        match u32::try_from(value) {
+            Ok(index) if index != u32::MAX => PropertyKey::Index(index),
+            _ => PropertyKey::String(JsString::from(value.to_string())),
         }
     }
 }
 
 impl From<u64> for PropertyKey {
     fn from(value: u64) -> Self {
-        if let Ok(index) = u32::try_from(value) {
-            PropertyKey::Index(index)
-        } else {
-            PropertyKey::String(JsString::from(value.to_string()))
+        match u32::try_from(value) {
+            Ok(index) if index != u32::MAX => PropertyKey::Index(index),
+            _ => PropertyKey::String(JsString::from(value.to_string())),
         }
     }
 }
 
 impl From<isize> for PropertyKey {
     fn from(value: isize) -> Self {
-        if let Ok(index) = u32::try_from(value) {
-            PropertyKey::Index(index)
-        } else {
-            PropertyKey::String(JsString::from(value.to_string()))
+        match u32::try_from(value) {
+            Ok(index) if index != u32::MAX => PropertyKey::Index(index),
+            _ => PropertyKey::String(JsString::from(value.to_string())),
         }
     }
 }
 
 impl From<i32> for PropertyKey {
     fn from(value: i32) -> Self {
-        if let Ok(index) = u32::try_from(value) {
-            PropertyKey::Index(index)
-        } else {
-            PropertyKey::String(JsString::from(value.to_string()))
+        match u32::try_from(value) {
+            Ok(index) if index != u32::MAX => PropertyKey::Index(index),
+            _ => PropertyKey::String(JsString::from(value.to_string())),
         }
     }
 }
@@ -647,7 +650,7 @@ impl From<i32> for PropertyKey {
 impl From<f64> for PropertyKey {
     fn from(value: f64) -> Self {
         use num_traits::cast::FromPrimitive;
-        if let Some(index) = u32::from_f64(value) {
+        if let Some(index) = u32::from_f64(value).filter(|index| *index != u32::MAX) {
             return PropertyKey::Index(index);
         }
 
@@ -7,11 +7,13 @@ use std::{
     alloc::{alloc, dealloc, Layout},
     borrow::Borrow,
     cell::Cell,
+    cmp::Ordering,
     hash::{Hash, Hasher},
     marker::PhantomData,
     ops::Deref,
     ptr::{copy_nonoverlapping, NonNull},
 };
+use unicode_normalization::UnicodeNormalization;
 
 const CONSTANTS_ARRAY: [&str; 127] = [
     // Empty string
@@ -299,6 +301,24 @@ impl Inner {
     }
 }
 
+/// A Unicode normalization form, as accepted by `String.prototype.normalize`.
+///
+/// More information:
+///  - [Unicode Standard Annex #15][uax15]
+///
+/// [uax15]: https://unicode.org/reports/tr15/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NormalizationForm {
+    /// Canonical Decomposition, followed by Canonical Composition.
+    Nfc,
+    /// Canonical Decomposition.
+    Nfd,
+    /// Compatibility Decomposition, followed by Canonical Composition.
+    Nfkc,
+    /// Compatibility Decomposition.
+    Nfkd,
+}
+
 /// This represents a JavaScript primitive string.
 ///
 /// This is similar to `Rc<str>`. But unlike `Rc<str>` which stores the length
@@ -412,6 +432,70 @@ impl JsString {
         x.inner == y.inner
     }
 
+    /// Returns the string normalized to the given Unicode normalization `form`.
+    ///
+    /// This is what `String.prototype.normalize` does once it has read the form name.
+    ///
+    /// ```
+    /// # use boa::{string::NormalizationForm, JsString};
+    /// let composed = JsString::new("\u{e9}");
+    /// let decomposed = JsString::new("e\u{301}");
+    ///
+    /// assert_eq!(decomposed.normalize(NormalizationForm::Nfc), composed);
+    /// assert_eq!(composed.normalize(NormalizationForm::Nfd), decomposed);
+    /// ```
+    pub fn normalize(&self, form: NormalizationForm) -> JsString {
+        let normalized: String = match form {
+            NormalizationForm::Nfc => self.nfc().collect(),
+            NormalizationForm::Nfd => self.nfd().collect(),
+            NormalizationForm::Nfkc => self.nfkc().collect(),
+            NormalizationForm::Nfkd => self.nfkd().collect(),
+        };
+        JsString::new(normalized)
+    }
+
+    /// Returns `true` if the two strings are equal once every code point has been mapped to
+    /// lowercase.
+    ///
+    /// The mapping is the full, context-free one, so a code point may lowercase to several
+    /// code points (`"\u{130}"` becomes `"i\u{307}"`), but the final sigma rule used by
+    /// `String.prototype.toLowerCase` doesn't apply.
+    ///
+    /// ```
+    /// # use boa::JsString;
+    /// assert!(JsString::new("Straße").eq_ignore_case("STRAßE"));
+    /// assert!(!JsString::new("a").eq_ignore_case("b"));
+    /// ```
+    pub fn eq_ignore_case<S: AsRef<str>>(&self, other: S) -> bool {
+        self.chars()
+            .flat_map(char::to_lowercase)
+            .eq(other.as_ref().chars().flat_map(char::to_lowercase))
+    }
+
+    /// Compares the two strings by their UTF-16 code units, the order used by the relational
+    /// operators and the default `Array.prototype.sort` comparator.
+    ///
+    /// This differs from the [`Ord`] implementation, which compares code points: a code point
+    /// outside the Basic Multilingual Plane is encoded with surrogates, which sort before
+    /// `U+E000..=U+FFFF`.
+    ///
+    /// ```
+    /// # use boa::JsString;
+    /// # use std::cmp::Ordering;
+    /// let astral = JsString::new("\u{1f600}");
+    /// let private_use = JsString::new("\u{e000}");
+    ///
+    /// assert_eq!(astral.cmp_code_units(&private_use), Ordering::Less);
+    /// assert_eq!(astral.cmp(&private_use), Ordering::Greater);
+    /// ```
+    pub fn cmp_code_units(&self, other: &JsString) -> Ordering {
+        if Self::ptr_eq(self, other) {
+            return Ordering::Equal;
+        }
+
+        self.encode_utf16().cmp(other.encode_utf16())
+    }
+
     /// `6.1.4.1 StringIndexOf ( string, searchValue, fromIndex )`
     ///
     /// Note: Instead of returning an isize with `-1` as the "not found" value,
@@ -604,14 +688,14 @@ impl Hash for JsString {
 
 impl PartialOrd for JsString {
     #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.as_str().partial_cmp(other.as_str())
     }
 }
 
 impl Ord for JsString {
     #[inline]
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> Ordering {
         self.as_str().cmp(other)
     }
 }
@@ -646,8 +730,8 @@ impl PartialEq<JsString> for &str {
 
 #[cfg(test)]
 mod tests {
-    use super::JsString;
-    use std::mem::size_of;
+    use super::{JsString, NormalizationForm};
+    use std::{cmp::Ordering, mem::size_of};
 
     #[test]
     fn empty() {
@@ -743,4 +827,51 @@ mod tests {
         assert_eq!(xyzw, "hello, world!");
         assert_eq!(JsString::refcount(&xyzw), 1);
     }
+
+    #[test]
+    fn normalize() {
+        let composed = JsString::new("\u{1e9b}\u{323}");
+
+        assert_eq!(
+            composed.normalize(NormalizationForm::Nfc),
+            "\u{1e9b}\u{323}"
+        );
+        assert_eq!(
+            composed.normalize(NormalizationForm::Nfd),
+            "\u{17f}\u{323}\u{307}"
+        );
+        assert_eq!(composed.normalize(NormalizationForm::Nfkc), "\u{1e69}");
+        assert_eq!(
+            composed.normalize(NormalizationForm::Nfkd),
+            "s\u{323}\u{307}"
+        );
+        assert_eq!(JsString::empty().normalize(NormalizationForm::Nfd), "");
+    }
+
+    #[test]
+    fn eq_ignore_case() {
+        let x = JsString::new("Hello, World");
+
+        assert!(x.eq_ignore_case("hello, world"));
+        assert!(x.eq_ignore_case(JsString::new("HELLO, WORLD")));
+        assert!(!x.eq_ignore_case("hello"));
+        assert!(JsString::new("\u{130}").eq_ignore_case("i\u{307}"));
+        assert!(JsString::new("ΣΑΣ").eq_ignore_case("σασ"));
+        assert!(JsString::empty().eq_ignore_case(""));
+    }
+
+    #[test]
+    fn cmp_code_units() {
+        let a = JsString::new("a");
+        let ab = JsString::new("ab");
+        let astral = JsString::new("\u{1f600}");
+        let bmp = JsString::new("\u{ffff}");
+
+        assert_eq!(a.cmp_code_units(&ab), Ordering::Less);
+        assert_eq!(ab.cmp_code_units(&a), Ordering::Greater);
+        assert_eq!(a.cmp_code_units(&JsString::new("a")), Ordering::Equal);
+        assert_eq!(JsString::empty().cmp_code_units(&a), Ordering::Less);
+        assert_eq!(astral.cmp_code_units(&bmp), Ordering::Less);
+        assert_eq!(astral.cmp(&bmp), Ordering::Greater);
+    }
 }
@@ -26,11 +26,18 @@ pub struct AwaitExpr {
     expr: Box<Node>,
 }
 
+impl AwaitExpr {
+    /// Gets the expression that is awaited.
+    pub(crate) fn expr(&self) -> &Node {
+        &self.expr
+    }
+}
+
 impl Executable for AwaitExpr {
-    fn run(&self, _: &mut Context) -> JsResult<JsValue> {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
         let _timer = BoaProfiler::global().start_event("AwaitExpression", "exec");
-        // TODO: Implement AwaitExpr
-        Ok(JsValue::undefined())
+        // The tree-walker can't suspend a running function, async functions are only run by the VM.
+        context.throw_syntax_error("async functions require the `vm` feature")
     }
 }
 
@@ -1,9 +1,12 @@
 #[test]
 fn fmt() {
-    // TODO: `let a = await fn()` is invalid syntax as of writing. It should be tested here once implemented.
     super::super::test_formatting(
         r#"
-        await function_call();
+        async function f() {
+            await function_call();
+            let a = await fn();
+            return a + await b;
+        };
         "#,
     );
 }
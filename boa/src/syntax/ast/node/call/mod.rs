@@ -1,7 +1,9 @@
 use crate::{
+    builtins::{eval::Eval, JsArgs},
     exec::Executable,
     exec::InterpreterState,
     gc::{Finalize, Trace},
+    object::JsObject,
     syntax::ast::node::{join_nodes, Node},
     BoaProfiler, Context, JsResult, JsValue,
 };
@@ -58,19 +60,33 @@ impl Call {
     }
 }
 
-impl Executable for Call {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        let _timer = BoaProfiler::global().start_event("Call", "exec");
-        let (this, func) = match self.expr() {
+impl Call {
+    /// Evaluates the function, the `this` value and the arguments of the call.
+    pub(crate) fn evaluate(
+        &self,
+        context: &mut Context,
+    ) -> JsResult<(JsValue, JsValue, Vec<JsValue>)> {
+        let (this, func) = Self::evaluate_callee(self.expr(), context)?;
+        let v_args = Self::evaluate_args(self.args(), context)?;
+        Ok((func, this, v_args))
+    }
+
+    /// Evaluates the expression being called, returning the `this` value of the call along
+    /// with the function.
+    pub(crate) fn evaluate_callee(
+        expr: &Node,
+        context: &mut Context,
+    ) -> JsResult<(JsValue, JsValue)> {
+        match expr {
             Node::GetConstField(ref get_const_field) => {
                 let mut obj = get_const_field.obj().run(context)?;
                 if !obj.is_object() {
                     obj = JsValue::from(obj.to_object(context)?);
                 }
-                (
+                Ok((
                     obj.clone(),
                     obj.get_field(get_const_field.field(), context)?,
-                )
+                ))
             }
             Node::GetField(ref get_field) => {
                 let mut obj = get_field.obj().run(context)?;
@@ -78,19 +94,37 @@ impl Executable for Call {
                     obj = JsValue::from(obj.to_object(context)?);
                 }
                 let field = get_field.field().run(context)?;
-                (
+                Ok((
                     obj.clone(),
                     obj.get_field(field.to_property_key(context)?, context)?,
-                )
+                ))
             }
-            _ => (
+            _ => Ok((
                 // 'this' binding should come from the function's self-contained environment
                 context.global_object().into(),
-                self.expr().run(context)?,
-            ),
-        };
-        let mut v_args = Vec::with_capacity(self.args().len());
-        for arg in self.args() {
+                expr.run(context)?,
+            )),
+        }
+    }
+
+    /// Returns `true` if calling `func` is a direct eval: a call of the original `eval` through
+    /// the plain `eval` name, which runs in the scope of the caller.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function-calls-runtime-semantics-evaluation
+    pub(crate) fn is_direct_eval(&self, func: &JsValue, context: &Context) -> bool {
+        matches!(*self.expr, Node::Identifier(ref ident) if ident.as_ref() == "eval")
+            && func.as_object().map_or(false, |func| {
+                JsObject::equals(func, &context.intrinsics().eval())
+            })
+    }
+
+    /// Evaluates the arguments of a call, spreading any `...iterable` argument.
+    pub(crate) fn evaluate_args(args: &[Node], context: &mut Context) -> JsResult<Vec<JsValue>> {
+        let mut v_args = Vec::with_capacity(args.len());
+        for arg in args {
             if let Node::Spread(ref x) = arg {
                 let val = x.run(context)?;
                 let iterator_record = val.get_iterator(context, None, None)?;
@@ -107,9 +141,21 @@ impl Executable for Call {
                 v_args.push(arg.run(context)?);
             }
         }
+        Ok(v_args)
+    }
+}
+
+impl Executable for Call {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("Call", "exec");
+        let (func, this, v_args) = self.evaluate(context)?;
 
         // execute the function call itself
-        let fnct_result = context.call(&func, &this, &v_args);
+        let fnct_result = if self.is_direct_eval(&func, context) {
+            Eval::perform_eval(v_args.get_or_undefined(0), context.strict(), true, context)
+        } else {
+            context.call(&func, &this, &v_args)
+        };
 
         // unset the early return flag
         context
@@ -24,10 +24,11 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#prod-ArrowFunction
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Arrow_functions
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct ArrowFunctionDecl {
     params: Box<[FormalParameter]>,
     body: StatementList,
+    source: Option<Box<str>>,
 }
 
 impl ArrowFunctionDecl {
@@ -40,9 +41,16 @@ impl ArrowFunctionDecl {
         Self {
             params: params.into(),
             body: body.into(),
+            source: None,
         }
     }
 
+    /// Sets the source text of the arrow function.
+    pub(in crate::syntax) fn with_source(mut self, source: Option<Box<str>>) -> Self {
+        self.source = source;
+        self
+    }
+
     /// Gets the list of parameters of the arrow function.
     pub(crate) fn params(&self) -> &[FormalParameter] {
         &self.params
@@ -53,6 +61,11 @@ impl ArrowFunctionDecl {
         &self.body
     }
 
+    /// Gets the source text of the arrow function, if it was recorded by the parser.
+    pub(crate) fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -71,6 +84,13 @@ impl ArrowFunctionDecl {
     }
 }
 
+// The source text is not part of the structure of the node.
+impl PartialEq for ArrowFunctionDecl {
+    fn eq(&self, other: &Self) -> bool {
+        self.params == other.params && self.body == other.body
+    }
+}
+
 impl Executable for ArrowFunctionDecl {
     fn run(&self, context: &mut Context) -> JsResult<JsValue> {
         context.create_function(
@@ -78,7 +98,9 @@ impl Executable for ArrowFunctionDecl {
             self.params().to_vec(),
             self.body().clone(),
             false,
+            false,
             ThisMode::Lexical,
+            self.source(),
         )
     }
 }
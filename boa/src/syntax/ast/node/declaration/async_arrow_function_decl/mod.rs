@@ -0,0 +1,89 @@
+//! Async Arrow Function Declaration.
+
+use crate::{
+    exec::Executable,
+    gc::{Finalize, Trace},
+    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// An async arrow function is an arrow function whose body can `await` promises, and which
+/// returns a promise settled with the result of its body.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncArrowFunction
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Arrow_functions
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct AsyncArrowFunctionDecl {
+    params: Box<[FormalParameter]>,
+    body: StatementList,
+}
+
+impl AsyncArrowFunctionDecl {
+    /// Creates a new `AsyncArrowFunctionDecl` AST node.
+    pub(in crate::syntax) fn new<P, B>(params: P, body: B) -> Self
+    where
+        P: Into<Box<[FormalParameter]>>,
+        B: Into<StatementList>,
+    {
+        Self {
+            params: params.into(),
+            body: body.into(),
+        }
+    }
+
+    /// Gets the list of parameters of the async arrow function.
+    pub(crate) fn params(&self) -> &[FormalParameter] {
+        &self.params
+    }
+
+    /// Gets the body of the async arrow function.
+    pub(crate) fn body(&self) -> &StatementList {
+        &self.body
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        write!(f, "async (")?;
+        join_nodes(f, &self.params)?;
+        if self.body().items().is_empty() {
+            f.write_str(") => {}")
+        } else {
+            f.write_str(") => {\n")?;
+            self.body.display(f, indentation + 1)?;
+            write!(f, "{}}}", "    ".repeat(indentation))
+        }
+    }
+}
+
+impl Executable for AsyncArrowFunctionDecl {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("AsyncArrowFunctionDecl", "exec");
+        // The tree-walker can't suspend a running function, async functions are only run by the VM.
+        context.throw_syntax_error("async functions require the `vm` feature")
+    }
+}
+
+impl fmt::Display for AsyncArrowFunctionDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<AsyncArrowFunctionDecl> for Node {
+    fn from(decl: AsyncArrowFunctionDecl) -> Self {
+        Self::AsyncArrowFunctionDecl(decl)
+    }
+}
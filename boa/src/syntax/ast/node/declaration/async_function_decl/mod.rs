@@ -53,8 +53,8 @@ impl AsyncFunctionDecl {
     }
 
     /// Gets the body of the async function declaration.
-    pub fn body(&self) -> &[Node] {
-        self.body.items()
+    pub fn body(&self) -> &StatementList {
+        &self.body
     }
 
     /// Implements the display formatting with indentation.
@@ -65,7 +65,7 @@ impl AsyncFunctionDecl {
     ) -> fmt::Result {
         write!(f, "async function {}(", self.name())?;
         join_nodes(f, &self.parameters)?;
-        if self.body().is_empty() {
+        if self.body().items().is_empty() {
             f.write_str(") {}")
         } else {
             f.write_str(") {\n")?;
@@ -76,10 +76,10 @@ impl AsyncFunctionDecl {
 }
 
 impl Executable for AsyncFunctionDecl {
-    fn run(&self, _: &mut Context) -> JsResult<JsValue> {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
         let _timer = BoaProfiler::global().start_event("AsyncFunctionDecl", "exec");
-        // TODO: Implement AsyncFunctionDecl
-        Ok(JsValue::undefined())
+        // The tree-walker can't suspend a running function, async functions are only run by the VM.
+        context.throw_syntax_error("async functions require the `vm` feature")
     }
 }
 
@@ -54,8 +54,8 @@ impl AsyncFunctionExpr {
     }
 
     /// Gets the body of the function declaration.
-    pub fn body(&self) -> &[Node] {
-        self.body.items()
+    pub fn body(&self) -> &StatementList {
+        &self.body
     }
 
     /// Implements the display formatting with indentation.
@@ -70,7 +70,7 @@ impl AsyncFunctionExpr {
         }
         f.write_str("(")?;
         join_nodes(f, &self.parameters)?;
-        if self.body().is_empty() {
+        if self.body().items().is_empty() {
             f.write_str(") {}")
         } else {
             f.write_str(") {\n")?;
@@ -81,9 +81,9 @@ impl AsyncFunctionExpr {
 }
 
 impl Executable for AsyncFunctionExpr {
-    fn run(&self, _: &mut Context) -> JsResult<JsValue> {
-        // TODO: Implement AsyncFunctionExpr
-        Ok(JsValue::undefined())
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        // The tree-walker can't suspend a running function, async functions are only run by the VM.
+        context.throw_syntax_error("async functions require the `vm` feature")
     }
 }
 
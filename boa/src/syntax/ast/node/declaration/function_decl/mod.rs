@@ -30,11 +30,12 @@ use serde::{Deserialize, Serialize};
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/function
 /// [func_expr]: ../enum.Node.html#variant.FunctionExpr
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct FunctionDecl {
     name: Box<str>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    source: Option<Box<str>>,
 }
 
 impl FunctionDecl {
@@ -49,9 +50,16 @@ impl FunctionDecl {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            source: None,
         }
     }
 
+    /// Sets the source text of the function declaration.
+    pub(in crate::syntax) fn with_source(mut self, source: Option<Box<str>>) -> Self {
+        self.source = source;
+        self
+    }
+
     /// Gets the name of the function declaration.
     pub fn name(&self) -> &str {
         &self.name
@@ -67,6 +75,11 @@ impl FunctionDecl {
         &self.body
     }
 
+    /// Gets the source text of the function declaration, if it was recorded by the parser.
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -85,6 +98,13 @@ impl FunctionDecl {
     }
 }
 
+// The source text is not part of the structure of the node.
+impl PartialEq for FunctionDecl {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.parameters == other.parameters && self.body == other.body
+    }
+}
+
 impl Executable for FunctionDecl {
     fn run(&self, context: &mut Context) -> JsResult<JsValue> {
         let _timer = BoaProfiler::global().start_event("FunctionDecl", "exec");
@@ -93,7 +113,9 @@ impl Executable for FunctionDecl {
             self.parameters().to_vec(),
             self.body().clone(),
             true,
+            false,
             ThisMode::Global,
+            self.source(),
         )?;
 
         if context.has_binding(self.name())? {
@@ -27,11 +27,12 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#sec-terms-and-definitions-function
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/function
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct FunctionExpr {
     name: Option<Box<str>>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    source: Option<Box<str>>,
 }
 
 impl FunctionExpr {
@@ -46,9 +47,16 @@ impl FunctionExpr {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            source: None,
         }
     }
 
+    /// Sets the source text of the function expression.
+    pub(in crate::syntax) fn with_source(mut self, source: Option<Box<str>>) -> Self {
+        self.source = source;
+        self
+    }
+
     /// Gets the name of the function declaration.
     pub fn name(&self) -> Option<&str> {
         self.name.as_ref().map(Box::as_ref)
@@ -64,6 +72,11 @@ impl FunctionExpr {
         &self.body
     }
 
+    /// Gets the source text of the function expression, if it was recorded by the parser.
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -97,6 +110,13 @@ impl FunctionExpr {
     }
 }
 
+// The source text is not part of the structure of the node.
+impl PartialEq for FunctionExpr {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.parameters == other.parameters && self.body == other.body
+    }
+}
+
 impl Executable for FunctionExpr {
     fn run(&self, context: &mut Context) -> JsResult<JsValue> {
         let val = context.create_function(
@@ -104,7 +124,9 @@ impl Executable for FunctionExpr {
             self.parameters().to_vec(),
             self.body().clone(),
             true,
+            false,
             ThisMode::Global,
+            self.source(),
         )?;
 
         Ok(val)
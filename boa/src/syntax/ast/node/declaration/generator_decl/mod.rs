@@ -1,4 +1,6 @@
 use crate::{
+    builtins::function::ThisMode,
+    environment::lexical_environment::VariableScope,
     exec::Executable,
     gc::{Finalize, Trace},
     syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
@@ -52,8 +54,8 @@ impl GeneratorDecl {
     }
 
     /// Gets the body of the generator declaration.
-    pub fn body(&self) -> &[Node] {
-        self.body.items()
+    pub fn body(&self) -> &StatementList {
+        &self.body
     }
 
     /// Implements the display formatting with indentation.
@@ -64,7 +66,7 @@ impl GeneratorDecl {
     ) -> fmt::Result {
         write!(f, "function* {}(", self.name)?;
         join_nodes(f, &self.parameters)?;
-        if self.body().is_empty() {
+        if self.body().items().is_empty() {
             f.write_str(") {}")
         } else {
             f.write_str(") {\n")?;
@@ -75,10 +77,25 @@ impl GeneratorDecl {
 }
 
 impl Executable for GeneratorDecl {
-    fn run(&self, _context: &mut Context) -> JsResult<JsValue> {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
         let _timer = BoaProfiler::global().start_event("GeneratorDecl", "exec");
-        // TODO: Implement GeneratorFunction
-        // https://tc39.es/ecma262/#sec-generatorfunction-objects
+        let val = context.create_function(
+            self.name(),
+            self.parameters().to_vec(),
+            self.body().clone(),
+            false,
+            true,
+            ThisMode::Global,
+            None,
+        )?;
+
+        if context.has_binding(self.name())? {
+            context.set_mutable_binding(self.name(), val, context.strict())?;
+        } else {
+            context.create_mutable_binding(self.name(), false, VariableScope::Function)?;
+
+            context.initialize_binding(self.name(), val)?;
+        }
         Ok(JsValue::undefined())
     }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    builtins::function::ThisMode,
     exec::Executable,
     gc::{Finalize, Trace},
     syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
@@ -89,10 +90,16 @@ impl GeneratorExpr {
 }
 
 impl Executable for GeneratorExpr {
-    fn run(&self, _context: &mut Context) -> JsResult<JsValue> {
-        // TODO: Implement GeneratorFunction
-        // https://tc39.es/ecma262/#sec-generatorfunction-objects
-        Ok(JsValue::undefined())
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        context.create_function(
+            self.name().unwrap_or(""),
+            self.parameters().to_vec(),
+            self.body().clone(),
+            false,
+            true,
+            ThisMode::Global,
+            None,
+        )
     }
 }
 
@@ -13,6 +13,7 @@ use std::fmt;
 use serde::{Deserialize, Serialize};
 
 pub mod arrow_function_decl;
+pub mod async_arrow_function_decl;
 pub mod async_function_decl;
 pub mod async_function_expr;
 pub mod async_generator_decl;
@@ -23,10 +24,10 @@ pub mod generator_decl;
 pub mod generator_expr;
 
 pub use self::{
-    arrow_function_decl::ArrowFunctionDecl, async_function_decl::AsyncFunctionDecl,
-    async_function_expr::AsyncFunctionExpr, async_generator_decl::AsyncGeneratorDecl,
-    async_generator_expr::AsyncGeneratorExpr, function_decl::FunctionDecl,
-    function_expr::FunctionExpr,
+    arrow_function_decl::ArrowFunctionDecl, async_arrow_function_decl::AsyncArrowFunctionDecl,
+    async_function_decl::AsyncFunctionDecl, async_function_expr::AsyncFunctionExpr,
+    async_generator_decl::AsyncGeneratorDecl, async_generator_expr::AsyncGeneratorExpr,
+    function_decl::FunctionDecl, function_expr::FunctionExpr,
 };
 
 #[cfg(test)]
@@ -98,7 +99,6 @@ pub enum DeclarationList {
 impl Executable for DeclarationList {
     fn run(&self, context: &mut Context) -> JsResult<JsValue> {
         for decl in self.as_ref() {
-            use DeclarationList::*;
             let val = match decl.init() {
                 None if self.is_const() => {
                     return context.throw_syntax_error("missing = in const declaration")
@@ -107,11 +107,68 @@ impl Executable for DeclarationList {
                 None => JsValue::undefined(),
             };
 
-            match &decl {
-                Declaration::Identifier { ident, init } => {
+            self.declare(decl, val, context)?;
+        }
+
+        Ok(JsValue::undefined())
+    }
+}
+
+impl DeclarationList {
+    #[allow(dead_code)]
+    pub(in crate::syntax) fn is_let(&self) -> bool {
+        matches!(self, Self::Let(_))
+    }
+    pub(crate) fn is_const(&self) -> bool {
+        matches!(self, Self::Const(_))
+    }
+    pub(in crate::syntax) fn is_var(&self) -> bool {
+        matches!(self, Self::Var(_))
+    }
+
+    /// Binds the names declared by `decl`, one of the declarations of the list, to `val`, the
+    /// value of its initializer.
+    pub(crate) fn declare(
+        &self,
+        decl: &Declaration,
+        val: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        use DeclarationList::*;
+        match &decl {
+            Declaration::Identifier { ident, init } => {
+                if self.is_var() && context.has_binding(ident.as_ref())? {
+                    if init.is_some() {
+                        context.set_mutable_binding(ident.as_ref(), val, context.strict())?;
+                    }
+                    return Ok(());
+                }
+
+                match &self {
+                    Const(_) => context.create_immutable_binding(
+                        ident.as_ref(),
+                        false,
+                        VariableScope::Block,
+                    )?,
+                    Let(_) => context.create_mutable_binding(
+                        ident.as_ref(),
+                        false,
+                        VariableScope::Block,
+                    )?,
+                    Var(_) => context.create_mutable_binding(
+                        ident.as_ref(),
+                        false,
+                        VariableScope::Function,
+                    )?,
+                }
+
+                context.initialize_binding(ident.as_ref(), val)?;
+            }
+            Declaration::Pattern(p) => {
+                for (ident, value) in p.run(Some(val), context)? {
                     if self.is_var() && context.has_binding(ident.as_ref())? {
-                        if init.is_some() {
-                            context.set_mutable_binding(ident.as_ref(), val, context.strict())?;
+                        if !value.is_undefined() {
+                            context.set_mutable_binding(ident.as_ref(), value, context.strict())?;
                         }
                         continue;
                     }
@@ -134,59 +191,12 @@ impl Executable for DeclarationList {
                         )?,
                     }
 
-                    context.initialize_binding(ident.as_ref(), val)?;
-                }
-                Declaration::Pattern(p) => {
-                    for (ident, value) in p.run(None, context)? {
-                        if self.is_var() && context.has_binding(ident.as_ref())? {
-                            if !value.is_undefined() {
-                                context.set_mutable_binding(
-                                    ident.as_ref(),
-                                    value,
-                                    context.strict(),
-                                )?;
-                            }
-                            continue;
-                        }
-
-                        match &self {
-                            Const(_) => context.create_immutable_binding(
-                                ident.as_ref(),
-                                false,
-                                VariableScope::Block,
-                            )?,
-                            Let(_) => context.create_mutable_binding(
-                                ident.as_ref(),
-                                false,
-                                VariableScope::Block,
-                            )?,
-                            Var(_) => context.create_mutable_binding(
-                                ident.as_ref(),
-                                false,
-                                VariableScope::Function,
-                            )?,
-                        }
-
-                        context.initialize_binding(ident.as_ref(), value)?;
-                    }
+                    context.initialize_binding(ident.as_ref(), value)?;
                 }
             }
         }
 
-        Ok(JsValue::undefined())
-    }
-}
-
-impl DeclarationList {
-    #[allow(dead_code)]
-    pub(in crate::syntax) fn is_let(&self) -> bool {
-        matches!(self, Self::Let(_))
-    }
-    pub(in crate::syntax) fn is_const(&self) -> bool {
-        matches!(self, Self::Const(_))
-    }
-    pub(in crate::syntax) fn is_var(&self) -> bool {
-        matches!(self, Self::Var(_))
+        Ok(())
     }
 }
 
@@ -323,6 +333,23 @@ impl Declaration {
             Self::Pattern(pattern) => pattern.init(),
         }
     }
+
+    /// Returns `true` if the initializers of the declaration reference `arguments`.
+    #[inline]
+    pub(crate) fn contains_arguments(&self) -> bool {
+        match &self {
+            Self::Identifier { init, .. } => init.as_ref().map_or(false, Node::contains_arguments),
+            Self::Pattern(pattern) => pattern.contains_arguments(),
+        }
+    }
+
+    /// Returns `true` if the initializers of the declaration contain a `yield` expression.
+    pub(crate) fn contains_yield(&self) -> bool {
+        match &self {
+            Self::Identifier { init, .. } => init.as_ref().map_or(false, Node::contains_yield),
+            Self::Pattern(pattern) => pattern.contains_yield(),
+        }
+    }
 }
 
 /// DeclarationPattern represents an object or array binding pattern.
@@ -390,6 +417,24 @@ impl DeclarationPattern {
             DeclarationPattern::Array(pattern) => pattern.init(),
         }
     }
+
+    /// Returns `true` if the initializer or the default values of the binding pattern reference
+    /// `arguments`.
+    #[inline]
+    pub(crate) fn contains_arguments(&self) -> bool {
+        match &self {
+            DeclarationPattern::Object(pattern) => pattern.contains_arguments(),
+            DeclarationPattern::Array(pattern) => pattern.contains_arguments(),
+        }
+    }
+
+    /// Returns `true` if the initializers of the binding pattern contain a `yield` expression.
+    pub(crate) fn contains_yield(&self) -> bool {
+        match &self {
+            DeclarationPattern::Object(pattern) => pattern.contains_yield(),
+            DeclarationPattern::Array(pattern) => pattern.contains_yield(),
+        }
+    }
 }
 
 /// DeclarationPatternObject represents an object binding pattern.
@@ -441,6 +486,59 @@ impl DeclarationPatternObject {
         self.init.as_ref()
     }
 
+    /// Gets the bindings of the object binding pattern.
+    #[cfg(feature = "vm")]
+    #[inline]
+    pub(crate) fn bindings(&self) -> &[BindingPatternTypeObject] {
+        &self.bindings
+    }
+
+    /// Returns `true` if the initializer or the default values of the object binding pattern
+    /// reference `arguments`.
+    pub(in crate::syntax) fn contains_arguments(&self) -> bool {
+        self.init.as_ref().map_or(false, Node::contains_arguments)
+            || self.bindings.iter().any(|binding| match binding {
+                BindingPatternTypeObject::Empty | BindingPatternTypeObject::RestProperty { .. } => {
+                    false
+                }
+                BindingPatternTypeObject::SingleName { default_init, .. } => default_init
+                    .as_ref()
+                    .map_or(false, Node::contains_arguments),
+                BindingPatternTypeObject::BindingPattern {
+                    pattern,
+                    default_init,
+                    ..
+                } => {
+                    pattern.contains_arguments()
+                        || default_init
+                            .as_ref()
+                            .map_or(false, Node::contains_arguments)
+                }
+            })
+    }
+
+    /// Returns `true` if the initializer or the default values of the object binding pattern
+    /// contain a `yield` expression.
+    pub(in crate::syntax) fn contains_yield(&self) -> bool {
+        self.init.as_ref().map_or(false, Node::contains_yield)
+            || self.bindings.iter().any(|binding| match binding {
+                BindingPatternTypeObject::Empty | BindingPatternTypeObject::RestProperty { .. } => {
+                    false
+                }
+                BindingPatternTypeObject::SingleName { default_init, .. } => {
+                    default_init.as_ref().map_or(false, Node::contains_yield)
+                }
+                BindingPatternTypeObject::BindingPattern {
+                    pattern,
+                    default_init,
+                    ..
+                } => {
+                    pattern.contains_yield()
+                        || default_init.as_ref().map_or(false, Node::contains_yield)
+                }
+            })
+    }
+
     /// Initialize the values of an object binding pattern.
     ///
     /// More information:
@@ -649,6 +747,42 @@ impl DeclarationPatternArray {
         self.init.as_ref()
     }
 
+    /// Returns `true` if the initializer or the default values of the array binding pattern
+    /// reference `arguments`.
+    pub(in crate::syntax) fn contains_arguments(&self) -> bool {
+        self.init.as_ref().map_or(false, Node::contains_arguments)
+            || self.bindings.iter().any(|binding| match binding {
+                BindingPatternTypeArray::Empty
+                | BindingPatternTypeArray::Elision
+                | BindingPatternTypeArray::SingleNameRest { .. } => false,
+                BindingPatternTypeArray::SingleName { default_init, .. } => default_init
+                    .as_ref()
+                    .map_or(false, Node::contains_arguments),
+                BindingPatternTypeArray::BindingPattern { pattern }
+                | BindingPatternTypeArray::BindingPatternRest { pattern } => {
+                    pattern.contains_arguments()
+                }
+            })
+    }
+
+    /// Returns `true` if the initializer or the default values of the array binding pattern
+    /// contain a `yield` expression.
+    pub(in crate::syntax) fn contains_yield(&self) -> bool {
+        self.init.as_ref().map_or(false, Node::contains_yield)
+            || self.bindings.iter().any(|binding| match binding {
+                BindingPatternTypeArray::Empty
+                | BindingPatternTypeArray::Elision
+                | BindingPatternTypeArray::SingleNameRest { .. } => false,
+                BindingPatternTypeArray::SingleName { default_init, .. } => {
+                    default_init.as_ref().map_or(false, Node::contains_yield)
+                }
+                BindingPatternTypeArray::BindingPattern { pattern }
+                | BindingPatternTypeArray::BindingPatternRest { pattern } => {
+                    pattern.contains_yield()
+                }
+            })
+    }
+
     /// Initialize the values of an array binding pattern.
     ///
     /// More information:
@@ -58,36 +58,9 @@ impl ForInLoop {
         self.label = Some(label);
     }
 
-    pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
-        if let Some(ref label) = self.label {
-            write!(f, "{}: ", label)?;
-        }
-        write!(f, "for ({} in {}) ", self.variable, self.expr)?;
-        self.body().display(f, indentation)
-    }
-}
-
-impl fmt::Display for ForInLoop {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.display(f, 0)
-    }
-}
-
-impl From<ForInLoop> for Node {
-    fn from(for_in: ForInLoop) -> Node {
-        Self::ForInLoop(for_in)
-    }
-}
-
-impl Executable for ForInLoop {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        let _timer = BoaProfiler::global().start_event("ForIn", "exec");
-        let object = self.expr().run(context)?;
-        let mut result = JsValue::undefined();
-
-        if object.is_null_or_undefined() {
-            return Ok(result);
-        }
+    /// Creates the iterator over the enumerable property names of `object`, the value of the
+    /// expression of the loop.
+    pub(crate) fn iterator(object: JsValue, context: &mut Context) -> JsResult<IteratorRecord> {
         let object = object.to_object(context)?;
         let for_in_iterator = ForInIterator::create_for_in_iterator(JsValue::new(object), context);
         let next_function = for_in_iterator
@@ -96,42 +69,34 @@ impl Executable for ForInLoop {
             .map(|p| p.expect_value())
             .cloned()
             .ok_or_else(|| context.construct_type_error("Could not find property `next`"))?;
-        let iterator = IteratorRecord::new(for_in_iterator, next_function);
-
-        loop {
-            {
-                let env = context.get_current_environment();
-                context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
-            }
-            let iterator_result = iterator.next(context)?;
-            if iterator_result.done {
-                context.pop_environment();
-                break;
-            }
-            let next_result = iterator_result.value;
+        Ok(IteratorRecord::new(for_in_iterator, next_function))
+    }
 
-            match self.variable() {
-                Node::Identifier(ref name) => {
-                    if context.has_binding(name.as_ref())? {
-                        // Binding already exists
-                        context.set_mutable_binding(
-                            name.as_ref(),
-                            next_result.clone(),
-                            context.strict(),
-                        )?;
-                    } else {
-                        context.create_mutable_binding(
-                            name.as_ref(),
-                            true,
-                            VariableScope::Function,
-                        )?;
-                        context.initialize_binding(name.as_ref(), next_result)?;
-                    }
+    /// Binds the variable of the loop to `next_result`, the value of the current iteration.
+    pub(crate) fn bind_variable(
+        &self,
+        next_result: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        match self.variable() {
+            Node::Identifier(ref name) => {
+                if context.has_binding(name.as_ref())? {
+                    // Binding already exists
+                    context.set_mutable_binding(
+                        name.as_ref(),
+                        next_result.clone(),
+                        context.strict(),
+                    )?;
+                } else {
+                    context.create_mutable_binding(name.as_ref(), true, VariableScope::Function)?;
+                    context.initialize_binding(name.as_ref(), next_result)?;
                 }
-                Node::VarDeclList(ref list) => match list.as_ref() {
+            }
+            Node::VarDeclList(ref list) => {
+                match list.as_ref() {
                     [var] => {
                         if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
+                            return Err(context.construct_syntax_error("a declaration in the head of a for-in loop can't have an initializer"));
                         }
 
                         match &var {
@@ -172,15 +137,17 @@ impl Executable for ForInLoop {
                         }
                     }
                     _ => {
-                        return context.throw_syntax_error(
+                        return Err(context.construct_syntax_error(
                             "only one variable can be declared in the head of a for-in loop",
-                        )
+                        ));
                     }
-                },
-                Node::LetDeclList(ref list) => match list.as_ref() {
+                }
+            }
+            Node::LetDeclList(ref list) => {
+                match list.as_ref() {
                     [var] => {
                         if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
+                            return Err(context.construct_syntax_error("a declaration in the head of a for-in loop can't have an initializer"));
                         }
 
                         match &var {
@@ -205,15 +172,17 @@ impl Executable for ForInLoop {
                         }
                     }
                     _ => {
-                        return context.throw_syntax_error(
+                        return Err(context.construct_syntax_error(
                             "only one variable can be declared in the head of a for-in loop",
-                        )
+                        ));
                     }
-                },
-                Node::ConstDeclList(ref list) => match list.as_ref() {
+                }
+            }
+            Node::ConstDeclList(ref list) => {
+                match list.as_ref() {
                     [var] => {
                         if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
+                            return Err(context.construct_syntax_error("a declaration in the head of a for-in loop can't have an initializer"));
                         }
 
                         match &var {
@@ -238,21 +207,72 @@ impl Executable for ForInLoop {
                         }
                     }
                     _ => {
-                        return context.throw_syntax_error(
+                        return Err(context.construct_syntax_error(
                             "only one variable can be declared in the head of a for-in loop",
-                        )
+                        ));
                     }
-                },
-                Node::Assign(_) => {
-                    return context.throw_syntax_error(
-                        "a declaration in the head of a for-in loop can't have an initializer",
-                    );
-                }
-                _ => {
-                    return context
-                        .throw_syntax_error("unknown left hand side in head of for-in loop")
                 }
             }
+            Node::Assign(_) => {
+                return Err(context.construct_syntax_error(
+                    "a declaration in the head of a for-in loop can't have an initializer",
+                ));
+            }
+            _ => {
+                return Err(
+                    context.construct_syntax_error("unknown left hand side in head of for-in loop")
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        if let Some(ref label) = self.label {
+            write!(f, "{}: ", label)?;
+        }
+        write!(f, "for ({} in {}) ", self.variable, self.expr)?;
+        self.body().display(f, indentation)
+    }
+}
+
+impl fmt::Display for ForInLoop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<ForInLoop> for Node {
+    fn from(for_in: ForInLoop) -> Node {
+        Self::ForInLoop(for_in)
+    }
+}
+
+impl Executable for ForInLoop {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("ForIn", "exec");
+        let object = self.expr().run(context)?;
+        let mut result = JsValue::undefined();
+
+        if object.is_null_or_undefined() {
+            return Ok(result);
+        }
+        let iterator = Self::iterator(object, context)?;
+
+        loop {
+            {
+                let env = context.get_current_environment();
+                context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+            }
+            let iterator_result = iterator.next(context)?;
+            if iterator_result.done {
+                context.pop_environment();
+                break;
+            }
+            let next_result = iterator_result.value;
+
+            self.bind_variable(next_result, context)?;
 
             result = self.body().run(context)?;
             match context.executor().get_current_state() {
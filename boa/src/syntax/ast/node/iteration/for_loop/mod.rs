@@ -1,8 +1,11 @@
 use crate::{
-    environment::declarative_environment_record::DeclarativeEnvironmentRecord,
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        lexical_environment::VariableScope,
+    },
     exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Declaration, Node},
     BoaProfiler, Context, JsResult, JsValue,
 };
 use std::fmt;
@@ -95,6 +98,70 @@ impl ForLoop {
     pub fn set_label(&mut self, label: Box<str>) {
         self.label = Some(label);
     }
+
+    /// Returns the bindings declared by a `let` in the loop head, which get a fresh copy on every
+    /// iteration, so closures created in the body each capture their own value.
+    pub(crate) fn per_iteration_bindings(&self) -> Vec<Box<str>> {
+        match self.init() {
+            Some(Node::LetDeclList(list)) => list
+                .as_ref()
+                .iter()
+                .flat_map(|decl| match decl {
+                    Declaration::Identifier { ident, .. } => vec![ident.as_ref()],
+                    Declaration::Pattern(pattern) => pattern.idents(),
+                })
+                .map(Into::into)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// `CreatePerIterationEnvironment ( perIterationBindings )`
+    ///
+    /// Replaces the environment of the current iteration with a new one, holding a copy of each
+    /// of the `bindings`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createperiterationenvironment
+    pub(crate) fn create_per_iteration_environment(
+        bindings: &[Box<str>],
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. If perIterationBindings has any elements, then
+        if bindings.is_empty() {
+            return Ok(());
+        }
+
+        // a. Let lastIterationEnv be the running execution context's LexicalEnvironment.
+        // e. For each element bn of perIterationBindings, do
+        //    ii. Let lastValue be ? lastIterationEnv.GetBindingValue(bn, true).
+        let values = bindings
+            .iter()
+            .map(|name| context.get_binding_value(name))
+            .collect::<JsResult<Vec<_>>>()?;
+
+        // b. Let outer be lastIterationEnv.[[OuterEnv]].
+        // c. Assert: outer is not null.
+        let _ = context.pop_environment();
+        let outer = context.get_current_environment();
+
+        // d. Let thisIterationEnv be NewDeclarativeEnvironment(outer).
+        // f. Set the running execution context's LexicalEnvironment to thisIterationEnv.
+        context.push_environment(DeclarativeEnvironmentRecord::new(Some(outer)));
+
+        for (name, value) in bindings.iter().zip(values) {
+            // i. Perform ! thisIterationEnv.CreateMutableBinding(bn, false).
+            context.create_mutable_binding(name, false, VariableScope::Block)?;
+
+            // iii. Perform thisIterationEnv.InitializeBinding(bn, lastValue).
+            context.initialize_binding(name, value)?;
+        }
+
+        // 2. Return unused.
+        Ok(())
+    }
 }
 
 impl Executable for ForLoop {
@@ -110,6 +177,9 @@ impl Executable for ForLoop {
             init.run(context)?;
         }
 
+        let per_iteration_bindings = self.per_iteration_bindings();
+        Self::create_per_iteration_environment(&per_iteration_bindings, context)?;
+
         while self
             .condition()
             .map(|cond| cond.run(context).map(|v| v.to_boolean()))
@@ -135,6 +205,8 @@ impl Executable for ForLoop {
                 }
             }
 
+            Self::create_per_iteration_environment(&per_iteration_bindings, context)?;
+
             if let Some(final_expr) = self.final_expr() {
                 final_expr.run(context)?;
             }
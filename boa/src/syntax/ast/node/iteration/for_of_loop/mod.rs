@@ -57,68 +57,31 @@ impl ForOfLoop {
         self.label = Some(label);
     }
 
-    pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
-        if let Some(ref label) = self.label {
-            write!(f, "{}: ", label)?;
-        }
-        write!(f, "for ({} of {}) ", self.variable, self.iterable)?;
-        self.body().display(f, indentation)
-    }
-}
-
-impl fmt::Display for ForOfLoop {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.display(f, 0)
-    }
-}
-
-impl From<ForOfLoop> for Node {
-    fn from(for_of: ForOfLoop) -> Node {
-        Self::ForOfLoop(for_of)
-    }
-}
-
-impl Executable for ForOfLoop {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        let _timer = BoaProfiler::global().start_event("ForOf", "exec");
-        let iterable = self.iterable().run(context)?;
-        let iterator = iterable.get_iterator(context, None, None)?;
-        let mut result = JsValue::undefined();
-
-        loop {
-            {
-                let env = context.get_current_environment();
-                context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
-            }
-            let iterator_result = iterator.next(context)?;
-            if iterator_result.done {
-                context.pop_environment();
-                break;
-            }
-            let next_result = iterator_result.value;
-
-            match self.variable() {
-                Node::Identifier(ref name) => {
-                    if context.has_binding(name.as_ref())? {
-                        // Binding already exists
-                        context.set_mutable_binding(
-                            name.as_ref(),
-                            next_result.clone(),
-                            context.strict(),
-                        )?;
-                    } else {
-                        context.create_mutable_binding(
-                            name.as_ref(),
-                            true,
-                            VariableScope::Function,
-                        )?;
-                        context.initialize_binding(name.as_ref(), next_result)?;
-                    }
+    /// Binds the variable of the loop to `next_result`, the value of the current iteration.
+    pub(crate) fn bind_variable(
+        &self,
+        next_result: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        match self.variable() {
+            Node::Identifier(ref name) => {
+                if context.has_binding(name.as_ref())? {
+                    // Binding already exists
+                    context.set_mutable_binding(
+                        name.as_ref(),
+                        next_result.clone(),
+                        context.strict(),
+                    )?;
+                } else {
+                    context.create_mutable_binding(name.as_ref(), true, VariableScope::Function)?;
+                    context.initialize_binding(name.as_ref(), next_result)?;
                 }
-                Node::VarDeclList(ref list) => match list.as_ref() {
+            }
+            Node::VarDeclList(ref list) => {
+                match list.as_ref() {
                     [var] => {
                         if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
+                            return Err(context.construct_syntax_error("a declaration in the head of a for-of loop can't have an initializer"));
                         }
 
                         match &var {
@@ -159,15 +122,17 @@ impl Executable for ForOfLoop {
                         }
                     }
                     _ => {
-                        return context.throw_syntax_error(
+                        return Err(context.construct_syntax_error(
                             "only one variable can be declared in the head of a for-of loop",
-                        )
+                        ));
                     }
-                },
-                Node::LetDeclList(ref list) => match list.as_ref() {
+                }
+            }
+            Node::LetDeclList(ref list) => {
+                match list.as_ref() {
                     [var] => {
                         if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
+                            return Err(context.construct_syntax_error("a declaration in the head of a for-of loop can't have an initializer"));
                         }
 
                         match &var {
@@ -192,15 +157,17 @@ impl Executable for ForOfLoop {
                         }
                     }
                     _ => {
-                        return context.throw_syntax_error(
+                        return Err(context.construct_syntax_error(
                             "only one variable can be declared in the head of a for-of loop",
-                        )
+                        ));
                     }
-                },
-                Node::ConstDeclList(ref list) => match list.as_ref() {
+                }
+            }
+            Node::ConstDeclList(ref list) => {
+                match list.as_ref() {
                     [var] => {
                         if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
+                            return Err(context.construct_syntax_error("a declaration in the head of a for-of loop can't have an initializer"));
                         }
 
                         match &var {
@@ -225,21 +192,68 @@ impl Executable for ForOfLoop {
                         }
                     }
                     _ => {
-                        return context.throw_syntax_error(
+                        return Err(context.construct_syntax_error(
                             "only one variable can be declared in the head of a for-of loop",
-                        )
+                        ));
                     }
-                },
-                Node::Assign(_) => {
-                    return context.throw_syntax_error(
-                        "a declaration in the head of a for-of loop can't have an initializer",
-                    );
-                }
-                _ => {
-                    return context
-                        .throw_syntax_error("unknown left hand side in head of for-of loop")
                 }
             }
+            Node::Assign(_) => {
+                return Err(context.construct_syntax_error(
+                    "a declaration in the head of a for-of loop can't have an initializer",
+                ));
+            }
+            _ => {
+                return Err(
+                    context.construct_syntax_error("unknown left hand side in head of for-of loop")
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        if let Some(ref label) = self.label {
+            write!(f, "{}: ", label)?;
+        }
+        write!(f, "for ({} of {}) ", self.variable, self.iterable)?;
+        self.body().display(f, indentation)
+    }
+}
+
+impl fmt::Display for ForOfLoop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<ForOfLoop> for Node {
+    fn from(for_of: ForOfLoop) -> Node {
+        Self::ForOfLoop(for_of)
+    }
+}
+
+impl Executable for ForOfLoop {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("ForOf", "exec");
+        let iterable = self.iterable().run(context)?;
+        let iterator = iterable.get_iterator(context, None, None)?;
+        let mut result = JsValue::undefined();
+
+        loop {
+            {
+                let env = context.get_current_environment();
+                context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+            }
+            let iterator_result = iterator.next(context)?;
+            if iterator_result.done {
+                context.pop_environment();
+                break;
+            }
+            let next_result = iterator_result.value;
+
+            self.bind_variable(next_result, context)?;
 
             result = self.body().run(context)?;
             match context.executor().get_current_state() {
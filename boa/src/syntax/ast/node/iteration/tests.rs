@@ -66,6 +66,63 @@ fn for_loop_return() {
     assert_eq!(&exec(scenario), "3");
 }
 
+#[test]
+fn for_loop_let_per_iteration_binding() {
+    let scenario = r#"
+        let closures = [];
+        for (let i = 0; i < 3; i++) {
+            closures.push(() => i);
+        }
+        closures.map(f => f()).join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"0,1,2\"");
+}
+
+#[test]
+fn for_loop_let_per_iteration_binding_is_copied() {
+    // Each iteration starts from the value the previous one ended with, and a closure keeps
+    // updating its own copy.
+    let scenario = r#"
+        let closures = [];
+        for (let i = 0; i < 3; i++) {
+            closures.push(() => i++);
+        }
+        [closures[0](), closures[0](), closures[1]()].join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"0,1,1\"");
+}
+
+#[test]
+fn for_loop_let_per_iteration_binding_with_continue_and_patterns() {
+    let scenario = r#"
+        let closures = [];
+        for (let [i, j] = [0, 10]; i < 3; i++, j--) {
+            if (i === 1) {
+                continue;
+            }
+            closures.push(() => i + ":" + j);
+        }
+        closures.map(f => f()).join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"0:10,2:8\"");
+}
+
+#[test]
+fn for_loop_var_shares_binding() {
+    let scenario = r#"
+        var closures = [];
+        for (var i = 0; i < 3; i++) {
+            closures.push(() => i);
+        }
+        closures.map(f => f()).join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"3,3,3\"");
+}
+
 #[test]
 fn do_loop_late_break() {
     // Ordering with statement before the break.
@@ -13,6 +13,7 @@ pub mod iteration;
 pub mod new;
 pub mod object;
 pub mod operator;
+pub mod optional;
 pub mod return_smt;
 pub mod spread;
 pub mod statement_list;
@@ -32,8 +33,8 @@ pub use self::{
     declaration::{
         async_generator_decl::AsyncGeneratorDecl, async_generator_expr::AsyncGeneratorExpr,
         generator_decl::GeneratorDecl, generator_expr::GeneratorExpr, ArrowFunctionDecl,
-        AsyncFunctionDecl, AsyncFunctionExpr, Declaration, DeclarationList, DeclarationPattern,
-        FunctionDecl, FunctionExpr,
+        AsyncArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, Declaration, DeclarationList,
+        DeclarationPattern, FunctionDecl, FunctionExpr,
     },
     field::{GetConstField, GetField},
     identifier::Identifier,
@@ -41,6 +42,7 @@ pub use self::{
     new::New,
     object::Object,
     operator::{Assign, BinOp, UnaryOp},
+    optional::{Optional, OptionalOperation, OptionalOperationKind},
     r#yield::Yield,
     return_smt::Return,
     spread::Spread,
@@ -77,6 +79,9 @@ pub enum Node {
     /// An assignment operator node. [More information](./operator/struct.Assign.html).
     Assign(Assign),
 
+    /// An async arrow function expression node. [More information](./declaration/struct.AsyncArrowFunctionDecl.html).
+    AsyncArrowFunctionDecl(AsyncArrowFunctionDecl),
+
     /// An async function declaration node. [More information](./declaration/struct.AsyncFunctionDecl.html).
     AsyncFunctionDecl(AsyncFunctionDecl),
 
@@ -164,6 +169,9 @@ pub enum Node {
     /// An object. [More information](./object/struct.Object.html).
     Object(Object),
 
+    /// An optional chain node. [More information](./optional/struct.Optional.html).
+    Optional(Optional),
+
     /// A return statement. [More information](./object/struct.Return.html).
     Return(Return),
 
@@ -259,6 +267,285 @@ impl Node {
         Self::This
     }
 
+    /// Returns `true` if the node references `arguments` outside of any nested function that
+    /// has its own `arguments` binding.
+    ///
+    /// Arrow functions and computed method names are searched, since they see the `arguments`
+    /// object of the enclosing function.
+    ///
+    /// A direct `eval(...)` call counts as a reference, since the evaluated code may use
+    /// `arguments`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-containsarguments
+    pub(crate) fn contains_arguments(&self) -> bool {
+        fn any(nodes: &[Node]) -> bool {
+            nodes.iter().any(Node::contains_arguments)
+        }
+
+        fn maybe(node: Option<&Node>) -> bool {
+            node.map_or(false, Node::contains_arguments)
+        }
+
+        match *self {
+            Self::Identifier(ref ident) => ident.as_ref() == "arguments",
+            Self::ArrayDecl(ref arr) => any(arr.as_ref()),
+            Self::ArrowFunctionDecl(ref decl) => {
+                decl.params()
+                    .iter()
+                    .any(FormalParameter::contains_arguments)
+                    || decl.body().contains_arguments()
+            }
+            Self::Assign(ref op) => op.lhs().contains_arguments() || op.rhs().contains_arguments(),
+            Self::AsyncArrowFunctionDecl(ref decl) => {
+                decl.params()
+                    .iter()
+                    .any(FormalParameter::contains_arguments)
+                    || decl.body().contains_arguments()
+            }
+            Self::AwaitExpr(ref expr) => expr.expr().contains_arguments(),
+            Self::BinOp(ref op) => op.lhs().contains_arguments() || op.rhs().contains_arguments(),
+            Self::Block(ref block) => any(block.items()),
+            Self::Call(ref call) => {
+                matches!(call.expr(), Self::Identifier(ref ident) if ident.as_ref() == "eval")
+                    || call.expr().contains_arguments()
+                    || any(call.args())
+            }
+            Self::ConditionalOp(ref op) => {
+                op.cond().contains_arguments()
+                    || op.if_true().contains_arguments()
+                    || op.if_false().contains_arguments()
+            }
+            Self::VarDeclList(ref list)
+            | Self::LetDeclList(ref list)
+            | Self::ConstDeclList(ref list) => {
+                list.as_ref().iter().any(Declaration::contains_arguments)
+            }
+            Self::DoWhileLoop(ref do_while) => {
+                do_while.body().contains_arguments() || do_while.cond().contains_arguments()
+            }
+            Self::ForLoop(ref for_loop) => {
+                maybe(for_loop.init())
+                    || maybe(for_loop.condition())
+                    || maybe(for_loop.final_expr())
+                    || for_loop.body().contains_arguments()
+            }
+            Self::ForInLoop(ref for_in) => {
+                for_in.variable().contains_arguments()
+                    || for_in.expr().contains_arguments()
+                    || for_in.body().contains_arguments()
+            }
+            Self::ForOfLoop(ref for_of) => {
+                for_of.variable().contains_arguments()
+                    || for_of.iterable().contains_arguments()
+                    || for_of.body().contains_arguments()
+            }
+            Self::GetConstField(ref get_const_field) => get_const_field.obj().contains_arguments(),
+            Self::GetField(ref get_field) => {
+                get_field.obj().contains_arguments() || get_field.field().contains_arguments()
+            }
+            Self::If(ref if_smt) => {
+                if_smt.cond().contains_arguments()
+                    || if_smt.body().contains_arguments()
+                    || maybe(if_smt.else_node())
+            }
+            Self::New(ref new) => new.expr().contains_arguments() || any(new.args()),
+            Self::Object(ref obj) => obj
+                .properties()
+                .iter()
+                .any(PropertyDefinition::contains_arguments),
+            Self::Optional(ref optional) => {
+                optional.target().contains_arguments()
+                    || optional
+                        .chain()
+                        .iter()
+                        .any(|operation| match operation.kind() {
+                            OptionalOperationKind::SimplePropertyAccess { .. } => false,
+                            OptionalOperationKind::PropertyAccess { field } => {
+                                field.contains_arguments()
+                            }
+                            OptionalOperationKind::Call { args } => any(args),
+                        })
+            }
+            Self::Return(ref ret) => maybe(ret.expr()),
+            Self::Spread(ref spread) => spread.val().contains_arguments(),
+            Self::Switch(ref switch) => {
+                switch.val().contains_arguments()
+                    || switch.cases().iter().any(|case| {
+                        case.condition().contains_arguments() || case.body().contains_arguments()
+                    })
+                    || switch.default().map_or(false, any)
+            }
+            Self::TaggedTemplate(ref template) => {
+                template.tag().contains_arguments() || any(template.exprs())
+            }
+            Self::TemplateLit(ref template) => {
+                template.elements().iter().any(|element| match element {
+                    template::TemplateElement::String(_) => false,
+                    template::TemplateElement::Expr(node) => node.contains_arguments(),
+                })
+            }
+            Self::Throw(ref throw) => throw.expr().contains_arguments(),
+            Self::Try(ref try_node) => {
+                any(try_node.block().items())
+                    || try_node.catch().map_or(false, |catch| {
+                        catch
+                            .parameter()
+                            .map_or(false, Declaration::contains_arguments)
+                            || any(catch.block().items())
+                    })
+                    || try_node
+                        .finally()
+                        .map_or(false, |finally| any(finally.items()))
+            }
+            Self::UnaryOp(ref op) => op.target().contains_arguments(),
+            Self::WhileLoop(ref while_loop) => {
+                while_loop.cond().contains_arguments() || while_loop.body().contains_arguments()
+            }
+            Self::Yield(ref y) => maybe(y.expr()),
+            // Non-arrow functions have their own `arguments` binding.
+            Self::AsyncFunctionDecl(_)
+            | Self::AsyncFunctionExpr(_)
+            | Self::AsyncGeneratorDecl(_)
+            | Self::AsyncGeneratorExpr(_)
+            | Self::FunctionDecl(_)
+            | Self::FunctionExpr(_)
+            | Self::GeneratorDecl(_)
+            | Self::GeneratorExpr(_) => false,
+            Self::Break(_) | Self::Const(_) | Self::Continue(_) | Self::Empty | Self::This => false,
+        }
+    }
+
+    /// Returns `true` if the node contains a `yield` expression outside of any nested function.
+    pub(crate) fn contains_yield(&self) -> bool {
+        fn any(nodes: &[Node]) -> bool {
+            nodes.iter().any(Node::contains_yield)
+        }
+
+        fn maybe(node: Option<&Node>) -> bool {
+            node.map_or(false, Node::contains_yield)
+        }
+
+        match *self {
+            Self::Yield(_) => true,
+            Self::ArrayDecl(ref arr) => any(arr.as_ref()),
+            Self::Assign(ref op) => op.lhs().contains_yield() || op.rhs().contains_yield(),
+            Self::AwaitExpr(ref expr) => expr.expr().contains_yield(),
+            Self::BinOp(ref op) => op.lhs().contains_yield() || op.rhs().contains_yield(),
+            Self::Block(ref block) => any(block.items()),
+            Self::Call(ref call) => call.expr().contains_yield() || any(call.args()),
+            Self::ConditionalOp(ref op) => {
+                op.cond().contains_yield()
+                    || op.if_true().contains_yield()
+                    || op.if_false().contains_yield()
+            }
+            Self::VarDeclList(ref list)
+            | Self::LetDeclList(ref list)
+            | Self::ConstDeclList(ref list) => {
+                list.as_ref().iter().any(Declaration::contains_yield)
+            }
+            Self::DoWhileLoop(ref do_while) => {
+                do_while.body().contains_yield() || do_while.cond().contains_yield()
+            }
+            Self::ForLoop(ref for_loop) => {
+                maybe(for_loop.init())
+                    || maybe(for_loop.condition())
+                    || maybe(for_loop.final_expr())
+                    || for_loop.body().contains_yield()
+            }
+            Self::ForInLoop(ref for_in) => {
+                for_in.variable().contains_yield()
+                    || for_in.expr().contains_yield()
+                    || for_in.body().contains_yield()
+            }
+            Self::ForOfLoop(ref for_of) => {
+                for_of.variable().contains_yield()
+                    || for_of.iterable().contains_yield()
+                    || for_of.body().contains_yield()
+            }
+            Self::GetConstField(ref get_const_field) => get_const_field.obj().contains_yield(),
+            Self::GetField(ref get_field) => {
+                get_field.obj().contains_yield() || get_field.field().contains_yield()
+            }
+            Self::If(ref if_smt) => {
+                if_smt.cond().contains_yield()
+                    || if_smt.body().contains_yield()
+                    || maybe(if_smt.else_node())
+            }
+            Self::New(ref new) => new.expr().contains_yield() || any(new.args()),
+            Self::Object(ref obj) => obj
+                .properties()
+                .iter()
+                .any(PropertyDefinition::contains_yield),
+            Self::Optional(ref optional) => {
+                optional.target().contains_yield()
+                    || optional
+                        .chain()
+                        .iter()
+                        .any(|operation| match operation.kind() {
+                            OptionalOperationKind::SimplePropertyAccess { .. } => false,
+                            OptionalOperationKind::PropertyAccess { field } => {
+                                field.contains_yield()
+                            }
+                            OptionalOperationKind::Call { args } => any(args),
+                        })
+            }
+            Self::Return(ref ret) => maybe(ret.expr()),
+            Self::Spread(ref spread) => spread.val().contains_yield(),
+            Self::Switch(ref switch) => {
+                switch.val().contains_yield()
+                    || switch
+                        .cases()
+                        .iter()
+                        .any(|case| case.condition().contains_yield() || any(case.body().items()))
+                    || switch.default().map_or(false, any)
+            }
+            Self::TaggedTemplate(ref template) => {
+                template.tag().contains_yield() || any(template.exprs())
+            }
+            Self::TemplateLit(ref template) => {
+                template.elements().iter().any(|element| match element {
+                    template::TemplateElement::String(_) => false,
+                    template::TemplateElement::Expr(node) => node.contains_yield(),
+                })
+            }
+            Self::Throw(ref throw) => throw.expr().contains_yield(),
+            Self::Try(ref try_node) => {
+                any(try_node.block().items())
+                    || try_node.catch().map_or(false, |catch| {
+                        catch.parameter().map_or(false, Declaration::contains_yield)
+                            || any(catch.block().items())
+                    })
+                    || try_node
+                        .finally()
+                        .map_or(false, |finally| any(finally.items()))
+            }
+            Self::UnaryOp(ref op) => op.target().contains_yield(),
+            Self::WhileLoop(ref while_loop) => {
+                while_loop.cond().contains_yield() || while_loop.body().contains_yield()
+            }
+            // A `yield` in a nested function belongs to that function.
+            Self::ArrowFunctionDecl(_)
+            | Self::AsyncArrowFunctionDecl(_)
+            | Self::AsyncFunctionDecl(_)
+            | Self::AsyncFunctionExpr(_)
+            | Self::AsyncGeneratorDecl(_)
+            | Self::AsyncGeneratorExpr(_)
+            | Self::FunctionDecl(_)
+            | Self::FunctionExpr(_)
+            | Self::GeneratorDecl(_)
+            | Self::GeneratorExpr(_) => false,
+            Self::Break(_)
+            | Self::Const(_)
+            | Self::Continue(_)
+            | Self::Empty
+            | Self::Identifier(_)
+            | Self::This => false,
+        }
+    }
+
     /// Displays the value of the node with the given indentation. For example, an indent
     /// level of 2 would produce this:
     ///
@@ -303,6 +590,7 @@ impl Node {
             Self::If(ref if_smt) => if_smt.display(f, indentation),
             Self::Switch(ref switch) => switch.display(f, indentation),
             Self::Object(ref obj) => obj.display(f, indentation),
+            Self::Optional(ref optional) => Display::fmt(optional, f),
             Self::ArrayDecl(ref arr) => Display::fmt(arr, f),
             Self::VarDeclList(ref list) => Display::fmt(list, f),
             Self::FunctionDecl(ref decl) => decl.display(f, indentation),
@@ -317,6 +605,7 @@ impl Node {
             Self::Assign(ref op) => Display::fmt(op, f),
             Self::LetDeclList(ref decl) => Display::fmt(decl, f),
             Self::ConstDeclList(ref decl) => Display::fmt(decl, f),
+            Self::AsyncArrowFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::AsyncFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::AsyncFunctionExpr(ref expr) => expr.display(f, indentation),
             Self::AwaitExpr(ref expr) => Display::fmt(expr, f),
@@ -333,7 +622,9 @@ impl Node {
 impl Executable for Node {
     fn run(&self, context: &mut Context) -> JsResult<JsValue> {
         let _timer = BoaProfiler::global().start_event("Executable", "exec");
+        context.check_deadline()?;
         match *self {
+            Node::AsyncArrowFunctionDecl(ref decl) => decl.run(context),
             Node::AsyncFunctionDecl(ref decl) => decl.run(context),
             Node::AsyncFunctionExpr(ref function_expr) => function_expr.run(context),
             Node::AsyncGeneratorExpr(ref expr) => expr.run(context),
@@ -363,6 +654,7 @@ impl Executable for Node {
             Node::ConditionalOp(ref op) => op.run(context),
             Node::Switch(ref switch) => switch.run(context),
             Node::Object(ref obj) => obj.run(context),
+            Node::Optional(ref optional) => optional.run(context),
             Node::ArrayDecl(ref arr) => arr.run(context),
             // <https://tc39.es/ecma262/#sec-createdynamicfunction>
             Node::FunctionDecl(ref decl) => decl.run(context),
@@ -495,6 +787,11 @@ impl FormalParameter {
     pub fn is_identifier(&self) -> bool {
         matches!(&self.declaration, Declaration::Identifier { .. })
     }
+
+    /// Returns `true` if the initializers of the formal parameter reference `arguments`.
+    pub(crate) fn contains_arguments(&self) -> bool {
+        self.declaration.contains_arguments()
+    }
 }
 
 impl Display for FormalParameter {
@@ -600,6 +897,30 @@ impl PropertyDefinition {
     {
         Self::SpreadObject(obj.into())
     }
+
+    /// Returns `true` if the property definition references `arguments`.
+    ///
+    /// Only the name of a method is searched, since its body has its own `arguments` binding.
+    pub(crate) fn contains_arguments(&self) -> bool {
+        match self {
+            Self::IdentifierReference(ident) => ident.as_ref() == "arguments",
+            Self::Property(name, value) => name.contains_arguments() || value.contains_arguments(),
+            Self::MethodDefinition(_, name, _) => name.contains_arguments(),
+            Self::SpreadObject(obj) => obj.contains_arguments(),
+        }
+    }
+
+    /// Returns `true` if the property definition contains a `yield` expression.
+    ///
+    /// Only the name of a method is searched, since its body is a function of its own.
+    pub(crate) fn contains_yield(&self) -> bool {
+        match self {
+            Self::IdentifierReference(_) => false,
+            Self::Property(name, value) => name.contains_yield() || value.contains_yield(),
+            Self::MethodDefinition(_, name, _) => name.contains_yield(),
+            Self::SpreadObject(obj) => obj.contains_yield(),
+        }
+    }
 }
 
 /// Method definition kinds.
@@ -717,6 +1038,24 @@ pub enum PropertyName {
     Computed(Node),
 }
 
+impl PropertyName {
+    /// Returns `true` if the property name is computed from an expression referencing `arguments`.
+    pub(crate) fn contains_arguments(&self) -> bool {
+        match self {
+            Self::Literal(_) => false,
+            Self::Computed(node) => node.contains_arguments(),
+        }
+    }
+
+    /// Returns `true` if the property name is computed from an expression containing a `yield`.
+    pub(crate) fn contains_yield(&self) -> bool {
+        match self {
+            Self::Literal(_) => false,
+            Self::Computed(node) => node.contains_yield(),
+        }
+    }
+}
+
 impl Display for PropertyName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
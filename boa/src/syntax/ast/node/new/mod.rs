@@ -1,5 +1,5 @@
 use crate::{
-    exec::Executable,
+    exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
     syntax::ast::node::{Call, Node},
     value::JsValue,
@@ -70,7 +70,7 @@ impl Executable for New {
             }
         }
 
-        func_object
+        let result = func_object
             .as_constructor()
             .ok_or_else(|| {
                 context.construct_type_error(format!(
@@ -78,7 +78,14 @@ impl Executable for New {
                     self.expr().to_string(),
                 ))
             })
-            .and_then(|cons| cons.construct(&v_args, &cons.clone().into(), context))
+            .and_then(|cons| cons.construct(&v_args, &cons.clone().into(), context));
+
+        // unset the early return flag
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        result
     }
 }
 
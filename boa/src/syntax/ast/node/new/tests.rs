@@ -7,3 +7,15 @@ fn fmt() {
         "#,
     );
 }
+
+#[test]
+fn return_in_constructor_does_not_end_script() {
+    let scenario = r#"
+        function MyClass() { return 1; }
+        let inst = new MyClass();
+        let after = "ran";
+        after;
+        "#;
+
+    assert_eq!(&crate::exec(scenario), "\"ran\"");
+}
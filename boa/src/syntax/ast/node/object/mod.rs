@@ -1,8 +1,10 @@
 //! Object node.
 
 use crate::{
+    builtins::function::ThisMode,
     exec::Executable,
     gc::{Finalize, Trace},
+    object::JsObject,
     property::PropertyDescriptor,
     syntax::ast::node::{join_nodes, MethodDefinitionKind, Node, PropertyDefinition, PropertyName},
     BoaProfiler, Context, JsResult, JsValue,
@@ -87,6 +89,139 @@ impl Object {
         }
         write!(f, "{}}}", "    ".repeat(indent))
     }
+
+    /// Defines `property`, one of the properties of the object literal, on `obj`.
+    pub(crate) fn define_property(
+        obj: &JsObject,
+        property: &PropertyDefinition,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        match property {
+            PropertyDefinition::Property(name, value) => {
+                let name = match name {
+                    PropertyName::Literal(name) => name.clone().into(),
+                    PropertyName::Computed(node) => node.run(context)?.to_property_key(context)?,
+                };
+                obj.__define_own_property__(
+                    name,
+                    PropertyDescriptor::builder()
+                        .value(value.run(context)?)
+                        .writable(true)
+                        .enumerable(true)
+                        .configurable(true)
+                        .build(),
+                    context,
+                )?;
+            }
+            PropertyDefinition::MethodDefinition(kind, name, func) => {
+                let name = match name {
+                    PropertyName::Literal(name) => name.clone().into(),
+                    PropertyName::Computed(node) => node.run(context)?.to_property_key(context)?,
+                };
+                match kind {
+                    MethodDefinitionKind::Ordinary => {
+                        obj.__define_own_property__(
+                            name,
+                            PropertyDescriptor::builder()
+                                .value(func.run(context)?)
+                                .writable(true)
+                                .enumerable(true)
+                                .configurable(true)
+                                .build(),
+                            context,
+                        )?;
+                    }
+                    MethodDefinitionKind::Get => {
+                        let set = obj
+                            .__get_own_property__(&name, context)?
+                            .as_ref()
+                            .and_then(|a| a.set())
+                            .cloned();
+                        obj.__define_own_property__(
+                            name,
+                            PropertyDescriptor::builder()
+                                .maybe_get(func.run(context)?.as_object().cloned())
+                                .maybe_set(set)
+                                .enumerable(true)
+                                .configurable(true)
+                                .build(),
+                            context,
+                        )?;
+                    }
+                    MethodDefinitionKind::Set => {
+                        let get = obj
+                            .__get_own_property__(&name, context)?
+                            .as_ref()
+                            .and_then(|a| a.get())
+                            .cloned();
+                        obj.__define_own_property__(
+                            name,
+                            PropertyDescriptor::builder()
+                                .maybe_get(get)
+                                .maybe_set(func.run(context)?.as_object().cloned())
+                                .enumerable(true)
+                                .configurable(true)
+                                .build(),
+                            context,
+                        )?;
+                    }
+                    &MethodDefinitionKind::Generator => {
+                        let function = context.create_function(
+                            func.name().unwrap_or(""),
+                            func.parameters().to_vec(),
+                            func.body().clone(),
+                            false,
+                            true,
+                            ThisMode::Global,
+                            func.source(),
+                        )?;
+                        obj.__define_own_property__(
+                            name,
+                            PropertyDescriptor::builder()
+                                .value(function)
+                                .writable(true)
+                                .enumerable(true)
+                                .configurable(true)
+                                .build(),
+                            context,
+                        )?;
+                    }
+                    &MethodDefinitionKind::AsyncGenerator => {
+                        // TODO: Implement async generator method definition execution.
+                        obj.__define_own_property__(
+                            name,
+                            PropertyDescriptor::builder()
+                                .value(JsValue::undefined())
+                                .writable(true)
+                                .enumerable(true)
+                                .configurable(true)
+                                .build(),
+                            context,
+                        )?;
+                    }
+                    &MethodDefinitionKind::Async => {
+                        // The tree-walker can't suspend a running function, async functions
+                        // are only run by the VM.
+                        return Err(context
+                            .construct_syntax_error("async functions require the `vm` feature"));
+                    }
+                }
+            }
+            // [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-propertydefinitionevaluation
+            PropertyDefinition::SpreadObject(node) => {
+                let val = node.run(context)?;
+
+                if val.is_null_or_undefined() {
+                    return Ok(());
+                }
+
+                obj.copy_data_properties::<String>(&val, vec![], context)?;
+            }
+            _ => {} // unimplemented!("{:?} type of property", i),
+        }
+
+        Ok(())
+    }
 }
 
 impl Executable for Object {
@@ -96,131 +231,7 @@ impl Executable for Object {
 
         // TODO: Implement the rest of the property types.
         for property in self.properties().iter() {
-            match property {
-                PropertyDefinition::Property(name, value) => {
-                    let name = match name {
-                        PropertyName::Literal(name) => name.clone().into(),
-                        PropertyName::Computed(node) => {
-                            node.run(context)?.to_property_key(context)?
-                        }
-                    };
-                    obj.__define_own_property__(
-                        name,
-                        PropertyDescriptor::builder()
-                            .value(value.run(context)?)
-                            .writable(true)
-                            .enumerable(true)
-                            .configurable(true)
-                            .build(),
-                        context,
-                    )?;
-                }
-                PropertyDefinition::MethodDefinition(kind, name, func) => {
-                    let name = match name {
-                        PropertyName::Literal(name) => name.clone().into(),
-                        PropertyName::Computed(node) => {
-                            node.run(context)?.to_property_key(context)?
-                        }
-                    };
-                    match kind {
-                        MethodDefinitionKind::Ordinary => {
-                            obj.__define_own_property__(
-                                name,
-                                PropertyDescriptor::builder()
-                                    .value(func.run(context)?)
-                                    .writable(true)
-                                    .enumerable(true)
-                                    .configurable(true)
-                                    .build(),
-                                context,
-                            )?;
-                        }
-                        MethodDefinitionKind::Get => {
-                            let set = obj
-                                .__get_own_property__(&name, context)?
-                                .as_ref()
-                                .and_then(|a| a.set())
-                                .cloned();
-                            obj.__define_own_property__(
-                                name,
-                                PropertyDescriptor::builder()
-                                    .maybe_get(func.run(context)?.as_object().cloned())
-                                    .maybe_set(set)
-                                    .enumerable(true)
-                                    .configurable(true)
-                                    .build(),
-                                context,
-                            )?;
-                        }
-                        MethodDefinitionKind::Set => {
-                            let get = obj
-                                .__get_own_property__(&name, context)?
-                                .as_ref()
-                                .and_then(|a| a.get())
-                                .cloned();
-                            obj.__define_own_property__(
-                                name,
-                                PropertyDescriptor::builder()
-                                    .maybe_get(get)
-                                    .maybe_set(func.run(context)?.as_object().cloned())
-                                    .enumerable(true)
-                                    .configurable(true)
-                                    .build(),
-                                context,
-                            )?;
-                        }
-                        &MethodDefinitionKind::Generator => {
-                            // TODO: Implement generator method definition execution.
-                            obj.__define_own_property__(
-                                name,
-                                PropertyDescriptor::builder()
-                                    .value(JsValue::undefined())
-                                    .writable(true)
-                                    .enumerable(true)
-                                    .configurable(true)
-                                    .build(),
-                                context,
-                            )?;
-                        }
-                        &MethodDefinitionKind::AsyncGenerator => {
-                            // TODO: Implement async generator method definition execution.
-                            obj.__define_own_property__(
-                                name,
-                                PropertyDescriptor::builder()
-                                    .value(JsValue::undefined())
-                                    .writable(true)
-                                    .enumerable(true)
-                                    .configurable(true)
-                                    .build(),
-                                context,
-                            )?;
-                        }
-                        &MethodDefinitionKind::Async => {
-                            obj.__define_own_property__(
-                                name,
-                                PropertyDescriptor::builder()
-                                    .value(JsValue::undefined())
-                                    .writable(true)
-                                    .enumerable(true)
-                                    .configurable(true)
-                                    .build(),
-                                context,
-                            )?;
-                        }
-                    }
-                }
-                // [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-propertydefinitionevaluation
-                PropertyDefinition::SpreadObject(node) => {
-                    let val = node.run(context)?;
-
-                    if val.is_null_or_undefined() {
-                        continue;
-                    }
-
-                    obj.copy_data_properties::<String>(&val, vec![], context)?;
-                }
-                _ => {} // unimplemented!("{:?} type of property", i),
-            }
+            Self::define_property(&obj, property, context)?;
         }
 
         Ok(obj.into())
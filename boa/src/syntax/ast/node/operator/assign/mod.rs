@@ -50,31 +50,27 @@ impl Assign {
     pub fn rhs(&self) -> &Node {
         &self.rhs
     }
-}
 
-impl Executable for Assign {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        let _timer = BoaProfiler::global().start_event("Assign", "exec");
-        let val = self.rhs().run(context)?;
+    /// Assigns `val`, the value of the right hand side, to the left hand side.
+    pub(crate) fn assign(&self, val: JsValue, context: &mut Context) -> JsResult<()> {
         match self.lhs() {
             Node::Identifier(ref name) => {
                 if context.has_binding(name.as_ref())? {
                     // Binding already exists
-                    context.set_mutable_binding(name.as_ref(), val.clone(), context.strict())?;
+                    context.set_mutable_binding(name.as_ref(), val, context.strict())?;
                 } else {
                     context.create_mutable_binding(name.as_ref(), true, VariableScope::Function)?;
-                    context.initialize_binding(name.as_ref(), val.clone())?;
+                    context.initialize_binding(name.as_ref(), val)?;
                 }
             }
             Node::GetConstField(ref get_const_field) => {
                 let value = get_const_field.obj().run(context)?;
                 let obj = value.to_object(context)?;
-                let succeeded =
-                    obj.__set__(get_const_field.field().into(), val.clone(), value, context)?;
+                let succeeded = obj.__set__(get_const_field.field().into(), val, value, context)?;
                 if !succeeded && context.strict() {
-                    return context.throw_type_error(
+                    return Err(context.construct_type_error(
                         "Assignment to read-only properties is not allowed in strict mode",
-                    );
+                    ));
                 }
             }
             Node::GetField(ref get_field) => {
@@ -82,15 +78,24 @@ impl Executable for Assign {
                 let obj = value.to_object(context)?;
                 let field = get_field.field().run(context)?;
                 let key = field.to_property_key(context)?;
-                let succeeded = obj.__set__(key, val.clone(), value, context)?;
+                let succeeded = obj.__set__(key, val, value, context)?;
                 if !succeeded && context.strict() {
-                    return context.throw_type_error(
+                    return Err(context.construct_type_error(
                         "Assignment to read-only properties is not allowed in strict mode",
-                    );
+                    ));
                 }
             }
             _ => (),
         }
+        Ok(())
+    }
+}
+
+impl Executable for Assign {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("Assign", "exec");
+        let val = self.rhs().run(context)?;
+        self.assign(val.clone(), context)?;
         Ok(val)
     }
 }
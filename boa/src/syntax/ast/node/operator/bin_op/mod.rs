@@ -58,39 +58,96 @@ impl BinOp {
 
     /// Runs the assignment operators.
     fn run_assign(op: AssignOp, x: JsValue, y: &Node, context: &mut Context) -> JsResult<JsValue> {
+        if Self::short_circuits(op, &x) {
+            return Ok(x);
+        }
+        let y = y.run(context)?;
+        Self::apply_assign(op, x, y, context)
+    }
+
+    /// Returns `true` if the logical assignment operator `op` keeps the value `x` of its left
+    /// hand side, without evaluating its right hand side.
+    pub(crate) fn short_circuits(op: AssignOp, x: &JsValue) -> bool {
         match op {
-            AssignOp::Add => x.add(&y.run(context)?, context),
-            AssignOp::Sub => x.sub(&y.run(context)?, context),
-            AssignOp::Mul => x.mul(&y.run(context)?, context),
-            AssignOp::Exp => x.pow(&y.run(context)?, context),
-            AssignOp::Div => x.div(&y.run(context)?, context),
-            AssignOp::Mod => x.rem(&y.run(context)?, context),
-            AssignOp::And => x.bitand(&y.run(context)?, context),
-            AssignOp::Or => x.bitor(&y.run(context)?, context),
-            AssignOp::Xor => x.bitxor(&y.run(context)?, context),
-            AssignOp::Shl => x.shl(&y.run(context)?, context),
-            AssignOp::Shr => x.shr(&y.run(context)?, context),
-            AssignOp::Ushr => x.ushr(&y.run(context)?, context),
-            AssignOp::BoolAnd => {
-                if x.to_boolean() {
-                    Ok(y.run(context)?)
-                } else {
-                    Ok(x)
-                }
-            }
-            AssignOp::BoolOr => {
-                if x.to_boolean() {
-                    Ok(x)
-                } else {
-                    Ok(y.run(context)?)
-                }
-            }
-            AssignOp::Coalesce => {
-                if x.is_null_or_undefined() {
-                    Ok(y.run(context)?)
-                } else {
-                    Ok(x)
+            AssignOp::BoolAnd => !x.to_boolean(),
+            AssignOp::BoolOr => x.to_boolean(),
+            AssignOp::Coalesce => !x.is_null_or_undefined(),
+            _ => false,
+        }
+    }
+
+    /// Computes the value assigned by the assignment operator `op`, from the value `x` of its
+    /// left hand side and the value `y` of its right hand side.
+    pub(crate) fn apply_assign(
+        op: AssignOp,
+        x: JsValue,
+        y: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        match op {
+            AssignOp::Add => x.add(&y, context),
+            AssignOp::Sub => x.sub(&y, context),
+            AssignOp::Mul => x.mul(&y, context),
+            AssignOp::Exp => x.pow(&y, context),
+            AssignOp::Div => x.div(&y, context),
+            AssignOp::Mod => x.rem(&y, context),
+            AssignOp::And => x.bitand(&y, context),
+            AssignOp::Or => x.bitor(&y, context),
+            AssignOp::Xor => x.bitxor(&y, context),
+            AssignOp::Shl => x.shl(&y, context),
+            AssignOp::Shr => x.shr(&y, context),
+            AssignOp::Ushr => x.ushr(&y, context),
+            AssignOp::BoolAnd | AssignOp::BoolOr | AssignOp::Coalesce => Ok(y),
+        }
+    }
+
+    /// Applies the numeric, bitwise or relational operator `op` to the values of its operands.
+    pub(crate) fn apply(
+        op: op::BinOp,
+        x: &JsValue,
+        y: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        match op {
+            op::BinOp::Num(op) => match op {
+                NumOp::Add => x.add(y, context),
+                NumOp::Sub => x.sub(y, context),
+                NumOp::Mul => x.mul(y, context),
+                NumOp::Exp => x.pow(y, context),
+                NumOp::Div => x.div(y, context),
+                NumOp::Mod => x.rem(y, context),
+            },
+            op::BinOp::Bit(op) => match op {
+                BitOp::And => x.bitand(y, context),
+                BitOp::Or => x.bitor(y, context),
+                BitOp::Xor => x.bitxor(y, context),
+                BitOp::Shl => x.shl(y, context),
+                BitOp::Shr => x.shr(y, context),
+                BitOp::UShr => x.ushr(y, context),
+            },
+            op::BinOp::Comp(op) => Ok(JsValue::new(match op {
+                CompOp::Equal => x.equals(y, context)?,
+                CompOp::NotEqual => !x.equals(y, context)?,
+                CompOp::StrictEqual => x.strict_equals(y),
+                CompOp::StrictNotEqual => !x.strict_equals(y),
+                CompOp::GreaterThan => x.gt(y, context)?,
+                CompOp::GreaterThanOrEqual => x.ge(y, context)?,
+                CompOp::LessThan => x.lt(y, context)?,
+                CompOp::LessThanOrEqual => x.le(y, context)?,
+                CompOp::In => {
+                    if !y.is_object() {
+                        return context.throw_type_error(format!(
+                            "right-hand side of 'in' should be an object, got {}",
+                            y.type_of()
+                        ));
+                    }
+                    let key = x.to_property_key(context)?;
+                    context.has_property(y, &key)?
                 }
+                CompOp::InstanceOf => x.instance_of(y, context)?,
+            })),
+            op::BinOp::Log(_) | op::BinOp::Assign(_) | op::BinOp::Comma => {
+                unreachable!("{} is not applied to the values of both operands", op)
             }
         }
     }
@@ -99,54 +156,10 @@ impl BinOp {
 impl Executable for BinOp {
     fn run(&self, context: &mut Context) -> JsResult<JsValue> {
         match self.op() {
-            op::BinOp::Num(op) => {
+            op::BinOp::Num(_) | op::BinOp::Bit(_) | op::BinOp::Comp(_) => {
                 let x = self.lhs().run(context)?;
                 let y = self.rhs().run(context)?;
-                match op {
-                    NumOp::Add => x.add(&y, context),
-                    NumOp::Sub => x.sub(&y, context),
-                    NumOp::Mul => x.mul(&y, context),
-                    NumOp::Exp => x.pow(&y, context),
-                    NumOp::Div => x.div(&y, context),
-                    NumOp::Mod => x.rem(&y, context),
-                }
-            }
-            op::BinOp::Bit(op) => {
-                let x = self.lhs().run(context)?;
-                let y = self.rhs().run(context)?;
-                match op {
-                    BitOp::And => x.bitand(&y, context),
-                    BitOp::Or => x.bitor(&y, context),
-                    BitOp::Xor => x.bitxor(&y, context),
-                    BitOp::Shl => x.shl(&y, context),
-                    BitOp::Shr => x.shr(&y, context),
-                    BitOp::UShr => x.ushr(&y, context),
-                }
-            }
-            op::BinOp::Comp(op) => {
-                let x = self.lhs().run(context)?;
-                let y = self.rhs().run(context)?;
-                Ok(JsValue::new(match op {
-                    CompOp::Equal => x.equals(&y, context)?,
-                    CompOp::NotEqual => !x.equals(&y, context)?,
-                    CompOp::StrictEqual => x.strict_equals(&y),
-                    CompOp::StrictNotEqual => !x.strict_equals(&y),
-                    CompOp::GreaterThan => x.gt(&y, context)?,
-                    CompOp::GreaterThanOrEqual => x.ge(&y, context)?,
-                    CompOp::LessThan => x.lt(&y, context)?,
-                    CompOp::LessThanOrEqual => x.le(&y, context)?,
-                    CompOp::In => {
-                        if !y.is_object() {
-                            return context.throw_type_error(format!(
-                                "right-hand side of 'in' should be an object, got {}",
-                                y.type_of()
-                            ));
-                        }
-                        let key = x.to_property_key(context)?;
-                        context.has_property(&y, &key)?
-                    }
-                    CompOp::InstanceOf => x.instance_of(&y, context)?,
-                }))
+                Self::apply(self.op(), &x, &y, context)
             }
             op::BinOp::Log(op) => Ok(match op {
                 LogOp::And => {
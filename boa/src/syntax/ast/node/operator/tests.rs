@@ -114,6 +114,44 @@ fn logical_assignment() {
     assert_eq!(&exec(scenario), "20");
 }
 
+#[test]
+fn nullish_coalescing() {
+    assert_eq!(&exec("0 ?? 1"), "0");
+    assert_eq!(&exec("null ?? 1"), "1");
+    assert_eq!(&exec("undefined ?? 1"), "1");
+    assert_eq!(
+        &exec("[false ?? 1, '' ?? 1, NaN ?? 1].join()"),
+        "\"false,,NaN\""
+    );
+    assert_eq!(&exec("null ?? undefined ?? 3"), "3");
+
+    // The right operand is only evaluated when the left one is nullish.
+    let scenario = r#"
+        let calls = 0;
+        function f() { calls++; return 2; }
+        1 ?? f();
+        null ?? f();
+        calls;
+        "#;
+
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn nullish_coalescing_mixed_with_logical_operators() {
+    let scenario = r#"
+        try {
+          eval("a || b ?? c");
+        } catch (err) {
+          err instanceof SyntaxError
+        }
+        "#;
+
+    assert_eq!(&exec(scenario), "true");
+    assert_eq!(&exec("(null || undefined) ?? 4"), "4");
+    assert_eq!(&exec("null ?? (0 && 1)"), "0");
+}
+
 #[test]
 fn fmt() {
     super::super::test_formatting(
@@ -47,13 +47,46 @@ impl UnaryOp {
     pub fn target(&self) -> &Node {
         self.target.as_ref()
     }
+
+    /// Applies the operator `op`, which only needs the value of its target, to `value`.
+    pub(crate) fn apply(
+        op: op::UnaryOp,
+        value: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Ok(match op {
+            op::UnaryOp::Minus => value.neg(context)?,
+            op::UnaryOp::Plus => JsValue::new(value.to_number(context)?),
+            op::UnaryOp::Not => value.not(context)?.into(),
+            op::UnaryOp::Tilde => match value.to_numeric(context)? {
+                Numeric::Number(x) => JsValue::new(Number::not(x)),
+                Numeric::BigInt(x) => JsValue::new(JsBigInt::not(&x)),
+            },
+            op::UnaryOp::Void => JsValue::undefined(),
+            op::UnaryOp::TypeOf => JsValue::new(value.type_of()),
+            op::UnaryOp::IncrementPost
+            | op::UnaryOp::IncrementPre
+            | op::UnaryOp::DecrementPost
+            | op::UnaryOp::DecrementPre
+            | op::UnaryOp::Delete => {
+                unreachable!("{} needs the reference of its target", op)
+            }
+        })
+    }
 }
 
 impl Executable for UnaryOp {
     fn run(&self, context: &mut Context) -> JsResult<JsValue> {
         Ok(match self.op() {
-            op::UnaryOp::Minus => self.target().run(context)?.neg(context)?,
-            op::UnaryOp::Plus => JsValue::new(self.target().run(context)?.to_number(context)?),
+            op::UnaryOp::Minus
+            | op::UnaryOp::Plus
+            | op::UnaryOp::Not
+            | op::UnaryOp::Tilde
+            | op::UnaryOp::Void
+            | op::UnaryOp::TypeOf => {
+                let value = self.target().run(context)?;
+                Self::apply(self.op(), value, context)?
+            }
             op::UnaryOp::IncrementPost => {
                 let x = self.target().run(context)?;
                 let ret = x.clone();
@@ -65,32 +98,28 @@ impl Executable for UnaryOp {
                 ret
             }
             op::UnaryOp::IncrementPre => {
-                let result = self.target().run(context)?.to_number(context)? + 1.0;
-                context.set_value(self.target(), result.into())?
+                let result = match self.target().run(context)?.to_numeric(context)? {
+                    Numeric::Number(n) => (n + 1.0).into(),
+                    Numeric::BigInt(b) => (JsBigInt::add(&b, &JsBigInt::from(1))).into(),
+                };
+                context.set_value(self.target(), result)?
             }
             op::UnaryOp::DecrementPost => {
                 let x = self.target().run(context)?;
                 let ret = x.clone();
-                let result = x.to_number(context)? - 1.0;
-                context.set_value(self.target(), result.into())?;
+                let result = match x.to_numeric(context)? {
+                    Numeric::Number(n) => (n - 1.0).into(),
+                    Numeric::BigInt(b) => (JsBigInt::sub(&b, &JsBigInt::from(1))).into(),
+                };
+                context.set_value(self.target(), result)?;
                 ret
             }
             op::UnaryOp::DecrementPre => {
-                let result = self.target().run(context)?.to_number(context)? - 1.0;
-                context.set_value(self.target(), result.into())?
-            }
-            op::UnaryOp::Not => self.target().run(context)?.not(context)?.into(),
-            op::UnaryOp::Tilde => {
-                let expr = self.target().run(context)?;
-                let old_v = expr.to_numeric(context)?;
-                match old_v {
-                    Numeric::Number(x) => JsValue::new(Number::not(x)),
-                    Numeric::BigInt(x) => JsValue::new(JsBigInt::not(&x)),
-                }
-            }
-            op::UnaryOp::Void => {
-                self.target().run(context)?;
-                JsValue::undefined()
+                let result = match self.target().run(context)?.to_numeric(context)? {
+                    Numeric::Number(n) => (n - 1.0).into(),
+                    Numeric::BigInt(b) => (JsBigInt::sub(&b, &JsBigInt::from(1))).into(),
+                };
+                context.set_value(self.target(), result)?
             }
             op::UnaryOp::Delete => match *self.target() {
                 Node::GetConstField(ref get_const_field) => {
@@ -118,11 +147,18 @@ impl Executable for UnaryOp {
                         JsValue::new(delete_status)
                     }
                 }
+                Node::Optional(ref optional) => {
+                    let delete_status = optional.delete(context)?;
+                    if !delete_status && context.strict() {
+                        return context.throw_type_error("Cannot delete property");
+                    } else {
+                        JsValue::new(delete_status)
+                    }
+                }
                 // TODO: implement delete on references.
                 Node::Identifier(_) => JsValue::new(false),
                 _ => JsValue::new(true),
             },
-            op::UnaryOp::TypeOf => JsValue::new(self.target().run(context)?.type_of()),
         })
     }
 }
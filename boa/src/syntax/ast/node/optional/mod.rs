@@ -0,0 +1,241 @@
+//! Optional chaining nodes
+
+use crate::{
+    exec::{Executable, InterpreterState},
+    gc::{Finalize, Trace},
+    property::PropertyKey,
+    syntax::ast::node::{join_nodes, Call, Node},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// An optional chain accesses properties or calls functions of a value, evaluating to
+/// `undefined` as soon as a link starting with `?.` finds a `null` or `undefined` value.
+///
+/// The short-circuit skips the rest of the chain, so `a?.b.c(d)` evaluates neither `c` nor
+/// `d` when `a` is nullish.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Optional {
+    target: Box<Node>,
+    chain: Box<[OptionalOperation]>,
+}
+
+impl Optional {
+    /// Creates a new `Optional` AST node.
+    ///
+    /// The first operation of the chain is expected to start with `?.`.
+    pub fn new<T, C>(target: T, chain: C) -> Self
+    where
+        T: Into<Node>,
+        C: Into<Box<[OptionalOperation]>>,
+    {
+        Self {
+            target: Box::new(target.into()),
+            chain: chain.into(),
+        }
+    }
+
+    /// Gets the expression the chain starts from.
+    pub fn target(&self) -> &Node {
+        &self.target
+    }
+
+    /// Gets the operations applied to the target, in order.
+    pub fn chain(&self) -> &[OptionalOperation] {
+        &self.chain
+    }
+
+    /// Evaluates the target and every operation of the chain except the last one.
+    ///
+    /// Returns `None` if the chain short-circuits before its last operation is applied, or the
+    /// value the last operation applies to, along with the `this` value it would be called with.
+    fn evaluate_until_last(&self, context: &mut Context) -> JsResult<Option<(JsValue, JsValue)>> {
+        let (last, init) = self
+            .chain
+            .split_last()
+            .expect("an optional chain has at least one operation");
+
+        let (mut this, mut value) = Call::evaluate_callee(self.target(), context)?;
+        for operation in init {
+            if operation.shorted() && value.is_null_or_undefined() {
+                return Ok(None);
+            }
+            let (next_this, next_value) = operation.kind().evaluate(this, value, context)?;
+            this = next_this;
+            value = next_value;
+        }
+
+        if last.shorted() && value.is_null_or_undefined() {
+            return Ok(None);
+        }
+        Ok(Some((this, value)))
+    }
+
+    /// Gets the last operation of the chain.
+    fn last(&self) -> &OptionalOperation {
+        self.chain
+            .last()
+            .expect("an optional chain has at least one operation")
+    }
+
+    /// Evaluates `delete` applied to the chain, returning whether the property could be deleted.
+    ///
+    /// A chain that short-circuits deletes nothing and evaluates to `true`.
+    pub(crate) fn delete(&self, context: &mut Context) -> JsResult<bool> {
+        let (this, value) = match self.evaluate_until_last(context)? {
+            Some(base) => base,
+            None => return Ok(true),
+        };
+        match self.last().kind() {
+            OptionalOperationKind::SimplePropertyAccess { field } => value
+                .to_object(context)?
+                .__delete__(&PropertyKey::from(&**field), context),
+            OptionalOperationKind::PropertyAccess { field } => {
+                let field = field.run(context)?;
+                let obj = value.to_object(context)?;
+                obj.__delete__(&field.to_property_key(context)?, context)
+            }
+            // A call isn't a reference, so there is nothing to delete.
+            kind @ OptionalOperationKind::Call { .. } => {
+                kind.evaluate(this, value, context)?;
+                Ok(true)
+            }
+        }
+    }
+}
+
+impl Executable for Optional {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("Optional", "exec");
+        match self.evaluate_until_last(context)? {
+            Some((this, value)) => Ok(self.last().kind().evaluate(this, value, context)?.1),
+            None => Ok(JsValue::undefined()),
+        }
+    }
+}
+
+impl fmt::Display for Optional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.target, f)?;
+        for operation in self.chain.iter() {
+            fmt::Display::fmt(operation, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Optional> for Node {
+    fn from(optional: Optional) -> Self {
+        Self::Optional(optional)
+    }
+}
+
+/// A property access or call in an optional chain.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct OptionalOperation {
+    kind: OptionalOperationKind,
+    shorted: bool,
+}
+
+impl OptionalOperation {
+    /// Creates a new `OptionalOperation`.
+    ///
+    /// `shorted` is `true` for an operation written with `?.`, which short-circuits the chain
+    /// when the value it applies to is `null` or `undefined`.
+    pub fn new(kind: OptionalOperationKind, shorted: bool) -> Self {
+        Self { kind, shorted }
+    }
+
+    /// Gets the kind of the operation.
+    pub fn kind(&self) -> &OptionalOperationKind {
+        &self.kind
+    }
+
+    /// Returns `true` if the operation is written with `?.`.
+    pub fn shorted(&self) -> bool {
+        self.shorted
+    }
+}
+
+impl fmt::Display for OptionalOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.shorted {
+            f.write_str("?.")?;
+        }
+        match self.kind {
+            OptionalOperationKind::SimplePropertyAccess { ref field } if self.shorted => {
+                f.write_str(field)
+            }
+            OptionalOperationKind::SimplePropertyAccess { ref field } => write!(f, ".{}", field),
+            OptionalOperationKind::PropertyAccess { ref field } => write!(f, "[{}]", field),
+            OptionalOperationKind::Call { ref args } => {
+                f.write_str("(")?;
+                join_nodes(f, args)?;
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// The kinds of operations an optional chain is made of.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum OptionalOperationKind {
+    /// A property access with a name, as in `a?.b`.
+    SimplePropertyAccess { field: Box<str> },
+    /// A property access with a computed key, as in `a?.[b]`.
+    PropertyAccess { field: Box<Node> },
+    /// A call, as in `a?.()`.
+    Call { args: Box<[Node]> },
+}
+
+impl OptionalOperationKind {
+    /// Applies the operation to `value`, returning the `this` value a call of the result
+    /// would get along with the result itself.
+    fn evaluate(
+        &self,
+        this: JsValue,
+        value: JsValue,
+        context: &mut Context,
+    ) -> JsResult<(JsValue, JsValue)> {
+        match self {
+            Self::SimplePropertyAccess { field } => {
+                let obj = value.to_object(context)?;
+                let value = obj.get(&**field, context)?;
+                Ok((obj.into(), value))
+            }
+            Self::PropertyAccess { field } => {
+                let field = field.run(context)?;
+                let obj = value.to_object(context)?;
+                let value = obj.get(field.to_property_key(context)?, context)?;
+                Ok((obj.into(), value))
+            }
+            Self::Call { args } => {
+                let args = Call::evaluate_args(args, context)?;
+                let result = context.call(&value, &this, &args);
+
+                // unset the early return flag
+                context
+                    .executor()
+                    .set_current_state(InterpreterState::Executing);
+
+                Ok((context.global_object().into(), result?))
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{exec, forward, Context};
+
+#[test]
+fn short_circuits_the_whole_chain() {
+    let scenario = r#"
+        let a = null;
+        let b = { c: undefined };
+        [a?.b, a?.b.c.d, a?.[0].e, a?.(), b.c?.d.e, b?.c].join(',')
+    "#;
+    assert_eq!(&exec(scenario), r#"",,,,,""#);
+}
+
+#[test]
+fn short_circuit_skips_type_error() {
+    let mut context = Context::new();
+    let init = r#"
+        var a = undefined;
+        var b = {};
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "a?.b.c.d()"), "undefined");
+    assert_eq!(forward(&mut context, "b.c?.d.e"), "undefined");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { b.c.d?.e } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { a?.b; (a?.b).c } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn short_circuit_skips_arguments() {
+    let mut context = Context::new();
+    let init = r#"
+        var calls = 0;
+        function count() { calls++; return 'x'; }
+        var a = null;
+        a?.(count());
+        a?.[count()];
+        a?.b.c(count(), count());
+        a?.b[count()];
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "calls"), "0");
+
+    forward(&mut context, "({ x: 1 })?.[count()]");
+    assert_eq!(forward(&mut context, "calls"), "1");
+}
+
+#[test]
+fn call_keeps_this() {
+    let scenario = r#"
+        let o = { m() { return this === o; } };
+        [o.m?.(), o?.m(), o?.["m"]?.()].join(',')
+    "#;
+    assert_eq!(&exec(scenario), r#""true,true,true""#);
+}
+
+#[test]
+fn delete_optional_chain() {
+    let mut context = Context::new();
+    let init = r#"
+        var a = null;
+        var o = { b: { c: 1 } };
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "delete a?.b"), "true");
+    assert_eq!(forward(&mut context, "delete o?.b.c"), "true");
+    assert_eq!(forward(&mut context, "'c' in o.b"), "false");
+    assert_eq!(forward(&mut context, "delete o?.b"), "true");
+    assert_eq!(forward(&mut context, "o.b"), "undefined");
+}
+
+#[test]
+fn question_dot_before_digit_is_conditional() {
+    assert_eq!(&exec("true?.5:1"), "0.5");
+}
+
+#[test]
+fn fmt() {
+    super::super::test_formatting(
+        r#"
+        a?.b;
+        a?.b.c(d)?.[e];
+        a?.[b]?.(c, d);
+        a.b?.();
+        "#,
+    );
+}
@@ -1,7 +1,11 @@
 use crate::{
-    exec::{Executable, InterpreterState},
+    builtins::{eval::Eval, JsArgs},
+    exec::{Executable, InterpreterState, TailCall},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::{
+        node::Node,
+        op::{BinOp, LogOp},
+    },
     Context, JsResult, JsValue,
 };
 use std::fmt;
@@ -58,11 +62,72 @@ impl Return {
             label: label.into(),
         }
     }
+
+    /// Evaluates an expression in tail position of strict mode code.
+    ///
+    /// The call the expression ends with is deferred to the returning function call when
+    /// possible, so it doesn't grow the native stack. Both branches of a conditional, the right
+    /// side of a logical operator and the last operand of a comma are in tail position.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-hascallintailposition
+    fn run_in_tail_position(expr: &Node, context: &mut Context) -> JsResult<JsValue> {
+        match expr {
+            Node::Call(call) => {
+                let (func, this, args) = call.evaluate(context)?;
+
+                // A direct eval runs in the scope of the caller, so it can't outlive it.
+                if call.is_direct_eval(&func, context) {
+                    return Eval::perform_eval(
+                        args.get_or_undefined(0),
+                        context.strict(),
+                        true,
+                        context,
+                    );
+                }
+
+                let tail_call = TailCall::new(func, this, args, context)?;
+                if tail_call.is_deferrable() {
+                    context.executor().set_tail_call(tail_call);
+                    Ok(JsValue::undefined())
+                } else {
+                    tail_call.run(context)
+                }
+            }
+            Node::ConditionalOp(op) => {
+                if op.cond().run(context)?.to_boolean() {
+                    Self::run_in_tail_position(op.if_true(), context)
+                } else {
+                    Self::run_in_tail_position(op.if_false(), context)
+                }
+            }
+            Node::BinOp(op) => {
+                let short_circuits: fn(&JsValue) -> bool = match op.op() {
+                    BinOp::Log(LogOp::And) => |left| !left.to_boolean(),
+                    BinOp::Log(LogOp::Or) => JsValue::to_boolean,
+                    BinOp::Log(LogOp::Coalesce) => |left| !left.is_null_or_undefined(),
+                    BinOp::Comma => |_| false,
+                    _ => return expr.run(context),
+                };
+
+                let left = op.lhs().run(context)?;
+                if short_circuits(&left) {
+                    Ok(left)
+                } else {
+                    Self::run_in_tail_position(op.rhs(), context)
+                }
+            }
+            _ => expr.run(context),
+        }
+    }
 }
 
 impl Executable for Return {
     fn run(&self, context: &mut Context) -> JsResult<JsValue> {
         let result = match self.expr() {
+            Some(v) if context.strict() => Self::run_in_tail_position(v, context),
             Some(v) => v.run(context),
             None => Ok(JsValue::undefined()),
         };
@@ -70,6 +70,16 @@ impl StatementList {
         Ok(())
     }
 
+    /// Returns `true` if any statement of the list references `arguments`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-containsarguments
+    pub(crate) fn contains_arguments(&self) -> bool {
+        self.items.iter().any(Node::contains_arguments)
+    }
+
     pub fn lexically_declared_names(&self) -> HashSet<&str> {
         let mut set = HashSet::new();
         for stmt in self.items() {
@@ -29,6 +29,11 @@ impl TemplateLit {
     pub fn new(elements: Vec<TemplateElement>) -> Self {
         TemplateLit { elements }
     }
+
+    /// Gets the elements of the template literal.
+    pub(crate) fn elements(&self) -> &[TemplateElement] {
+        &self.elements
+    }
 }
 
 impl Executable for TemplateLit {
@@ -87,6 +92,16 @@ impl TaggedTemplate {
             exprs,
         }
     }
+
+    /// Gets the tag of the template.
+    pub(crate) fn tag(&self) -> &Node {
+        &self.tag
+    }
+
+    /// Gets the substituted expressions of the template.
+    pub(crate) fn exprs(&self) -> &[Node] {
+        &self.exprs
+    }
 }
 
 impl Executable for TaggedTemplate {
@@ -3,7 +3,7 @@ use crate::{
         declarative_environment_record::DeclarativeEnvironmentRecord,
         lexical_environment::VariableScope,
     },
-    exec::Executable,
+    exec::{Executable, TailCall},
     gc::{Finalize, Trace},
     syntax::ast::node::{Block, Declaration, Node},
     BoaProfiler, Context, JsResult, JsValue,
@@ -97,51 +97,35 @@ impl Try {
 impl Executable for Try {
     fn run(&self, context: &mut Context) -> JsResult<JsValue> {
         let _timer = BoaProfiler::global().start_event("Try", "exec");
-        let res = self.block().run(context).map_or_else(
-            |err| {
-                if let Some(catch) = self.catch() {
-                    let env = context.get_current_environment();
-                    context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
-
-                    if let Some(param) = catch.parameter() {
-                        match param {
-                            Declaration::Identifier { ident, init } => {
-                                debug_assert!(init.is_none());
-
-                                context.create_mutable_binding(
-                                    ident.as_ref(),
-                                    false,
-                                    VariableScope::Block,
-                                )?;
-                                context.initialize_binding(ident.as_ref(), err)?;
-                            }
-                            Declaration::Pattern(pattern) => {
-                                debug_assert!(pattern.init().is_none());
-
-                                for (ident, value) in pattern.run(Some(err), context)? {
-                                    context.create_mutable_binding(
-                                        ident.as_ref(),
-                                        false,
-                                        VariableScope::Block,
-                                    )?;
-                                    context.initialize_binding(ident.as_ref(), value)?;
-                                }
-                            }
-                        }
+        // A `return` in the `try` block is not in tail position, so a call it deferred has to
+        // run here for the `catch` and `finally` blocks to see it.
+        let res = self
+            .block()
+            .run(context)
+            .and_then(|value| TailCall::complete(value, context))
+            .map_or_else(
+                |err| {
+                    if let Some(catch) = self.catch() {
+                        let env = context.get_current_environment();
+                        context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+
+                        catch.bind_parameter(err, context)?;
+
+                        let res = catch
+                            .block()
+                            .run(context)
+                            .and_then(|value| TailCall::complete(value, context));
+
+                        // pop the block env
+                        let _ = context.pop_environment();
+
+                        res
+                    } else {
+                        Err(err)
                     }
-
-                    let res = catch.block().run(context);
-
-                    // pop the block env
-                    let _ = context.pop_environment();
-
-                    res
-                } else {
-                    Err(err)
-                }
-            },
-            Ok,
-        );
+                },
+                Ok,
+            );
 
         if let Some(finally) = self.finally() {
             finally.run(context)?;
@@ -204,6 +188,35 @@ impl Catch {
         f.write_str(" ")?;
         self.block.display(f, indentation)
     }
+
+    /// Binds the parameter of the catch clause, if any, to `err`, the error thrown by the `try`
+    /// block.
+    pub(crate) fn bind_parameter(&self, err: JsValue, context: &mut Context) -> JsResult<()> {
+        if let Some(param) = self.parameter() {
+            match param {
+                Declaration::Identifier { ident, init } => {
+                    debug_assert!(init.is_none());
+
+                    context.create_mutable_binding(ident.as_ref(), false, VariableScope::Block)?;
+                    context.initialize_binding(ident.as_ref(), err)?;
+                }
+                Declaration::Pattern(pattern) => {
+                    debug_assert!(pattern.init().is_none());
+
+                    for (ident, value) in pattern.run(Some(err), context)? {
+                        context.create_mutable_binding(
+                            ident.as_ref(),
+                            false,
+                            VariableScope::Block,
+                        )?;
+                        context.initialize_binding(ident.as_ref(), value)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl fmt::Display for Catch {
@@ -47,9 +47,10 @@ impl Yield {
 }
 
 impl Executable for Yield {
-    fn run(&self, _context: &mut Context) -> JsResult<JsValue> {
-        // TODO: Implement Generator execution
-        Ok(JsValue::undefined())
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        // The body of a generator is evaluated by `GeneratorBody`, which only runs the nodes
+        // that don't contain a `yield` here.
+        context.throw_syntax_error("this use of `yield` requires the `vm` feature")
     }
 }
 
@@ -31,6 +31,22 @@ impl<R> Cursor<R> {
         self.pos = Position::new(next_line, 1);
     }
 
+    /// Gets the number of bytes consumed from the source code so far.
+    #[inline]
+    pub(super) fn offset(&self) -> usize {
+        self.iter.source.len()
+    }
+
+    /// Gets the source code between the byte offsets `start` and `end`, if it has already
+    /// been consumed and is valid UTF-8.
+    #[inline]
+    pub(super) fn source_text(&self, start: usize, end: usize) -> Option<&str> {
+        self.iter
+            .source
+            .get(start..end)
+            .and_then(|bytes| std::str::from_utf8(bytes).ok())
+    }
+
     #[inline]
     pub(super) fn strict_mode(&self) -> bool {
         self.strict_mode
@@ -291,6 +307,8 @@ struct InnerIter<R> {
     num_peeked_bytes: u8,
     peeked_bytes: u32,
     peeked_char: Option<Option<u32>>,
+    /// Every byte consumed so far, used to recover the source text of functions.
+    source: Vec<u8>,
 }
 
 impl<R> InnerIter<R> {
@@ -302,6 +320,7 @@ impl<R> InnerIter<R> {
             num_peeked_bytes: 0,
             peeked_bytes: 0,
             peeked_char: None,
+            source: Vec::new(),
         }
     }
 }
@@ -426,14 +445,16 @@ where
     #[inline]
     fn next_byte(&mut self) -> io::Result<Option<u8>> {
         self.peeked_char = None;
-        if self.num_peeked_bytes > 0 {
+        let byte = if self.num_peeked_bytes > 0 {
             let byte = (self.peeked_bytes & 0xFF) as u8;
             self.num_peeked_bytes -= 1;
             self.peeked_bytes >>= 8;
-            Ok(Some(byte))
+            Some(byte)
         } else {
-            self.iter.next().transpose()
-        }
+            self.iter.next().transpose()?
+        };
+        self.source.extend(byte);
+        Ok(byte)
     }
 
     /// Retrieves the next unchecked char in u32 code point.
@@ -179,12 +179,13 @@ impl<R> Lexer<R> {
     {
         let _timer = BoaProfiler::global().start_event("next()", "Lexing");
 
-        let (start, next_ch) = loop {
+        let (start, start_offset, next_ch) = loop {
             let start = self.cursor.pos();
+            let start_offset = self.cursor.offset();
             if let Some(next_ch) = self.cursor.next_char()? {
                 // Ignore whitespace
                 if !Self::is_whitespace(next_ch) {
-                    break (start, next_ch);
+                    break (start, start_offset, next_ch);
                 }
             } else {
                 return Ok(None);
@@ -203,7 +204,7 @@ impl<R> Lexer<R> {
         };
 
         if let Ok(c) = char::try_from(next_ch) {
-            let token = match c {
+            let mut token = match c {
                 '\r' | '\n' | '\u{2028}' | '\u{2029}' => Ok(Token::new(
                     TokenKind::LineTerminator,
                     Span::new(start, self.cursor.pos()),
@@ -281,6 +282,7 @@ impl<R> Lexer<R> {
                 // Skip comment
                 self.next()
             } else {
+                token.set_source_range(start_offset..self.cursor.offset());
                 Ok(Some(token))
             }
         } else {
@@ -302,6 +304,25 @@ impl<R> Lexer<R> {
     {
         TemplateLiteral.lex(&mut self.cursor, start)
     }
+
+    /// Gets the position of the next character to be lexed.
+    #[inline]
+    pub(crate) fn pos(&self) -> Position {
+        self.cursor.pos()
+    }
+
+    /// Gets the number of bytes consumed from the source code so far.
+    #[inline]
+    pub(crate) fn offset(&self) -> usize {
+        self.cursor.offset()
+    }
+
+    /// Gets the source code between the byte offsets `start` and `end`, if it has already
+    /// been lexed.
+    #[inline]
+    pub(crate) fn source_text(&self, start: usize, end: usize) -> Option<&str> {
+        self.cursor.source_text(start, end)
+    }
 }
 
 /// ECMAScript goal symbols.
@@ -134,6 +134,23 @@ impl<R> Tokenizer<R> for Operator {
                         Ok(Punctuator::Coalesce)
                     )
                 }
+                Some(b'.') => {
+                    // `?.` followed by a digit is a `?` and the start of a decimal literal, as
+                    // in `a?.5:1`.
+                    let next_bytes = cursor.peek_n(2)?;
+                    if ((next_bytes >> 8) as u8).is_ascii_digit() {
+                        Ok(Token::new(
+                            TokenKind::Punctuator(Punctuator::Question),
+                            Span::new(start_pos, cursor.pos()),
+                        ))
+                    } else {
+                        let _ = cursor.next_byte()?.expect(". vanished");
+                        Ok(Token::new(
+                            TokenKind::Punctuator(Punctuator::Optional),
+                            Span::new(start_pos, cursor.pos()),
+                        ))
+                    }
+                }
                 _ => Ok(Token::new(
                     TokenKind::Punctuator(Punctuator::Question),
                     Span::new(start_pos, cursor.pos()),
@@ -142,10 +142,7 @@ impl StringLiteral {
                     buf.push_code_point(ch);
                 }
                 _ => {
-                    return Err(Error::from(io::Error::new(
-                        ErrorKind::UnexpectedEof,
-                        "unterminated string literal",
-                    )));
+                    return Err(Error::syntax("unterminated string literal", start_pos));
                 }
             }
         }
@@ -107,12 +107,9 @@ impl<R> Tokenizer<R> for TemplateLiteral {
 
         let mut buf = Vec::new();
         loop {
-            let ch = cursor.next_char()?.ok_or_else(|| {
-                Error::from(io::Error::new(
-                    ErrorKind::UnexpectedEof,
-                    "unterminated template literal",
-                ))
-            })?;
+            let ch = cursor
+                .next_char()?
+                .ok_or_else(|| Error::syntax("unterminated template literal", start_pos))?;
 
             match ch {
                 0x0060 /* ` */ => {
@@ -12,7 +12,10 @@ use crate::{
     syntax::lexer::template::TemplateString,
     JsBigInt,
 };
-use std::fmt::{self, Debug, Display, Formatter};
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    ops::Range,
+};
 
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
@@ -30,13 +33,19 @@ pub struct Token {
     kind: TokenKind,
     /// The token position in the original source code.
     span: Span,
+    /// The byte offsets of the token in the original source code.
+    source_range: Range<usize>,
 }
 
 impl Token {
     /// Create a new detailed token from the token data, line number and column number
     #[inline]
     pub fn new(kind: TokenKind, span: Span) -> Self {
-        Self { kind, span }
+        Self {
+            kind,
+            span,
+            source_range: 0..0,
+        }
     }
 
     /// Gets the kind of the token.
@@ -50,6 +59,18 @@ impl Token {
     pub fn span(&self) -> Span {
         self.span
     }
+
+    /// Gets the byte offsets of the token in the original source code.
+    #[inline]
+    pub fn source_range(&self) -> Range<usize> {
+        self.source_range.clone()
+    }
+
+    /// Sets the byte offsets of the token in the original source code.
+    #[inline]
+    pub(crate) fn set_source_range(&mut self, source_range: Range<usize>) {
+        self.source_range = source_range;
+    }
 }
 
 impl Display for Token {
@@ -81,13 +81,34 @@ where
     pub(super) fn lex_regex(&mut self, start: Position) -> Result<Token, ParseError> {
         let _timer = BoaProfiler::global().start_event("cursor::lex_regex()", "Parsing");
         self.set_goal(InputElement::RegExp);
-        self.lexer.lex_slash_token(start).map_err(|e| e.into())
+        // The starting '/' is a single byte.
+        let start_offset = self.lexer.offset() - 1;
+        let mut token = self.lexer.lex_slash_token(start)?;
+        token.set_source_range(start_offset..self.lexer.offset());
+        Ok(token)
     }
 
     /// Lexes the next tokens as template middle or template tail assuming that the starting
     /// '}' has already been consumed.
     pub(super) fn lex_template(&mut self, start: Position) -> Result<Token, ParseError> {
-        self.lexer.lex_template(start).map_err(ParseError::from)
+        // The starting '}' is a single byte.
+        let start_offset = self.lexer.offset() - 1;
+        let mut token = self.lexer.lex_template(start)?;
+        token.set_source_range(start_offset..self.lexer.offset());
+        Ok(token)
+    }
+
+    /// Gets the source code between the byte offsets `start` and `end`, if it has already
+    /// been lexed.
+    #[inline]
+    pub(super) fn source_text(&self, start: usize, end: usize) -> Option<&str> {
+        self.lexer.source_text(start, end)
+    }
+
+    /// Gets the position the lexer has read up to, which is past any peeked tokens.
+    #[inline]
+    pub(super) fn pos(&self) -> Position {
+        self.lexer.pos()
     }
 
     #[inline]
@@ -22,6 +22,8 @@ pub(super) enum SemicolonResult<'s> {
 #[derive(Debug)]
 pub(super) struct Cursor<R> {
     buffered_lexer: BufferedLexer<R>,
+    /// The byte offset of the end of the last token returned by the cursor.
+    last_token_end: usize,
 }
 
 impl<R> Cursor<R>
@@ -33,6 +35,7 @@ where
     pub(super) fn new(reader: R) -> Self {
         Self {
             buffered_lexer: Lexer::new(reader).into(),
+            last_token_end: 0,
         }
     }
 
@@ -43,17 +46,42 @@ where
 
     #[inline]
     pub(super) fn lex_regex(&mut self, start: Position) -> Result<Token, ParseError> {
-        self.buffered_lexer.lex_regex(start)
+        let token = self.buffered_lexer.lex_regex(start)?;
+        self.last_token_end = token.source_range().end;
+        Ok(token)
     }
 
     #[inline]
     pub(super) fn lex_template(&mut self, start: Position) -> Result<Token, ParseError> {
-        self.buffered_lexer.lex_template(start)
+        let token = self.buffered_lexer.lex_template(start)?;
+        self.last_token_end = token.source_range().end;
+        Ok(token)
     }
 
     #[inline]
     pub(super) fn next(&mut self) -> Result<Option<Token>, ParseError> {
-        self.buffered_lexer.next(true)
+        let token = self.buffered_lexer.next(true)?;
+        if let Some(ref token) = token {
+            self.last_token_end = token.source_range().end;
+        }
+        Ok(token)
+    }
+
+    /// Gets the source code from the byte offset `start` up to the end of the last token
+    /// returned by the cursor.
+    #[inline]
+    pub(super) fn source_text_from(&self, start: usize) -> Option<Box<str>> {
+        self.buffered_lexer
+            .source_text(start, self.last_token_end)
+            .map(Into::into)
+    }
+
+    /// Gets the position the lexer has read up to.
+    ///
+    /// Once the cursor has run out of tokens, this is the end of the source code.
+    #[inline]
+    pub(super) fn lexer_pos(&self) -> Position {
+        self.buffered_lexer.pos()
     }
 
     #[inline]
@@ -74,6 +74,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ArrowFunction", "Parsing");
         let next_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = next_token.source_range().start;
 
         let (params, params_start_position) = if let TokenKind::Punctuator(Punctuator::OpenParen) =
             &next_token.kind()
@@ -108,7 +109,7 @@ where
         cursor.peek_expect_no_lineterminator(0, "arrow function")?;
 
         cursor.expect(TokenKind::Punctuator(Punctuator::Arrow), "arrow function")?;
-        let body = ConciseBody::new(self.allow_in).parse(cursor)?;
+        let body = ConciseBody::new(self.allow_in, false).parse(cursor)?;
 
         // Early Error: ArrowFormalParameters are UniqueFormalParameters.
         if params.has_duplicates {
@@ -147,24 +148,32 @@ where
             }
         }
 
-        Ok(ArrowFunctionDecl::new(params.parameters, body))
+        Ok(ArrowFunctionDecl::new(params.parameters, body)
+            .with_source(cursor.source_text_from(start)))
     }
 }
 
 /// <https://tc39.es/ecma262/#prod-ConciseBody>
+///
+/// With `allow_await` set, this parses an [`AsyncConciseBody`][spec] instead.
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncConciseBody
 #[derive(Debug, Clone, Copy)]
-struct ConciseBody {
+pub(super) struct ConciseBody {
     allow_in: AllowIn,
+    allow_await: AllowAwait,
 }
 
 impl ConciseBody {
     /// Creates a new `ConcideBody` parser.
-    fn new<I>(allow_in: I) -> Self
+    pub(super) fn new<I, A>(allow_in: I, allow_await: A) -> Self
     where
         I: Into<AllowIn>,
+        A: Into<AllowAwait>,
     {
         Self {
             allow_in: allow_in.into(),
+            allow_await: allow_await.into(),
         }
     }
 }
@@ -179,12 +188,12 @@ where
         match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 let _ = cursor.next();
-                let body = FunctionBody::new(false, false).parse(cursor)?;
+                let body = FunctionBody::new(false, self.allow_await).parse(cursor)?;
                 cursor.expect(Punctuator::CloseBlock, "arrow function")?;
                 Ok(body)
             }
             _ => Ok(StatementList::from(vec![Return::new(
-                ExpressionBody::new(self.allow_in, false).parse(cursor)?,
+                ExpressionBody::new(self.allow_in, self.allow_await).parse(cursor)?,
                 None,
             )
             .into()])),
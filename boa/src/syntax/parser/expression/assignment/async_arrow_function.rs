@@ -0,0 +1,146 @@
+//! Async arrow function parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Arrow_functions
+//! [spec]: https://tc39.es/ecma262/#sec-async-arrow-function-definitions
+
+use super::arrow_function::ConciseBody;
+use crate::{
+    syntax::{
+        ast::{
+            node::{declaration::Declaration, AsyncArrowFunctionDecl, FormalParameter},
+            Keyword, Punctuator,
+        },
+        lexer::{Error as LexError, Position, TokenKind},
+        parser::{
+            error::{ErrorContext, ParseError},
+            function::{FormalParameterList, FormalParameters},
+            statement::BindingIdentifier,
+            AllowIn, AllowYield, Cursor, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Async arrow function parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Arrow_functions
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncArrowFunction
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct AsyncArrowFunction {
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+}
+
+impl AsyncArrowFunction {
+    /// Creates a new `AsyncArrowFunction` parser.
+    pub(in crate::syntax::parser) fn new<I, Y>(allow_in: I, allow_yield: Y) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_yield: allow_yield.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for AsyncArrowFunction
+where
+    R: Read,
+{
+    type Output = AsyncArrowFunctionDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("AsyncArrowFunction", "Parsing");
+        cursor.expect(Keyword::Async, "async arrow function")?;
+        let next_token = cursor.peek_expect_no_lineterminator(0, "async arrow function")?;
+
+        let (params, params_start_position) =
+            if let TokenKind::Punctuator(Punctuator::OpenParen) = &next_token.kind() {
+                // CoverCallExpressionAndAsyncArrowHead
+                let params_start_position = cursor
+                    .expect(Punctuator::OpenParen, "async arrow function")?
+                    .span()
+                    .end();
+
+                let params = FormalParameters::new(false, true).parse(cursor)?;
+                cursor.expect(Punctuator::CloseParen, "async arrow function")?;
+                (params, params_start_position)
+            } else {
+                // AsyncArrowBindingIdentifier
+                let params_start_position = next_token.span().start();
+                let param = BindingIdentifier::new(self.allow_yield, true)
+                    .parse(cursor)
+                    .context("async arrow function")?;
+                (
+                    FormalParameterList {
+                        parameters: Box::new([FormalParameter::new(
+                            Declaration::new_with_identifier(param, None),
+                            false,
+                        )]),
+                        is_simple: true,
+                        has_duplicates: false,
+                    },
+                    params_start_position,
+                )
+            };
+
+        cursor.peek_expect_no_lineterminator(0, "async arrow function")?;
+
+        cursor.expect(
+            TokenKind::Punctuator(Punctuator::Arrow),
+            "async arrow function",
+        )?;
+        let body = ConciseBody::new(self.allow_in, true).parse(cursor)?;
+
+        // Early Error: AsyncArrowHead's ArrowFormalParameters are UniqueFormalParameters.
+        if params.has_duplicates {
+            return Err(ParseError::lex(LexError::Syntax(
+                "Duplicate parameter name not allowed in this context".into(),
+                params_start_position,
+            )));
+        }
+
+        // Early Error: It is a Syntax Error if AsyncConciseBodyContainsUseStrict of AsyncConciseBody
+        // is true and IsSimpleParameterList of the parameters is false.
+        if body.strict() && !params.is_simple {
+            return Err(ParseError::lex(LexError::Syntax(
+                "Illegal 'use strict' directive in function with non-simple parameter list".into(),
+                params_start_position,
+            )));
+        }
+
+        // It is a Syntax Error if any element of the BoundNames of the parameters
+        // also occurs in the LexicallyDeclaredNames of AsyncConciseBody.
+        // https://tc39.es/ecma262/#sec-async-arrow-function-definitions-static-semantics-early-errors
+        {
+            let lexically_declared_names = body.lexically_declared_names();
+            for param in params.parameters.as_ref() {
+                for param_name in param.names() {
+                    if lexically_declared_names.contains(param_name) {
+                        return Err(ParseError::lex(LexError::Syntax(
+                            format!("Redeclaration of formal parameter `{}`", param_name).into(),
+                            match cursor.peek(0)? {
+                                Some(token) => token.span().end(),
+                                None => Position::new(1, 1),
+                            },
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(AsyncArrowFunctionDecl::new(params.parameters, body))
+    }
+}
@@ -55,21 +55,29 @@ impl ExponentiationExpression {
 }
 
 /// Checks by looking at the next token to see whether it's a unary operator or not.
-fn is_unary_expression<R>(cursor: &mut Cursor<R>) -> Result<bool, ParseError>
+///
+/// `await` is only an operator where await expressions are allowed.
+fn is_unary_expression<R>(
+    cursor: &mut Cursor<R>,
+    allow_await: AllowAwait,
+) -> Result<bool, ParseError>
 where
     R: Read,
 {
     Ok(if let Some(tok) = cursor.peek(0)? {
-        matches!(
-            tok.kind(),
-            TokenKind::Keyword(Keyword::Delete)
-                | TokenKind::Keyword(Keyword::Void)
-                | TokenKind::Keyword(Keyword::TypeOf)
-                | TokenKind::Punctuator(Punctuator::Add)
-                | TokenKind::Punctuator(Punctuator::Sub)
-                | TokenKind::Punctuator(Punctuator::Not)
-                | TokenKind::Punctuator(Punctuator::Neg)
-        )
+        match tok.kind() {
+            TokenKind::Keyword(Keyword::Await) => allow_await.0,
+            kind => matches!(
+                kind,
+                TokenKind::Keyword(Keyword::Delete)
+                    | TokenKind::Keyword(Keyword::Void)
+                    | TokenKind::Keyword(Keyword::TypeOf)
+                    | TokenKind::Punctuator(Punctuator::Add)
+                    | TokenKind::Punctuator(Punctuator::Sub)
+                    | TokenKind::Punctuator(Punctuator::Not)
+                    | TokenKind::Punctuator(Punctuator::Neg)
+            ),
+        }
     } else {
         false
     })
@@ -84,7 +92,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("ExponentiationExpression", "Parsing");
 
-        if is_unary_expression(cursor)? {
+        if is_unary_expression(cursor, self.allow_await)? {
             return UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor);
         }
 
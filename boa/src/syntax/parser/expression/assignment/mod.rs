@@ -8,12 +8,16 @@
 //! [spec]: https://tc39.es/ecma262/#sec-assignment-operators
 
 mod arrow_function;
+mod async_arrow_function;
 mod conditional;
 mod exponentiation;
 mod r#yield;
 
 use self::r#yield::YieldExpression;
-use self::{arrow_function::ArrowFunction, conditional::ConditionalExpression};
+use self::{
+    arrow_function::ArrowFunction, async_arrow_function::AsyncArrowFunction,
+    conditional::ConditionalExpression,
+};
 use crate::syntax::lexer::{Error as LexError, InputElement, TokenKind};
 use crate::{
     syntax::{
@@ -36,7 +40,7 @@ use std::io::Read;
 ///  - [`ConditionalExpression`](../conditional_operator/struct.ConditionalExpression.html)
 ///  - `YieldExpression`
 ///  - [`ArrowFunction`](../../function/arrow_function/struct.ArrowFunction.html)
-///  - `AsyncArrowFunction`
+///  - [`AsyncArrowFunction`](../../function/async_arrow_function/struct.AsyncArrowFunction.html)
 ///  - [`LeftHandSideExpression`][lhs] `=` `AssignmentExpression`
 ///  - [`LeftHandSideExpression`][lhs] `AssignmentOperator` `AssignmentExpression`
 ///
@@ -105,6 +109,16 @@ where
                     }
                 }
             }
+            // AsyncArrowFunction[?In, ?Yield, ?Await]
+            TokenKind::Keyword(Keyword::Async) => {
+                if let Ok(tok) = cursor.peek_expect_no_lineterminator(1, "assignment expression") {
+                    if tok.kind() != &TokenKind::Keyword(Keyword::Function) {
+                        return AsyncArrowFunction::new(self.allow_in, self.allow_yield)
+                            .parse(cursor)
+                            .map(Node::AsyncArrowFunctionDecl);
+                    }
+                }
+            }
             // ArrowFunction[?In, ?Yield, ?Await] -> ArrowParameters[?Yield, ?Await] -> CoverParenthesizedExpressionAndArrowParameterList[?Yield, ?Await]
             TokenKind::Punctuator(Punctuator::OpenParen) => {
                 if let Some(next_token) = cursor.peek(1)? {
@@ -0,0 +1,136 @@
+//! Optional expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+//! [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+
+use super::arguments::Arguments;
+use crate::{
+    syntax::{
+        ast::{
+            node::{Node, Optional, OptionalOperation, OptionalOperationKind},
+            Punctuator,
+        },
+        lexer::{Token, TokenKind},
+        parser::{
+            expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
+            TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Parses an optional expression.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+#[derive(Debug)]
+pub(super) struct OptionalExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    target: Node,
+}
+
+impl OptionalExpression {
+    /// Creates a new `OptionalExpression` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, target: Node) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            target,
+        }
+    }
+
+    /// Parses the name of a property accessed with `.` or `?.`.
+    fn parse_field_name(token: Token) -> Result<OptionalOperationKind, ParseError> {
+        match token.kind() {
+            TokenKind::Identifier(name) => Ok(OptionalOperationKind::SimplePropertyAccess {
+                field: name.clone(),
+            }),
+            TokenKind::Keyword(kw) => Ok(OptionalOperationKind::SimplePropertyAccess {
+                field: kw.to_string().into(),
+            }),
+            _ => Err(ParseError::expected(
+                vec![TokenKind::identifier("identifier")],
+                token,
+                "optional chain",
+            )),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for OptionalExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("OptionalExpression", "Parsing");
+
+        let mut chain = Vec::new();
+        while let Some(token) = cursor.peek(0)? {
+            let shorted = match token.kind() {
+                TokenKind::Punctuator(Punctuator::Optional) => {
+                    cursor.next()?.expect("?. token vanished"); // We move the parser forward.
+                    true
+                }
+                TokenKind::Punctuator(
+                    Punctuator::Dot | Punctuator::OpenBracket | Punctuator::OpenParen,
+                ) => false,
+                TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
+                    return Err(ParseError::general(
+                        "tagged template cannot be used in optional chain",
+                        token.span().start(),
+                    ));
+                }
+                _ => break,
+            };
+
+            let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            let kind = match token.kind() {
+                TokenKind::Punctuator(Punctuator::OpenParen) => OptionalOperationKind::Call {
+                    args: Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?,
+                },
+                TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    cursor.next()?.expect("[ token vanished"); // We move the parser forward.
+                    let field =
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::CloseBracket, "optional chain")?;
+                    OptionalOperationKind::PropertyAccess {
+                        field: Box::new(field),
+                    }
+                }
+                TokenKind::Punctuator(Punctuator::Dot) if !shorted => {
+                    cursor.next()?.expect(". token vanished"); // We move the parser forward.
+                    let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                    Self::parse_field_name(token)?
+                }
+                TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
+                    return Err(ParseError::general(
+                        "tagged template cannot be used in optional chain",
+                        token.span().start(),
+                    ));
+                }
+                _ => {
+                    let token = cursor.next()?.expect("token vanished");
+                    Self::parse_field_name(token)?
+                }
+            };
+            chain.push(OptionalOperation::new(kind, shorted));
+        }
+
+        Ok(Optional::new(self.target, chain).into())
+    }
+}
@@ -89,7 +89,11 @@ where
                 if next_token.kind() == &TokenKind::Punctuator(Punctuator::Mul) {
                     GeneratorExpression.parse(cursor).map(Node::from)
                 } else {
-                    FunctionExpression.parse(cursor).map(Node::from)
+                    let start = tok.source_range().start;
+                    FunctionExpression
+                        .parse(cursor)
+                        .map(|function| function.with_source(cursor.source_text_from(start)))
+                        .map(Node::from)
                 }
             }
             TokenKind::Keyword(Keyword::Async) => {
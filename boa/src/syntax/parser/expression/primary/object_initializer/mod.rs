@@ -186,6 +186,12 @@ where
             }
         }
 
+        let start = cursor
+            .peek(0)?
+            .ok_or(ParseError::AbruptEnd)?
+            .source_range()
+            .start;
+
         //  ... AssignmentExpression[+In, ?Yield, ?Await]
         if cursor.next_if(Punctuator::Spread)?.is_some() {
             let node = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
@@ -263,7 +269,8 @@ where
                 return Ok(node::PropertyDefinition::method_definition(
                     MethodDefinitionKind::AsyncGenerator,
                     property_name,
-                    FunctionExpr::new(None, params.parameters, body),
+                    FunctionExpr::new(None, params.parameters, body)
+                        .with_source(cursor.source_text_from(start)),
                 ));
             } else {
                 // MethodDefinition[?Yield, ?Await] -> AsyncMethod[?Yield, ?Await]
@@ -326,7 +333,8 @@ where
                 return Ok(node::PropertyDefinition::method_definition(
                     MethodDefinitionKind::Async,
                     property_name,
-                    FunctionExpr::new(None, params.parameters, body),
+                    FunctionExpr::new(None, params.parameters, body)
+                        .with_source(cursor.source_text_from(start)),
                 ));
             }
         }
@@ -395,7 +403,8 @@ where
             return Ok(node::PropertyDefinition::method_definition(
                 MethodDefinitionKind::Generator,
                 property_name,
-                FunctionExpr::new(None, params.parameters, body),
+                FunctionExpr::new(None, params.parameters, body)
+                    .with_source(cursor.source_text_from(start)),
             ));
         }
 
@@ -440,7 +449,7 @@ where
                 Ok(node::PropertyDefinition::method_definition(
                     MethodDefinitionKind::Get,
                     property_name,
-                    FunctionExpr::new(None, [], body),
+                    FunctionExpr::new(None, [], body).with_source(cursor.source_text_from(start)),
                 ))
             }
             // MethodDefinition[?Yield, ?Await] -> set ClassElementName[?Yield, ?Await] ( PropertySetParameterList ) { FunctionBody[~Yield, ~Await] }
@@ -490,7 +499,8 @@ where
                 Ok(node::PropertyDefinition::method_definition(
                     MethodDefinitionKind::Set,
                     property_name,
-                    FunctionExpr::new(None, params.parameters, body),
+                    FunctionExpr::new(None, params.parameters, body)
+                        .with_source(cursor.source_text_from(start)),
                 ))
             }
             // MethodDefinition[?Yield, ?Await] -> ClassElementName[?Yield, ?Await] ( UniqueFormalParameters[~Yield, ~Await] ) { FunctionBody[~Yield, ~Await] }
@@ -539,7 +549,8 @@ where
                 Ok(node::PropertyDefinition::method_definition(
                     MethodDefinitionKind::Ordinary,
                     property_name,
-                    FunctionExpr::new(None, params.parameters, body),
+                    FunctionExpr::new(None, params.parameters, body)
+                        .with_source(cursor.source_text_from(start)),
                 ))
             }
         }
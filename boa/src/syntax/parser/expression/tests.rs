@@ -281,3 +281,10 @@ fn check_logical_expressions() {
     check_invalid("a ?? b || c");
     check_invalid("a || b ?? c");
 }
+
+#[test]
+fn check_optional_chain_invalid() {
+    check_invalid("a?.b = 1");
+    check_invalid("a?.b`c`");
+    check_invalid("a?.`c`");
+}
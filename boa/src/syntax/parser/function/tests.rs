@@ -1,7 +1,7 @@
 use crate::syntax::{
     ast::node::{
-        ArrowFunctionDecl, BinOp, Declaration, DeclarationList, FormalParameter, FunctionDecl,
-        Identifier, Node, Return,
+        ArrowFunctionDecl, AsyncArrowFunctionDecl, AwaitExpr, BinOp, Declaration, DeclarationList,
+        FormalParameter, FunctionDecl, Identifier, Node, Return,
     },
     ast::op::NumOp,
     parser::{tests::check_parser, Parser},
@@ -479,3 +479,83 @@ fn check_arrow_assignment_3arg_nobrackets() {
         .into()],
     );
 }
+
+#[test]
+fn check_async_arrow_noparenthesis_nobrackets() {
+    check_parser(
+        "let foo = async a => await a;",
+        vec![DeclarationList::Let(
+            vec![Declaration::new_with_identifier(
+                Identifier::from("foo"),
+                Some(
+                    AsyncArrowFunctionDecl::new(
+                        vec![FormalParameter::new(
+                            Declaration::new_with_identifier("a", None),
+                            false,
+                        )],
+                        vec![Return::new::<Node, Option<_>, Option<_>>(
+                            Some(AwaitExpr::from(Node::from(Identifier::from("a"))).into()),
+                            None,
+                        )
+                        .into()],
+                    )
+                    .into(),
+                ),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn check_async_arrow_2arg() {
+    check_parser(
+        "let foo = async (a, b) => { return await a + b; };",
+        vec![DeclarationList::Let(
+            vec![Declaration::new_with_identifier(
+                Identifier::from("foo"),
+                Some(
+                    AsyncArrowFunctionDecl::new(
+                        vec![
+                            FormalParameter::new(
+                                Declaration::new_with_identifier("a", None),
+                                false,
+                            ),
+                            FormalParameter::new(
+                                Declaration::new_with_identifier("b", None),
+                                false,
+                            ),
+                        ],
+                        vec![Return::new::<Node, Option<_>, Option<_>>(
+                            Some(
+                                BinOp::new(
+                                    NumOp::Add,
+                                    AwaitExpr::from(Node::from(Identifier::from("a"))),
+                                    Identifier::from("b"),
+                                )
+                                .into(),
+                            ),
+                            None,
+                        )
+                        .into()],
+                    )
+                    .into(),
+                ),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks that `await` is only an operator in the body of async functions.
+#[test]
+fn check_await_outside_async_function() {
+    for js in [
+        "function f() { return await 1; }",
+        "async () => { let f = () => await 1; }",
+    ] {
+        assert!(Parser::new(js.as_bytes(), false).parse_all().is_err());
+    }
+}
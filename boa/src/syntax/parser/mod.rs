@@ -102,7 +102,13 @@ impl<R> Parser<R> {
     where
         R: Read,
     {
-        Script.parse(&mut self.cursor)
+        Script.parse(&mut self.cursor).map_err(|err| match err {
+            // Running out of tokens means the lexer has reached the end of the source code.
+            ParseError::AbruptEnd => {
+                ParseError::general("unexpected end of input", self.cursor.lexer_pos())
+            }
+            err => err,
+        })
     }
 }
 
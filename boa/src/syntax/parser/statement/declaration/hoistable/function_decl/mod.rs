@@ -80,10 +80,14 @@ where
     type Output = FunctionDecl;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        cursor.expect(Keyword::Function, "function declaration")?;
+        let start = cursor
+            .expect(Keyword::Function, "function declaration")?
+            .source_range()
+            .start;
 
         let result = parse_callable_declaration(&self, cursor)?;
 
-        Ok(FunctionDecl::new(result.0, result.1, result.2))
+        Ok(FunctionDecl::new(result.0, result.1, result.2)
+            .with_source(cursor.source_text_from(start)))
     }
 }
@@ -54,7 +54,6 @@ use crate::{
             Keyword, Node, Punctuator,
         },
         lexer::{Error as LexError, InputElement, Position, TokenKind},
-        parser::expression::await_expr::AwaitExpression,
     },
     BoaProfiler,
 };
@@ -124,9 +123,6 @@ where
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         match tok.kind() {
-            TokenKind::Keyword(Keyword::Await) => AwaitExpression::new(self.allow_yield)
-                .parse(cursor)
-                .map(Node::from),
             TokenKind::Keyword(Keyword::If) => {
                 IfStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)
@@ -519,6 +515,15 @@ where
         let next_token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
 
         match next_token.kind() {
+            // Early Error: It is a Syntax Error if the code matched by this production is contained in strict mode code and the StringValue of Identifier is "arguments" or "eval".
+            TokenKind::Identifier(ref s)
+                if cursor.strict_mode() && ["eval", "arguments"].contains(&s.as_ref()) =>
+            {
+                Err(ParseError::general(
+                    "unexpected identifier 'eval' or 'arguments' in strict mode",
+                    next_token.span().start(),
+                ))
+            }
             TokenKind::Identifier(ref s) => Ok(s.clone()),
             TokenKind::Keyword(Keyword::Yield) if self.allow_yield.0 => {
                 // Early Error: It is a Syntax Error if this production has a [Yield] parameter and StringValue of Identifier is "yield".
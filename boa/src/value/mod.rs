@@ -7,6 +7,7 @@ mod tests;
 
 use crate::{
     builtins::{
+        error::NativeError,
         number::{f64_to_int32, f64_to_uint32},
         Number,
     },
@@ -137,6 +138,29 @@ impl JsValue {
         }
     }
 
+    /// Gets the name and message of the value, if it's an `Error` object.
+    ///
+    /// Any other thrown value, like a string or an ordinary object, returns `None`, and can only
+    /// be inspected as a `JsValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa::Context;
+    /// let mut context = Context::new();
+    /// let error = context.eval("null.x").unwrap_err();
+    /// let error = error.try_native_error(&mut context).unwrap().unwrap();
+    /// assert_eq!(error.name().as_str(), "TypeError");
+    /// ```
+    pub fn try_native_error(&self, context: &mut Context) -> JsResult<Option<NativeError>> {
+        match self {
+            Self::Object(object) if object.is_error() => {
+                NativeError::from_object(object, context).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
     /// It determines if the value is a callable function with a `[[Call]]` internal method.
     ///
     /// More information:
@@ -1044,16 +1068,23 @@ impl JsValue {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-isarray
-    pub(crate) fn is_array(&self, _context: &mut Context) -> JsResult<bool> {
+    pub(crate) fn is_array(&self, context: &mut Context) -> JsResult<bool> {
         // 1. If Type(argument) is not Object, return false.
         if let Some(object) = self.as_object() {
             // 2. If argument is an Array exotic object, return true.
-            //     a. If argument.[[ProxyHandler]] is null, throw a TypeError exception.
+            if object.is_array() {
+                return Ok(true);
+            }
             // 3. If argument is a Proxy exotic object, then
-            //     b. Let target be argument.[[ProxyTarget]].
-            //     c. Return ? IsArray(target).
-            // TODO: handle ProxyObjects
-            Ok(object.is_array())
+            let proxy = object.borrow().as_proxy().cloned();
+            if let Some(proxy) = proxy {
+                // a. If argument.[[ProxyHandler]] is null, throw a TypeError exception.
+                // b. Let target be argument.[[ProxyTarget]].
+                let (target, _) = proxy.try_data(context)?;
+                // c. Return ? IsArray(target).
+                return JsValue::from(target).is_array(context);
+            }
+            Ok(false)
         } else {
             // 4. Return false.
             Ok(false)
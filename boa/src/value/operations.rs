@@ -1,5 +1,6 @@
 use super::*;
 use crate::builtins::number::{f64_to_int32, f64_to_uint32, Number};
+use std::cmp::Ordering;
 
 impl JsValue {
     #[inline]
@@ -496,18 +497,7 @@ impl JsValue {
 
                 match (px, py) {
                     (Self::String(ref x), Self::String(ref y)) => {
-                        if x.starts_with(y.as_str()) {
-                            return Ok(AbstractRelation::False);
-                        }
-                        if y.starts_with(x.as_str()) {
-                            return Ok(AbstractRelation::True);
-                        }
-                        for (x, y) in x.chars().zip(y.chars()) {
-                            if x != y {
-                                return Ok((x < y).into());
-                            }
-                        }
-                        unreachable!()
+                        (x.cmp_code_units(y) == Ordering::Less).into()
                     }
                     (Self::BigInt(ref x), Self::String(ref y)) => {
                         if let Some(y) = JsBigInt::from_string(y) {
@@ -533,12 +523,9 @@ impl JsValue {
                             if y.is_infinite() {
                                 return Ok(y.is_sign_positive().into());
                             }
-                            let n = if y.is_sign_negative() {
-                                y.floor()
-                            } else {
-                                y.ceil()
-                            };
-                            (*x < JsBigInt::try_from(n).unwrap()).into()
+                            // A bigint is less than a number iff it is less than the
+                            // smallest integer that isn't less than the number.
+                            (*x < JsBigInt::try_from(y.ceil()).unwrap()).into()
                         }
                         (Numeric::Number(x), Numeric::BigInt(ref y)) => {
                             if x.is_nan() {
@@ -547,12 +534,9 @@ impl JsValue {
                             if x.is_infinite() {
                                 return Ok(x.is_sign_negative().into());
                             }
-                            let n = if x.is_sign_negative() {
-                                x.floor()
-                            } else {
-                                x.ceil()
-                            };
-                            (JsBigInt::try_from(n).unwrap() < *y).into()
+                            // A number is less than a bigint iff the largest integer that
+                            // isn't greater than the number is.
+                            (JsBigInt::try_from(x.floor()).unwrap() < *y).into()
                         }
                     },
                 }
@@ -34,6 +34,44 @@ fn get_set_field() {
     );
 }
 
+#[test]
+fn get_set_v() {
+    let mut context = Context::new();
+    let five = JsValue::new(5);
+
+    let to_fixed = five.get_v("toFixed", &mut context).unwrap();
+    assert!(to_fixed.is_callable());
+    let fixed = context.call(&to_fixed, &five, &[2.into()]).unwrap();
+    assert_eq!(fixed.display().to_string(), "\"5.00\"");
+    assert_eq!(
+        JsValue::new("abc")
+            .get_v("length", &mut context)
+            .unwrap()
+            .as_number(),
+        Some(3.0)
+    );
+
+    // Primitives can't hold own properties, but setters on their prototype still run.
+    assert_eq!(five.set_v("digits", 1, false, &mut context), Ok(false));
+    assert!(five.set_v("digits", 1, true, &mut context).is_err());
+    forward(
+        &mut context,
+        r#"
+        Object.defineProperty(Number.prototype, "setter", {
+            set(value) { "use strict"; Number.prototype.received = typeof this + " " + value; }
+        });
+        "#,
+    );
+    assert_eq!(five.set_v("setter", 1, true, &mut context), Ok(true));
+    assert_eq!(
+        forward(&mut context, "Number.prototype.received"),
+        "\"number 1\""
+    );
+
+    assert!(JsValue::undefined().get_v("a", &mut context).is_err());
+    assert!(JsValue::null().set_v("a", 1, false, &mut context).is_err());
+}
+
 #[test]
 fn integer_is_true() {
     assert!(JsValue::new(1).to_boolean());
@@ -802,6 +840,16 @@ mod abstract_relational_comparison {
         check_comparison!(context, "'aa' < 'ab'" => true);
     }
 
+    #[test]
+    fn string_less_than_string_compares_code_units() {
+        let mut context = Context::new();
+        // U+1F600 is encoded as the surrogates D83D DE00, which come before U+FFFF.
+        check_comparison!(context, "'\u{1F600}' < '\u{FFFF}'" => true);
+        check_comparison!(context, "'\u{FFFF}' < '\u{1F600}'" => false);
+        check_comparison!(context, "'\u{1F600}' > '\u{E000}'" => false);
+        check_comparison!(context, "'\u{1F600}' < '\u{1F601}'" => true);
+    }
+
     #[test]
     fn string_object_less_than_string() {
         let mut context = Context::new();
@@ -0,0 +1,123 @@
+//! This module implements the suspension of async functions on `await`.
+//!
+//! When an async function awaits a value, its call frame is removed from the VM, along with the
+//! values it has on the stack, and kept by the functions that the awaited promise calls once it
+//! is settled. These functions put the frame back on the VM and resume its execution right after
+//! the `await`.
+
+use super::{CallFrame, CompletionType};
+use crate::{
+    builtins::{promise::Promise, JsArgs},
+    object::FunctionBuilder,
+    Context, JsResult, JsValue,
+};
+use gc::{Gc, GcCell};
+
+/// The call frame of a suspended async function, along with the values it had on the stack.
+type SuspendedFrame = Gc<GcCell<Option<(Box<CallFrame>, Vec<JsValue>)>>>;
+
+impl Context {
+    /// `Await ( value )`
+    ///
+    /// Suspends the running async function until `value` is settled. The caller is expected to
+    /// exit the VM loop afterwards.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#await
+    pub(crate) fn await_value(&mut self, value: JsValue) -> JsResult<()> {
+        // 1. Let asyncContext be the running execution context.
+        let suspended: SuspendedFrame = Gc::new(GcCell::new(None));
+
+        // 3. Let fulfilledClosure be a new Abstract Closure with parameters (value) that captures asyncContext and performs the following steps when called:
+        // 4. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 1, "", « »).
+        let on_fulfilled =
+            FunctionBuilder::native_with_data(self, Self::resume_fulfilled, suspended.clone())
+                .name("")
+                .length(1)
+                .build();
+
+        // 5. Let rejectedClosure be a new Abstract Closure with parameters (reason) that captures asyncContext and performs the following steps when called:
+        // 6. Let onRejected be CreateBuiltinFunction(rejectedClosure, 1, "", « »).
+        let on_rejected =
+            FunctionBuilder::native_with_data(self, Self::resume_rejected, suspended.clone())
+                .name("")
+                .length(1)
+                .build();
+
+        // 2. Let promise be ? PromiseResolve(%Promise%, value).
+        // 7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
+        Promise::perform_await(value, on_fulfilled, on_rejected, self)?;
+
+        // 8. Remove asyncContext from the execution context stack and restore the execution context that is at the top of the execution context stack as the running execution context.
+        let frame = self
+            .vm
+            .pop_frame()
+            .expect("await must be evaluated in a call frame");
+        let stack = self.vm.stack.split_off(frame.fp);
+        *suspended.borrow_mut() = Some((frame, stack));
+
+        Ok(())
+    }
+
+    /// The `fulfilledClosure` of `Await`, which resumes the async function with the value of
+    /// the awaited promise.
+    fn resume_fulfilled(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // d. Resume the suspended evaluation of asyncContext using NormalCompletion(value) as the result of the operation that suspended it.
+        context.resume_async_function(CompletionType::Normal, args.get_or_undefined(0).clone())
+    }
+
+    /// The `rejectedClosure` of `Await`, which throws the reason of the awaited promise from
+    /// the async function.
+    fn resume_rejected(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // d. Resume the suspended evaluation of asyncContext using ThrowCompletion(reason) as the result of the operation that suspended it.
+        context.resume_async_function(CompletionType::Throw, args.get_or_undefined(0).clone())
+    }
+
+    /// Puts the frame of the suspended async function back on the VM, and runs it with
+    /// `completion` as the result of the `await`.
+    fn resume_async_function(
+        &mut self,
+        completion: CompletionType,
+        value: JsValue,
+    ) -> JsResult<JsValue> {
+        let suspended = self
+            .current_function_data::<SuspendedFrame>()
+            .expect("resume functions must have the suspended frame")
+            .clone();
+        let (mut frame, stack) = suspended
+            .borrow_mut()
+            .take()
+            .expect("an async function can only be resumed once");
+        let capability = frame
+            .promise_capability
+            .clone()
+            .expect("only async functions can be suspended");
+
+        // a. Let prevContext be the running execution context.
+        // b. Suspend prevContext.
+        // c. Push asyncContext onto the execution context stack; asyncContext is now the running execution context.
+        frame.fp = self.vm.stack.len();
+        frame.exit_on_return = true;
+        frame.resumed_with = completion;
+        self.vm.stack.extend(stack);
+        self.vm.push(value);
+        self.push_environment(frame.environment.clone());
+        self.vm.push_frame(*frame);
+
+        let result = self.run();
+
+        self.pop_environment();
+
+        // An exception that isn't caught in the function rejects its promise.
+        if let Err(error) = result {
+            capability
+                .reject
+                .call(&JsValue::undefined(), &[error], self)?;
+        }
+
+        // f. Return undefined.
+        Ok(JsValue::undefined())
+    }
+}
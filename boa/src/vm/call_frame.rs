@@ -2,10 +2,16 @@
 //! This module will provides everything needed to implement the CallFrame
 
 use super::CodeBlock;
-use crate::{environment::lexical_environment::Environment, JsValue};
+pub(crate) use crate::exec::CompletionType;
+use crate::{
+    builtins::promise::PromiseCapability,
+    environment::lexical_environment::Environment,
+    gc::{Finalize, Trace},
+    JsValue,
+};
 use gc::Gc;
 
-#[derive(Debug)]
+#[derive(Debug, Trace, Finalize)]
 pub struct CallFrame {
     pub(crate) prev: Option<Box<Self>>,
     pub(crate) code: Gc<CodeBlock>,
@@ -14,4 +20,34 @@ pub struct CallFrame {
     pub(crate) exit_on_return: bool,
     pub(crate) this: JsValue,
     pub(crate) environment: Environment,
+
+    /// The capability of the promise returned by an async function, settled when the function
+    /// returns or throws.
+    pub(crate) promise_capability: Option<PromiseCapability>,
+
+    /// The handlers of the `try` statements the frame is currently in, the innermost one last.
+    pub(crate) handlers: Vec<TryHandler>,
+
+    /// How a suspended generator was resumed, read by the instruction after the `yield`.
+    #[unsafe_ignore_trace]
+    pub(crate) resumed_with: CompletionType,
+
+    /// Whether the frame is a generator that yielded, instead of returning.
+    pub(crate) yielded: bool,
+}
+
+/// Where to go when an exception is thrown, or the frame returns, inside a `try` statement.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct TryHandler {
+    /// The address of the `catch` block, if it hasn't been entered yet.
+    pub(crate) catch: Option<u32>,
+
+    /// The address of the `finally` block that runs after an exception or a return.
+    pub(crate) finally: Option<u32>,
+
+    /// The size of the stack of the frame when the `try` statement started.
+    pub(crate) sp: usize,
+
+    /// The environment when the `try` statement started.
+    pub(crate) environment: Environment,
 }
@@ -1,6 +1,10 @@
 use crate::{
-    builtins::function::{
-        Captures, ClosureFunctionSignature, Function, NativeFunctionSignature, ThisMode,
+    builtins::{
+        function::{
+            Captures, ClosureFunctionSignature, Function, NativeFunctionSignature, ThisMode,
+        },
+        generator::{Generator, GeneratorContext},
+        promise::Promise,
     },
     context::StandardObjects,
     environment::{
@@ -18,7 +22,7 @@ use gc::Gc;
 
 use std::{convert::TryInto, fmt::Write, mem::size_of};
 
-use super::CallFrame;
+use super::{CallFrame, CompletionType};
 
 /// This represents wether a value can be read from [`CodeBlock`] code.
 pub unsafe trait Readable {}
@@ -51,6 +55,12 @@ pub struct CodeBlock {
     /// [[ThisMode]]
     pub(crate) this_mode: ThisMode,
 
+    /// Is this function an async function.
+    pub(crate) is_async: bool,
+
+    /// Is this function a generator function.
+    pub(crate) is_generator: bool,
+
     pub(crate) params: Box<[FormalParameter]>,
 
     /// Bytecode
@@ -78,6 +88,8 @@ impl CodeBlock {
             strict,
             constructor,
             this_mode: ThisMode::Global,
+            is_async: false,
+            is_generator: false,
             params: Vec::new().into_boxed_slice(),
         }
     }
@@ -103,6 +115,29 @@ impl CodeBlock {
         unsafe { self.read_unchecked(offset) }
     }
 
+    /// Binds the parameters of the function to `args` in `local_env`, the environment of the
+    /// call.
+    pub(crate) fn bind_parameters(
+        &self,
+        args: &[JsValue],
+        local_env: &Environment,
+        context: &mut Context,
+    ) {
+        for (i, param) in self.params.iter().enumerate() {
+            // Rest Parameters
+            if param.is_rest_param() {
+                todo!("Rest parameter");
+            }
+
+            let value = match args.get(i).cloned() {
+                None => JsValue::undefined(),
+                Some(value) => value,
+            };
+
+            Function::add_arguments_to_environment(param, value, local_env, context);
+        }
+    }
+
     pub(crate) fn instruction_operands(&self, pc: &mut usize) -> String {
         let opcode: Opcode = self.code[*pc].try_into().unwrap();
         *pc += size_of::<Opcode>();
@@ -137,11 +172,21 @@ impl CodeBlock {
             | Opcode::LogicalAnd
             | Opcode::LogicalOr
             | Opcode::Coalesce
-            | Opcode::Call => {
+            | Opcode::Call
+            | Opcode::CallEval
+            | Opcode::TailCall
+            | Opcode::GeneratorNextDelegate => {
                 let result = self.read::<u32>(*pc).to_string();
                 *pc += size_of::<u32>();
                 result
             }
+            Opcode::TryStart => {
+                let catch = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                let finally = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                format!("catch: {}, finally: {}", catch, finally)
+            }
             Opcode::GetFunction => {
                 let operand = self.read::<u32>(*pc);
                 *pc += size_of::<u32>();
@@ -217,8 +262,17 @@ impl CodeBlock {
             | Opcode::DeletePropertyByValue
             | Opcode::ToBoolean
             | Opcode::Throw
+            | Opcode::ThrowSyntaxError
             | Opcode::This
             | Opcode::Return
+            | Opcode::Await
+            | Opcode::TryEnd
+            | Opcode::FinallyEnd
+            | Opcode::PushDeclarativeEnvironment
+            | Opcode::PopEnvironment
+            | Opcode::Yield
+            | Opcode::GeneratorNext
+            | Opcode::InitIterator
             | Opcode::Nop => String::new(),
         }
     }
@@ -300,8 +354,6 @@ impl JsVmFunction {
     pub fn new(code: Gc<CodeBlock>, environment: Environment, context: &mut Context) -> JsObject {
         let function_prototype = context.standard_objects().function_object().prototype();
 
-        let prototype = context.construct_object();
-
         let name_property = PropertyDescriptor::builder()
             .value(code.name.clone())
             .writable(true)
@@ -316,32 +368,58 @@ impl JsVmFunction {
             .configurable(true)
             .build();
 
+        let is_async = code.is_async;
+        let is_generator = code.is_generator;
         let function = Function::VmOrdinary { code, environment };
 
         let constructor =
             JsObject::from_proto_and_data(function_prototype, ObjectData::function(function));
 
-        let constructor_property = PropertyDescriptor::builder()
-            .value(constructor.clone())
-            .writable(true)
-            .enumerable(false)
-            .configurable(true)
-            .build();
-
-        prototype
-            .define_property_or_throw("constructor", constructor_property, context)
-            .unwrap();
-
-        let prototype_property = PropertyDescriptor::builder()
-            .value(prototype)
-            .writable(false)
-            .enumerable(false)
-            .configurable(true)
-            .build();
-
-        constructor
-            .define_property_or_throw("prototype", prototype_property, context)
-            .unwrap();
+        // The `prototype` of a generator function is the prototype of the generators it returns,
+        // which has no `constructor`.
+        // <https://tc39.es/ecma262/#sec-runtime-semantics-instantiategeneratorfunctionobject>
+        if is_generator {
+            let prototype = JsObject::from_proto_and_data(
+                context.iterator_prototypes().generator(),
+                ObjectData::ordinary(),
+            );
+
+            let prototype_property = PropertyDescriptor::builder()
+                .value(prototype)
+                .writable(true)
+                .enumerable(false)
+                .configurable(false)
+                .build();
+
+            constructor
+                .define_property_or_throw("prototype", prototype_property, context)
+                .unwrap();
+        // Async functions can't be constructed, so they have no `prototype` property.
+        } else if !is_async {
+            let prototype = context.construct_object();
+
+            let constructor_property = PropertyDescriptor::builder()
+                .value(constructor.clone())
+                .writable(true)
+                .enumerable(false)
+                .configurable(true)
+                .build();
+
+            prototype
+                .define_property_or_throw("constructor", constructor_property, context)
+                .unwrap();
+
+            let prototype_property = PropertyDescriptor::builder()
+                .value(prototype)
+                .writable(false)
+                .enumerable(false)
+                .configurable(true)
+                .build();
+
+            constructor
+                .define_property_or_throw("prototype", prototype_property, context)
+                .unwrap();
+        }
         constructor
             .define_property_or_throw("name", name_property, context)
             .unwrap();
@@ -360,6 +438,7 @@ pub(crate) enum FunctionBody {
     },
     Native {
         function: NativeFunctionSignature,
+        data: Option<Captures>,
     },
     Closure {
         function: Box<dyn ClosureFunctionSignature>,
@@ -369,6 +448,41 @@ pub(crate) enum FunctionBody {
 
 // TODO: this should be modified to not take `exit_on_return` and then moved to `internal_methods`
 impl JsObject {
+    /// Creates the environment of a call of this function, whose code is `code` and whose scope
+    /// is `environment`.
+    ///
+    /// <https://tc39.es/ecma262/#sec-prepareforordinarycall>
+    pub(crate) fn new_call_environment(
+        &self,
+        code: &CodeBlock,
+        environment: Environment,
+        this: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Environment> {
+        let lexical_this_mode = code.this_mode == ThisMode::Lexical;
+
+        // Create a new Function environment whose parent is set to the scope of the function declaration (self.environment)
+        let local_env = FunctionEnvironmentRecord::new(
+            self.clone(),
+            if !lexical_this_mode {
+                Some(this.clone())
+            } else {
+                None
+            },
+            Some(environment),
+            // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
+            if lexical_this_mode {
+                BindingStatus::Lexical
+            } else {
+                BindingStatus::Uninitialized
+            },
+            JsValue::undefined(),
+            context,
+        )?;
+
+        Ok(local_env.into())
+    }
+
     pub(crate) fn call_internal(
         &self,
         this: &JsValue,
@@ -376,9 +490,6 @@ impl JsObject {
         context: &mut Context,
         exit_on_return: bool,
     ) -> JsResult<JsValue> {
-        let this_function_object = self.clone();
-        // let mut has_parameter_expressions = false;
-
         if !self.is_callable() {
             return context.throw_type_error("not a callable function");
         }
@@ -388,8 +499,9 @@ impl JsObject {
             let function = object.as_function().unwrap();
 
             match function {
-                Function::Native { function, .. } => FunctionBody::Native {
+                Function::Native { function, data, .. } => FunctionBody::Native {
                     function: *function,
+                    data: data.clone(),
                 },
                 Function::Closure {
                     function, captures, ..
@@ -406,55 +518,28 @@ impl JsObject {
         };
 
         match body {
-            FunctionBody::Native { function } => function(this, args, context),
-            FunctionBody::Closure { function, captures } => {
-                (function)(this, args, captures, context)
+            FunctionBody::Native { function, data } => {
+                context.with_function_data(data, |context| function(this, args, context))
             }
+            FunctionBody::Closure { function, captures } => context
+                .with_function_data(None, |context| (function)(this, args, captures, context)),
             FunctionBody::Ordinary { code, environment } => {
-                let lexical_this_mode = code.this_mode == ThisMode::Lexical;
-
-                // Create a new Function environment whose parent is set to the scope of the function declaration (self.environment)
-                // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
-                let local_env = FunctionEnvironmentRecord::new(
-                    this_function_object,
-                    if !lexical_this_mode {
-                        Some(this.clone())
-                    } else {
-                        None
-                    },
-                    Some(environment.clone()),
-                    // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
-                    if lexical_this_mode {
-                        BindingStatus::Lexical
-                    } else {
-                        BindingStatus::Uninitialized
-                    },
-                    JsValue::undefined(),
-                    context,
-                )?;
+                // The promise returned by an async function.
+                // <https://tc39.es/ecma262/#sec-async-functions-abstract-operations-async-function-start>
+                let promise_capability = if code.is_async {
+                    let promise = context.standard_objects().promise_object().constructor();
+                    Some(Promise::new_promise_capability(&promise.into(), context)?)
+                } else {
+                    None
+                };
 
-                // Turn local_env into Environment so it can be cloned
-                let local_env: Environment = local_env.into();
+                let local_env = self.new_call_environment(&code, environment, this, context)?;
 
                 // Push the environment first so that it will be used by default parameters
                 context.push_environment(local_env.clone());
+                code.bind_parameters(args, &local_env, context);
 
-                // Add argument bindings to the function environment
-                for (i, param) in code.params.iter().enumerate() {
-                    // Rest Parameters
-                    if param.is_rest_param() {
-                        todo!("Rest parameter");
-                    }
-
-                    let value = match args.get(i).cloned() {
-                        None => JsValue::undefined(),
-                        Some(value) => value,
-                    };
-
-                    Function::add_arguments_to_environment(param, value, &local_env, context);
-                }
-
-                context.vm.push_frame(CallFrame {
+                let frame = CallFrame {
                     prev: None,
                     code,
                     this: this.clone(),
@@ -462,13 +547,37 @@ impl JsObject {
                     fp: context.vm.stack.len(),
                     exit_on_return,
                     environment: local_env,
-                });
+                    promise_capability: promise_capability.clone(),
+                    handlers: Vec::new(),
+                    resumed_with: CompletionType::Normal,
+                    yielded: false,
+                };
+
+                // The body of a generator function only starts running when the `next` method
+                // of the generator is called.
+                // <https://tc39.es/ecma262/#sec-generator-function-definitions-runtime-semantics-evaluatebody>
+                if frame.code.is_generator {
+                    context.pop_environment();
+                    let generator_context = GeneratorContext::Frame(Box::new(frame), Vec::new());
+                    return Ok(Generator::create(self, generator_context, context)?.into());
+                }
+
+                context.vm.push_frame(frame);
 
                 let result = context.run();
 
                 context.pop_environment();
 
-                result
+                match (result, promise_capability) {
+                    // An exception thrown by an async function rejects the promise it returns.
+                    (Err(error), Some(capability)) => {
+                        capability
+                            .reject
+                            .call(&JsValue::undefined(), &[error], context)?;
+                        Ok(capability.promise.clone().into())
+                    }
+                    (result, _) => result,
+                }
             }
         }
     }
@@ -479,7 +588,9 @@ impl JsObject {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        self.call_internal(this, args, context, true)
+        context.with_call_frame(self, |context| {
+            self.call_internal(this, args, context, true)
+        })
     }
 
     pub(crate) fn construct_internal(
@@ -501,8 +612,9 @@ impl JsObject {
             let function = object.as_function().unwrap();
 
             match function {
-                Function::Native { function, .. } => FunctionBody::Native {
+                Function::Native { function, data, .. } => FunctionBody::Native {
                     function: *function,
+                    data: data.clone(),
                 },
                 Function::Closure {
                     function, captures, ..
@@ -519,10 +631,13 @@ impl JsObject {
         };
 
         match body {
-            FunctionBody::Native { function, .. } => function(this_target, args, context),
-            FunctionBody::Closure { function, captures } => {
-                (function)(this_target, args, captures, context)
+            FunctionBody::Native { function, data } => {
+                context.with_function_data(data, |context| function(this_target, args, context))
             }
+            FunctionBody::Closure { function, captures } => context
+                .with_function_data(None, |context| {
+                    (function)(this_target, args, captures, context)
+                }),
             FunctionBody::Ordinary { code, environment } => {
                 let this: JsValue = {
                     // If the prototype of the constructor is not an object, then use the default object
@@ -560,20 +675,7 @@ impl JsObject {
                 // Push the environment first so that it will be used by default parameters
                 context.push_environment(local_env.clone());
 
-                // Add argument bindings to the function environment
-                for (i, param) in code.params.iter().enumerate() {
-                    // Rest Parameters
-                    if param.is_rest_param() {
-                        todo!("Rest parameter");
-                    }
-
-                    let value = match args.get(i).cloned() {
-                        None => JsValue::undefined(),
-                        Some(value) => value,
-                    };
-
-                    Function::add_arguments_to_environment(param, value, &local_env, context);
-                }
+                code.bind_parameters(args, &local_env, context);
 
                 context.vm.push_frame(CallFrame {
                     prev: None,
@@ -583,6 +685,10 @@ impl JsObject {
                     fp: context.vm.stack.len(),
                     exit_on_return,
                     environment: local_env,
+                    promise_capability: None,
+                    handlers: Vec::new(),
+                    resumed_with: CompletionType::Normal,
+                    yielded: false,
                 });
 
                 let _result = context.run();
@@ -600,6 +706,8 @@ impl JsObject {
         this_target: &JsValue,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        self.construct_internal(args, this_target, context, true)
+        context.with_call_frame(self, |context| {
+            self.construct_internal(args, this_target, context, true)
+        })
     }
 }
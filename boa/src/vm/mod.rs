@@ -3,16 +3,32 @@
 //! plus an interpreter to execute those instructions
 
 use crate::{
-    builtins::Array, environment::lexical_environment::VariableScope, property::PropertyDescriptor,
-    vm::code_block::Readable, BoaProfiler, Context, JsResult, JsValue,
+    builtins::{
+        eval::Eval,
+        function::Function,
+        iterable::{create_iter_result_object, IteratorRecord},
+        Array, JsArgs, Number,
+    },
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        lexical_environment::{Environment, VariableScope},
+    },
+    exec::TailCall,
+    object::JsObject,
+    property::PropertyDescriptor,
+    value::Numeric,
+    vm::code_block::Readable,
+    BoaProfiler, Context, JsBigInt, JsResult, JsValue,
 };
 use std::{convert::TryInto, mem::size_of, time::Instant};
 
+mod async_function;
 mod call_frame;
 mod code_block;
 mod opcode;
 
 pub use call_frame::CallFrame;
+pub(crate) use call_frame::{CompletionType, TryHandler};
 pub use code_block::{CodeBlock, JsVmFunction};
 pub use opcode::Opcode;
 
@@ -233,11 +249,21 @@ impl Context {
                 self.vm.push(value);
             }
             Opcode::Inc => {
-                let value = self.vm.pop().add(&JsValue::Integer(1), self)?;
+                let value = match self.vm.pop().to_numeric(self)? {
+                    Numeric::Number(number) => JsValue::new(number + 1.0),
+                    Numeric::BigInt(bigint) => {
+                        JsValue::new(JsBigInt::add(&bigint, &JsBigInt::from(1)))
+                    }
+                };
                 self.vm.push(value);
             }
             Opcode::Dec => {
-                let value = self.vm.pop().sub(&JsValue::Integer(1), self)?;
+                let value = match self.vm.pop().to_numeric(self)? {
+                    Numeric::Number(number) => JsValue::new(number - 1.0),
+                    Numeric::BigInt(bigint) => {
+                        JsValue::new(JsBigInt::sub(&bigint, &JsBigInt::from(1)))
+                    }
+                };
                 self.vm.push(value);
             }
             Opcode::LogicalNot => {
@@ -245,13 +271,9 @@ impl Context {
                 self.vm.push(!value.to_boolean());
             }
             Opcode::BitNot => {
-                let target = self.vm.pop();
-                let num = target.to_number(self)?;
-                let value = if num.is_nan() {
-                    -1
-                } else {
-                    // TODO: this is not spec compliant.
-                    !(num as i32)
+                let value = match self.vm.pop().to_numeric(self)? {
+                    Numeric::Number(number) => JsValue::new(Number::not(number)),
+                    Numeric::BigInt(bigint) => JsValue::new(JsBigInt::not(&bigint)),
                 };
                 self.vm.push(value);
             }
@@ -321,7 +343,7 @@ impl Context {
                 let lhs = self.vm.pop();
                 if !lhs.to_boolean() {
                     self.vm.frame_mut().pc = exit as usize;
-                    self.vm.push(false);
+                    self.vm.push(lhs);
                 }
             }
             Opcode::LogicalOr => {
@@ -329,7 +351,7 @@ impl Context {
                 let lhs = self.vm.pop();
                 if lhs.to_boolean() {
                     self.vm.frame_mut().pc = exit as usize;
-                    self.vm.push(true);
+                    self.vm.push(lhs);
                 }
             }
             Opcode::Coalesce => {
@@ -513,6 +535,10 @@ impl Context {
                 let value = self.vm.pop();
                 return Err(value);
             }
+            Opcode::ThrowSyntaxError => {
+                let message = self.vm.pop().to_string(self)?;
+                return Err(self.construct_syntax_error(message.as_str()));
+            }
             Opcode::This => {
                 let this = self.get_this_binding()?;
                 self.vm.push(this);
@@ -557,16 +583,238 @@ impl Context {
                     _ => return Err(self.construct_type_error("not a callable function")),
                 };
 
-                let result = object.call_internal(&this, &args, self, false)?;
+                let result = object.call(&this, &args, self)?;
+
+                self.vm.push(result);
+            }
+            Opcode::CallEval => {
+                if self.vm.stack_size_limit <= self.vm.stack.len() {
+                    return Err(self.construct_range_error("Maximum call stack size exceeded"));
+                }
+                let argc = self.vm.read::<u32>();
+                let func = self.vm.pop();
+                let this = self.vm.pop();
+                let mut args = Vec::with_capacity(argc as usize);
+                for _ in 0..argc {
+                    args.push(self.vm.pop());
+                }
+
+                let object = match func {
+                    JsValue::Object(ref object) if object.is_callable() => object.clone(),
+                    _ => return Err(self.construct_type_error("not a callable function")),
+                };
+
+                // A direct eval runs in the environment of the frame, which is the current one.
+                let result = if JsObject::equals(&object, &self.intrinsics().eval()) {
+                    let strict = self.vm.frame().code.strict;
+                    Eval::perform_eval(args.get_or_undefined(0), strict, true, self)?
+                } else {
+                    object.call(&this, &args, self)?
+                };
 
                 self.vm.push(result);
             }
+            Opcode::TailCall => {
+                if self.vm.stack_size_limit <= self.vm.stack.len() {
+                    return Err(self.construct_range_error("Maximum call stack size exceeded"));
+                }
+                let argc = self.vm.read::<u32>();
+                let func = self.vm.pop();
+                let this = self.vm.pop();
+                let mut args = Vec::with_capacity(argc as usize);
+                for _ in 0..argc {
+                    args.push(self.vm.pop());
+                }
+
+                let tail_call = TailCall::new(func, this, args, self)?;
+                let object = match tail_call.function {
+                    JsValue::Object(ref object) if object.is_callable() => object.clone(),
+                    _ => return Err(self.construct_type_error("not a callable function")),
+                };
+
+                // Only the code of an ordinary function can run in the frame of the caller.
+                let callee = match object.borrow().as_function() {
+                    Some(Function::VmOrdinary { code, environment })
+                        if !code.is_async && !code.is_generator =>
+                    {
+                        Some((code.clone(), environment.clone()))
+                    }
+                    _ => None,
+                };
+
+                if let Some((code, environment)) = callee {
+                    let local_env =
+                        object.new_call_environment(&code, environment, &tail_call.this, self)?;
+                    self.pop_environment();
+                    self.push_environment(local_env.clone());
+                    code.bind_parameters(&tail_call.args, &local_env, self);
+                    self.replace_call_frame(object);
+
+                    let fp = self.vm.frame().fp;
+                    self.vm.stack.truncate(fp);
+                    let frame = self.vm.frame_mut();
+                    frame.code = code;
+                    frame.this = tail_call.this;
+                    frame.pc = 0;
+                    frame.environment = local_env;
+                } else {
+                    let result = object.call(&tail_call.this, &tail_call.args, self)?;
+                    self.vm.push(result);
+                }
+            }
             Opcode::Return => {
-                let exit = self.vm.frame().exit_on_return;
+                let value = self.vm.pop();
+                return Ok(self.return_completion(value));
+            }
+            Opcode::Await => {
+                let value = self.vm.pop();
+                let promise = self
+                    .vm
+                    .frame()
+                    .promise_capability
+                    .as_ref()
+                    .expect("await must be in an async function")
+                    .promise
+                    .clone();
+
+                self.await_value(value)?;
+
+                // The suspended function returns its promise to the caller.
+                self.vm.push(promise);
+                return Ok(true);
+            }
+            Opcode::TryStart => {
+                let catch = self.vm.read::<u32>();
+                let finally = self.vm.read::<u32>();
+
+                let frame = self.vm.frame();
+                let handler = TryHandler {
+                    catch: (catch != 0).then(|| catch),
+                    finally: (finally != 0).then(|| finally),
+                    sp: self.vm.stack.len() - frame.fp,
+                    environment: frame.environment.clone(),
+                };
+                self.vm.frame_mut().handlers.push(handler);
+            }
+            Opcode::TryEnd => {
+                self.vm.frame_mut().handlers.pop();
+            }
+            Opcode::FinallyEnd => {
+                let is_throw = self.vm.pop().to_boolean();
+                let value = self.vm.pop();
+                if is_throw {
+                    return Err(value);
+                }
+                return Ok(self.return_completion(value));
+            }
+            Opcode::PushDeclarativeEnvironment => {
+                let outer = self.vm.frame().environment.clone();
+                let environment = DeclarativeEnvironmentRecord::new(Some(outer));
+                self.set_frame_environment(environment.into());
+            }
+            Opcode::PopEnvironment => {
+                let outer = self
+                    .vm
+                    .frame()
+                    .environment
+                    .get_outer_environment()
+                    .expect("the environment of a block must have an outer environment");
+                self.set_frame_environment(outer);
+            }
+            Opcode::Yield => {
+                let value = self.vm.pop();
+
+                // GeneratorYield ( CreateIterResultObject(value, false) )
+                let result = create_iter_result_object(value, false, self);
+                self.vm.push(result);
+                self.vm.frame_mut().yielded = true;
+                return Ok(true);
+            }
+            Opcode::GeneratorNext => {
+                let resumed_with = self.vm.frame().resumed_with;
+                self.vm.frame_mut().resumed_with = CompletionType::Normal;
+
+                match resumed_with {
+                    CompletionType::Normal => {}
+                    CompletionType::Throw => return Err(self.vm.pop()),
+                    CompletionType::Return => {
+                        let value = self.vm.pop();
+                        return Ok(self.return_completion(value));
+                    }
+                }
+            }
+            Opcode::InitIterator => {
+                let iterable = self.vm.pop();
+                let iterator = iterable.get_iterator(self, None, None)?;
+                self.vm.push(iterator.iterator_object().clone());
+                self.vm.push(iterator.next_function().clone());
+            }
+            Opcode::GeneratorNextDelegate => {
+                let done_address = self.vm.read::<u32>();
+                let received = self.vm.pop();
+                let next_method = self.vm.pop();
+                let iterator = self.vm.pop();
 
-                let _ = self.vm.pop_frame();
+                let resumed_with = self.vm.frame().resumed_with;
+                self.vm.frame_mut().resumed_with = CompletionType::Normal;
 
-                if exit {
+                // <https://tc39.es/ecma262/#sec-generator-function-definitions-runtime-semantics-evaluation>
+                let inner_result = match resumed_with {
+                    // a. If received.[[Type]] is normal, then
+                    // i. Let innerResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]], « received.[[Value]] »).
+                    CompletionType::Normal => self.call(&next_method, &iterator, &[received])?,
+                    // b. Else if received.[[Type]] is throw, then
+                    CompletionType::Throw => {
+                        // i. Let throw be ? GetMethod(iterator, "throw").
+                        // ii. If throw is not undefined, then
+                        if let Some(throw) = iterator.get_method("throw", self)? {
+                            // 1. Let innerResult be ? Call(throw, iterator, « received.[[Value]] »).
+                            throw.call(&iterator, &[received], self)?
+                        } else {
+                            // iii. Else,
+                            // 1. NOTE: If iterator does not have a throw method, this throw is going to terminate the yield* loop. But first we need to give iterator a chance to clean up.
+                            // 4. Perform ? IteratorClose(iteratorRecord, closeCompletion).
+                            IteratorRecord::new(iterator, next_method)
+                                .close(Ok(JsValue::undefined()), self)?;
+                            // 6. Throw a TypeError exception.
+                            return Err(self.construct_type_error(
+                                "the iterator delegated to by yield* has no throw method",
+                            ));
+                        }
+                    }
+                    // c. Else,
+                    CompletionType::Return => {
+                        // ii. Let return be ? GetMethod(iterator, "return").
+                        // iii. If return is undefined, then
+                        // 1. Return Completion(received).
+                        // iv. Let innerReturnResult be ? Call(return, iterator, « received.[[Value]] »).
+                        match iterator.get_method("return", self)? {
+                            Some(r#return) => r#return.call(&iterator, &[received], self)?,
+                            None => return Ok(self.return_completion(received)),
+                        }
+                    }
+                };
+
+                // If Type(innerResult) is not Object, throw a TypeError exception.
+                if !inner_result.is_object() {
+                    return Err(self.construct_type_error("iterator result is not an object"));
+                }
+
+                // Let done be ? IteratorComplete(innerResult).
+                if inner_result.get_field("done", self)?.to_boolean() {
+                    // Return ? IteratorValue(innerResult).
+                    let value = inner_result.get_field("value", self)?;
+                    if resumed_with == CompletionType::Return {
+                        return Ok(self.return_completion(value));
+                    }
+                    self.vm.push(value);
+                    self.vm.frame_mut().pc = done_address as usize;
+                } else {
+                    // Set received to GeneratorYield(innerResult).
+                    self.vm.push(iterator);
+                    self.vm.push(next_method);
+                    self.vm.push(inner_result);
+                    self.vm.frame_mut().yielded = true;
                     return Ok(true);
                 }
             }
@@ -575,16 +823,98 @@ impl Context {
         Ok(false)
     }
 
+    /// Returns `value` from the current frame, after running the `finally` blocks it is in.
+    ///
+    /// Returns whether the VM loop should exit.
+    fn return_completion(&mut self, value: JsValue) -> bool {
+        while let Some(handler) = self.vm.frame_mut().handlers.pop() {
+            if let Some(finally) = handler.finally {
+                self.restore_try_state(&handler);
+                self.vm.push(value);
+                self.vm.push(false);
+                self.vm.frame_mut().pc = finally as usize;
+                return false;
+            }
+        }
+
+        let exit = self.vm.frame().exit_on_return;
+        let frame = self.vm.pop_frame().expect("return must be in a call frame");
+        self.vm.stack.truncate(frame.fp);
+
+        // An async function fulfills its promise with the returned value, and returns the
+        // promise instead.
+        if let Some(capability) = &frame.promise_capability {
+            capability
+                .resolve
+                .call(&JsValue::undefined(), &[value], self)
+                .expect("the resolve function of a promise capability never throws");
+            self.vm.push(capability.promise.clone());
+        } else {
+            self.vm.push(value);
+        }
+
+        exit
+    }
+
+    /// Jumps to the `catch` or `finally` block of the innermost `try` statement the current
+    /// frame is in, or gives the error back if there is none.
+    fn handle_exception(&mut self, error: JsValue) -> Result<(), JsValue> {
+        let handler = match self.vm.frame_mut().handlers.pop() {
+            Some(handler) => handler,
+            None => return Err(error),
+        };
+        self.restore_try_state(&handler);
+
+        if let Some(catch) = handler.catch {
+            // The `finally` block still runs if the `catch` block throws or returns.
+            if handler.finally.is_some() {
+                let mut handler = handler.clone();
+                handler.catch = None;
+                self.vm.frame_mut().handlers.push(handler);
+            }
+            self.vm.push(error);
+            self.vm.frame_mut().pc = catch as usize;
+        } else {
+            let finally = handler
+                .finally
+                .expect("a try statement has a catch or a finally block");
+            self.vm.push(error);
+            self.vm.push(true);
+            self.vm.frame_mut().pc = finally as usize;
+        }
+
+        Ok(())
+    }
+
+    /// Restores the stack and the environment of the current frame to where they were when a
+    /// `try` statement started.
+    fn restore_try_state(&mut self, handler: &TryHandler) {
+        let fp = self.vm.frame().fp;
+        self.vm.stack.truncate(fp + handler.sp);
+        self.set_frame_environment(handler.environment.clone());
+    }
+
+    /// Sets the environment of the current frame.
+    ///
+    /// A frame always has a single environment on the environment stack, which is replaced
+    /// when a block scope is entered or left, so that nothing needs to be popped when a frame
+    /// is suspended or left by an exception.
+    fn set_frame_environment(&mut self, environment: Environment) {
+        self.pop_environment();
+        self.push_environment(environment.clone());
+        self.vm.frame_mut().environment = environment;
+    }
+
     /// Unwind the stack.
     fn unwind(&mut self) -> bool {
         let mut fp = 0;
         while let Some(mut frame) = self.vm.frame.take() {
             fp = frame.fp;
+            self.vm.frame = frame.prev.take();
+
             if frame.exit_on_return {
                 break;
             }
-
-            self.vm.frame = frame.prev.take();
         }
         while self.vm.stack.len() > fp {
             let _ = self.vm.pop();
@@ -625,9 +955,10 @@ impl Context {
             );
         }
 
-        self.vm.frame_mut().pc = 0;
         while self.vm.frame().pc < self.vm.frame().code.code.len() {
-            let result = if self.vm.trace {
+            let result = if let Err(error) = self.check_deadline() {
+                Err(error)
+            } else if self.vm.trace {
                 let mut pc = self.vm.frame().pc;
                 let opcode: Opcode = self.vm.frame().code.read::<u8>(pc).try_into().unwrap();
                 let operands = self.vm.frame().code.instruction_operands(&mut pc);
@@ -671,11 +1002,13 @@ impl Context {
                     }
                 }
                 Err(e) => {
-                    let should_exit = self.unwind();
-                    if should_exit {
-                        return Err(e);
-                    } else {
-                        self.vm.push(e);
+                    if let Err(e) = self.handle_exception(e) {
+                        let should_exit = self.unwind();
+                        if should_exit {
+                            return Err(e);
+                        } else {
+                            self.vm.push(e);
+                        }
                     }
                 }
             }
@@ -545,6 +545,13 @@ pub enum Opcode {
     /// Stack: `exc` **=>**
     Throw,
 
+    /// Throw a `SyntaxError` with the message on top of the stack, for code the vm can't run yet.
+    ///
+    /// Operands:
+    ///
+    /// Stack: `message` **=>**
+    ThrowSyntaxError,
+
     /// Pops value converts it to boolean and pushes it back.
     ///
     /// Operands:
@@ -588,9 +595,119 @@ pub enum Opcode {
     /// Stack: `func`, `this`, `arg1`, `arg2`,...`argn` **=>**
     Call,
 
+    /// Call a function through the plain `eval` name.
+    ///
+    /// If the function is the original `eval`, this is a direct eval, which runs the code in
+    /// the scope of the caller. Otherwise it is an ordinary call.
+    ///
+    /// Operands: argc: `u32`
+    ///
+    /// Stack: `func`, `this`, `arg1`, `arg2`,...`argn` **=>**
+    CallEval,
+
+    /// Call a function in tail position of strict mode code.
+    ///
+    /// A call of an ordinary function replaces the frame of the caller, so a chain of tail calls
+    /// runs in constant space. Any other function is called as usual.
+    ///
+    /// Operands: argc: `u32`
+    ///
+    /// Stack: `func`, `this`, `arg1`, `arg2`,...`argn` **=>**
+    TailCall,
+
     /// Return from a function.
     Return,
 
+    /// Suspend the running async function until the awaited value settles.
+    ///
+    /// The function is resumed with the settled value on top of the stack, and `GeneratorNext`
+    /// throws it if the promise was rejected.
+    ///
+    /// Operands:
+    ///
+    /// Stack: `value` **=>** `result`
+    Await,
+
+    /// Start a `try` statement, whose `catch` or `finally` block runs if an exception is thrown.
+    ///
+    /// The `finally` block also runs if the function returns. An address of `0` means there is
+    /// no such block.
+    ///
+    /// Operands: catch_address: `u32`, finally_address: `u32`
+    ///
+    /// Stack: **=>**
+    TryStart,
+
+    /// End the innermost `try` block, or the `catch` block of a `try` statement with a
+    /// `finally` block.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    TryEnd,
+
+    /// End a `finally` block that was entered because of an exception or a return, and throw or
+    /// return again.
+    ///
+    /// The exception or the return value is below a boolean that is `true` for an exception.
+    ///
+    /// Operands:
+    ///
+    /// Stack: `value`, `is_throw` **=>**
+    FinallyEnd,
+
+    /// Push a new declarative environment, for the bindings of a block.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    PushDeclarativeEnvironment,
+
+    /// Pop the environment of a block.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    PopEnvironment,
+
+    /// Suspend the running generator, and make the `next` method return an iterator result
+    /// for the value.
+    ///
+    /// The generator is resumed with the value passed to its `next`, `throw` or `return`
+    /// method on top of the stack.
+    ///
+    /// Operands:
+    ///
+    /// Stack: `value` **=>** `received`
+    Yield,
+
+    /// Continue a resumed generator the way it was resumed: leave the received value for a
+    /// call to `next`, throw it for `throw`, or return it for `return`.
+    ///
+    /// Operands:
+    ///
+    /// Stack: `received` **=>** `received`
+    GeneratorNext,
+
+    /// Get the iterator of an iterable, with its `next` method.
+    ///
+    /// Operands:
+    ///
+    /// Stack: `iterable` **=>** `iterator`, `next_method`
+    InitIterator,
+
+    /// Forward the value received by a generator to the iterator it delegates to with `yield*`.
+    ///
+    /// If the iterator isn't done, the generator yields its result, and is resumed on the next
+    /// instruction. Otherwise, the iterator is popped and the result value is pushed before
+    /// jumping to `address`.
+    ///
+    /// Operands: address: `u32`
+    ///
+    /// Stack: `iterator`, `next_method`, `received` **=>** `iterator`, `next_method`, `received`
+    /// (if the iterator isn't done), or `value` (if it is done)
+    GeneratorNextDelegate,
+
     /// No-operation instruction, does nothing.
     ///
     /// Operands:
@@ -685,13 +802,26 @@ impl Opcode {
             Opcode::JumpIfFalse => "JumpIfFalse",
             Opcode::JumpIfTrue => "JumpIfTrue",
             Opcode::Throw => "Throw",
+            Opcode::ThrowSyntaxError => "ThrowSyntaxError",
             Opcode::ToBoolean => "ToBoolean",
             Opcode::This => "This",
             Opcode::Case => "Case",
             Opcode::Default => "Default",
             Opcode::GetFunction => "GetFunction",
             Opcode::Call => "Call",
+            Opcode::CallEval => "CallEval",
+            Opcode::TailCall => "TailCall",
             Opcode::Return => "Return",
+            Opcode::Await => "Await",
+            Opcode::TryStart => "TryStart",
+            Opcode::TryEnd => "TryEnd",
+            Opcode::FinallyEnd => "FinallyEnd",
+            Opcode::PushDeclarativeEnvironment => "PushDeclarativeEnvironment",
+            Opcode::PopEnvironment => "PopEnvironment",
+            Opcode::Yield => "Yield",
+            Opcode::GeneratorNext => "GeneratorNext",
+            Opcode::InitIterator => "InitIterator",
+            Opcode::GeneratorNextDelegate => "GeneratorNextDelegate",
             Opcode::Nop => "Nop",
         }
     }
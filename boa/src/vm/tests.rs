@@ -1,4 +1,4 @@
-use crate::exec;
+use crate::{exec, Context};
 
 #[test]
 fn typeof_string() {
@@ -27,3 +27,291 @@ fn basic_op() {
     "#;
     assert_eq!(&exec(basic_op), "3");
 }
+
+/// Evaluates `src` with the VM, keeping the state of `context`, and returns the displayed result.
+fn eval(context: &mut Context, src: &str) -> String {
+    match context.eval(src) {
+        Ok(value) => value.display().to_string(),
+        Err(error) => format!("Uncaught {}", error.display()),
+    }
+}
+
+#[test]
+fn async_function_awaits_values() {
+    let mut context = Context::new();
+    let src = r#"
+        let log = [];
+        async function add(a, b) {
+            log.push("start");
+            let x = await a;
+            log.push("x " + x);
+            return x + await b;
+        }
+        let double = async n => 2 * await n;
+        add(1, Promise.resolve(2)).then(v => log.push("sum " + v));
+        double(add(3, 4)).then(v => log.push("double " + v));
+        log.push("sync");
+    "#;
+    eval(&mut context, src);
+    assert_eq!(eval(&mut context, "log.join()"), "\"start,start,sync\"");
+
+    context.run_jobs().unwrap();
+    assert_eq!(
+        eval(&mut context, "log.join()"),
+        "\"start,start,sync,x 1,x 3,sum 3,double 14\""
+    );
+}
+
+#[test]
+fn async_function_awaits_thenables() {
+    let mut context = Context::new();
+    let src = r#"
+        let log = [];
+        let thenable = { then(resolve) { log.push("then"); resolve("adopted"); } };
+        let f = async function () {
+            let value = await thenable;
+            log.push(value);
+            await Promise.reject("rejected");
+            log.push("unreachable");
+        };
+        f().catch(e => log.push("caught " + e));
+        log.push("sync");
+    "#;
+    eval(&mut context, src);
+    context.run_jobs().unwrap();
+    assert_eq!(
+        eval(&mut context, "log.join()"),
+        "\"sync,then,adopted,caught rejected\""
+    );
+}
+
+#[test]
+fn async_function_returns_promise() {
+    let mut context = Context::new();
+    let src = r#"
+        async function f() { throw "thrown"; }
+        let reason = null;
+        let promise = f();
+        promise.catch(e => { reason = e; });
+    "#;
+    eval(&mut context, src);
+    assert_eq!(eval(&mut context, "promise instanceof Promise"), "true");
+    assert_eq!(eval(&mut context, "f.prototype"), "undefined");
+    context.run_jobs().unwrap();
+    assert_eq!(eval(&mut context, "reason"), "\"thrown\"");
+}
+
+#[test]
+fn try_catch_finally() {
+    let mut context = Context::new();
+    let src = r#"
+        let log = "";
+        function f() {
+            try { throw "x"; } catch (e) { return "caught " + e; } finally { log = log + "finally;"; }
+        }
+        let result = f();
+        try {
+            try { null.x; } finally { log = log + "inner;"; }
+        } catch (e) {
+            log = log + e.name + ";";
+        }
+        let e = "outer";
+        try { throw "y"; } catch (e) { log = log + e + ";"; }
+        log + result + ";" + e
+    "#;
+    assert_eq!(
+        eval(&mut context, src),
+        "\"finally;inner;TypeError;y;caught x;outer\""
+    );
+}
+
+#[test]
+fn generator_delegates_to_iterables() {
+    let mut context = Context::new();
+    let src = r#"
+        function* inner() {
+            let received = yield 1;
+            yield received;
+            return "inner done";
+        }
+        function* outer() {
+            let result = yield* inner();
+            yield result;
+            yield* ["a", "b"];
+        }
+        let gen = outer();
+        let values = [];
+        let step = gen.next();
+        while (!step.done) {
+            values.push(step.value);
+            step = gen.next("sent");
+        }
+        values.join()
+    "#;
+    assert_eq!(eval(&mut context, src), "\"1,sent,inner done,a,b\"");
+    assert_eq!(
+        eval(&mut context, "Object.prototype.toString.call(gen)"),
+        "\"[object Generator]\""
+    );
+}
+
+#[test]
+fn generator_return_runs_finally() {
+    let mut context = Context::new();
+    let src = r#"
+        let log = [];
+        function* gen() {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                log.push("finally");
+            }
+        }
+        let it = gen();
+        it.next();
+        let result = it.return("early");
+    "#;
+    eval(&mut context, src);
+    assert_eq!(eval(&mut context, "result.value"), "\"early\"");
+    assert_eq!(eval(&mut context, "result.done"), "true");
+    assert_eq!(eval(&mut context, "log.join()"), "\"finally\"");
+    assert_eq!(eval(&mut context, "it.next().done"), "true");
+}
+
+#[test]
+fn async_function_catches_rejection() {
+    let mut context = Context::new();
+    let src = r#"
+        let log = [];
+        async function f() {
+            try {
+                await Promise.reject("rejected");
+            } catch (e) {
+                log.push("caught " + e);
+            }
+            return "done";
+        }
+        f().then(v => log.push(v));
+    "#;
+    eval(&mut context, src);
+    context.run_jobs().unwrap();
+    assert_eq!(eval(&mut context, "log.join()"), "\"caught rejected,done\"");
+}
+
+#[test]
+fn bigint_update_and_bitwise_not() {
+    let mut context = Context::new();
+    let src = r#"
+        let x = 1n;
+        x++;
+        ++x;
+        let y = x--;
+        [x, y, ~x].join()
+    "#;
+    assert_eq!(eval(&mut context, src), "\"2,3,-3\"");
+}
+
+#[test]
+fn catch_parameter_patterns() {
+    let src = r#"
+        var r = [];
+        try { throw Error("boom"); } catch ({ message }) { r.push(message); }
+        try { throw { a: 1, b: { c: 2 } }; } catch ({ a: x, b: { c }, d = 4 }) { r.push(x, c, d); }
+        try { try { throw null; } catch ({ m }) {} } catch (e) { r.push(e.name); }
+        try { try { throw [1]; } catch ([a]) {} } catch (e) { r.push(e.name); }
+        r.join();
+    "#;
+    assert_eq!(&exec(src), "\"boom,1,2,4,TypeError,SyntaxError\"");
+}
+
+#[test]
+fn capture_stack_trace_sees_vm_calls() {
+    let src = r#"
+        function inner() {
+            const target = { message: "oops" };
+            Error.captureStackTrace(target);
+            return target;
+        }
+        function outer() {
+            return inner();
+        }
+        outer().stack;
+    "#;
+    assert_eq!(&exec(src), "\"Error: oops\n    at inner\n    at outer\"");
+}
+
+#[test]
+fn direct_eval_extends_the_caller_scope() {
+    let src = r#"
+        function f() {
+            eval("var x = 5");
+            return x;
+        }
+        f();
+    "#;
+    assert_eq!(&exec(src), "5");
+
+    let src = r#"
+        function f() {
+            "use strict";
+            eval("var x = 5");
+            try { return x; } catch (e) { return e.name; }
+        }
+        function indirect() {
+            (0, eval)("var y = 6");
+            return typeof y;
+        }
+        var r = [f(), indirect()];
+        r.push("x" in globalThis, y);
+        r.join();
+    "#;
+    assert_eq!(&exec(src), "\"ReferenceError,number,false,6\"");
+}
+
+#[test]
+fn strict_tail_calls_reuse_the_frame() {
+    let src = r#"
+        "use strict";
+        function down(n) {
+            if (n === 0) {
+                return "done";
+            }
+            return n > 0 && down(n - 1);
+        }
+        down(1e5);
+    "#;
+    assert_eq!(&exec(src), "\"done\"");
+
+    // Logical operators evaluate to one of their operands.
+    assert_eq!(
+        &exec("[0 && 1, 2 && 3, 0 || 4, 5 || 6].join()"),
+        "\"0,3,4,5\""
+    );
+}
+
+#[test]
+fn strict_directive_in_generator_and_async_bodies() {
+    let mut context = Context::new();
+    let src = r#"
+        let log = [];
+        function* gen() {
+            "use strict";
+            eval("var x = 1");
+            try { yield x; } catch (e) { yield e.name; }
+        }
+        async function f() {
+            "use strict";
+            eval("var y = 2");
+            try { return y; } catch (e) { return e.name; }
+        }
+        log.push(gen().next().value);
+        f().then(v => log.push(v));
+    "#;
+    eval(&mut context, src);
+    context.run_jobs().unwrap();
+    assert_eq!(
+        eval(&mut context, "log.join()"),
+        "\"ReferenceError,ReferenceError\""
+    );
+}
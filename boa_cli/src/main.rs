@@ -164,6 +164,9 @@ pub fn main() -> Result<(), std::io::Error> {
                 Ok(v) => println!("{}", v.display()),
                 Err(v) => eprintln!("Uncaught {}", v.display()),
             }
+            while let Err(v) = context.run_jobs() {
+                eprintln!("Uncaught {}", v.display());
+            }
         }
     }
 
@@ -202,6 +205,9 @@ pub fn main() -> Result<(), std::io::Error> {
                                 eprintln!("{}: {}", "Uncaught".red(), v.display().to_string().red())
                             }
                         }
+                        while let Err(v) = context.run_jobs() {
+                            eprintln!("{}: {}", "Uncaught".red(), v.display().to_string().red())
+                        }
                     }
                 }
 